@@ -0,0 +1,71 @@
+//! Council election RPC extension.
+
+use std::sync::Arc;
+
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use node_runtime::opaque::Block;
+use node_runtime::runtime_api::{
+    CouncilElectionApi as CouncilElectionRuntimeApi, ElectionResultOf,
+};
+use runtime_primitives::traits::{Block as BlockT, ProvideRuntimeApi};
+use substrate_client::blockchain::HeaderBackend;
+
+/// Council election RPC methods.
+#[rpc]
+pub trait CouncilElectionApi<BlockHash> {
+    /// Returns the result of the election round if it is still kept in the history.
+    #[rpc(name = "councilElection_electionResult")]
+    fn election_result(
+        &self,
+        round: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Option<ElectionResultOf>>;
+
+    /// Returns the results of the most recent election rounds, oldest first.
+    #[rpc(name = "councilElection_electionHistory")]
+    fn election_history(&self, at: Option<BlockHash>) -> Result<Vec<(u32, ElectionResultOf)>>;
+}
+
+/// Council election RPC methods implementation.
+pub struct CouncilElection<C> {
+    client: Arc<C>,
+}
+
+impl<C> CouncilElection<C> {
+    /// Creates a new instance of the council election RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        CouncilElection { client }
+    }
+}
+
+impl<C> CouncilElectionApi<<Block as BlockT>::Hash> for CouncilElection<C>
+where
+    C: ProvideRuntimeApi + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: CouncilElectionRuntimeApi<Block>,
+{
+    fn election_result(
+        &self,
+        round: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<ElectionResultOf>> {
+        let at = crate::block_id(&*self.client, at);
+
+        self.client
+            .runtime_api()
+            .election_result(&at, round)
+            .map_err(|err| crate::runtime_error("Unable to query election result.", err))
+    }
+
+    fn election_history(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<(u32, ElectionResultOf)>> {
+        let at = crate::block_id(&*self.client, at);
+
+        self.client
+            .runtime_api()
+            .election_history(&at)
+            .map_err(|err| crate::runtime_error("Unable to query election history.", err))
+    }
+}
@@ -0,0 +1,97 @@
+//! Data directory RPC extension.
+
+use std::sync::Arc;
+
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use node_runtime::opaque::Block;
+use node_runtime::runtime_api::{
+    DataDirectoryApi as DataDirectoryRuntimeApi, MemberId, MemberStorageUsageInfo,
+    StorageRelationshipInfo,
+};
+use node_runtime::ContentId;
+use runtime_primitives::traits::{Block as BlockT, ProvideRuntimeApi};
+use substrate_client::blockchain::HeaderBackend;
+
+/// Data directory RPC methods.
+#[rpc]
+pub trait DataDirectoryApi<BlockHash> {
+    /// Returns storage provider relationships of the data object.
+    #[rpc(name = "dataDirectory_storageRelationships")]
+    fn storage_relationships(
+        &self,
+        content_id: ContentId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<StorageRelationshipInfo>>;
+
+    /// Returns the member storage quotas and current usage.
+    #[rpc(name = "dataDirectory_memberStorageUsage")]
+    fn member_storage_usage(
+        &self,
+        member_id: MemberId,
+        at: Option<BlockHash>,
+    ) -> Result<MemberStorageUsageInfo>;
+
+    /// Returns content ids with less ready storage relationships than their replication factor
+    /// requires.
+    #[rpc(name = "dataDirectory_underReplicatedContentIds")]
+    fn under_replicated_content_ids(&self, at: Option<BlockHash>) -> Result<Vec<ContentId>>;
+}
+
+/// Data directory RPC methods implementation.
+pub struct DataDirectory<C> {
+    client: Arc<C>,
+}
+
+impl<C> DataDirectory<C> {
+    /// Creates a new instance of the data directory RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        DataDirectory { client }
+    }
+}
+
+impl<C> DataDirectoryApi<<Block as BlockT>::Hash> for DataDirectory<C>
+where
+    C: ProvideRuntimeApi + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DataDirectoryRuntimeApi<Block>,
+{
+    fn storage_relationships(
+        &self,
+        content_id: ContentId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<StorageRelationshipInfo>> {
+        let at = crate::block_id(&*self.client, at);
+
+        self.client
+            .runtime_api()
+            .storage_relationships(&at, content_id)
+            .map_err(|err| crate::runtime_error("Unable to query storage relationships.", err))
+    }
+
+    fn member_storage_usage(
+        &self,
+        member_id: MemberId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<MemberStorageUsageInfo> {
+        let at = crate::block_id(&*self.client, at);
+
+        self.client
+            .runtime_api()
+            .member_storage_usage(&at, member_id)
+            .map_err(|err| crate::runtime_error("Unable to query member storage usage.", err))
+    }
+
+    fn under_replicated_content_ids(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<ContentId>> {
+        let at = crate::block_id(&*self.client, at);
+
+        self.client
+            .runtime_api()
+            .under_replicated_content_ids(&at)
+            .map_err(|err| {
+                crate::runtime_error("Unable to query under-replicated content ids.", err)
+            })
+    }
+}
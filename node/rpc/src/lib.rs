@@ -0,0 +1,90 @@
+//! Joystream node JSON-RPC extensions. Provides typed queries for the Joystream runtime modules
+//! using the runtime APIs declared in the `node_runtime::runtime_api`.
+
+#![warn(missing_docs)]
+
+use std::sync::Arc;
+
+use jsonrpc_core::{Error, ErrorCode};
+use node_runtime::opaque::Block;
+use node_runtime::runtime_api::{
+    CouncilElectionApi as CouncilElectionRuntimeApi, DataDirectoryApi as DataDirectoryRuntimeApi,
+    MembershipApi as MembershipRuntimeApi, ProposalsApi as ProposalsRuntimeApi,
+    ProposalsEngineApi as ProposalsEngineRuntimeApi,
+    ServiceDiscoveryApi as ServiceDiscoveryRuntimeApi,
+    VersionedStoreApi as VersionedStoreRuntimeApi, WorkingGroupApi as WorkingGroupRuntimeApi,
+};
+use runtime_primitives::generic::BlockId;
+use runtime_primitives::traits::{Block as BlockT, ProvideRuntimeApi};
+use substrate_client::blockchain::HeaderBackend;
+
+pub mod council_election;
+pub mod data_directory;
+pub mod membership;
+pub mod proposals;
+pub mod service_discovery;
+pub mod versioned_store;
+pub mod working_group;
+
+/// Instantiates all Joystream RPC extensions.
+pub fn create<C, M>(client: Arc<C>) -> jsonrpc_core::IoHandler<M>
+where
+    C: ProvideRuntimeApi + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ProposalsRuntimeApi<Block>
+        + ProposalsEngineRuntimeApi<Block>
+        + MembershipRuntimeApi<Block>
+        + WorkingGroupRuntimeApi<Block>
+        + DataDirectoryRuntimeApi<Block>
+        + ServiceDiscoveryRuntimeApi<Block>
+        + VersionedStoreRuntimeApi<Block>
+        + CouncilElectionRuntimeApi<Block>,
+    M: jsonrpc_core::Metadata + Default,
+{
+    use council_election::{CouncilElection, CouncilElectionApi};
+    use data_directory::{DataDirectory, DataDirectoryApi};
+    use membership::{Membership, MembershipApi};
+    use proposals::{Proposals, ProposalsApi};
+    use service_discovery::{ServiceDiscovery, ServiceDiscoveryApi};
+    use versioned_store::{VersionedStore, VersionedStoreApi};
+    use working_group::{WorkingGroup, WorkingGroupApi};
+
+    let mut io = jsonrpc_core::IoHandler::default();
+    io.extend_with(ProposalsApi::to_delegate(Proposals::new(client.clone())));
+    io.extend_with(MembershipApi::to_delegate(Membership::new(client.clone())));
+    io.extend_with(WorkingGroupApi::to_delegate(WorkingGroup::new(
+        client.clone(),
+    )));
+    io.extend_with(DataDirectoryApi::to_delegate(DataDirectory::new(
+        client.clone(),
+    )));
+    io.extend_with(ServiceDiscoveryApi::to_delegate(ServiceDiscovery::new(
+        client.clone(),
+    )));
+    io.extend_with(VersionedStoreApi::to_delegate(VersionedStore::new(
+        client.clone(),
+    )));
+    io.extend_with(CouncilElectionApi::to_delegate(CouncilElection::new(
+        client,
+    )));
+    io
+}
+
+// Error code for the failed runtime API calls.
+const RUNTIME_ERROR: i64 = 1;
+
+// Creates the RPC error for the failed runtime API call.
+fn runtime_error<E: std::fmt::Debug>(message: &str, error: E) -> Error {
+    Error {
+        code: ErrorCode::ServerError(RUNTIME_ERROR),
+        message: message.into(),
+        data: Some(format!("{:?}", error).into()),
+    }
+}
+
+// Returns the block id for the provided block hash. Falls back to the best block.
+fn block_id<C: HeaderBackend<Block>>(
+    client: &C,
+    at: Option<<Block as BlockT>::Hash>,
+) -> BlockId<Block> {
+    BlockId::hash(at.unwrap_or_else(|| client.info().best_hash))
+}
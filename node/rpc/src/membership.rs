@@ -0,0 +1,55 @@
+//! Membership RPC extension.
+
+use std::sync::Arc;
+
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use node_runtime::opaque::Block;
+use node_runtime::runtime_api::{
+    MemberId, MemberProfileInfo, MembershipApi as MembershipRuntimeApi,
+};
+use runtime_primitives::traits::{Block as BlockT, ProvideRuntimeApi};
+use substrate_client::blockchain::HeaderBackend;
+
+/// Membership RPC methods.
+#[rpc]
+pub trait MembershipApi<BlockHash> {
+    /// Returns the member profile with its roles.
+    #[rpc(name = "members_memberProfile")]
+    fn member_profile(
+        &self,
+        member_id: MemberId,
+        at: Option<BlockHash>,
+    ) -> Result<Option<MemberProfileInfo>>;
+}
+
+/// Membership RPC methods implementation.
+pub struct Membership<C> {
+    client: Arc<C>,
+}
+
+impl<C> Membership<C> {
+    /// Creates a new instance of the membership RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Membership { client }
+    }
+}
+
+impl<C> MembershipApi<<Block as BlockT>::Hash> for Membership<C>
+where
+    C: ProvideRuntimeApi + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: MembershipRuntimeApi<Block>,
+{
+    fn member_profile(
+        &self,
+        member_id: MemberId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<MemberProfileInfo>> {
+        let at = crate::block_id(&*self.client, at);
+
+        self.client
+            .runtime_api()
+            .member_profile(&at, member_id)
+            .map_err(|err| crate::runtime_error("Unable to query member profile.", err))
+    }
+}
@@ -0,0 +1,85 @@
+//! Proposals RPC extension.
+
+use std::sync::Arc;
+
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use node_runtime::opaque::Block;
+use node_runtime::runtime_api::{
+    ProposalId, ProposalOf, ProposalStatusFilter, ProposalVotingOutcomeOf,
+    ProposalsApi as ProposalsRuntimeApi, ProposalsEngineApi as ProposalsEngineRuntimeApi,
+    VotingResults,
+};
+use runtime_primitives::traits::{Block as BlockT, ProvideRuntimeApi};
+use substrate_client::blockchain::HeaderBackend;
+
+/// Proposals RPC methods.
+#[rpc]
+pub trait ProposalsApi<BlockHash> {
+    /// Returns proposals with the provided status and ids in the `[from_id, from_id + limit)`
+    /// range. The limit is capped by the runtime.
+    #[rpc(name = "proposals_proposalsByStatus")]
+    fn proposals_by_status(
+        &self,
+        status: ProposalStatusFilter,
+        from_id: ProposalId,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<(ProposalId, ProposalOf)>>;
+
+    /// Simulates the voting for the active proposal with the provided additional votes.
+    #[rpc(name = "proposals_votingOutcome")]
+    fn voting_outcome(
+        &self,
+        proposal_id: ProposalId,
+        additional_votes: VotingResults,
+        at: Option<BlockHash>,
+    ) -> Result<Option<ProposalVotingOutcomeOf>>;
+}
+
+/// Proposals RPC methods implementation.
+pub struct Proposals<C> {
+    client: Arc<C>,
+}
+
+impl<C> Proposals<C> {
+    /// Creates a new instance of the proposals RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Proposals { client }
+    }
+}
+
+impl<C> ProposalsApi<<Block as BlockT>::Hash> for Proposals<C>
+where
+    C: ProvideRuntimeApi + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ProposalsRuntimeApi<Block> + ProposalsEngineRuntimeApi<Block>,
+{
+    fn proposals_by_status(
+        &self,
+        status: ProposalStatusFilter,
+        from_id: ProposalId,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<(ProposalId, ProposalOf)>> {
+        let at = crate::block_id(&*self.client, at);
+
+        self.client
+            .runtime_api()
+            .proposals_by_status(&at, status, from_id, limit)
+            .map_err(|err| crate::runtime_error("Unable to query proposals.", err))
+    }
+
+    fn voting_outcome(
+        &self,
+        proposal_id: ProposalId,
+        additional_votes: VotingResults,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<ProposalVotingOutcomeOf>> {
+        let at = crate::block_id(&*self.client, at);
+
+        self.client
+            .runtime_api()
+            .voting_outcome(&at, proposal_id, additional_votes)
+            .map_err(|err| crate::runtime_error("Unable to simulate proposal voting.", err))
+    }
+}
@@ -0,0 +1,54 @@
+//! Service discovery RPC extension.
+
+use std::sync::Arc;
+
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use node_runtime::opaque::Block;
+use node_runtime::runtime_api::{
+    EndpointRecordOf, ServiceDiscoveryApi as ServiceDiscoveryRuntimeApi,
+};
+use node_runtime::ActorId;
+use runtime_primitives::traits::{Block as BlockT, ProvideRuntimeApi};
+use substrate_client::blockchain::HeaderBackend;
+
+/// Service discovery RPC methods.
+#[rpc]
+pub trait ServiceDiscoveryApi<BlockHash> {
+    /// Returns non-expired service endpoints records of the active storage providers.
+    #[rpc(name = "discovery_liveStorageProviders")]
+    fn live_storage_providers(
+        &self,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<(ActorId, EndpointRecordOf)>>;
+}
+
+/// Service discovery RPC methods implementation.
+pub struct ServiceDiscovery<C> {
+    client: Arc<C>,
+}
+
+impl<C> ServiceDiscovery<C> {
+    /// Creates a new instance of the service discovery RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        ServiceDiscovery { client }
+    }
+}
+
+impl<C> ServiceDiscoveryApi<<Block as BlockT>::Hash> for ServiceDiscovery<C>
+where
+    C: ProvideRuntimeApi + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ServiceDiscoveryRuntimeApi<Block>,
+{
+    fn live_storage_providers(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<(ActorId, EndpointRecordOf)>> {
+        let at = crate::block_id(&*self.client, at);
+
+        self.client
+            .runtime_api()
+            .live_storage_providers(&at)
+            .map_err(|err| crate::runtime_error("Unable to query live storage providers.", err))
+    }
+}
@@ -0,0 +1,59 @@
+//! Versioned store RPC extension.
+
+use std::sync::Arc;
+
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use node_runtime::opaque::Block;
+use node_runtime::runtime_api::{
+    ClassId, EntityId, PropertyValue, VersionedStoreApi as VersionedStoreRuntimeApi,
+};
+use runtime_primitives::traits::{Block as BlockT, ProvideRuntimeApi};
+use substrate_client::blockchain::HeaderBackend;
+
+/// Versioned store RPC methods.
+#[rpc]
+pub trait VersionedStoreApi<BlockHash> {
+    /// Returns the entity of the class with the provided value of the unique property.
+    #[rpc(name = "versionedStore_entityIdByUniqueValue")]
+    fn entity_id_by_unique_value(
+        &self,
+        class_id: ClassId,
+        in_class_index: u16,
+        value: PropertyValue,
+        at: Option<BlockHash>,
+    ) -> Result<Option<EntityId>>;
+}
+
+/// Versioned store RPC methods implementation.
+pub struct VersionedStore<C> {
+    client: Arc<C>,
+}
+
+impl<C> VersionedStore<C> {
+    /// Creates a new instance of the versioned store RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        VersionedStore { client }
+    }
+}
+
+impl<C> VersionedStoreApi<<Block as BlockT>::Hash> for VersionedStore<C>
+where
+    C: ProvideRuntimeApi + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: VersionedStoreRuntimeApi<Block>,
+{
+    fn entity_id_by_unique_value(
+        &self,
+        class_id: ClassId,
+        in_class_index: u16,
+        value: PropertyValue,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<EntityId>> {
+        let at = crate::block_id(&*self.client, at);
+
+        self.client
+            .runtime_api()
+            .entity_id_by_unique_value(&at, class_id, in_class_index, value)
+            .map_err(|err| crate::runtime_error("Unable to query entity by unique value.", err))
+    }
+}
@@ -0,0 +1,78 @@
+//! Working groups RPC extension.
+
+use std::sync::Arc;
+
+use common::working_group::WorkingGroup as WorkingGroupType;
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use node_runtime::opaque::Block;
+use node_runtime::runtime_api::{
+    OpeningId, OpeningOf, WorkerOf, WorkingGroupApi as WorkingGroupRuntimeApi,
+};
+use node_runtime::ActorId;
+use runtime_primitives::traits::{Block as BlockT, ProvideRuntimeApi};
+use substrate_client::blockchain::HeaderBackend;
+
+/// Working groups RPC methods.
+#[rpc]
+pub trait WorkingGroupApi<BlockHash> {
+    /// Returns active workers (including the leader) of the working group.
+    #[rpc(name = "workingGroup_activeWorkers")]
+    fn active_workers(
+        &self,
+        group: WorkingGroupType,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<(ActorId, WorkerOf)>>;
+
+    /// Returns openings of the working group which are not deactivated yet.
+    #[rpc(name = "workingGroup_activeOpenings")]
+    fn active_openings(
+        &self,
+        group: WorkingGroupType,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<(OpeningId, OpeningOf)>>;
+}
+
+/// Working groups RPC methods implementation.
+pub struct WorkingGroup<C> {
+    client: Arc<C>,
+}
+
+impl<C> WorkingGroup<C> {
+    /// Creates a new instance of the working groups RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        WorkingGroup { client }
+    }
+}
+
+impl<C> WorkingGroupApi<<Block as BlockT>::Hash> for WorkingGroup<C>
+where
+    C: ProvideRuntimeApi + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: WorkingGroupRuntimeApi<Block>,
+{
+    fn active_workers(
+        &self,
+        group: WorkingGroupType,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<(ActorId, WorkerOf)>> {
+        let at = crate::block_id(&*self.client, at);
+
+        self.client
+            .runtime_api()
+            .active_workers(&at, group)
+            .map_err(|err| crate::runtime_error("Unable to query workers.", err))
+    }
+
+    fn active_openings(
+        &self,
+        group: WorkingGroupType,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<(OpeningId, OpeningOf)>> {
+        let at = crate::block_id(&*self.client, at);
+
+        self.client
+            .runtime_api()
+            .active_openings(&at, group)
+            .map_err(|err| crate::runtime_error("Unable to query openings.", err))
+    }
+}
@@ -20,13 +20,13 @@
 #![allow(clippy::identity_op)]
 
 use node_runtime::{
-    versioned_store::InputValidationLengthConstraint as VsInputValidation,
+    default_member_quota, versioned_store::InputValidationLengthConstraint as VsInputValidation,
     AuthorityDiscoveryConfig, BabeConfig, Balance, BalancesConfig, ContentWorkingGroupConfig,
-    CouncilConfig, CouncilElectionConfig, DataObjectStorageRegistryConfig,
-    DataObjectTypeRegistryConfig, ElectionParameters, GrandpaConfig, ImOnlineConfig, IndicesConfig,
-    MembersConfig, MigrationConfig, Perbill, ProposalsCodexConfig, SessionConfig, SessionKeys,
-    Signature, StakerStatus, StakingConfig, StorageWorkingGroupConfig, SudoConfig, SystemConfig,
-    VersionedStoreConfig, DAYS, WASM_BINARY,
+    CouncilConfig, CouncilElectionConfig, DataDirectoryConfig, DataObjectStorageRegistryConfig,
+    DataObjectTypeRegistryConfig, ElectionParameters, ForumWorkingGroupConfig, GrandpaConfig,
+    ImOnlineConfig, IndicesConfig, MembersConfig, MigrationConfig, Perbill, ProposalsCodexConfig,
+    SessionConfig, SessionKeys, Signature, StakerStatus, StakingConfig, StorageWorkingGroupConfig,
+    SudoConfig, SystemConfig, TallyMode, VersionedStoreConfig, DAYS, WASM_BINARY,
 };
 pub use node_runtime::{AccountId, GenesisConfig};
 use primitives::{sr25519, Pair, Public};
@@ -254,21 +254,30 @@ pub fn testnet_genesis(
                 min_council_stake: 10 * DOLLARS,
                 new_term_duration: 14 * DAYS,
                 min_voting_stake: 1 * DOLLARS,
+                tally_mode: TallyMode::SingleChoice,
             },
         }),
         members: Some(MembersConfig {
             default_paid_membership_fee: 100u128,
             members: vec![],
         }),
-        forum: Some(crate::forum_config::from_serialized::create(
-            endowed_accounts[0].clone(),
-        )),
+        forum: Some(crate::forum_config::from_serialized::create()),
         data_object_type_registry: Some(DataObjectTypeRegistryConfig {
             first_data_object_type_id: 1,
         }),
+        data_directory: Some(DataDirectoryConfig {
+            default_quota: default_member_quota(),
+        }),
         data_object_storage_registry: Some(DataObjectStorageRegistryConfig {
             first_relationship_id: 1,
         }),
+        working_group_Instance1: Some(ForumWorkingGroupConfig {
+            phantom: Default::default(),
+            storage_working_group_mint_capacity: 0,
+            opening_human_readable_text_constraint: default_text_constraint,
+            worker_application_human_readable_text_constraint: default_text_constraint,
+            worker_exit_rationale_text_constraint: default_text_constraint,
+        }),
         working_group_Instance2: Some(StorageWorkingGroupConfig {
             phantom: Default::default(),
             storage_working_group_mint_capacity: 0,
@@ -364,6 +373,14 @@ pub fn testnet_genesis(
                 .terminate_working_group_leader_role_proposal_voting_period,
             terminate_working_group_leader_role_proposal_grace_period: cpcp
                 .terminate_working_group_leader_role_proposal_grace_period,
+            recall_council_member_proposal_voting_period: cpcp
+                .recall_council_member_proposal_voting_period,
+            recall_council_member_proposal_grace_period: cpcp
+                .recall_council_member_proposal_grace_period,
+            slash_council_member_proposal_voting_period: cpcp
+                .slash_council_member_proposal_voting_period,
+            slash_council_member_proposal_grace_period: cpcp
+                .slash_council_member_proposal_grace_period,
         }),
     }
 }
@@ -3,7 +3,7 @@
 use super::new_validation;
 use node_runtime::{
     forum::{Category, CategoryId, Post, Thread},
-    AccountId, BlockNumber, ForumConfig, Moment, PostId, ThreadId,
+    AccountId, BlockNumber, ForumConfig, MemberId, Moment, PostId, ThreadId,
 };
 use serde::Deserialize;
 use serde_json::Result;
@@ -13,9 +13,9 @@ struct ForumData {
     categories: Vec<(CategoryId, Category<BlockNumber, Moment, AccountId>)>,
     posts: Vec<(
         PostId,
-        Post<BlockNumber, Moment, AccountId, ThreadId, PostId>,
+        Post<BlockNumber, Moment, MemberId, ThreadId, PostId>,
     )>,
-    threads: Vec<(ThreadId, Thread<BlockNumber, Moment, AccountId, ThreadId>)>,
+    threads: Vec<(ThreadId, Thread<BlockNumber, Moment, MemberId, ThreadId>)>,
 }
 
 fn parse_forum_json() -> Result<ForumData> {
@@ -23,7 +23,7 @@ fn parse_forum_json() -> Result<ForumData> {
     serde_json::from_str(data)
 }
 
-pub fn create(forum_sudo: AccountId) -> ForumConfig {
+pub fn create() -> ForumConfig {
     let forum_data = parse_forum_json().expect("failed loading forum data");
 
     let next_category_id: CategoryId = forum_data
@@ -46,6 +46,6 @@ pub fn create(forum_sudo: AccountId) -> ForumConfig {
         post_text_constraint: new_validation(10, 990),
         thread_moderation_rationale_constraint: new_validation(10, 290),
         post_moderation_rationale_constraint: new_validation(10, 290),
-        forum_sudo,
+        poll_alternative_text_constraint: new_validation(1, 99),
     }
 }
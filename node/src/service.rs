@@ -61,7 +61,7 @@ native_executor_instance!(
 #[macro_export]
 macro_rules! new_full_start {
     ($config:expr) => {{
-        // type RpcExtension = jsonrpc_core::IoHandler<substrate_rpc::Metadata>;
+        type RpcExtension = jsonrpc_core::IoHandler<substrate_rpc::Metadata>;
         let mut import_setup = None;
         let inherent_data_providers = inherents::InherentDataProviders::new();
 
@@ -110,11 +110,10 @@ macro_rules! new_full_start {
 
             import_setup = Some((block_import, grandpa_link, babe_link));
             Ok(import_queue)
+        })?
+        .with_rpc_extensions(|client, _pool| -> RpcExtension {
+            joystream_rpc::create(client)
         })?;
-        // We don't have any custom rpc commands...
-        // .with_rpc_extensions(|client, pool| -> RpcExtension {
-        // 	node_rpc::create(client, pool)
-        // })?;
 
         (builder, import_setup, inherent_data_providers)
     }};
@@ -294,7 +293,7 @@ pub fn new_full<C: Send + Default + 'static>(config: NodeConfiguration<C>)
 pub fn new_light<C: Send + Default + 'static>(
     config: NodeConfiguration<C>,
 ) -> Result<impl AbstractService, ServiceError> {
-    // type RpcExtension = jsonrpc_core::IoHandler<substrate_rpc::Metadata>;
+    type RpcExtension = jsonrpc_core::IoHandler<substrate_rpc::Metadata>;
     let inherent_data_providers = InherentDataProviders::new();
 
     let service = ServiceBuilder::new_light::<Block, RuntimeApi, Executor>(config)?
@@ -347,10 +346,9 @@ pub fn new_light<C: Send + Default + 'static>(
         .with_finality_proof_provider(|client, backend| {
             Ok(Arc::new(GrandpaFinalityProofProvider::new(backend, client)) as _)
         })?
-        // We don't have any custom rpc extensions
-        // .with_rpc_extensions(|client, pool| -> RpcExtension {
-        // 	node_rpc::create(client, pool)
-        // })?
+        .with_rpc_extensions(|client, _pool| -> RpcExtension {
+            joystream_rpc::create(client)
+        })?
         .build()?;
 
     Ok(service)
@@ -8,7 +8,7 @@ import BN from 'bn.js';
 
 import { Option, bool } from '@polkadot/types';
 import { ThreadId } from '@joystream/types/common';
-import { CategoryId, Category, Thread } from '@joystream/types/forum';
+import { CategoryId, Category, Thread, VecThreadId } from '@joystream/types/forum';
 import { ViewThread } from './ViewThread';
 import { MutedSpan } from '@polkadot/joy-utils/MutedText';
 import { UrlHasIdProps, CategoryCrumbs, Pagination, ThreadsPerPage, usePagination } from './utils';
@@ -19,8 +19,9 @@ import { withMulti, withApi } from '@polkadot/react-api';
 import { ApiProps } from '@polkadot/react-api/types';
 import { bnToStr, isEmptyArr } from '@polkadot/joy-utils/index';
 import TxButton from '@polkadot/joy-utils/TxButton';
-import { IfIAmForumSudo } from './ForumSudo';
+import { IfIAmForumLead } from './ForumModerators';
 import { MemberPreview } from '@polkadot/joy-members/MemberPreview';
+import { MoveCategory } from './MoveToCategory';
 
 type CategoryActionsProps = {
   id: CategoryId;
@@ -50,17 +51,17 @@ function CategoryActions (props: CategoryActionsProps) {
 
   if (category.archived) {
     return (
-      <IfIAmForumSudo>
+      <IfIAmForumLead>
         <UpdateCategoryButton icon='file archive outline' label='Unarchive' archive={false} />
-      </IfIAmForumSudo>
+      </IfIAmForumLead>
     );
   }
 
   if (category.deleted) {
     return (
-      <IfIAmForumSudo>
+      <IfIAmForumLead>
         <UpdateCategoryButton icon='trash alternate outline' label='Undelete' delete={false} />;
-      </IfIAmForumSudo>
+      </IfIAmForumLead>
     );
   }
 
@@ -81,7 +82,7 @@ function CategoryActions (props: CategoryActionsProps) {
         <span className='text'>Edit</span>
       </Link> */}
 
-      <IfIAmForumSudo>
+      <IfIAmForumLead>
         <Dropdown floating button className='icon small' style={{ display: 'inline-block', width: 'auto', margin: 0 }} trigger={<></>}>
           <Dropdown.Menu>
             <Link className='item' role='option' to={`/forum/categories/${id.toString()}/newSubcategory`}>
@@ -92,9 +93,13 @@ function CategoryActions (props: CategoryActionsProps) {
             <UpdateCategoryButton icon='trash alternate outline' label='Delete' delete={true} />
           </Dropdown.Menu>
         </Dropdown>
-      </IfIAmForumSudo>
+      </IfIAmForumLead>
 
     </Button.Group>
+
+    <IfIAmForumLead>
+      <MoveCategory id={id} />
+    </IfIAmForumLead>
   </span>;
 }
 
@@ -169,7 +174,7 @@ function InnerViewCategory (props: InnerViewCategoryProps) {
     </Segment>
 
     {category.hasSubcategories &&
-      <Section title={`Subcategories (${category.num_direct_subcategories.toString()})`}>
+      <Section title={`Subcategories (${category.num_direct_subcategories.sub(category.num_direct_moved_subcategories).toString()})`}>
         <CategoryList parentId={id} />
       </Section>
     }
@@ -216,6 +221,7 @@ function InnerCategoryThreads (props: CategoryThreadsProps) {
   const threadCount = category.num_threads_created.toNumber();
   const [loaded, setLoaded] = useState(false);
   const [threads, setThreads] = useState(new Array<Thread>());
+  const [pinnedThreadIds, setPinnedThreadIds] = useState(new Array<ThreadId>());
 
   useEffect(() => {
     const loadThreads = async () => {
@@ -230,6 +236,8 @@ function InnerCategoryThreads (props: CategoryThreadsProps) {
       }
 
       const allThreads = await Promise.all<Thread>(apiCalls);
+      const pinnedIds = [...await api.query.forum.pinnedThreadIdsByCategory(category.id) as VecThreadId];
+      const isPinned = (thread: Thread) => pinnedIds.some(pinnedId => pinnedId.eq(thread.id));
       const threadsInThisCategory = allThreads.filter(item =>
         !item.isEmpty &&
         item.category_id.eq(category.id)
@@ -239,17 +247,18 @@ function InnerCategoryThreads (props: CategoryThreadsProps) {
         // TODO UX: Replace sort by id with sort by blocktime of the last reply.
         [
           x => x.moderated,
-          // x => x.pinned,
+          x => isPinned(x),
           x => x.nr_in_category.toNumber()
         ],
         [
           'asc',
-          // 'desc',
+          'desc',
           'desc'
         ]
       );
 
       setThreads(sortedThreads);
+      setPinnedThreadIds(pinnedIds);
       setLoaded(true);
     };
 
@@ -273,14 +282,17 @@ function InnerCategoryThreads (props: CategoryThreadsProps) {
   const pagination =
     <Pagination
       currentPage={currentPage}
-      totalItems={threadCount}
+      totalItems={threads.length}
       itemsPerPage={itemsPerPage}
       onPageChange={setCurrentPage}
     />;
 
   const pageOfItems = threads
     .filter((_thread, i) => i >= minIdx && i <= maxIdx)
-    .map((thread, i) => <ViewThread key={i} category={category} thread={thread} preview />);
+    .map((thread, i) => {
+      const pinned = pinnedThreadIds.some(pinnedId => pinnedId.eq(thread.id));
+      return <ViewThread key={i} category={category} thread={thread} pinned={pinned} preview />;
+    });
 
   return <>
     {pagination}
@@ -351,7 +363,7 @@ function InnerCategoryList (props: CategoryListProps) {
       const allCats = await Promise.all<Category>(apiCalls);
       const filteredCats = allCats.filter(cat =>
         !cat.isEmpty &&
-        !cat.deleted && // TODO show deleted categories if current user is forum sudo
+        !cat.deleted && // TODO show deleted categories if current user is forum lead
         (parentId ? parentId.eq(cat.parent_id) : cat.isRoot)
       );
 
@@ -4,15 +4,14 @@
 import React, { useReducer, createContext, useContext } from 'react';
 import { Category, Thread, Reply, ModerationAction } from '@joystream/types/forum';
 import { BlockAndTime } from '@joystream/types/common';
-import { Option, Text, GenericAccountId } from '@polkadot/types';
+import { MemberId } from '@joystream/types/members';
+import { Option, Text } from '@polkadot/types';
 
 type CategoryId = number;
 type ThreadId = number;
 type ReplyId = number;
 
 export type ForumState = {
-  sudo?: string;
-
   nextCategoryId: CategoryId;
   categoryById: Map<CategoryId, Category>;
   rootCategoryIds: CategoryId[];
@@ -28,8 +27,6 @@ export type ForumState = {
 };
 
 const initialState: ForumState = {
-  sudo: undefined,
-
   nextCategoryId: 1,
   categoryById: new Map(),
   rootCategoryIds: [],
@@ -44,11 +41,6 @@ const initialState: ForumState = {
   replyIdsByThreadId: new Map()
 };
 
-type SetForumSudo = {
-  type: 'SetForumSudo';
-  sudo?: string;
-};
-
 type NewCategoryAction = {
   type: 'NewCategory';
   category: Category;
@@ -76,7 +68,7 @@ type UpdateThreadAction = {
 type ModerateThreadAction = {
   type: 'ModerateThread';
   id: ThreadId;
-  moderator: string;
+  moderator: MemberId;
   rationale: string;
 };
 
@@ -95,12 +87,11 @@ type UpdateReplyAction = {
 type ModerateReplyAction = {
   type: 'ModerateReply';
   id: ReplyId;
-  moderator: string;
+  moderator: MemberId;
   rationale: string;
 };
 
 type ForumAction =
-  SetForumSudo |
   NewCategoryAction |
   UpdateCategoryAction |
   NewThreadAction |
@@ -112,14 +103,6 @@ type ForumAction =
 
 function reducer (state: ForumState, action: ForumAction): ForumState {
   switch (action.type) {
-    case 'SetForumSudo': {
-      const { sudo } = action;
-      return {
-        ...state,
-        sudo
-      };
-    }
-
     case 'NewCategory': {
       const { category, onCreated } = action;
       const { parent_id } = category;
@@ -222,7 +205,7 @@ function reducer (state: ForumState, action: ForumAction): ForumState {
       const thread = threadById.get(id) as Thread;
       const moderation = new ModerationAction({
         moderated_at: BlockAndTime.newEmpty(),
-        moderator_id: new GenericAccountId(moderator),
+        moderator_id: moderator,
         rationale: new Text(rationale)
       });
       const threadUpd = new Thread(Object.assign(
@@ -287,7 +270,7 @@ function reducer (state: ForumState, action: ForumAction): ForumState {
       const reply = replyById.get(id) as Reply;
       const moderation = new ModerationAction({
         moderated_at: BlockAndTime.newEmpty(),
-        moderator_id: new GenericAccountId(moderator),
+        moderator_id: moderator,
         rationale: new Text(rationale)
       });
       const replyUpd = new Reply(Object.assign(
@@ -15,7 +15,7 @@ import { CategoryId, Category } from '@joystream/types/forum';
 import Section from '@polkadot/joy-utils/Section';
 import { useMyAccount } from '@polkadot/joy-utils/MyAccountContext';
 import { UrlHasIdProps, CategoryCrumbs } from './utils';
-import { withOnlyForumSudo } from './ForumSudo';
+import { withOnlyForumLead } from './ForumModerators';
 import { withForumCalls } from './calls';
 import { ValidationProps, withCategoryValidation } from './validation';
 import { TxFailedCallback, TxCallback } from '@polkadot/react-components/Status/types';
@@ -251,19 +251,19 @@ function NewSubcategoryForm (props: UrlHasIdProps & OuterProps) {
 
 export const NewCategory = withMulti(
   EditForm,
-  withOnlyForumSudo,
+  withOnlyForumLead,
   withCategoryValidation
 );
 
 export const NewSubcategory = withMulti(
   NewSubcategoryForm,
-  withOnlyForumSudo,
+  withOnlyForumLead,
   withCategoryValidation
 );
 
 export const EditCategory = withMulti(
   FormOrLoading,
-  withOnlyForumSudo,
+  withOnlyForumLead,
   withIdFromUrl,
   withCategoryValidation,
   withForumCalls<OuterProps>(
@@ -14,7 +14,7 @@ import { PostId, ThreadId } from '@joystream/types/common';
 import { Post } from '@joystream/types/forum';
 import { withOnlyMembers } from '@polkadot/joy-utils/MyAccount';
 import Section from '@polkadot/joy-utils/Section';
-import { useMyAccount } from '@polkadot/joy-utils/MyAccountContext';
+import { useMyMembership } from '@polkadot/joy-utils/MyMembershipContext';
 import { withForumCalls } from './calls';
 import { ValidationProps, withReplyValidation } from './validation';
 import { TxFailedCallback, TxCallback } from '@polkadot/react-components/Status/types';
@@ -76,6 +76,8 @@ const InnerForm = (props: FormProps) => {
     onEditCancel
   } = props;
 
+  const { myMemberId } = useMyMembership();
+
   const {
     text
   } = values;
@@ -107,9 +109,9 @@ const InnerForm = (props: FormProps) => {
 
     const textParam = new Text(text);
     if (!id) {
-      return [threadId, textParam];
+      return [myMemberId, threadId, textParam];
     } else {
-      return [id, textParam];
+      return [myMemberId, id, textParam];
     }
   };
 
@@ -203,10 +205,10 @@ const EditForm = withFormik<OuterProps, FormValues>({
 })(InnerForm);
 
 function FormOrLoading (props: OuterProps) {
-  const { state: { address } } = useMyAccount();
+  const { myMemberId } = useMyMembership();
   const { struct } = props;
 
-  if (!address || !struct) {
+  if (!myMemberId || !struct) {
     return <em>Loading reply...</em>;
   }
 
@@ -214,7 +216,7 @@ function FormOrLoading (props: OuterProps) {
     return <em>Reply not found</em>;
   }
 
-  const isMyStruct = address === struct.author_id.toString();
+  const isMyStruct = struct.author_id.eq(myMemberId);
   if (isMyStruct) {
     return <EditForm {...props} threadId={struct.thread_id} />;
   }
@@ -11,24 +11,33 @@ import { SubmittableResult } from '@polkadot/api';
 import { withMulti } from '@polkadot/react-api/with';
 
 import * as JoyForms from '@polkadot/joy-utils/forms';
-import { Text } from '@polkadot/types';
+import { Option, Text, u32 } from '@polkadot/types';
 import { ThreadId } from '@joystream/types/common';
-import { Thread, CategoryId } from '@joystream/types/forum';
+import { Thread, CategoryId, PollParameters, VecPollAlternativeText } from '@joystream/types/forum';
 import { withOnlyMembers } from '@polkadot/joy-utils/MyAccount';
 import Section from '@polkadot/joy-utils/Section';
-import { useMyAccount } from '@polkadot/joy-utils/MyAccountContext';
+import { useMyMembership } from '@polkadot/joy-utils/MyMembershipContext';
 import { UrlHasIdProps, CategoryCrumbs } from './utils';
 import { withForumCalls } from './calls';
 import { ValidationProps, withThreadValidation } from './validation';
 import { TxFailedCallback, TxCallback } from '@polkadot/react-components/Status/types';
 
+// Should be in sync with the poll alternatives bounds of the forum runtime module.
+const MinPollAlternatives = 2;
+const MaxPollAlternatives = 10;
+
+// Poll alternatives are entered one per line.
+const parsePollAlternatives = (value?: string): string[] =>
+  (value || '').split('\n').map(line => line.trim()).filter(line => line.length > 0);
+
 const buildSchema = (props: ValidationProps) => {
   const {
     threadTitleConstraint,
-    postTextConstraint
+    postTextConstraint,
+    pollAlternativeTextConstraint
   } = props;
 
-  if (!threadTitleConstraint || !postTextConstraint) {
+  if (!threadTitleConstraint || !postTextConstraint || !pollAlternativeTextConstraint) {
     throw new Error('Missing some validation constraints');
   }
 
@@ -36,6 +45,8 @@ const buildSchema = (props: ValidationProps) => {
   const maxTitle = threadTitleConstraint.max.toNumber();
   const minText = postTextConstraint.min.toNumber();
   const maxText = postTextConstraint.max.toNumber();
+  const minAlternative = pollAlternativeTextConstraint.min.toNumber();
+  const maxAlternative = pollAlternativeTextConstraint.max.toNumber();
 
   return Yup.object().shape({
 
@@ -47,7 +58,32 @@ const buildSchema = (props: ValidationProps) => {
     text: Yup.string()
       .min(minText, `Thread text is too short. Minimum length is ${minText} chars.`)
       .max(maxText, `Thread text is too long. Maximum length is ${maxText} chars.`)
-      .required('Thread text is required')
+      .required('Thread text is required'),
+
+    pollAlternatives: Yup.string()
+      .test(
+        'pollAlternativesCount',
+        `A poll must have from ${MinPollAlternatives} to ${MaxPollAlternatives} alternatives.`,
+        (value?: string) => {
+          const count = parsePollAlternatives(value).length;
+          return count === 0 || (count >= MinPollAlternatives && count <= MaxPollAlternatives);
+        }
+      )
+      .test(
+        'pollAlternativesLength',
+        `Each poll alternative must be from ${minAlternative} to ${maxAlternative} chars long.`,
+        (value?: string) => parsePollAlternatives(value)
+          .every(alternative => alternative.length >= minAlternative && alternative.length <= maxAlternative)
+      ),
+
+    pollEndBlock: Yup.number()
+      .when('pollAlternatives', {
+        is: (value?: string) => parsePollAlternatives(value).length > 0,
+        then: Yup.number()
+          .integer('Poll end block must be an integer')
+          .positive('Poll end block must be positive')
+          .required('Poll end block is required')
+      })
   });
 };
 
@@ -62,6 +98,8 @@ type FormValues = {
   // pinned: boolean,
   title: string;
   text: string;
+  pollAlternatives: string;
+  pollEndBlock: string;
 };
 
 type FormProps = OuterProps & FormikProps<FormValues>;
@@ -84,10 +122,14 @@ const InnerForm = (props: FormProps) => {
     resetForm
   } = props;
 
+  const { myMemberId } = useMyMembership();
+
   const {
     // pinned,
     title,
-    text
+    text,
+    pollAlternatives,
+    pollEndBlock
   } = values;
 
   const onSubmit = (sendTx: () => void) => {
@@ -139,10 +181,20 @@ const InnerForm = (props: FormProps) => {
     if (!isValid) return [];
 
     if (isNew) {
+      const alternatives = parsePollAlternatives(pollAlternatives);
+      const poll = alternatives.length > 0
+        ? new PollParameters({
+          end_block: new u32(pollEndBlock),
+          alternatives: new VecPollAlternativeText(alternatives.map(alternative => new Text(alternative)))
+        })
+        : null;
+
       return [
+        myMemberId,
         resolvedCategoryId,
         new Text(title),
-        new Text(text)
+        new Text(text),
+        new Option(PollParameters, poll)
       ];
     } else {
       // NOTE: currently forum SRML doesn't support thread update.
@@ -179,6 +231,14 @@ const InnerForm = (props: FormProps) => {
         <Field component='textarea' id='text' name='text' disabled={isSubmitting} rows={5} placeholder='Type here. You can use Markdown.' />
       </LabelledField>
 
+      {isNew && <>
+        <LabelledField name='pollAlternatives' label='Poll (optional)' {...props}>
+          <Field component='textarea' id='pollAlternatives' name='pollAlternatives' disabled={isSubmitting} rows={3} placeholder='Type poll alternatives here, one per line.' />
+        </LabelledField>
+
+        <LabelledText name='pollEndBlock' label='Poll end block' placeholder={'Block at which the voting ends'} {...props} />
+      </>}
+
       <LabelledField {...props}>
         <TxButton
           type='submit'
@@ -226,7 +286,9 @@ const EditForm = withFormik<OuterProps, FormValues>({
     return {
       // pinned: struct && struct.pinned || false,
       title: '',
-      text: ''
+      text: '',
+      pollAlternatives: '',
+      pollEndBlock: ''
     };
   },
 
@@ -238,10 +300,10 @@ const EditForm = withFormik<OuterProps, FormValues>({
 })(InnerForm);
 
 function FormOrLoading (props: OuterProps) {
-  const { state: { address } } = useMyAccount();
+  const { myMemberId } = useMyMembership();
   const { struct } = props;
 
-  if (!address || !struct) {
+  if (!myMemberId || !struct) {
     return <em>Loading thread...</em>;
   }
 
@@ -249,7 +311,7 @@ function FormOrLoading (props: OuterProps) {
     return <em>Thread not found</em>;
   }
 
-  const isMyStruct = address === struct.author_id.toString();
+  const isMyStruct = struct.author_id.eq(myMemberId);
   if (isMyStruct) {
     return <EditForm {...props} />;
   }
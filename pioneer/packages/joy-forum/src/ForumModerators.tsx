@@ -0,0 +1,104 @@
+import React, { useContext, createContext } from 'react';
+
+import { useMyAccount } from '@polkadot/joy-utils/MyAccountContext';
+import { JoyError } from '@polkadot/joy-utils/JoyStatus';
+import AddressMini from '@polkadot/react-components/AddressMiniJoy';
+import { useTransport, usePromise } from '@polkadot/joy-utils/react/hooks';
+import { WorkerData } from '@polkadot/joy-utils/types/workingGroups';
+import { Worker, WorkerId } from '@joystream/types/working-group';
+
+type ForumModeratorsContextProps = {
+  loaded: boolean;
+  leadAccount?: string;
+  moderatorAccounts: string[];
+};
+
+export const ForumModeratorsContext = createContext<ForumModeratorsContextProps>({
+  loaded: false,
+  moderatorAccounts: []
+});
+
+export function ForumModeratorsProvider (props: React.PropsWithChildren<{}>) {
+  const transport = useTransport();
+  const [lead, leadError, leadLoading] = usePromise<WorkerData | null>(
+    () => transport.workingGroups.currentLead('Forum'),
+    null
+  );
+  const [workers, workersError, workersLoading] = usePromise<[WorkerId, Worker][]>(
+    () => transport.workingGroups.activeWorkers('Forum'),
+    []
+  );
+
+  const loaded = !leadLoading && !workersLoading && !leadError && !workersError;
+  const leadAccount = lead ? lead.worker.role_account_id.toString() : undefined;
+  const moderatorAccounts = workers.map(([, worker]) => worker.role_account_id.toString());
+
+  return (
+    <ForumModeratorsContext.Provider value={{ loaded, leadAccount, moderatorAccounts }}>
+      {props.children}
+    </ForumModeratorsContext.Provider>
+  );
+}
+
+export function useForumModerators () {
+  return useContext(ForumModeratorsContext);
+}
+
+function useIAmForumLead () {
+  const { leadAccount } = useForumModerators();
+  const { state: { address: myAddress } } = useMyAccount();
+  return leadAccount !== undefined && leadAccount === myAddress;
+}
+
+function useIAmForumModerator () {
+  const { moderatorAccounts } = useForumModerators();
+  const { state: { address: myAddress } } = useMyAccount();
+  return myAddress !== undefined && moderatorAccounts.includes(myAddress);
+}
+
+export const IfIAmForumLead = (props: React.PropsWithChildren<any>) => {
+  return useIAmForumLead() ? props.children : null;
+};
+
+export const IfIAmForumModerator = (props: React.PropsWithChildren<any>) => {
+  return useIAmForumModerator() ? props.children : null;
+};
+
+export function withOnlyForumLead<P extends {}> (Component: React.ComponentType<P>) {
+  return function (props: P) {
+    const { loaded, leadAccount } = useForumModerators();
+    const iAmForumLead = useIAmForumLead();
+
+    if (!loaded) {
+      return <em>Loading forum lead...</em>;
+    }
+
+    if (iAmForumLead) {
+      return <Component {...props} />;
+    } else {
+      return (
+        <JoyError title={'Only the forum working group lead can access this functionality.'}>
+          <div>Current forum lead:</div>
+          <div>{leadAccount ? <AddressMini value={leadAccount} /> : 'NONE'}</div>
+        </JoyError>
+      );
+    }
+  };
+}
+
+export function withOnlyForumModerator<P extends {}> (Component: React.ComponentType<P>) {
+  return function (props: P) {
+    const { loaded } = useForumModerators();
+    const iAmForumModerator = useIAmForumModerator();
+
+    if (!loaded) {
+      return <em>Loading forum moderators...</em>;
+    }
+
+    if (iAmForumModerator) {
+      return <Component {...props} />;
+    } else {
+      return <JoyError title={'Only the forum working group workers can moderate the forum.'} />;
+    }
+  };
+}
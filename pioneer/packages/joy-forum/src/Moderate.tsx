@@ -12,7 +12,7 @@ import { Text } from '@polkadot/types';
 import { ThreadId } from '@joystream/types/common';
 import { ReplyId } from '@joystream/types/forum';
 import Section from '@polkadot/joy-utils/Section';
-import { withOnlyForumSudo } from './ForumSudo';
+import { withOnlyForumModerator } from './ForumModerators';
 import { ValidationProps, withPostModerationValidation } from './validation';
 import { TxFailedCallback, TxCallback } from '@polkadot/react-components/Status/types';
 
@@ -159,6 +159,6 @@ const EditForm = withFormik<OuterProps, FormValues>({
 
 export const Moderate = withMulti<OuterProps>(
   EditForm,
-  withOnlyForumSudo,
+  withOnlyForumModerator,
   withPostModerationValidation
 );
@@ -0,0 +1,72 @@
+import React, { useState } from 'react';
+import { Input } from 'semantic-ui-react';
+
+import { Option } from '@polkadot/types';
+import TxButton from '@polkadot/joy-utils/TxButton';
+import { ThreadId } from '@joystream/types/common';
+import { CategoryId } from '@joystream/types/forum';
+
+type MoveThreadProps = {
+  id: ThreadId;
+  onMoved?: () => void;
+};
+
+type MoveCategoryProps = {
+  id: CategoryId;
+  onMoved?: () => void;
+};
+
+const isValidCategoryId = (value: string) => /^\d+$/.test(value);
+
+export function MoveThread (props: MoveThreadProps) {
+  const { id, onMoved } = props;
+  const [newCategoryId, setNewCategoryId] = useState('');
+
+  return <Input
+    size='small'
+    placeholder='Category ID'
+    value={newCategoryId}
+    onChange={(_e, data) => setNewCategoryId(data.value)}
+    action
+  >
+    <input />
+    <TxButton
+      type='button'
+      size='small'
+      isPrimary={false}
+      isDisabled={!isValidCategoryId(newCategoryId)}
+      label='Move'
+      params={[id, new CategoryId(newCategoryId)]}
+      tx={'forum.moveThread'}
+      txSuccessCb={onMoved}
+    />
+  </Input>;
+}
+
+export function MoveCategory (props: MoveCategoryProps) {
+  const { id, onMoved } = props;
+  const [newParentId, setNewParentId] = useState('');
+
+  // An empty parent id moves the category to the root of the forum.
+  const newParent = newParentId === '' ? null : new CategoryId(newParentId);
+
+  return <Input
+    size='small'
+    placeholder='Parent category ID (empty for root)'
+    value={newParentId}
+    onChange={(_e, data) => setNewParentId(data.value)}
+    action
+  >
+    <input />
+    <TxButton
+      type='button'
+      size='small'
+      isPrimary={false}
+      isDisabled={newParentId !== '' && !isValidCategoryId(newParentId)}
+      label='Move'
+      params={[id, new Option(CategoryId, newParent)]}
+      tx={'forum.moveCategory'}
+      txSuccessCb={onMoved}
+    />
+  </Input>;
+}
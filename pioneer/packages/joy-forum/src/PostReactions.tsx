@@ -0,0 +1,78 @@
+import React, { useState, useEffect } from 'react';
+import styled from 'styled-components';
+import { Icon, SemanticICONS } from 'semantic-ui-react';
+
+import { withApi } from '@polkadot/react-api';
+import { ApiProps } from '@polkadot/react-api/types';
+import { Option } from '@polkadot/types';
+import TxButton from '@polkadot/joy-utils/TxButton';
+import { useMyMembership } from '@polkadot/joy-utils/MyMembershipContext';
+import { Post, PostReaction, PostReactionDef, PostReactionKey } from '@joystream/types/forum';
+
+const ReactionIcons: Record<PostReactionKey, SemanticICONS> = {
+  Like: 'thumbs up outline',
+  Dislike: 'thumbs down outline',
+  Thanks: 'heart outline',
+  Laugh: 'smile outline'
+};
+
+const ReactionsRow = styled.span`
+  display: inline-flex;
+  margin-left: .5rem;
+`;
+
+function reactionCount (post: Post, reaction: PostReactionKey): number {
+  const counts = post.reaction_counts;
+  switch (reaction) {
+    case 'Like': return counts.likes.toNumber();
+    case 'Dislike': return counts.dislikes.toNumber();
+    case 'Thanks': return counts.thanks.toNumber();
+    case 'Laugh': return counts.laughs.toNumber();
+  }
+}
+
+type PostReactionsProps = ApiProps & {
+  post: Post;
+  disabled?: boolean;
+};
+
+function InnerPostReactions (props: PostReactionsProps) {
+  const { api, post, disabled = false } = props;
+  const { myMemberId } = useMyMembership();
+  const [myReaction, setMyReaction] = useState<PostReactionKey | undefined>();
+
+  useEffect(() => {
+    const loadMyReaction = async () => {
+      if (!myMemberId) return;
+
+      const reaction = await api.query.forum.postReactionByForumUser(post.id, myMemberId) as Option<PostReaction>;
+      setMyReaction(reaction.isSome ? reaction.unwrap().type as PostReactionKey : undefined);
+    };
+
+    loadMyReaction();
+  }, [post.id.toString(), myMemberId?.toString()]);
+
+  const reactionKeys = Object.keys(PostReactionDef) as PostReactionKey[];
+
+  return <ReactionsRow>
+    {reactionKeys.map(reaction => {
+      const isMine = myReaction === reaction;
+      // Reacting again with my current reaction removes it
+      const newReaction = isMine ? null : new PostReaction(reaction);
+      return <TxButton
+        key={reaction}
+        type='button'
+        size='mini'
+        isBasic={!isMine}
+        isPrimary={false}
+        isDisabled={disabled || !myMemberId}
+        label={<><Icon name={ReactionIcons[reaction]} />{reactionCount(post, reaction)}</>}
+        params={[myMemberId, post.id, new Option(PostReaction, newReaction)]}
+        tx={'forum.reactToPost'}
+        txSuccessCb={() => setMyReaction(isMine ? undefined : reaction)}
+      />;
+    })}
+  </ReactionsRow>;
+}
+
+export const PostReactions = withApi(InnerPostReactions);
@@ -0,0 +1,95 @@
+import React, { useState, useEffect } from 'react';
+import styled from 'styled-components';
+import { Progress, Segment, Icon } from 'semantic-ui-react';
+import BN from 'bn.js';
+
+import { withApi } from '@polkadot/react-api';
+import { withCalls, withMulti } from '@polkadot/react-api/with';
+import { ApiProps } from '@polkadot/react-api/types';
+import { Option, u32 } from '@polkadot/types';
+import TxButton from '@polkadot/joy-utils/TxButton';
+import { MutedSpan } from '@polkadot/joy-utils/MutedText';
+import { useMyMembership } from '@polkadot/joy-utils/MyMembershipContext';
+import { queryToProp } from '@polkadot/joy-utils/index';
+import { Thread, Poll } from '@joystream/types/forum';
+
+const PollAlternativeRow = styled.div`
+  display: flex;
+  align-items: center;
+  margin-bottom: .5rem;
+
+  .ui.progress {
+    flex-grow: 1;
+    margin: 0 1rem 0 0;
+  }
+`;
+
+type ThreadPollProps = ApiProps & {
+  thread: Thread;
+  poll: Poll;
+  votingDisabled?: boolean;
+  bestNumber?: BN;
+};
+
+function InnerThreadPoll (props: ThreadPollProps) {
+  const { api, thread, poll, votingDisabled = false, bestNumber } = props;
+  const { myMemberId } = useMyMembership();
+  const [myVote, setMyVote] = useState<number | undefined>();
+
+  useEffect(() => {
+    const loadMyVote = async () => {
+      if (!myMemberId) return;
+
+      const vote = await api.query.forum.pollVoteByForumUser(thread.id, myMemberId) as Option<u32>;
+      setMyVote(vote.isSome ? vote.unwrap().toNumber() : undefined);
+    };
+
+    loadMyVote();
+  }, [thread.id.toString(), myMemberId?.toString()]);
+
+  const ended = bestNumber !== undefined && bestNumber.gte(poll.end_block);
+  const totalVotes = poll.alternatives.reduce((total, alternative) => total + alternative.vote_count.toNumber(), 0);
+
+  return <Segment>
+    <h3><Icon name='chart bar outline' />Poll</h3>
+    {poll.alternatives.map((alternative, index) => {
+      const votes = alternative.vote_count.toNumber();
+      const isMyVote = myVote === index;
+      return <PollAlternativeRow key={index}>
+        <Progress
+          value={votes}
+          total={totalVotes || 1}
+          label={`${alternative.text} (${votes})`}
+          color={isMyVote ? 'blue' : 'grey'}
+          size='small'
+        />
+        {!ended && !votingDisabled &&
+          <TxButton
+            type='button'
+            size='mini'
+            isPrimary={false}
+            isDisabled={isMyVote || !myMemberId}
+            label={isMyVote ? 'Voted' : 'Vote'}
+            params={[myMemberId, thread.id, index]}
+            tx={'forum.voteOnPoll'}
+            txSuccessCb={() => setMyVote(index)}
+          />
+        }
+      </PollAlternativeRow>;
+    })}
+    <MutedSpan>
+      {ended
+        ? `Voting ended at block #${poll.end_block.toString()}.`
+        : `Voting ends at block #${poll.end_block.toString()}. You can change your vote until then.`
+      }
+    </MutedSpan>
+  </Segment>;
+}
+
+export const ThreadPoll = withMulti(
+  InnerThreadPoll,
+  withApi,
+  withCalls<ThreadPollProps>(
+    queryToProp('derive.chain.bestNumber')
+  )
+);
@@ -7,8 +7,9 @@ import { Button, Icon } from 'semantic-ui-react';
 import { Post, Category, Thread } from '@joystream/types/forum';
 import { Moderate } from './Moderate';
 import { JoyWarn } from '@polkadot/joy-utils/JoyStatus';
-import { useMyAccount } from '@polkadot/joy-utils/MyAccountContext';
-import { IfIAmForumSudo } from './ForumSudo';
+import { useMyMembership } from '@polkadot/joy-utils/MyMembershipContext';
+import { IfIAmForumModerator } from './ForumModerators';
+import { PostReactions } from './PostReactions';
 import { MemberPreview } from '@polkadot/joy-members/MemberPreview';
 import { TimeAgoDate, ReplyIdxQueryParam } from './utils';
 
@@ -68,7 +69,7 @@ type ViewReplyProps = {
 
 // eslint-disable-next-line react/display-name
 export const ViewReply = React.forwardRef((props: ViewReplyProps, ref: React.Ref<HTMLDivElement>) => {
-  const { state: { address: myAddress } } = useMyAccount();
+  const { myMemberId } = useMyMembership();
   const [showModerateForm, setShowModerateForm] = useState(false);
   const { pathname, search } = useLocation();
   const { reply, thread, category, selected = false, onEdit, onQuote } = props;
@@ -96,7 +97,7 @@ export const ViewReply = React.forwardRef((props: ViewReplyProps, ref: React.Ref
     if (reply.moderated || thread.moderated || category.archived || category.deleted) {
       return null;
     }
-    const isMyPost = reply.author_id.eq(myAddress);
+    const isMyPost = myMemberId !== undefined && reply.author_id.eq(myMemberId);
     return <ReplyFooterActionsRow>
       <div>
         {isMyPost &&
@@ -106,19 +107,23 @@ export const ViewReply = React.forwardRef((props: ViewReplyProps, ref: React.Ref
           </Button>
         }
 
-        <IfIAmForumSudo>
+        <IfIAmForumModerator>
           <Button
             size="mini"
             onClick={() => setShowModerateForm(!showModerateForm)}
           >
             Moderate
           </Button>
-        </IfIAmForumSudo>
+        </IfIAmForumModerator>
+
+        <PostReactions post={reply} />
       </div>
-      <Button onClick={onQuote} size="mini">
-        <Icon name="quote left" />
-        Quote
-      </Button>
+      {!thread.locked &&
+        <Button onClick={onQuote} size="mini">
+          <Icon name="quote left" />
+          Quote
+        </Button>
+      }
     </ReplyFooterActionsRow>;
   };
 
@@ -129,7 +134,7 @@ export const ViewReply = React.forwardRef((props: ViewReplyProps, ref: React.Ref
     <ReplyContainer className="ui segment" ref={ref} selected={selected}>
       <ReplyHeader>
         <ReplyHeaderAuthorRow>
-          <MemberPreview accountId={reply.author_id} />
+          <MemberPreview memberId={reply.author_id} />
         </ReplyHeaderAuthorRow>
         <ReplyHeaderDetailsRow>
           <TimeAgoDate date={reply.created_at.momentDate} id={reply.id} />
@@ -6,7 +6,7 @@ import { Table, Button, Label, Icon } from 'semantic-ui-react';
 import BN from 'bn.js';
 
 import { ThreadId, PostId } from '@joystream/types/common';
-import { Category, Thread, Post } from '@joystream/types/forum';
+import { Category, Thread, Post, VecThreadId } from '@joystream/types/forum';
 import { Pagination, RepliesPerPage, CategoryCrumbs, TimeAgoDate, usePagination, useQueryParam, ReplyIdxQueryParam, ReplyEditIdQueryParam } from './utils';
 import { ViewReply } from './ViewReply';
 import { Moderate } from './Moderate';
@@ -17,24 +17,33 @@ import { withApi, withMulti } from '@polkadot/react-api';
 import { ApiProps } from '@polkadot/react-api/types';
 import { orderBy } from 'lodash';
 import { bnToStr } from '@polkadot/joy-utils/index';
-import { IfIAmForumSudo } from './ForumSudo';
+import { IfIAmForumModerator } from './ForumModerators';
 import { MemberPreview } from '@polkadot/joy-members/MemberPreview';
 import { formatDate } from '@polkadot/joy-utils/functions/date';
 import { NewReply, EditReply } from './EditReply';
+import { ThreadPoll } from './ThreadPoll';
+import { MoveThread } from './MoveToCategory';
+import TxButton from '@polkadot/joy-utils/TxButton';
 
 type ThreadTitleProps = {
   thread: Thread;
+  pinned?: boolean;
   className?: string;
 };
 
 function ThreadTitle (props: ThreadTitleProps) {
-  const { thread, className } = props;
+  const { thread, pinned = false, className } = props;
   return <span className={className}>
-    {/* {thread.pinned && <i
+    {pinned && <i
       className='star icon'
-      title='This post is pinned by moderator'
+      title='This thread is pinned by moderator'
       style={{ marginRight: '.5rem' }}
-    />} */}
+    />}
+    {thread.locked && <i
+      className='lock icon'
+      title='This thread is locked by moderator'
+      style={{ marginRight: '.5rem' }}
+    />}
     {thread.title}
   </span>;
 }
@@ -85,11 +94,12 @@ const ReplyEditContainer = styled.div`
 
 type ThreadPreviewProps = {
   thread: Thread;
+  pinned: boolean;
   repliesCount: number;
 }
 
-const ThreadPreview: React.FC<ThreadPreviewProps> = ({ thread, repliesCount }) => {
-  const title = <ThreadTitle thread={thread} />;
+const ThreadPreview: React.FC<ThreadPreviewProps> = ({ thread, pinned, repliesCount }) => {
+  const title = <ThreadTitle thread={thread} pinned={pinned} />;
 
   return (
     <Table.Row>
@@ -110,7 +120,7 @@ const ThreadPreview: React.FC<ThreadPreviewProps> = ({ thread, repliesCount }) =
         {repliesCount}
       </Table.Cell>
       <Table.Cell>
-        <MemberPreview accountId={thread.author_id} />
+        <MemberPreview memberId={thread.author_id} />
       </Table.Cell>
       <Table.Cell>
         {formatDate(thread.created_at.momentDate)}
@@ -122,6 +132,7 @@ const ThreadPreview: React.FC<ThreadPreviewProps> = ({ thread, repliesCount }) =
 type InnerViewThreadProps = {
   category: Category;
   thread: Thread;
+  pinned?: boolean;
   preview?: boolean;
 };
 
@@ -131,6 +142,7 @@ type ViewThreadProps = ApiProps & InnerViewThreadProps & {
 
 function InnerViewThread (props: ViewThreadProps) {
   const [showModerateForm, setShowModerateForm] = useState(false);
+  const [showMoveForm, setShowMoveForm] = useState(false);
   const [displayedPosts, setDisplayedPosts] = useState<Post[]>([]);
   const [quotedPost, setQuotedPost] = useState<Post | null>(null);
 
@@ -144,7 +156,7 @@ function InnerViewThread (props: ViewThreadProps) {
   const parsedSelectedPostIdx = rawSelectedPostIdx ? parseInt(rawSelectedPostIdx) : null;
   const selectedPostIdx = (parsedSelectedPostIdx && !Number.isNaN(parsedSelectedPostIdx)) ? parsedSelectedPostIdx : null;
 
-  const { category, thread, preview = false } = props;
+  const { category, thread, pinned = false, preview = false } = props;
 
   const editedPostId = rawEditedPostId && new PostId(rawEditedPostId);
 
@@ -175,7 +187,7 @@ function InnerViewThread (props: ViewThreadProps) {
   }
 
   if (preview) {
-    return <ThreadPreview thread={thread} repliesCount={totalPostsInThread - 1} />;
+    return <ThreadPreview thread={thread} pinned={pinned} repliesCount={totalPostsInThread - 1} />;
   }
 
   const { api, nextPostId } = props;
@@ -341,10 +353,12 @@ function InnerViewThread (props: ViewThreadProps) {
       return null;
     }
     return <span className='JoyInlineActions'>
-      <Button onClick={onThreadReplyClick}>
-        <Icon name="reply" />
-        Reply
-      </Button>
+      {!thread.locked &&
+        <Button onClick={onThreadReplyClick}>
+          <Icon name="reply" />
+          Reply
+        </Button>
+      }
 
       {/* TODO show 'Edit' button only if I am owner */}
       {/* <Link
@@ -355,14 +369,36 @@ function InnerViewThread (props: ViewThreadProps) {
         Edit
       </Link> */}
 
-      <IfIAmForumSudo>
+      <IfIAmForumModerator>
+        <TxButton
+          type='button'
+          size='small'
+          isPrimary={false}
+          label={thread.locked ? 'Unlock' : 'Lock'}
+          params={[id]}
+          tx={thread.locked ? 'forum.unlockThread' : 'forum.lockThread'}
+        />
+        <TxButton
+          type='button'
+          size='small'
+          isPrimary={false}
+          label={pinned ? 'Unpin' : 'Pin'}
+          params={[id]}
+          tx={pinned ? 'forum.unpinThread' : 'forum.pinThread'}
+        />
+        <Button
+          type='button'
+          size='small'
+          content={'Move'}
+          onClick={() => setShowMoveForm(!showMoveForm)}
+        />
         <Button
           type='button'
           size='small'
           content={'Moderate'}
           onClick={() => setShowModerateForm(!showModerateForm)}
         />
-      </IfIAmForumSudo>
+      </IfIAmForumModerator>
     </span>;
   };
 
@@ -380,12 +416,12 @@ function InnerViewThread (props: ViewThreadProps) {
     <CategoryCrumbs categoryId={thread.category_id} />
     <ThreadHeader>
       <h1 className='ForumPageTitle'>
-        <ThreadTitle thread={thread} className='TitleText' />
+        <ThreadTitle thread={thread} pinned={pinned} className='TitleText' />
       </h1>
       <ThreadInfoAndActions>
         <ThreadInfo>
           Created
-          <ThreadInfoMemberPreview accountId={thread.author_id} inline prefixLabel="by" />
+          <ThreadInfoMemberPreview memberId={thread.author_id} inline prefixLabel="by" />
           <TimeAgoDate date={thread.created_at.momentDate} id="thread" />
         </ThreadInfo>
         {renderActions()}
@@ -397,9 +433,20 @@ function InnerViewThread (props: ViewThreadProps) {
         No new replies can be posted.
       </JoyWarn>
     }
+    {thread.locked && !thread.moderated &&
+      <JoyWarn title={'This thread is locked.'}>
+        No new replies can be posted.
+      </JoyWarn>
+    }
     {showModerateForm &&
       <Moderate id={id} onCloseForm={() => setShowModerateForm(false)} />
     }
+    {showMoveForm &&
+      <MoveThread id={id} onMoved={() => setShowMoveForm(false)} />
+    }
+    {thread.poll && !thread.moderated &&
+      <ThreadPoll thread={thread} poll={thread.poll} votingDisabled={category.archived || category.deleted} />
+    }
     {thread.moderated
       ? renderModerationRationale()
       : renderPageOfPosts()
@@ -409,7 +456,7 @@ function InnerViewThread (props: ViewThreadProps) {
         editedPostId ? (
           <EditReply id={editedPostId} key={editedPostId.toString()} onEditSuccess={onPostEditSuccess} onEditCancel={clearEditedPost} />
         ) : (
-          <NewReply threadId={thread.id} key={quotedPost?.id.toString()} quotedPost={quotedPost} />
+          !thread.locked && <NewReply threadId={thread.id} key={quotedPost?.id.toString()} quotedPost={quotedPost} />
         )
       }
     </ReplyEditContainer>
@@ -446,6 +493,7 @@ function InnerViewThreadById (props: ViewThreadByIdProps) {
   const [loaded, setLoaded] = useState(false);
   const [thread, setThread] = useState(Thread.newEmpty());
   const [category, setCategory] = useState(Category.newEmpty());
+  const [pinned, setPinned] = useState(false);
 
   useEffect(() => {
     const loadThreadAndCategory = async () => {
@@ -453,9 +501,11 @@ function InnerViewThreadById (props: ViewThreadByIdProps) {
 
       const thread = await api.query.forum.threadById(threadId) as Thread;
       const category = await api.query.forum.categoryById(thread.category_id) as Category;
+      const pinnedThreadIds = await api.query.forum.pinnedThreadIdsByCategory(thread.category_id) as VecThreadId;
 
       setThread(thread);
       setCategory(category);
+      setPinned(pinnedThreadIds.some(pinnedId => pinnedId.eq(threadId)));
       setLoaded(true);
     };
 
@@ -476,7 +526,7 @@ function InnerViewThreadById (props: ViewThreadByIdProps) {
     return <em>{ 'Thread\'s category was not found' }</em>;
   }
 
-  return <ViewThread id={threadId} category={category} thread={thread} />;
+  return <ViewThread id={threadId} category={category} thread={thread} pinned={pinned} />;
 }
 
 export const ViewThreadById = withApi(InnerViewThreadById);
@@ -3,8 +3,6 @@ import React from 'react';
 import { ApiProps, SubtractProps } from '@polkadot/react-api/types';
 import { Options } from '@polkadot/react-api/with/types';
 import { withApi, withCall as withSubstrateCall } from '@polkadot/react-api';
-import { Option } from '@polkadot/types/codec';
-import { AccountId } from '@polkadot/types/interfaces';
 import { u64 } from '@polkadot/types';
 import { Constructor } from '@polkadot/types/types';
 import { Category, Thread, Reply } from '@joystream/types/forum';
@@ -26,7 +24,6 @@ const getReactValue = (state: ForumState, endpoint: string, paramValue: any): an
   };
 
   switch (endpoint) {
-    case 'forumSudo': return new Option<AccountId>('AccountId', state.sudo);
     case 'categoryById': return getEntityById(endpoint, Category);
     case 'threadById': return getEntityById(endpoint, Thread);
     case 'replyById': return getEntityById(endpoint, Reply);
@@ -9,7 +9,7 @@ import './index.css';
 
 import translate from './translate';
 import { ForumProvider } from './Context';
-import { ForumSudoProvider } from './ForumSudo';
+import { ForumModeratorsProvider } from './ForumModerators';
 import { NewSubcategory, EditCategory } from './EditCategory';
 import { NewThread, EditThread } from './EditThread';
 import { CategoryList, ViewCategoryById } from './CategoryList';
@@ -28,7 +28,7 @@ class App extends React.PureComponent<Props> {
     const { basePath } = this.props;
     return (
       <ForumProvider>
-        <ForumSudoProvider>
+        <ForumModeratorsProvider>
           <ForumContentWrapper className='forum--App'>
             <Switch>
               {/* routes for handling legacy format of forum paging within the routing path */}
@@ -36,7 +36,6 @@ class App extends React.PureComponent<Props> {
               <Route path={`${basePath}/categories/:id/page/:page`} component={LegacyPagingRedirect} />
               <Route path={`${basePath}/threads/:id/page/:page`} component={LegacyPagingRedirect} />
 
-              {/* <Route path={`${basePath}/sudo`} component={EditForumSudo} /> */}
               {/* <Route path={`${basePath}/categories/new`} component={NewCategory} /> */}
 
               <Route path={`${basePath}/categories/:id/newSubcategory`} component={NewSubcategory} />
@@ -51,7 +50,7 @@ class App extends React.PureComponent<Props> {
               <Route component={ForumRoot} />
             </Switch>
           </ForumContentWrapper>
-        </ForumSudoProvider>
+        </ForumModeratorsProvider>
       </ForumProvider>
     );
   }
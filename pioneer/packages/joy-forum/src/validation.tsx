@@ -11,6 +11,7 @@ export type ValidationProps = {
   postTextConstraint?: InputValidationLengthConstraint;
   threadModerationRationaleConstraint?: InputValidationLengthConstraint;
   postModerationRationaleConstraint?: InputValidationLengthConstraint;
+  pollAlternativeTextConstraint?: InputValidationLengthConstraint;
 };
 
 const loadAllValidationConstraints = withForumCalls<ValidationProps>(
@@ -19,7 +20,8 @@ const loadAllValidationConstraints = withForumCalls<ValidationProps>(
   ['threadTitleConstraint', {}],
   ['postTextConstraint', {}],
   ['threadModerationRationaleConstraint', {}],
-  ['postModerationRationaleConstraint', {}]
+  ['postModerationRationaleConstraint', {}],
+  ['pollAlternativeTextConstraint', {}]
 );
 
 function waitForRequiredConstraints (
@@ -54,7 +56,7 @@ export const withCategoryValidation = withValidationConstraints(
   ['categoryTitleConstraint', 'categoryDescriptionConstraint']);
 
 export const withThreadValidation = withValidationConstraints(
-  ['threadTitleConstraint', 'postTextConstraint']);
+  ['threadTitleConstraint', 'postTextConstraint', 'pollAlternativeTextConstraint']);
 
 export const withReplyValidation = withValidationConstraints(
   ['postTextConstraint']);
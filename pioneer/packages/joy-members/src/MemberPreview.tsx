@@ -20,7 +20,7 @@ const AvatarSizePx = 36;
 const InlineAvatarSizePx = 24;
 
 type MemberPreviewProps = ApiProps & I18nProps & {
-  accountId: AccountId;
+  accountId?: AccountId;
   memberId?: MemberId;
   memberProfile?: Option<any>; // TODO refactor to Option<Profile>
   activeCouncil?: Seat[];
@@ -39,11 +39,12 @@ class InnerMemberPreview extends React.PureComponent<MemberPreviewProps> {
   }
 
   private renderProfile (memberProfile: Profile) {
-    const { activeCouncil = [], accountId, prefixLabel, inline, className, style } = this.props;
-    const { handle, avatar_uri } = memberProfile;
+    const { activeCouncil = [], prefixLabel, inline, className, style } = this.props;
+    const { handle, avatar_uri, root_account } = memberProfile;
+    const accountId = this.props.accountId || root_account;
 
     const hasAvatar = avatar_uri && nonEmptyStr(avatar_uri.toString());
-    const isCouncilor: boolean = accountId !== undefined && activeCouncil.find(x => accountId.eq(x.member)) !== undefined;
+    const isCouncilor: boolean = activeCouncil.find(x => accountId.eq(x.member)) !== undefined;
 
     const avatarSize = inline ? InlineAvatarSizePx : AvatarSizePx;
 
@@ -87,10 +88,14 @@ const withMemberIdByAccountId = withCalls<WithMemberIdByAccountIdProps>(
   queryMembershipToProp('memberIdsByControllerAccountId', 'accountId')
 );
 
-// Get first matching memberid controlled by an account
+// Get first matching memberid controlled by an account, unless the member id is already known
 function setMemberIdByAccountId (Component: React.ComponentType<MemberPreviewProps>) {
   return function (props: WithMemberIdByAccountIdProps & MemberPreviewProps) {
-    const { memberIdsByRootAccountId, memberIdsByControllerAccountId } = props;
+    const { memberId, memberIdsByRootAccountId, memberIdsByControllerAccountId } = props;
+
+    if (memberId !== undefined) {
+      return <Component {...props} />;
+    }
 
     if (memberIdsByRootAccountId && memberIdsByControllerAccountId) {
       memberIdsByRootAccountId.concat(memberIdsByControllerAccountId);
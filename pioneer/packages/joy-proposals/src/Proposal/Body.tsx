@@ -121,7 +121,8 @@ const paramParsers: { [x in ProposalType]: (params: any[]) => ParsedParam[]} = {
     new ParsedParam('Candidacy limit', params.candidacy_limit + ' members'),
     new ParsedParam('New term duration', params.new_term_duration + ' blocks'),
     new ParsedParam('Min. council stake', formatBalance(params.min_council_stake)),
-    new ParsedParam('Min. voting stake', formatBalance(params.min_voting_stake))
+    new ParsedParam('Min. voting stake', formatBalance(params.min_voting_stake)),
+    new ParsedParam('Tally mode', params.tally_mode)
   ],
   Spending: ([amount, account]) => [
     new ParsedParam('Amount', formatBalance(amount)),
@@ -279,7 +280,17 @@ const paramParsers: { [x in ProposalType]: (params: any[]) => ParsedParam[]} = {
       new ParsedParam('Slash stake', slash.isTrue ? 'YES' : 'NO'),
       new ParsedParam('Lead', <LeadInfoFromId group={workingGroup.type as WorkingGroupKey} leadId={leadId.toNumber()}/>, true)
     ];
-  }
+  },
+  RecallCouncilMember: ([councilor, startByElection]) => [
+    new ParsedParam('Council member', <ProposedAddress address={councilor} />, true),
+    new ParsedParam('Start by-election', startByElection ? 'YES' : 'NO')
+  ],
+  SlashCouncilMember: ([councilor, amount, slashBackers, destination]) => [
+    new ParsedParam('Council member', <ProposedAddress address={councilor} />, true),
+    new ParsedParam('Slash amount', formatBalance(amount)),
+    new ParsedParam('Slash backers', slashBackers ? 'YES' : 'NO'),
+    new ParsedParam('Slashed funds', destination === 'CouncilMint' ? 'Returned to the council mint' : 'Burned')
+  ]
 };
 
 const StyledProposalDescription = styled(Card.Description)`
@@ -0,0 +1,122 @@
+import React from 'react';
+import { Dropdown, Label, Checkbox, DropdownItemProps, DropdownProps } from 'semantic-ui-react';
+import { getFormErrorLabelsProps } from './errorHandling';
+import * as Yup from 'yup';
+import {
+  GenericProposalForm,
+  GenericFormValues,
+  genericFormDefaultOptions,
+  genericFormDefaultValues,
+  withProposalFormData,
+  ProposalFormExportProps,
+  ProposalFormContainerProps,
+  ProposalFormInnerProps
+} from './GenericProposalForm';
+import Validation from '../validationSchema';
+import { FormField } from './FormFields';
+import { withFormContainer } from './FormContainer';
+import { useTransport, usePromise } from '@polkadot/joy-utils/react/hooks';
+import { ParsedMember } from '@polkadot/joy-utils/types/members';
+import { MemberId } from '@joystream/types/members';
+import { PromiseComponent } from '@polkadot/joy-utils/react/components';
+import './forms.css';
+
+export type FormValues = GenericFormValues & {
+  councilor: string;
+  startByElection: boolean;
+};
+
+const defaultValues: FormValues = {
+  ...genericFormDefaultValues,
+  councilor: '',
+  startByElection: true
+};
+
+type FormAdditionalProps = {}; // Aditional props coming all the way from export comonent into the inner form.
+type ExportComponentProps = ProposalFormExportProps<FormAdditionalProps, FormValues>;
+type FormContainerProps = ProposalFormContainerProps<ExportComponentProps>;
+type FormInnerProps = ProposalFormInnerProps<FormContainerProps, FormValues>;
+
+type CouncilMember = ParsedMember & { memberId: MemberId };
+
+function councilMembersToOptions (members: CouncilMember[]): DropdownItemProps[] {
+  return members.map(member => ({
+    key: member.controller_account,
+    text: `${member.handle} (id:${member.memberId.toString()})`,
+    value: member.controller_account,
+    image: member.avatar_uri ? { avatar: true, src: member.avatar_uri } : null
+  }));
+}
+
+const RecallCouncilMemberForm: React.FunctionComponent<FormInnerProps> = props => {
+  const { handleChange, errors, touched, values, setFieldValue } = props;
+  const errorLabelsProps = getFormErrorLabelsProps<FormValues>(errors, touched);
+  const transport = useTransport();
+  const [councilMembers, error, loading] = usePromise<CouncilMember[]>(
+    () => transport.council.councilMembers(),
+    []
+  );
+
+  return (
+    <PromiseComponent error={error} loading={loading} message="Fetching council members...">
+      <GenericProposalForm
+        {...props}
+        txMethod="createRecallCouncilMemberProposal"
+        proposalType="RecallCouncilMember"
+        submitParams={[
+          props.myMemberId,
+          values.title,
+          values.rationale,
+          '{STAKE}',
+          values.councilor,
+          values.startByElection
+        ]}
+      >
+        <FormField
+          error={errorLabelsProps.councilor}
+          label="Council member"
+          help="The council member whose seat you propose to vacate"
+        >
+          <Dropdown
+            name="councilor"
+            placeholder="Select council member..."
+            fluid
+            selection
+            options={councilMembersToOptions(councilMembers)}
+            onChange={
+              (e: React.ChangeEvent<any>, data: DropdownProps) => {
+                // Fix TypeScript issue
+                const originalHandler = handleChange as (e: React.ChangeEvent<any>, data: DropdownProps) => void;
+                originalHandler(e, data);
+              }
+            }
+            value={values.councilor}
+          />
+          {errorLabelsProps.councilor && <Label {...errorLabelsProps.councilor} prompt />}
+        </FormField>
+        <FormField>
+          <Checkbox
+            toggle
+            onChange={(e, data) => { setFieldValue('startByElection', data.checked); }}
+            label="Start a by-election for the vacant seat"
+            checked={values.startByElection}/>
+        </FormField>
+      </GenericProposalForm>
+    </PromiseComponent>
+  );
+};
+
+const FormContainer = withFormContainer<FormContainerProps, FormValues>({
+  mapPropsToValues: (props: FormContainerProps) => ({
+    ...defaultValues,
+    ...(props.initialData || {})
+  }),
+  validationSchema: Yup.object().shape({
+    ...genericFormDefaultOptions.validationSchema,
+    ...Validation.RecallCouncilMember()
+  }),
+  handleSubmit: genericFormDefaultOptions.handleSubmit,
+  displayName: 'RecallCouncilMemberForm'
+})(RecallCouncilMemberForm);
+
+export default withProposalFormData<FormContainerProps, ExportComponentProps>(FormContainer);
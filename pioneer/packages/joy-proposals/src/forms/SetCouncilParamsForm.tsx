@@ -19,7 +19,7 @@ import { createType } from '@polkadot/types';
 import './forms.css';
 import { useTransport, usePromise } from '@polkadot/joy-utils/react/hooks';
 import _ from 'lodash';
-import { ElectionParameters } from '@joystream/types/council';
+import { ElectionParameters, TallyMode } from '@joystream/types/council';
 import { PromiseComponent } from '@polkadot/joy-utils/react/components';
 
 export type FormValues = GenericFormValues & {
@@ -50,7 +50,7 @@ type ExportComponentProps = ProposalFormExportProps<FormAdditionalProps, FormVal
 type FormContainerProps = ProposalFormContainerProps<ExportComponentProps>;
 type FormInnerProps = ProposalFormInnerProps<FormContainerProps, FormValues>;
 
-function createElectionParameters (values: FormValues): ElectionParameters {
+function createElectionParameters (values: FormValues, tallyMode: TallyMode): ElectionParameters {
   return new ElectionParameters({
     announcing_period: createType('BlockNumber', parseInt(values.announcingPeriod)),
     voting_period: createType('BlockNumber', parseInt(values.votingPeriod)),
@@ -59,7 +59,8 @@ function createElectionParameters (values: FormValues): ElectionParameters {
     candidacy_limit: createType('u32', values.candidacyLimit),
     new_term_duration: createType('BlockNumber', parseInt(values.newTermDuration)),
     min_council_stake: createType('Balance', values.minCouncilStake),
-    min_voting_stake: createType('Balance', values.minVotingStake)
+    min_voting_stake: createType('Balance', values.minVotingStake),
+    tally_mode: tallyMode
   });
 }
 
@@ -92,6 +93,9 @@ const SetCouncilParamsForm: React.FunctionComponent<FormInnerProps> = props => {
     }
   }, [councilParams]);
 
+  // The tally mode is not editable in this form, the current one is kept
+  const tallyMode = councilParams ? councilParams.tally_mode : new TallyMode();
+
   // This logic may be moved somewhere else in the future, but it's quite easy to enforce it here:
   if (!errors.candidacyLimit && !errors.councilSize && parseInt(values.candidacyLimit) < parseInt(values.councilSize)) {
     setFieldError('candidacyLimit', `Candidacy limit must be >= council size (${values.councilSize})`);
@@ -103,7 +107,7 @@ const SetCouncilParamsForm: React.FunctionComponent<FormInnerProps> = props => {
         {...props}
         txMethod="createSetElectionParametersProposal"
         proposalType="SetElectionParameters"
-        submitParams={[props.myMemberId, values.title, values.rationale, '{STAKE}', createElectionParameters(values)]}
+        submitParams={[props.myMemberId, values.title, values.rationale, '{STAKE}', createElectionParameters(values, tallyMode)]}
       >
         <Divider horizontal>Voting </Divider>
         <Form.Group widths="equal" style={{ marginBottom: '8rem' }}>
@@ -0,0 +1,157 @@
+import React from 'react';
+import { Dropdown, Label, Checkbox, DropdownItemProps, DropdownProps } from 'semantic-ui-react';
+import { getFormErrorLabelsProps } from './errorHandling';
+import * as Yup from 'yup';
+import {
+  GenericProposalForm,
+  GenericFormValues,
+  genericFormDefaultOptions,
+  genericFormDefaultValues,
+  withProposalFormData,
+  ProposalFormExportProps,
+  ProposalFormContainerProps,
+  ProposalFormInnerProps
+} from './GenericProposalForm';
+import Validation from '../validationSchema';
+import { FormField, InputFormField } from './FormFields';
+import { withFormContainer } from './FormContainer';
+import { useTransport, usePromise } from '@polkadot/joy-utils/react/hooks';
+import { ParsedMember } from '@polkadot/joy-utils/types/members';
+import { MemberId } from '@joystream/types/members';
+import { PromiseComponent } from '@polkadot/joy-utils/react/components';
+import { SlashedStakeDestinationKey } from '@joystream/types/council';
+import { formatBalance } from '@polkadot/util';
+import './forms.css';
+
+export type FormValues = GenericFormValues & {
+  councilor: string;
+  amount: string;
+  slashBackers: boolean;
+  destination: SlashedStakeDestinationKey;
+};
+
+const defaultValues: FormValues = {
+  ...genericFormDefaultValues,
+  councilor: '',
+  amount: '',
+  slashBackers: false,
+  destination: 'CouncilMint'
+};
+
+type FormAdditionalProps = {}; // Aditional props coming all the way from export comonent into the inner form.
+type ExportComponentProps = ProposalFormExportProps<FormAdditionalProps, FormValues>;
+type FormContainerProps = ProposalFormContainerProps<ExportComponentProps>;
+type FormInnerProps = ProposalFormInnerProps<FormContainerProps, FormValues>;
+
+type CouncilMember = ParsedMember & { memberId: MemberId };
+
+function councilMembersToOptions (members: CouncilMember[]): DropdownItemProps[] {
+  return members.map(member => ({
+    key: member.controller_account,
+    text: `${member.handle} (id:${member.memberId.toString()})`,
+    value: member.controller_account,
+    image: member.avatar_uri ? { avatar: true, src: member.avatar_uri } : null
+  }));
+}
+
+const destinationOptions: DropdownItemProps[] = [
+  { key: 'CouncilMint', text: 'Return to the council mint', value: 'CouncilMint' },
+  { key: 'Burn', text: 'Burn', value: 'Burn' }
+];
+
+const SlashCouncilMemberForm: React.FunctionComponent<FormInnerProps> = props => {
+  const { handleChange, errors, touched, values, setFieldValue } = props;
+  const errorLabelsProps = getFormErrorLabelsProps<FormValues>(errors, touched);
+  const transport = useTransport();
+  const [councilMembers, error, loading] = usePromise<CouncilMember[]>(
+    () => transport.council.councilMembers(),
+    []
+  );
+
+  return (
+    <PromiseComponent error={error} loading={loading} message="Fetching council members...">
+      <GenericProposalForm
+        {...props}
+        txMethod="createSlashCouncilMemberProposal"
+        proposalType="SlashCouncilMember"
+        submitParams={[
+          props.myMemberId,
+          values.title,
+          values.rationale,
+          '{STAKE}',
+          values.councilor,
+          values.amount,
+          values.slashBackers,
+          values.destination
+        ]}
+      >
+        <FormField
+          error={errorLabelsProps.councilor}
+          label="Council member"
+          help="The council member whose seat stake you propose to slash"
+        >
+          <Dropdown
+            name="councilor"
+            placeholder="Select council member..."
+            fluid
+            selection
+            options={councilMembersToOptions(councilMembers)}
+            onChange={
+              (e: React.ChangeEvent<any>, data: DropdownProps) => {
+                // Fix TypeScript issue
+                const originalHandler = handleChange as (e: React.ChangeEvent<any>, data: DropdownProps) => void;
+                originalHandler(e, data);
+              }
+            }
+            value={values.councilor}
+          />
+          {errorLabelsProps.councilor && <Label {...errorLabelsProps.councilor} prompt />}
+        </FormField>
+        <InputFormField
+          label="Amount to slash"
+          help="The amount to slash from the seat stake of the council member"
+          onChange={handleChange}
+          name="amount"
+          error={errorLabelsProps.amount}
+          value={values.amount}
+          unit={formatBalance.getDefaults().unit}
+        />
+        <FormField>
+          <Checkbox
+            toggle
+            onChange={(e, data) => { setFieldValue('slashBackers', data.checked); }}
+            label="Slash the stakes backing the seat by the same proportion"
+            checked={values.slashBackers}/>
+        </FormField>
+        <FormField
+          label="Slashed funds"
+          help="Whether the slashed funds are burned or returned to the council mint"
+        >
+          <Dropdown
+            name="destination"
+            fluid
+            selection
+            options={destinationOptions}
+            onChange={(e, data) => setFieldValue('destination', data.value)}
+            value={values.destination}
+          />
+        </FormField>
+      </GenericProposalForm>
+    </PromiseComponent>
+  );
+};
+
+const FormContainer = withFormContainer<FormContainerProps, FormValues>({
+  mapPropsToValues: (props: FormContainerProps) => ({
+    ...defaultValues,
+    ...(props.initialData || {})
+  }),
+  validationSchema: Yup.object().shape({
+    ...genericFormDefaultOptions.validationSchema,
+    ...Validation.SlashCouncilMember()
+  }),
+  handleSubmit: genericFormDefaultOptions.handleSubmit,
+  displayName: 'SlashCouncilMemberForm'
+})(SlashCouncilMemberForm);
+
+export default withProposalFormData<FormContainerProps, ExportComponentProps>(FormContainer);
@@ -15,3 +15,5 @@ export { default as DecreaseWorkingGroupLeadStakeFrom } from './DecreaseWorkingG
 export { default as SlashWorkingGroupLeadStakeForm } from './SlashWorkingGroupLeadStakeForm';
 export { default as SetWorkingGroupLeadRewardForm } from './SetWorkingGroupLeadRewardForm';
 export { default as TerminateWorkingGroupLeaderForm } from './TerminateWorkingGroupLeaderForm';
+export { default as RecallCouncilMemberForm } from './RecallCouncilMemberForm';
+export { default as SlashCouncilMemberForm } from './SlashCouncilMemberForm';
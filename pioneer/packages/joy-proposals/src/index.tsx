@@ -28,7 +28,9 @@ import {
   DecreaseWorkingGroupLeadStakeFrom,
   SlashWorkingGroupLeadStakeForm,
   SetWorkingGroupLeadRewardForm,
-  TerminateWorkingGroupLeaderForm
+  TerminateWorkingGroupLeaderForm,
+  RecallCouncilMemberForm,
+  SlashCouncilMemberForm
 } from './forms';
 
 interface Props extends AppProps, I18nProps {}
@@ -92,6 +94,8 @@ function App (props: Props): React.ReactElement<Props> {
           <Route exact path={`${basePath}/new/slash-working-group-leader-stake`} component={SlashWorkingGroupLeadStakeForm} />
           <Route exact path={`${basePath}/new/set-working-group-leader-reward`} component={SetWorkingGroupLeadRewardForm} />
           <Route exact path={`${basePath}/new/terminate-working-group-leader-role`} component={TerminateWorkingGroupLeaderForm} />
+          <Route exact path={`${basePath}/new/recall-council-member`} component={RecallCouncilMemberForm} />
+          <Route exact path={`${basePath}/new/slash-council-member`} component={SlashCouncilMemberForm} />
           <Route exact path={`${basePath}/active`} component={NotDone} />
           <Route exact path={`${basePath}/finalized`} component={NotDone} />
           <Route exact path={`${basePath}/:id`} component={ProposalFromId} />
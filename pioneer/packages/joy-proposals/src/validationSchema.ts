@@ -10,6 +10,8 @@ import { FormValues as SpendingProposalFormValues } from './forms/SpendingPropos
 import { FormValues as SetContentWorkingGroupLeadFormValues } from './forms/SetContentWorkingGroupLeadForm';
 import { FormValues as SetContentWorkingGroupMintCapacityFormValues } from './forms/MintCapacityForm';
 import { FormValues as SetMaxValidatorCountFormValues } from './forms/SetMaxValidatorCountForm';
+import { FormValues as RecallCouncilMemberFormValues } from './forms/RecallCouncilMemberForm';
+import { FormValues as SlashCouncilMemberFormValues } from './forms/SlashCouncilMemberForm';
 import { FormValues as AddWorkingGroupLeaderOpeningFormValues } from './forms/AddWorkingGroupOpeningForm';
 import { FormValues as SetWorkingGroupMintCapacityFormValues } from './forms/SetWorkingGroupMintCapacityForm';
 import { FormValues as BeginReviewLeaderApplicationsFormValues } from './forms/BeginReviewLeaderApplicationsForm';
@@ -105,6 +107,9 @@ const DECREASE_LEAD_STAKE_MIN = 1;
 const SLASH_LEAD_STAKE_MIN = 1;
 // Max is validated in form component, because it depends on selected working group's leader stake
 
+// Slash Council Member (the max is validated by the runtime, it depends on the councilor's seat stake)
+const SLASH_COUNCIL_MEMBER_STAKE_MIN = 1;
+
 function errorMessage (name: string, min?: number | string, max?: number | string, unit?: string): string {
   return `${name} should be at least ${min} and no more than ${max}${unit ? ` ${unit}.` : '.'}`;
 }
@@ -149,6 +154,8 @@ type FormValuesByType<T extends ValidationTypeKeys> =
   T extends 'SlashWorkingGroupLeaderStake' ? Omit<SlashWorkingGroupLeadStakeFormValues, keyof GenericFormValues> :
   T extends 'SetWorkingGroupLeaderReward' ? Omit<SetWorkingGroupLeadRewardFormValues, keyof GenericFormValues> :
   T extends 'TerminateWorkingGroupLeaderRole' ? Omit<TerminateWorkingGroupLeaderFormValues, keyof GenericFormValues> :
+  T extends 'RecallCouncilMember' ? Omit<RecallCouncilMemberFormValues, keyof GenericFormValues> :
+  T extends 'SlashCouncilMember' ? Omit<SlashCouncilMemberFormValues, keyof GenericFormValues> :
   never;
 
 type ValidationSchemaFuncParamsByType<T extends ValidationTypeKeys> =
@@ -428,6 +435,18 @@ const Validation: ValidationType = {
       .min(min.toNumber(), `Termination rationale must be at least ${min.toNumber()} character(s) long`)
       .max(max.toNumber(), `Termination rationale cannot be more than ${max.toNumber()} character(s) long`),
     slashStake: Yup.boolean()
+  }),
+  RecallCouncilMember: () => ({
+    councilor: Yup.string().required('Council member is required!'),
+    startByElection: Yup.boolean()
+  }),
+  SlashCouncilMember: () => ({
+    councilor: Yup.string().required('Council member is required!'),
+    amount: Yup.number()
+      .required('Amount is required!')
+      .min(SLASH_COUNCIL_MEMBER_STAKE_MIN, `Amount must be greater than ${SLASH_COUNCIL_MEMBER_STAKE_MIN}`),
+    slashBackers: Yup.boolean(),
+    destination: Yup.string()
   })
 };
 
@@ -155,6 +155,24 @@ export const metadata: { [k in ProposalType]: ProposalMeta } = {
     approvalThreshold: 80,
     slashingQuorum: 60,
     slashingThreshold: 80
+  },
+  RecallCouncilMember: {
+    description: 'Recall Council Member Proposal',
+    category: 'Council',
+    stake: 200000,
+    approvalQuorum: 80,
+    approvalThreshold: 100,
+    slashingQuorum: 60,
+    slashingThreshold: 80
+  },
+  SlashCouncilMember: {
+    description: 'Slash Council Member Proposal',
+    category: 'Council',
+    stake: 200000,
+    approvalQuorum: 80,
+    approvalThreshold: 100,
+    slashingQuorum: 60,
+    slashingThreshold: 80
   }
 };
 
@@ -222,6 +240,14 @@ export const apiMethods: { [k in ProposalType]?: ProposalsApiMethodNames } = {
   TerminateWorkingGroupLeaderRole: {
     votingPeriod: 'terminateWorkingGroupLeaderRoleProposalVotingPeriod',
     gracePeriod: 'terminateWorkingGroupLeaderRoleProposalGracePeriod'
+  },
+  RecallCouncilMember: {
+    votingPeriod: 'recallCouncilMemberProposalVotingPeriod',
+    gracePeriod: 'recallCouncilMemberProposalGracePeriod'
+  },
+  SlashCouncilMember: {
+    votingPeriod: 'slashCouncilMemberProposalVotingPeriod',
+    gracePeriod: 'slashCouncilMemberProposalGracePeriod'
   }
 } as const;
 
@@ -1,4 +1,5 @@
 import { WorkingGroupKey } from '@joystream/types/common';
 export const apiModuleByGroup: { [k in WorkingGroupKey]: string } = {
+  Forum: 'forumWorkingGroup',
   Storage: 'storageWorkingGroup'
 };
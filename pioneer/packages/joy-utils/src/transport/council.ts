@@ -1,6 +1,6 @@
 import { ParsedMember } from '../types/members';
 import BaseTransport from './base';
-import { Seats, ElectionParameters } from '@joystream/types/council';
+import { Seats, ElectionParameters, TallyMode } from '@joystream/types/council';
 import { MemberId, Profile } from '@joystream/types/members';
 import { u32, Vec } from '@polkadot/types/';
 import { Balance, BlockNumber } from '@polkadot/types/interfaces';
@@ -76,6 +76,7 @@ export default class CouncilTransport extends BaseTransport {
     const min_voting_stake = (await this.councilElection.minVotingStake()) as Balance;
     const candidacy_limit = (await this.councilElection.candidacyLimit()) as u32;
     const council_size = (await this.councilElection.councilSize()) as u32;
+    const tally_mode = (await this.councilElection.voteTallyMode()) as TallyMode;
 
     return new ElectionParameters({
       announcing_period,
@@ -85,7 +86,8 @@ export default class CouncilTransport extends BaseTransport {
       min_council_stake,
       min_voting_stake,
       candidacy_limit,
-      council_size
+      council_size,
+      tally_mode
     });
   }
 }
@@ -63,6 +63,21 @@ export default class WorkingGroupsTransport extends BaseTransport {
     return this.groupMemberById(group, leadWorkerId);
   }
 
+  public async activeWorkers (group: WorkingGroupKey): Promise<[WorkerId, Worker][]> {
+    const nextId = (await this.queryByGroup(group).nextWorkerId()) as WorkerId;
+
+    if (nextId.eq(0)) {
+      return [];
+    }
+
+    const query = this.queryByGroup(group).workerById();
+    const { linked_keys: workerIds, linked_values: workers } = new MultipleLinkedMapEntry(WorkerId, Worker, await query);
+
+    return workers
+      .map((worker, index): [WorkerId, Worker] => [workerIds[index], worker])
+      .filter(([, worker]) => worker.is_active);
+  }
+
   public async allOpenings (group: WorkingGroupKey, type?: OpeningTypeKey): Promise<OpeningData[]> {
     const nextId = (await this.queryByGroup(group).nextOpeningId()) as OpeningId;
 
@@ -20,7 +20,9 @@ export const ProposalTypes = [
   'SlashWorkingGroupLeaderStake',
   'DecreaseWorkingGroupLeaderStake',
   'SetWorkingGroupLeaderReward',
-  'TerminateWorkingGroupLeaderRole'
+  'TerminateWorkingGroupLeaderRole',
+  'RecallCouncilMember',
+  'SlashCouncilMember'
 ] as const;
 
 export type ProposalType = typeof ProposalTypes[number];
@@ -1228,6 +1228,8 @@ decl_module! {
         /// Notice that working group participants cannot do this.
         /// Notice that censored or unlisted channel may still be transferred.
         /// Notice that transfers are unilateral, so new owner cannot block. This may be problematic: https://github.com/Joystream/substrate-runtime-joystream/issues/95
+        /// Notice that the channel gets a new principal, and the versioned store entities maintained
+        /// by the channel are transferred to it.
         pub fn transfer_channel_ownership(origin, channel_id: ChannelId<T>, new_owner: T::MemberId, new_role_account: T::AccountId) {
 
             // Ensure channel owner has signed
@@ -1244,10 +1246,23 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
+            // Issue a new principal for the channel, so the credentials granted to the previous
+            // owner through the old principal are revoked.
+            let new_principal_id = Self::add_new_principal(&Principal::ChannelOwner(channel_id));
+
+            PrincipalById::<T>::remove(channel.principal_id);
+
+            // Transfer the entities maintained by the channel to the new principal
+            <versioned_store_permissions::Module<T>>::transfer_maintained_entities(
+                channel.principal_id,
+                new_principal_id
+            );
+
             // Construct new channel with altered properties
             let new_channel = Channel {
                 owner: new_owner,
                 role_account: new_role_account,
+                principal_id: new_principal_id,
                 ..channel
             };
 
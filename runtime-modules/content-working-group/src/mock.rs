@@ -55,6 +55,7 @@ mod lib {
 impl_outer_event! {
     pub enum TestEvent for Test {
         versioned_store<T>,
+        versioned_store_permissions<T>,
         members<T>,
         balances<T>,
         lib<T>,
@@ -173,10 +174,13 @@ impl hiring::Trait for Test {
 
 impl versioned_store::Trait for Test {
     type Event = TestEvent;
+    type ContentId = H256;
+    type DataObjectChecker = ();
 }
 
 type TestPrincipalId = u64;
 impl versioned_store_permissions::Trait for Test {
+    type Event = TestEvent;
     type Credential = TestPrincipalId;
     type CredentialChecker = ();
     type CreateClassPermissionsChecker = ();
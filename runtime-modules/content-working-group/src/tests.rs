@@ -7,7 +7,7 @@ use hiring;
 use rstd::collections::btree_map::BTreeMap;
 use rstd::collections::btree_set::BTreeSet;
 use sr_primitives::traits::One;
-use srml_support::{assert_err, assert_ok, StorageLinkedMap, StorageValue};
+use srml_support::{assert_err, assert_ok, StorageLinkedMap, StorageMap, StorageValue};
 
 use common::constraints::InputValidationLengthConstraint;
 
@@ -196,6 +196,17 @@ fn transfer_channel_ownership_success() {
 
             let original_channel = ChannelById::<Test>::get(channel_id);
 
+            // Entity maintained by the channel
+            let entity_id = 1;
+            versioned_store_permissions::EntityMaintainerByEntityId::<Test>::insert(
+                entity_id,
+                original_channel.principal_id,
+            );
+            versioned_store_permissions::EntitiesByMaintainer::<Test>::insert(
+                original_channel.principal_id,
+                vec![entity_id],
+            );
+
             let new_role_account = 3333;
 
             let transfer_result = ContentWorkingGroup::transfer_channel_ownership(
@@ -214,9 +225,38 @@ fn transfer_channel_ownership_success() {
                 Channel {
                     owner: channel_creator_member_id_2,
                     role_account: new_role_account,
+                    principal_id: updated_channel.principal_id,
                     ..original_channel
                 }
             );
+
+            assert_ne!(updated_channel.principal_id, original_channel.principal_id);
+            assert!(!PrincipalById::<Test>::exists(
+                original_channel.principal_id
+            ));
+            assert_eq!(
+                PrincipalById::<Test>::get(updated_channel.principal_id),
+                Principal::ChannelOwner(channel_id)
+            );
+
+            assert_eq!(
+                versioned_store_permissions::Module::<Test>::entity_maintainer_by_entity_id(
+                    entity_id
+                ),
+                Some(updated_channel.principal_id)
+            );
+            assert_eq!(
+                versioned_store_permissions::Module::<Test>::entities_by_maintainer(
+                    updated_channel.principal_id
+                ),
+                vec![entity_id]
+            );
+            assert!(
+                versioned_store_permissions::Module::<Test>::entities_by_maintainer(
+                    original_channel.principal_id
+                )
+                .is_empty()
+            );
         });
 }
 
@@ -10,16 +10,19 @@
 use serde_derive::{Deserialize, Serialize};
 
 use rstd::borrow::ToOwned;
+use rstd::collections::btree_map::BTreeMap;
 use rstd::prelude::*;
 
 use codec::{Codec, Decode, Encode};
-use runtime_primitives::traits::{MaybeSerialize, Member, One, SimpleArithmetic};
+use runtime_primitives::traits::{MaybeSerialize, Member, One, SimpleArithmetic, Zero};
+use srml_support::storage::{generator, unhashed};
 use srml_support::{decl_event, decl_module, decl_storage, dispatch, ensure, Parameter};
 
 mod mock;
 mod tests;
 
 use common::constraints::InputValidationLengthConstraint;
+use common::origin::ActorOriginValidator;
 use common::BlockAndTime;
 
 /// Constants
@@ -29,9 +32,18 @@ use common::BlockAndTime;
 /// The depth of a root category is 0.
 const MAX_CATEGORY_DEPTH: u16 = 3;
 
+/// The greatest number of threads pinned in a category.
+const MAX_PINNED_THREADS_IN_CATEGORY: usize = 5;
+
+/// The smallest number of alternatives in a thread poll.
+const MIN_POLL_ALTERNATIVES: usize = 2;
+
+/// The greatest number of alternatives in a thread poll.
+const MAX_POLL_ALTERNATIVES: usize = 10;
+
 /// Error messages for dispatchables
-const ERROR_FORUM_SUDO_NOT_SET: &str = "Forum sudo not set.";
-const ERROR_ORIGIN_NOT_FORUM_SUDO: &str = "Origin not forum sudo.";
+const ERROR_ORIGIN_NOT_FORUM_LEAD: &str = "Origin not forum lead.";
+const ERROR_ORIGIN_NOT_FORUM_MODERATOR: &str = "Origin not forum moderator.";
 const ERROR_CATEGORY_TITLE_TOO_SHORT: &str = "Category title too short.";
 const ERROR_CATEGORY_TITLE_TOO_LONG: &str = "Category title too long.";
 const ERROR_CATEGORY_DESCRIPTION_TOO_SHORT: &str = "Category description too long.";
@@ -50,41 +62,73 @@ const ERROR_THREAD_MODERATION_RATIONALE_TOO_SHORT: &str = "Thread moderation rat
 const ERROR_THREAD_MODERATION_RATIONALE_TOO_LONG: &str = "Thread moderation rationale too long.";
 const ERROR_THREAD_ALREADY_MODERATED: &str = "Thread already moderated.";
 const ERROR_THREAD_MODERATED: &str = "Thread is moderated.";
+const ERROR_THREAD_LOCKED: &str = "Thread is locked.";
+const ERROR_THREAD_ALREADY_LOCKED: &str = "Thread already locked.";
+const ERROR_THREAD_NOT_LOCKED: &str = "Thread not locked.";
+const ERROR_THREAD_ALREADY_PINNED: &str = "Thread already pinned.";
+const ERROR_THREAD_NOT_PINNED: &str = "Thread not pinned.";
+const ERROR_MAX_PINNED_THREADS_EXCEEDED: &str =
+    "Maximum number of pinned threads in category exceeded.";
 const ERROR_POST_DOES_NOT_EXIST: &str = "Post does not exist.";
-const ERROR_ACCOUNT_DOES_NOT_MATCH_POST_AUTHOR: &str = "Account does not match post author.";
+const ERROR_FORUM_USER_DOES_NOT_MATCH_POST_AUTHOR: &str = "Forum user does not match post author.";
 const ERROR_POST_MODERATED: &str = "Post is moderated.";
 const ERROR_POST_MODERATION_RATIONALE_TOO_SHORT: &str = "Post moderation rationale too short.";
 const ERROR_POST_MODERATION_RATIONALE_TOO_LONG: &str = "Post moderation rationale too long.";
+const ERROR_POST_REACTION_NOT_CHANGED: &str = "Post reaction not changed.";
+const ERROR_POLL_ALTERNATIVES_TOO_FEW: &str = "Poll has too few alternatives.";
+const ERROR_POLL_ALTERNATIVES_TOO_MANY: &str = "Poll has too many alternatives.";
+const ERROR_POLL_ALTERNATIVE_TEXT_TOO_SHORT: &str = "Poll alternative text too short.";
+const ERROR_POLL_ALTERNATIVE_TEXT_TOO_LONG: &str = "Poll alternative text too long.";
+const ERROR_POLL_END_BLOCK_NOT_IN_FUTURE: &str = "Poll end block is not in the future.";
+const ERROR_THREAD_HAS_NO_POLL: &str = "Thread has no poll.";
+const ERROR_POLL_ENDED: &str = "Poll ended.";
+const ERROR_POLL_ALTERNATIVE_DOES_NOT_EXIST: &str = "Poll alternative does not exist.";
+const ERROR_POLL_VOTE_NOT_CHANGED: &str = "Poll vote not changed.";
 const ERROR_CATEGORY_NOT_BEING_UPDATED: &str = "Category not being updated.";
 const ERROR_CATEGORY_CANNOT_BE_UNARCHIVED_WHEN_DELETED: &str =
     "Category cannot be unarchived when deleted.";
+const ERROR_THREAD_ALREADY_IN_CATEGORY: &str = "Thread already in category.";
+const ERROR_CATEGORY_ALREADY_IN_PARENT: &str = "Category already in parent category.";
+const ERROR_CATEGORY_CANNOT_BE_MOVED_INTO_ITSELF: &str =
+    "Category cannot be moved into itself or its subcategory.";
 
-use system::{ensure_root, ensure_signed};
+use system::ensure_signed;
 
 /// Represents a user in this forum.
 #[derive(Debug, Copy, Clone)]
-pub struct ForumUser<AccountId> {
+pub struct ForumUser<ForumUserId> {
     /// Identifier of user
-    pub id: AccountId, // In the future one could add things like
-                       // - updating post count of a user
-                       // - updating status (e.g. hero, new, etc.)
-                       //
+    pub id: ForumUserId, // In the future one could add things like
+                         // - updating post count of a user
+                         // - updating status (e.g. hero, new, etc.)
+                         //
 }
 
 /// Represents a regsitry of `ForumUser` instances.
-pub trait ForumUserRegistry<AccountId> {
-    fn get_forum_user(id: &AccountId) -> Option<ForumUser<AccountId>>;
+pub trait ForumUserRegistry<ForumUserId> {
+    /// Returns the forum user if it is allowed to participate in the forum.
+    fn get_forum_user(id: &ForumUserId) -> Option<ForumUser<ForumUserId>>;
+}
+
+/// Represents a registry of the accounts allowed to manage and moderate the forum.
+pub trait ForumModeratorRegistry<AccountId, ForumUserId> {
+    /// Whether the account is the forum lead, who manages the categories.
+    fn is_forum_lead(id: &AccountId) -> bool;
+
+    /// Returns the forum user behind the account, if the account is allowed to moderate
+    /// threads and posts.
+    fn get_forum_moderator(id: &AccountId) -> Option<ForumUserId>;
 }
 
 /// Represents a moderation outcome applied to a post or a thread.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
-pub struct ModerationAction<BlockNumber, Moment, AccountId> {
+pub struct ModerationAction<BlockNumber, Moment, ForumUserId> {
     /// When action occured.
     moderated_at: BlockAndTime<BlockNumber, Moment>,
 
-    /// Account forum sudo which acted.
-    moderator_id: AccountId,
+    /// Forum user of the moderator which acted.
+    moderator_id: ForumUserId,
 
     /// Moderation rationale
     rationale: Vec<u8>,
@@ -101,10 +145,66 @@ pub struct PostTextChange<BlockNumber, Moment> {
     text: Vec<u8>,
 }
 
+/// Represents a reaction of a forum user to a post.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PostReaction {
+    /// Agreement or appreciation.
+    Like,
+
+    /// Disagreement.
+    Dislike,
+
+    /// Gratitude for a helpful post.
+    Thanks,
+
+    /// Amusement.
+    Laugh,
+}
+
+/// Represents the number of reactions of each kind to a post.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct PostReactionCounts {
+    /// 'Like' reactions counter
+    likes: u32,
+
+    /// 'Dislike' reactions counter
+    dislikes: u32,
+
+    /// 'Thanks' reactions counter
+    thanks: u32,
+
+    /// 'Laugh' reactions counter
+    laughs: u32,
+}
+
+impl PostReactionCounts {
+    fn counter_mut(&mut self, reaction: PostReaction) -> &mut u32 {
+        match reaction {
+            PostReaction::Like => &mut self.likes,
+            PostReaction::Dislike => &mut self.dislikes,
+            PostReaction::Thanks => &mut self.thanks,
+            PostReaction::Laugh => &mut self.laughs,
+        }
+    }
+
+    /// Add reaction to the related counter
+    fn add_reaction(&mut self, reaction: PostReaction) {
+        *self.counter_mut(reaction) += 1;
+    }
+
+    /// Remove reaction from the related counter
+    fn remove_reaction(&mut self, reaction: PostReaction) {
+        let counter = self.counter_mut(reaction);
+        *counter = counter.saturating_sub(1);
+    }
+}
+
 /// Represents a thread post
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
-pub struct Post<BlockNumber, Moment, AccountId, ThreadId, PostId> {
+pub struct Post<BlockNumber, Moment, ForumUserId, ThreadId, PostId> {
     /// Post identifier
     id: PostId,
 
@@ -122,7 +222,7 @@ pub struct Post<BlockNumber, Moment, AccountId, ThreadId, PostId> {
     current_text: Vec<u8>,
 
     /// Possible moderation of this post
-    moderation: Option<ModerationAction<BlockNumber, Moment, AccountId>>,
+    moderation: Option<ModerationAction<BlockNumber, Moment, ForumUserId>>,
 
     /// Edits of post ordered chronologically by edit time.
     text_change_history: Vec<PostTextChange<BlockNumber, Moment>>,
@@ -131,13 +231,49 @@ pub struct Post<BlockNumber, Moment, AccountId, ThreadId, PostId> {
     created_at: BlockAndTime<BlockNumber, Moment>,
 
     /// Author of post.
-    author_id: AccountId,
+    author_id: ForumUserId,
+
+    /// Number of reactions of each kind to this post.
+    reaction_counts: PostReactionCounts,
+}
+
+/// Represents an alternative of a thread poll.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct PollAlternative {
+    /// Text describing the alternative
+    text: Vec<u8>,
+
+    /// Number of votes for the alternative
+    vote_count: u32,
+}
+
+/// Represents a poll attached to a thread.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct Poll<BlockNumber> {
+    /// Block at which the voting ends.
+    end_block: BlockNumber,
+
+    /// Alternatives of the poll along with their tally.
+    alternatives: Vec<PollAlternative>,
+}
+
+/// Parameters of a poll attached to a thread when it is created.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct PollParameters<BlockNumber> {
+    /// Block at which the voting ends.
+    pub end_block: BlockNumber,
+
+    /// Texts describing the poll alternatives.
+    pub alternatives: Vec<Vec<u8>>,
 }
 
 /// Represents a thread
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
-pub struct Thread<BlockNumber, Moment, AccountId, ThreadId> {
+pub struct Thread<BlockNumber, Moment, ForumUserId, ThreadId> {
     /// Thread identifier
     id: ThreadId,
 
@@ -155,7 +291,7 @@ pub struct Thread<BlockNumber, Moment, AccountId, ThreadId> {
     nr_in_category: u32,
 
     /// Possible moderation of this thread
-    moderation: Option<ModerationAction<BlockNumber, Moment, AccountId>>,
+    moderation: Option<ModerationAction<BlockNumber, Moment, ForumUserId>>,
 
     /// Number of unmoderated and moderated posts in this thread.
     /// The sum of these two only increases, and former is incremented
@@ -173,11 +309,19 @@ pub struct Thread<BlockNumber, Moment, AccountId, ThreadId> {
     /// When thread was established.
     created_at: BlockAndTime<BlockNumber, Moment>,
 
-    /// Author of post.
-    author_id: AccountId,
+    /// Author of thread.
+    author_id: ForumUserId,
+
+    /// Whether thread is locked, i.e. no new posts can be added to it.
+    locked: bool,
+
+    /// Possible poll attached to this thread.
+    poll: Option<Poll<BlockNumber>>,
 }
 
-impl<BlockNumber, Moment, AccountId, ThreadId> Thread<BlockNumber, Moment, AccountId, ThreadId> {
+impl<BlockNumber, Moment, ForumUserId, ThreadId>
+    Thread<BlockNumber, Moment, ForumUserId, ThreadId>
+{
     fn num_posts_ever_created(&self) -> u32 {
         self.num_unmoderated_posts + self.num_moderated_posts
     }
@@ -238,6 +382,17 @@ pub struct Category<BlockNumber, Moment, AccountId> {
     num_direct_unmoderated_threads: u32,
     num_direct_moderated_threads: u32,
 
+    /// Number of subcategories and threads moved out of this category.
+    ///
+    /// Moving a subcategory or a thread out leaves a gap in the child numbers or
+    /// `nr_in_category` values of this category, so these values let light clients
+    /// tell the moved out entities from the censored ones.
+    ///
+    /// When a thread is moved out, the unmoderated threads counter is decremented and the
+    /// moved threads counter incremented, so that the sum of the thread counters only increases.
+    num_direct_moved_subcategories: u32,
+    num_direct_moved_threads: u32,
+
     /// Position as child in parent, if present, otherwise this category is a root category
     position_in_parent_category: Option<ChildPositionInParentCategory>,
 
@@ -247,7 +402,9 @@ pub struct Category<BlockNumber, Moment, AccountId> {
 
 impl<BlockNumber, Moment, AccountId> Category<BlockNumber, Moment, AccountId> {
     fn num_threads_created(&self) -> u32 {
-        self.num_direct_unmoderated_threads + self.num_direct_moderated_threads
+        self.num_direct_unmoderated_threads
+            + self.num_direct_moderated_threads
+            + self.num_direct_moved_threads
     }
 }
 
@@ -256,10 +413,87 @@ impl<BlockNumber, Moment, AccountId> Category<BlockNumber, Moment, AccountId> {
 type CategoryTreePath<BlockNumber, Moment, AccountId> =
     Vec<Category<BlockNumber, Moment, AccountId>>;
 
+/// Moderation action layout before the moderators were identified by forum user ids.
+#[derive(Decode)]
+struct ModerationActionV1<BlockNumber, Moment, AccountId> {
+    moderated_at: BlockAndTime<BlockNumber, Moment>,
+    moderator_id: AccountId,
+    rationale: Vec<u8>,
+}
+
+/// Post layout before the authors were identified by forum user ids and posts had reactions.
+#[derive(Decode)]
+struct PostV1<BlockNumber, Moment, AccountId, ThreadId, PostId> {
+    id: PostId,
+    thread_id: ThreadId,
+    nr_in_thread: u32,
+    current_text: Vec<u8>,
+    moderation: Option<ModerationActionV1<BlockNumber, Moment, AccountId>>,
+    text_change_history: Vec<PostTextChange<BlockNumber, Moment>>,
+    created_at: BlockAndTime<BlockNumber, Moment>,
+    author_id: AccountId,
+}
+
+/// Thread layout before the authors were identified by forum user ids and threads could be
+/// locked or have a poll.
+#[derive(Decode)]
+struct ThreadV1<BlockNumber, Moment, AccountId, ThreadId> {
+    id: ThreadId,
+    title: Vec<u8>,
+    category_id: CategoryId,
+    nr_in_category: u32,
+    moderation: Option<ModerationActionV1<BlockNumber, Moment, AccountId>>,
+    num_unmoderated_posts: u32,
+    num_moderated_posts: u32,
+    created_at: BlockAndTime<BlockNumber, Moment>,
+    author_id: AccountId,
+}
+
+/// Category layout before subcategories and threads could be moved out of a category.
+#[derive(Decode)]
+struct CategoryV1<BlockNumber, Moment, AccountId> {
+    id: CategoryId,
+    title: Vec<u8>,
+    description: Vec<u8>,
+    created_at: BlockAndTime<BlockNumber, Moment>,
+    deleted: bool,
+    archived: bool,
+    num_direct_subcategories: u32,
+    num_direct_unmoderated_threads: u32,
+    num_direct_moderated_threads: u32,
+    position_in_parent_category: Option<ChildPositionInParentCategory>,
+    moderator_id: AccountId,
+}
+
+/// Decodes a value stored with an old layout, failing unless all the stored bytes are consumed,
+/// so that values already stored with the new layout are not decoded.
+fn decode_old_layout<V: Decode>(key: &[u8]) -> Option<V> {
+    let raw = unhashed::get_raw(key)?;
+    let mut input = &raw[..];
+
+    match V::decode(&mut input) {
+        Ok(value) if input.is_empty() => Some(value),
+        _ => None,
+    }
+}
+
 pub trait Trait: system::Trait + timestamp::Trait + Sized {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
-    type MembershipRegistry: ForumUserRegistry<Self::AccountId>;
+    /// Forum user Id type, identifies the authors of threads and posts
+    type ForumUserId: Parameter + Member + Codec + Default + Copy + MaybeSerialize + PartialEq;
+
+    /// Validates forum user id and origin combination
+    type ForumUserOriginValidator: ActorOriginValidator<
+        Self::Origin,
+        Self::ForumUserId,
+        Self::AccountId,
+    >;
+
+    type MembershipRegistry: ForumUserRegistry<Self::ForumUserId>;
+
+    /// Provides the forum lead and moderators.
+    type ModeratorRegistry: ForumModeratorRegistry<Self::AccountId, Self::ForumUserId>;
 
     /// Thread Id type
     type ThreadId: Parameter
@@ -292,19 +526,29 @@ decl_storage! {
         pub NextCategoryId get(next_category_id) config(): CategoryId;
 
         /// Map thread identifier to corresponding thread.
-        pub ThreadById get(thread_by_id) config(): map T::ThreadId => Thread<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId>;
+        pub ThreadById get(thread_by_id) config(): map T::ThreadId => Thread<T::BlockNumber, T::Moment, T::ForumUserId, T::ThreadId>;
 
         /// Thread identifier value to be used for next Thread in threadById.
         pub NextThreadId get(next_thread_id) config(): T::ThreadId;
 
         /// Map post identifier to corresponding post.
-        pub PostById get(post_by_id) config(): map T::PostId => Post<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId, T::PostId>;
+        pub PostById get(post_by_id) config(): map T::PostId => Post<T::BlockNumber, T::Moment, T::ForumUserId, T::ThreadId, T::PostId>;
 
         /// Post identifier value to be used for for next post created.
         pub NextPostId get(next_post_id) config(): T::PostId;
 
-        /// Account of forum sudo.
-        pub ForumSudo get(forum_sudo) config(): Option<T::AccountId>;
+        /// Map post identifier and forum user to the reaction of the user to the post.
+        pub PostReactionByForumUser get(post_reaction_by_forum_user):
+            double_map T::PostId, twox_256(T::ForumUserId) => Option<PostReaction>;
+
+        /// Map category identifier to the identifiers of the threads pinned in the category,
+        /// in pinning order.
+        pub PinnedThreadIdsByCategory get(pinned_thread_ids_by_category): map CategoryId => Vec<T::ThreadId>;
+
+        /// Map thread identifier and forum user to the index of the poll alternative voted
+        /// for by the user.
+        pub PollVoteByForumUser get(poll_vote_by_forum_user):
+            double_map T::ThreadId, twox_256(T::ForumUserId) => Option<u32>;
 
         /// Input constraints
         /// These are all forward looking, that is they are enforced on all
@@ -315,38 +559,16 @@ decl_storage! {
         pub PostTextConstraint get(post_text_constraint) config(): InputValidationLengthConstraint;
         pub ThreadModerationRationaleConstraint get(thread_moderation_rationale_constraint) config(): InputValidationLengthConstraint;
         pub PostModerationRationaleConstraint get(post_moderation_rationale_constraint) config(): InputValidationLengthConstraint;
+        pub PollAlternativeTextConstraint get(poll_alternative_text_constraint) config(): InputValidationLengthConstraint;
     }
-    /*
-    JUST GIVING UP ON ALL THIS FOR NOW BECAUSE ITS TAKING TOO LONG
-    Review : https://github.com/paritytech/polkadot/blob/620b8610431e7b5fdd71ce3e94c3ee0177406dcc/runtime/src/parachains.rs#L123-L141
-
-    add_extra_genesis {
-
-        // Explain why we need to put this here.
-        config(initial_forum_sudo) : Option<T::AccountId>;
-
-        build(|
-            storage: &mut generator::StorageOverlay,
-            _: &mut generator::ChildrenStorageOverlay,
-            config: &GenesisConfig<T>
-            | {
-
-
-            if let Some(account_id) = &config.initial_forum_sudo {
-                println!("{}: <ForumSudo<T>>::put(account_id)", account_id);
-                <ForumSudo<T> as generator::StorageValue<_>>::put(&account_id, storage);
-            }
-        })
-    }
-    */
 }
 
 decl_event!(
     pub enum Event<T>
     where
-        <T as system::Trait>::AccountId,
         <T as Trait>::ThreadId,
         <T as Trait>::PostId,
+        <T as Trait>::ForumUserId,
     {
         /// A category was introduced
         CategoryCreated(CategoryId),
@@ -356,12 +578,31 @@ decl_event!(
         /// The third argument reflects the new deletion status of the category, if changed.
         CategoryUpdated(CategoryId, Option<bool>, Option<bool>),
 
+        /// A category with given id was moved.
+        /// The second argument reflects the new parent category, or `None` if moved to root.
+        CategoryMoved(CategoryId, Option<CategoryId>),
+
         /// A thread with given id was created.
         ThreadCreated(ThreadId),
 
         /// A thread with given id was moderated.
         ThreadModerated(ThreadId),
 
+        /// A thread with given id was moved to the category with given id.
+        ThreadMoved(ThreadId, CategoryId),
+
+        /// A thread with given id was locked.
+        ThreadLocked(ThreadId),
+
+        /// A thread with given id was unlocked.
+        ThreadUnlocked(ThreadId),
+
+        /// A thread with given id was pinned in its category.
+        ThreadPinned(ThreadId),
+
+        /// A thread with given id was unpinned from its category.
+        ThreadUnpinned(ThreadId),
+
         /// Post with given id was created.
         PostAdded(PostId),
 
@@ -372,8 +613,13 @@ decl_event!(
         /// The second argument reflects the number of total edits when the text update occurs.
         PostTextUpdated(PostId, u64),
 
-        /// Given account was set as forum sudo.
-        ForumSudoSet(Option<AccountId>, Option<AccountId>),
+        /// Forum user reacted to the post with given id.
+        /// The third argument reflects the new reaction, or its removal if `None`.
+        PostReacted(PostId, ForumUserId, Option<PostReaction>),
+
+        /// Forum user voted in the poll of the thread with given id.
+        /// The third argument reflects the index of the voted alternative.
+        PollVoted(ThreadId, ForumUserId, u32),
     }
 );
 
@@ -382,39 +628,14 @@ decl_module! {
 
         fn deposit_event() = default;
 
-        /// Set forum sudo.
-        fn set_forum_sudo(origin, new_forum_sudo: Option<T::AccountId>) -> dispatch::Result {
-            ensure_root(origin)?;
-
-            /*
-             * Question: when this routine is called by non sudo or with bad signature, what error is raised?
-             * Update ERror set in spec
-             */
-
-            // Hold on to old value
-            let old_forum_sudo = <ForumSudo<T>>::get();
-
-            // Update forum sudo
-            match new_forum_sudo.clone() {
-                Some(account_id) => <ForumSudo<T>>::put(account_id),
-                None => <ForumSudo<T>>::kill()
-            };
-
-            // Generate event
-            Self::deposit_event(RawEvent::ForumSudoSet(old_forum_sudo, new_forum_sudo));
-
-            // All good.
-            Ok(())
-        }
-
         /// Add a new category.
         fn create_category(origin, parent: Option<CategoryId>, title: Vec<u8>, description: Vec<u8>) -> dispatch::Result {
 
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Not signed by forum SUDO
-            Self::ensure_is_forum_sudo(&who)?;
+            // Not signed by forum lead
+            Self::ensure_is_forum_lead(&who)?;
 
             // Validate title
             Self::ensure_category_title_is_valid(&title)?;
@@ -470,6 +691,8 @@ decl_module! {
                 num_direct_subcategories: 0,
                 num_direct_unmoderated_threads: 0,
                 num_direct_moderated_threads: 0,
+                num_direct_moved_subcategories: 0,
+                num_direct_moved_threads: 0,
                 position_in_parent_category: position_in_parent_category_field,
                 moderator_id: who
             };
@@ -492,8 +715,8 @@ decl_module! {
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Not signed by forum SUDO
-            Self::ensure_is_forum_sudo(&who)?;
+            // Not signed by forum lead
+            Self::ensure_is_forum_lead(&who)?;
 
             // Make sure something is actually being changed
             ensure!(
@@ -549,8 +772,93 @@ decl_module! {
             Ok(())
         }
 
-        /// Create new thread in category
-        fn create_thread(origin, category_id: CategoryId, title: Vec<u8>, text: Vec<u8>) -> dispatch::Result {
+        /// Move category, along with its subcategories and threads, to a new parent category,
+        /// or make it a root category.
+        fn move_category(origin, category_id: CategoryId, new_parent: Option<CategoryId>) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Not signed by forum lead
+            Self::ensure_is_forum_lead(&who)?;
+
+            // Get path from category to root of category tree.
+            let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(category_id)?;
+
+            let category = category_tree_path[0].clone();
+
+            let old_parent = category.position_in_parent_category
+                .as_ref()
+                .map(|position| position.parent_id);
+
+            // Make sure something is actually being changed
+            ensure!(old_parent != new_parent, ERROR_CATEGORY_ALREADY_IN_PARENT);
+
+            // Ancestors of the category are mutable. Like in `update_category`, we skip
+            // checking the category itself.
+            Self::ensure_can_mutate_in_path_leaf(&category_tree_path[1..])?;
+
+            // If not moved to root, then check that we can move to the new parent category
+            if let Some(new_parent_id) = new_parent {
+
+                let new_parent_tree_path =
+                    Self::ensure_valid_category_and_build_category_tree_path(new_parent_id)?;
+
+                // Category is not an ancestor of its new parent
+                ensure!(
+                    !new_parent_tree_path.iter().any(|c| c.id == category_id),
+                    ERROR_CATEGORY_CANNOT_BE_MOVED_INTO_ITSELF
+                );
+
+                // Can we move the category along with its subcategories in the new parent?
+                Self::ensure_can_move_subcategory_to_path_leaf(
+                    &new_parent_tree_path,
+                    Self::subcategory_tree_depth(category_id)
+                )?;
+            }
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            // Leave a gap in the subcategories of the old parent category
+            if let Some(old_parent_id) = old_parent {
+                <CategoryById<T>>::mutate(old_parent_id, |c| {
+                    c.num_direct_moved_subcategories += 1;
+                });
+            }
+
+            // Add category as a child of the new parent category
+            let position_in_parent_category_field = new_parent.map(|new_parent_id| {
+                <CategoryById<T>>::mutate(new_parent_id, |c| {
+                    c.num_direct_subcategories += 1;
+                });
+
+                ChildPositionInParentCategory {
+                    parent_id: new_parent_id,
+                    child_nr_in_parent_category: <CategoryById<T>>::get(new_parent_id).num_direct_subcategories
+                }
+            });
+
+            <CategoryById<T>>::mutate(category_id, |c| {
+                c.position_in_parent_category = position_in_parent_category_field;
+            });
+
+            // Generate event
+            Self::deposit_event(RawEvent::CategoryMoved(category_id, new_parent));
+
+            Ok(())
+        }
+
+        /// Create new thread in category, with an optional poll attached
+        fn create_thread(
+            origin,
+            forum_user_id: T::ForumUserId,
+            category_id: CategoryId,
+            title: Vec<u8>,
+            text: Vec<u8>,
+            poll: Option<PollParameters<T::BlockNumber>>
+        ) -> dispatch::Result {
 
             /*
              * Update SPEC with new errors,
@@ -558,11 +866,8 @@ decl_module! {
              * as well as side effect to update Category::num_threads_created.
              */
 
-            // Check that its a valid signature
-            let who = ensure_signed(origin)?;
-
-            // Check that account is forum member
-            Self::ensure_is_forum_member(&who)?;
+            // Check that forum user is valid and signed the extrinsic
+            Self::ensure_is_forum_user(origin, &forum_user_id)?;
 
             // Get path from parent to root of category tree.
             let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(category_id)?;
@@ -576,15 +881,20 @@ decl_module! {
             // Validate post text
             Self::ensure_post_text_is_valid(&text)?;
 
+            // Validate poll
+            if let Some(ref poll) = poll {
+                Self::ensure_poll_is_valid(poll)?;
+            }
+
             /*
              * Here it is safe to mutate state.
              */
 
             // Add thread
-            let thread = Self::add_new_thread(category_id, &title, &who);
+            let thread = Self::add_new_thread(category_id, &title, &forum_user_id, poll);
 
             // Add inital post to thread
-            Self::add_new_post(thread.id, &text, &who);
+            Self::add_new_post(thread.id, &text, &forum_user_id);
 
             // Generate event
             Self::deposit_event(RawEvent::ThreadCreated(thread.id));
@@ -598,8 +908,8 @@ decl_module! {
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Signed by forum SUDO
-            Self::ensure_is_forum_sudo(&who)?;
+            // Signed by forum moderator
+            let moderator_id = Self::ensure_is_forum_moderator(&who)?;
 
             // Get thread
             let mut thread = Self::ensure_thread_exists(thread_id)?;
@@ -625,7 +935,7 @@ decl_module! {
             // Add moderation to thread
             thread.moderation = Some(ModerationAction {
                 moderated_at: common::current_block_time::<T>(),
-                moderator_id: who,
+                moderator_id,
                 rationale
             });
 
@@ -637,24 +947,206 @@ decl_module! {
                 category.num_direct_moderated_threads += 1;
             });
 
+            // Moderated thread is no longer pinned
+            <PinnedThreadIdsByCategory<T>>::mutate(thread.category_id, |pinned_thread_ids| {
+                pinned_thread_ids.retain(|id| *id != thread_id);
+            });
+
             // Generate event
             Self::deposit_event(RawEvent::ThreadModerated(thread_id));
 
             Ok(())
         }
 
-        /// Edit post text
-        fn add_post(origin, thread_id: T::ThreadId, text: Vec<u8>) -> dispatch::Result {
+        /// Move thread to another category
+        fn move_thread(origin, thread_id: T::ThreadId, new_category_id: CategoryId) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Signed by forum moderator
+            Self::ensure_is_forum_moderator(&who)?;
+
+            // Make sure thread exists and is mutable
+            let thread = Self::ensure_thread_is_mutable(thread_id)?;
+
+            // Make sure something is actually being changed
+            ensure!(thread.category_id != new_category_id, ERROR_THREAD_ALREADY_IN_CATEGORY);
+
+            // Get path from new category to root of category tree.
+            let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(new_category_id)?;
+
+            // No ancestor is blocking us doing mutation in new category
+            Self::ensure_can_mutate_in_path_leaf(&category_tree_path)?;
 
             /*
-             * Update SPEC with new errors,
+             * Here we are safe to mutate
              */
 
+            // Leave a gap in the threads of the old category
+            <CategoryById<T>>::mutate(thread.category_id, |c| {
+                c.num_direct_unmoderated_threads -= 1;
+                c.num_direct_moved_threads += 1;
+            });
+
+            // Moved thread is no longer pinned
+            <PinnedThreadIdsByCategory<T>>::mutate(thread.category_id, |pinned_thread_ids| {
+                pinned_thread_ids.retain(|id| *id != thread_id);
+            });
+
+            // Add thread to the new category, as if it was created there
+            let new_category = &category_tree_path[0];
+
+            <ThreadById<T>>::mutate(thread_id, |t| {
+                t.category_id = new_category_id;
+                t.nr_in_category = new_category.num_threads_created() + 1;
+            });
+
+            <CategoryById<T>>::mutate(new_category_id, |c| {
+                c.num_direct_unmoderated_threads += 1;
+            });
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadMoved(thread_id, new_category_id));
+
+            Ok(())
+        }
+
+        /// Lock thread, so that no posts can be added to it
+        fn lock_thread(origin, thread_id: T::ThreadId) -> dispatch::Result {
+
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Check that account is forum member
-            Self::ensure_is_forum_member(&who)?;
+            // Signed by forum moderator
+            Self::ensure_is_forum_moderator(&who)?;
+
+            // Make sure thread exists and is mutable
+            let thread = Self::ensure_thread_is_mutable(thread_id)?;
+
+            // Thread is not already locked
+            ensure!(!thread.locked, ERROR_THREAD_ALREADY_LOCKED);
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            <ThreadById<T>>::mutate(thread_id, |t| {
+                t.locked = true;
+            });
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadLocked(thread_id));
+
+            Ok(())
+        }
+
+        /// Unlock thread, so that posts can be added to it again
+        fn unlock_thread(origin, thread_id: T::ThreadId) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Signed by forum moderator
+            Self::ensure_is_forum_moderator(&who)?;
+
+            // Make sure thread exists and is mutable
+            let thread = Self::ensure_thread_is_mutable(thread_id)?;
+
+            // Thread is locked
+            ensure!(thread.locked, ERROR_THREAD_NOT_LOCKED);
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            <ThreadById<T>>::mutate(thread_id, |t| {
+                t.locked = false;
+            });
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadUnlocked(thread_id));
+
+            Ok(())
+        }
+
+        /// Pin thread in its category
+        fn pin_thread(origin, thread_id: T::ThreadId) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Signed by forum moderator
+            Self::ensure_is_forum_moderator(&who)?;
+
+            // Make sure thread exists and is mutable
+            let thread = Self::ensure_thread_is_mutable(thread_id)?;
+
+            let mut pinned_thread_ids = Self::pinned_thread_ids_by_category(thread.category_id);
+
+            // Thread is not already pinned
+            ensure!(!pinned_thread_ids.contains(&thread_id), ERROR_THREAD_ALREADY_PINNED);
+
+            // Category has room for another pinned thread
+            ensure!(
+                pinned_thread_ids.len() < MAX_PINNED_THREADS_IN_CATEGORY,
+                ERROR_MAX_PINNED_THREADS_EXCEEDED
+            );
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            pinned_thread_ids.push(thread_id);
+
+            <PinnedThreadIdsByCategory<T>>::insert(thread.category_id, pinned_thread_ids);
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadPinned(thread_id));
+
+            Ok(())
+        }
+
+        /// Unpin thread from its category
+        fn unpin_thread(origin, thread_id: T::ThreadId) -> dispatch::Result {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Signed by forum moderator
+            Self::ensure_is_forum_moderator(&who)?;
+
+            // Make sure thread exists, threads in archived or deleted categories can be unpinned
+            let thread = Self::ensure_thread_exists(thread_id)?;
+
+            let mut pinned_thread_ids = Self::pinned_thread_ids_by_category(thread.category_id);
+
+            // Thread is pinned
+            ensure!(pinned_thread_ids.contains(&thread_id), ERROR_THREAD_NOT_PINNED);
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            pinned_thread_ids.retain(|id| *id != thread_id);
+
+            <PinnedThreadIdsByCategory<T>>::insert(thread.category_id, pinned_thread_ids);
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadUnpinned(thread_id));
+
+            Ok(())
+        }
+
+        /// Edit post text
+        fn add_post(origin, forum_user_id: T::ForumUserId, thread_id: T::ThreadId, text: Vec<u8>) -> dispatch::Result {
+
+            /*
+             * Update SPEC with new errors,
+             */
+
+            // Check that forum user is valid and signed the extrinsic
+            Self::ensure_is_forum_user(origin, &forum_user_id)?;
 
             // Validate post text
             Self::ensure_post_text_is_valid(&text)?;
@@ -662,6 +1154,9 @@ decl_module! {
             // Make sure thread exists and is mutable
             let thread = Self::ensure_thread_is_mutable(thread_id)?;
 
+            // Thread is not locked
+            ensure!(!thread.locked, ERROR_THREAD_LOCKED);
+
             // Get path from parent to root of category tree.
             let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(thread.category_id)?;
 
@@ -672,7 +1167,7 @@ decl_module! {
              * Here we are safe to mutate
              */
 
-            let post = Self::add_new_post(thread_id, &text, &who);
+            let post = Self::add_new_post(thread_id, &text, &forum_user_id);
 
             // Generate event
             Self::deposit_event(RawEvent::PostAdded(post.id));
@@ -681,18 +1176,15 @@ decl_module! {
         }
 
         /// Edit post text
-        fn edit_post_text(origin, post_id: T::PostId, new_text: Vec<u8>) -> dispatch::Result {
+        fn edit_post_text(origin, forum_user_id: T::ForumUserId, post_id: T::PostId, new_text: Vec<u8>) -> dispatch::Result {
 
             /* Edit spec.
               - forum member guard missing
               - check that both post and thread and category are mutable
             */
 
-            // Check that its a valid signature
-            let who = ensure_signed(origin)?;
-
-            // Check that account is forum member
-            Self::ensure_is_forum_member(&who)?;
+            // Check that forum user is valid and signed the extrinsic
+            Self::ensure_is_forum_user(origin, &forum_user_id)?;
 
             // Validate post text
             Self::ensure_post_text_is_valid(&new_text)?;
@@ -700,8 +1192,8 @@ decl_module! {
             // Make sure there exists a mutable post with post id `post_id`
             let post = Self::ensure_post_is_mutable(post_id)?;
 
-            // Signer does not match creator of post with identifier postId
-            ensure!(post.author_id == who, ERROR_ACCOUNT_DOES_NOT_MATCH_POST_AUTHOR);
+            // Forum user does not match creator of post with identifier postId
+            ensure!(post.author_id == forum_user_id, ERROR_FORUM_USER_DOES_NOT_MATCH_POST_AUTHOR);
 
             /*
              * Here we are safe to mutate
@@ -733,8 +1225,8 @@ decl_module! {
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Signed by forum SUDO
-            Self::ensure_is_forum_sudo(&who)?;
+            // Signed by forum moderator
+            let moderator_id = Self::ensure_is_forum_moderator(&who)?;
 
             // Make sure post exists and is mutable
             let post = Self::ensure_post_is_mutable(post_id)?;
@@ -748,7 +1240,7 @@ decl_module! {
             // Update moderation action on post
             let moderation_action = ModerationAction{
                 moderated_at: common::current_block_time::<T>(),
-                moderator_id: who,
+                moderator_id,
                 rationale
             };
 
@@ -768,6 +1260,115 @@ decl_module! {
             Ok(())
         }
 
+        /// Vote in the poll of the thread, replacing the previous vote of the forum user if any
+        fn vote_on_poll(
+            origin,
+            forum_user_id: T::ForumUserId,
+            thread_id: T::ThreadId,
+            alternative_index: u32
+        ) -> dispatch::Result {
+
+            // Check that forum user is valid and signed the extrinsic
+            Self::ensure_is_forum_user(origin, &forum_user_id)?;
+
+            // Make sure thread exists and is mutable
+            let thread = Self::ensure_thread_is_mutable(thread_id)?;
+
+            // Get path from parent to root of category tree.
+            let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(thread.category_id)?;
+
+            // No ancestor is blocking us doing mutation in this category
+            Self::ensure_can_mutate_in_path_leaf(&category_tree_path)?;
+
+            // Thread has a poll
+            let poll = thread.poll.ok_or(ERROR_THREAD_HAS_NO_POLL)?;
+
+            // Poll has not ended yet
+            ensure!(<system::Module<T>>::block_number() < poll.end_block, ERROR_POLL_ENDED);
+
+            // Alternative exists
+            ensure!(
+                (alternative_index as usize) < poll.alternatives.len(),
+                ERROR_POLL_ALTERNATIVE_DOES_NOT_EXIST
+            );
+
+            let old_alternative_index = Self::poll_vote_by_forum_user(thread_id, forum_user_id);
+
+            // Vote is actually being changed
+            ensure!(
+                old_alternative_index != Some(alternative_index),
+                ERROR_POLL_VOTE_NOT_CHANGED
+            );
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            // Update poll tally
+            <ThreadById<T>>::mutate(thread_id, |t| {
+                if let Some(ref mut poll) = t.poll {
+                    if let Some(old_alternative_index) = old_alternative_index {
+                        poll.alternatives[old_alternative_index as usize].vote_count -= 1;
+                    }
+
+                    poll.alternatives[alternative_index as usize].vote_count += 1;
+                }
+            });
+
+            <PollVoteByForumUser<T>>::insert(thread_id, forum_user_id, alternative_index);
+
+            // Generate event
+            Self::deposit_event(RawEvent::PollVoted(thread_id, forum_user_id, alternative_index));
+
+            Ok(())
+        }
+
+        /// React to post, replacing the previous reaction of the forum user if any.
+        /// Passing `None` as the reaction removes the previous reaction.
+        fn react_to_post(
+            origin,
+            forum_user_id: T::ForumUserId,
+            post_id: T::PostId,
+            reaction: Option<PostReaction>
+        ) -> dispatch::Result {
+
+            // Check that forum user is valid and signed the extrinsic
+            Self::ensure_is_forum_user(origin, &forum_user_id)?;
+
+            // Make sure post exists and is mutable
+            Self::ensure_post_is_mutable(post_id)?;
+
+            let old_reaction = Self::post_reaction_by_forum_user(post_id, forum_user_id);
+
+            // Reaction is actually being changed
+            ensure!(old_reaction != reaction, ERROR_POST_REACTION_NOT_CHANGED);
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            // Update reaction counters of the post
+            <PostById<T>>::mutate(post_id, |p| {
+                if let Some(old_reaction) = old_reaction {
+                    p.reaction_counts.remove_reaction(old_reaction);
+                }
+
+                if let Some(reaction) = reaction {
+                    p.reaction_counts.add_reaction(reaction);
+                }
+            });
+
+            match reaction {
+                Some(reaction) => <PostReactionByForumUser<T>>::insert(post_id, forum_user_id, reaction),
+                None => <PostReactionByForumUser<T>>::remove(post_id, forum_user_id),
+            }
+
+            // Generate event
+            Self::deposit_event(RawEvent::PostReacted(post_id, forum_user_id, reaction));
+
+            Ok(())
+        }
+
     }
 }
 
@@ -820,9 +1421,36 @@ impl<T: Trait> Module<T> {
         )
     }
 
+    fn ensure_poll_is_valid(poll: &PollParameters<T::BlockNumber>) -> dispatch::Result {
+        ensure!(
+            poll.alternatives.len() >= MIN_POLL_ALTERNATIVES,
+            ERROR_POLL_ALTERNATIVES_TOO_FEW
+        );
+
+        ensure!(
+            poll.alternatives.len() <= MAX_POLL_ALTERNATIVES,
+            ERROR_POLL_ALTERNATIVES_TOO_MANY
+        );
+
+        for alternative in poll.alternatives.iter() {
+            PollAlternativeTextConstraint::get().ensure_valid(
+                alternative.len(),
+                ERROR_POLL_ALTERNATIVE_TEXT_TOO_SHORT,
+                ERROR_POLL_ALTERNATIVE_TEXT_TOO_LONG,
+            )?;
+        }
+
+        ensure!(
+            poll.end_block > <system::Module<T>>::block_number(),
+            ERROR_POLL_END_BLOCK_NOT_IN_FUTURE
+        );
+
+        Ok(())
+    }
+
     fn ensure_post_is_mutable(
         post_id: T::PostId,
-    ) -> Result<Post<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId, T::PostId>, &'static str>
+    ) -> Result<Post<T::BlockNumber, T::Moment, T::ForumUserId, T::ThreadId, T::PostId>, &'static str>
     {
         // Make sure post exists
         let post = Self::ensure_post_exists(post_id)?;
@@ -838,7 +1466,7 @@ impl<T: Trait> Module<T> {
 
     fn ensure_post_exists(
         post_id: T::PostId,
-    ) -> Result<Post<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId, T::PostId>, &'static str>
+    ) -> Result<Post<T::BlockNumber, T::Moment, T::ForumUserId, T::ThreadId, T::PostId>, &'static str>
     {
         if <PostById<T>>::exists(post_id) {
             Ok(<PostById<T>>::get(post_id))
@@ -849,7 +1477,7 @@ impl<T: Trait> Module<T> {
 
     fn ensure_thread_is_mutable(
         thread_id: T::ThreadId,
-    ) -> Result<Thread<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId>, &'static str> {
+    ) -> Result<Thread<T::BlockNumber, T::Moment, T::ForumUserId, T::ThreadId>, &'static str> {
         // Make sure thread exists
         let thread = Self::ensure_thread_exists(thread_id)?;
 
@@ -864,7 +1492,7 @@ impl<T: Trait> Module<T> {
 
     fn ensure_thread_exists(
         thread_id: T::ThreadId,
-    ) -> Result<Thread<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId>, &'static str> {
+    ) -> Result<Thread<T::BlockNumber, T::Moment, T::ForumUserId, T::ThreadId>, &'static str> {
         if <ThreadById<T>>::exists(thread_id) {
             Ok(<ThreadById<T>>::get(thread_id))
         } else {
@@ -872,33 +1500,31 @@ impl<T: Trait> Module<T> {
         }
     }
 
-    fn ensure_forum_sudo_set() -> Result<T::AccountId, &'static str> {
-        match <ForumSudo<T>>::get() {
-            Some(account_id) => Ok(account_id),
-            None => Err(ERROR_FORUM_SUDO_NOT_SET),
-        }
-    }
-
-    fn ensure_is_forum_sudo(account_id: &T::AccountId) -> dispatch::Result {
-        let forum_sudo_account = Self::ensure_forum_sudo_set()?;
-
+    fn ensure_is_forum_lead(account_id: &T::AccountId) -> dispatch::Result {
         ensure!(
-            *account_id == forum_sudo_account,
-            ERROR_ORIGIN_NOT_FORUM_SUDO
+            T::ModeratorRegistry::is_forum_lead(account_id),
+            ERROR_ORIGIN_NOT_FORUM_LEAD
         );
         Ok(())
     }
 
-    fn ensure_is_forum_member(
+    fn ensure_is_forum_moderator(
         account_id: &T::AccountId,
-    ) -> Result<ForumUser<T::AccountId>, &'static str> {
-        let forum_user_query = T::MembershipRegistry::get_forum_user(account_id);
+    ) -> Result<T::ForumUserId, &'static str> {
+        T::ModeratorRegistry::get_forum_moderator(account_id)
+            .ok_or(ERROR_ORIGIN_NOT_FORUM_MODERATOR)
+    }
 
-        if let Some(forum_user) = forum_user_query {
-            Ok(forum_user)
-        } else {
-            Err(ERROR_NOT_FORUM_USER)
-        }
+    fn ensure_is_forum_user(
+        origin: T::Origin,
+        forum_user_id: &T::ForumUserId,
+    ) -> Result<ForumUser<T::ForumUserId>, &'static str> {
+        let forum_user =
+            T::MembershipRegistry::get_forum_user(forum_user_id).ok_or(ERROR_NOT_FORUM_USER)?;
+
+        T::ForumUserOriginValidator::ensure_actor_origin(origin, *forum_user_id)?;
+
+        Ok(forum_user)
     }
 
     fn ensure_catgory_is_mutable(category_id: CategoryId) -> dispatch::Result {
@@ -907,12 +1533,8 @@ impl<T: Trait> Module<T> {
         Self::ensure_can_mutate_in_path_leaf(&category_tree_path)
     }
 
-    // TODO: remove post-Constaninople
-    // Clippy linter warning.
-    // Disable it because of possible frontend API break.
-    #[allow(clippy::ptr_arg)]
     fn ensure_can_mutate_in_path_leaf(
-        category_tree_path: &CategoryTreePath<T::BlockNumber, T::Moment, T::AccountId>,
+        category_tree_path: &[Category<T::BlockNumber, T::Moment, T::AccountId>],
     ) -> dispatch::Result {
         // Is parent category directly or indirectly deleted or archived category
         ensure!(
@@ -944,6 +1566,64 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    fn ensure_can_move_subcategory_to_path_leaf(
+        category_tree_path: &[Category<T::BlockNumber, T::Moment, T::AccountId>],
+        subcategory_tree_depth: usize,
+    ) -> dispatch::Result {
+        Self::ensure_can_mutate_in_path_leaf(category_tree_path)?;
+
+        // Does moving the category along with its subcategories exceed maximum depth
+        let depth_of_deepest_moved_category =
+            1 + 1 + category_tree_path.len() + subcategory_tree_depth;
+
+        ensure!(
+            depth_of_deepest_moved_category <= MAX_CATEGORY_DEPTH as usize,
+            ERROR_MAX_VALID_CATEGORY_DEPTH_EXCEEDED
+        );
+
+        Ok(())
+    }
+
+    /// Number of category levels below the category, 0 when it has no subcategories.
+    /// Requires that `category_id` is valid
+    fn subcategory_tree_depth(category_id: CategoryId) -> usize {
+        // Index the subcategories by their parent in a single pass over the categories
+        let mut subcategory_ids_by_parent_id: BTreeMap<CategoryId, Vec<CategoryId>> =
+            BTreeMap::new();
+
+        for id in 0..NextCategoryId::get() {
+            if !<CategoryById<T>>::exists(id) {
+                continue;
+            }
+
+            if let Some(position) = <CategoryById<T>>::get(id).position_in_parent_category {
+                subcategory_ids_by_parent_id
+                    .entry(position.parent_id)
+                    .or_insert_with(Vec::new)
+                    .push(id);
+            }
+        }
+
+        // Walk down the subcategory tree level by level
+        let mut depth = 0;
+        let mut level = vec![category_id];
+
+        loop {
+            level = level
+                .iter()
+                .filter_map(|id| subcategory_ids_by_parent_id.get(id))
+                .flatten()
+                .cloned()
+                .collect();
+
+            if level.is_empty() {
+                return depth;
+            }
+
+            depth += 1;
+        }
+    }
+
     fn ensure_valid_category_and_build_category_tree_path(
         category_id: CategoryId,
     ) -> Result<CategoryTreePath<T::BlockNumber, T::Moment, T::AccountId>, &'static str> {
@@ -1001,8 +1681,9 @@ impl<T: Trait> Module<T> {
     fn add_new_thread(
         category_id: CategoryId,
         title: &[u8],
-        author_id: &T::AccountId,
-    ) -> Thread<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId> {
+        author_id: &T::ForumUserId,
+        poll: Option<PollParameters<T::BlockNumber>>,
+    ) -> Thread<T::BlockNumber, T::Moment, T::ForumUserId, T::ThreadId> {
         // Get category
         let category = <CategoryById<T>>::get(category_id);
 
@@ -1018,7 +1699,19 @@ impl<T: Trait> Module<T> {
             num_unmoderated_posts: 0,
             num_moderated_posts: 0,
             created_at: common::current_block_time::<T>(),
-            author_id: author_id.clone(),
+            author_id: *author_id,
+            locked: false,
+            poll: poll.map(|poll| Poll {
+                end_block: poll.end_block,
+                alternatives: poll
+                    .alternatives
+                    .into_iter()
+                    .map(|text| PollAlternative {
+                        text,
+                        vote_count: 0,
+                    })
+                    .collect(),
+            }),
         };
 
         // Store thread
@@ -1042,8 +1735,8 @@ impl<T: Trait> Module<T> {
     fn add_new_post(
         thread_id: T::ThreadId,
         text: &[u8],
-        author_id: &T::AccountId,
-    ) -> Post<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId, T::PostId> {
+        author_id: &T::ForumUserId,
+    ) -> Post<T::BlockNumber, T::Moment, T::ForumUserId, T::ThreadId, T::PostId> {
         // Get thread
         let thread = <ThreadById<T>>::get(thread_id);
 
@@ -1058,7 +1751,8 @@ impl<T: Trait> Module<T> {
             moderation: None,
             text_change_history: vec![],
             created_at: common::current_block_time::<T>(),
-            author_id: author_id.clone(),
+            author_id: *author_id,
+            reaction_counts: PostReactionCounts::default(),
         };
 
         // Store post
@@ -1076,4 +1770,115 @@ impl<T: Trait> Module<T> {
 
         new_post
     }
+
+    /// Re-encodes the categories stored before subcategories and threads could be moved out of
+    /// a category. Migrated categories have nothing moved out.
+    pub fn migrate_categories() {
+        for category_id in 0..NextCategoryId::get() {
+            let key = <CategoryById<T> as generator::StorageMap<
+                CategoryId,
+                Category<T::BlockNumber, T::Moment, T::AccountId>,
+            >>::storage_map_final_key(category_id);
+
+            if let Some(old_category) = decode_old_layout::<
+                CategoryV1<T::BlockNumber, T::Moment, T::AccountId>,
+            >(key.as_ref())
+            {
+                <CategoryById<T>>::insert(
+                    category_id,
+                    Category {
+                        id: old_category.id,
+                        title: old_category.title,
+                        description: old_category.description,
+                        created_at: old_category.created_at,
+                        deleted: old_category.deleted,
+                        archived: old_category.archived,
+                        num_direct_subcategories: old_category.num_direct_subcategories,
+                        num_direct_unmoderated_threads: old_category.num_direct_unmoderated_threads,
+                        num_direct_moderated_threads: old_category.num_direct_moderated_threads,
+                        num_direct_moved_subcategories: 0,
+                        num_direct_moved_threads: 0,
+                        position_in_parent_category: old_category.position_in_parent_category,
+                        moderator_id: old_category.moderator_id,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Re-encodes the threads and posts stored when their authors and moderators were
+    /// identified by accounts, `forum_user_id` provides the forum user of an account.
+    /// Migrated threads are unlocked and have no poll, migrated posts have no reactions.
+    pub fn migrate_forum_user_ids<F: Fn(&T::AccountId) -> T::ForumUserId>(forum_user_id: F) {
+        let migrate_moderation =
+            |moderation: Option<ModerationActionV1<T::BlockNumber, T::Moment, T::AccountId>>| {
+                moderation.map(|action| ModerationAction {
+                    moderated_at: action.moderated_at,
+                    moderator_id: forum_user_id(&action.moderator_id),
+                    rationale: action.rationale,
+                })
+            };
+
+        let mut thread_id = T::ThreadId::zero();
+        while thread_id < Self::next_thread_id() {
+            let key = <ThreadById<T> as generator::StorageMap<
+                T::ThreadId,
+                Thread<T::BlockNumber, T::Moment, T::ForumUserId, T::ThreadId>,
+            >>::storage_map_final_key(thread_id);
+
+            if let Some(old_thread) = decode_old_layout::<
+                ThreadV1<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId>,
+            >(key.as_ref())
+            {
+                <ThreadById<T>>::insert(
+                    thread_id,
+                    Thread {
+                        id: old_thread.id,
+                        title: old_thread.title,
+                        category_id: old_thread.category_id,
+                        nr_in_category: old_thread.nr_in_category,
+                        moderation: migrate_moderation(old_thread.moderation),
+                        num_unmoderated_posts: old_thread.num_unmoderated_posts,
+                        num_moderated_posts: old_thread.num_moderated_posts,
+                        created_at: old_thread.created_at,
+                        author_id: forum_user_id(&old_thread.author_id),
+                        locked: false,
+                        poll: None,
+                    },
+                );
+            }
+
+            thread_id += One::one();
+        }
+
+        let mut post_id = T::PostId::zero();
+        while post_id < Self::next_post_id() {
+            let key = <PostById<T> as generator::StorageMap<
+                T::PostId,
+                Post<T::BlockNumber, T::Moment, T::ForumUserId, T::ThreadId, T::PostId>,
+            >>::storage_map_final_key(post_id);
+
+            if let Some(old_post) = decode_old_layout::<
+                PostV1<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId, T::PostId>,
+            >(key.as_ref())
+            {
+                <PostById<T>>::insert(
+                    post_id,
+                    Post {
+                        id: old_post.id,
+                        thread_id: old_post.thread_id,
+                        nr_in_thread: old_post.nr_in_thread,
+                        current_text: old_post.current_text,
+                        moderation: migrate_moderation(old_post.moderation),
+                        text_change_history: old_post.text_change_history,
+                        created_at: old_post.created_at,
+                        author_id: forum_user_id(&old_post.author_id),
+                        reaction_counts: PostReactionCounts::default(),
+                    },
+                );
+            }
+
+            post_id += One::one();
+        }
+    }
 }
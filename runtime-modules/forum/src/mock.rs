@@ -20,15 +20,20 @@ pub mod registry {
     use super::*;
     // use srml_support::*;
 
+    pub const ERROR_ORIGIN_NOT_FORUM_USER_CONTROLLER: &str =
+        "Origin does not match forum user controller account.";
+
     #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
-    pub struct Member<AccountId> {
-        pub id: AccountId,
+    pub struct Member<ForumUserId, AccountId> {
+        pub id: ForumUserId,
+        pub controller_account: AccountId,
+        pub suspended: bool,
     }
 
     decl_storage! {
         trait Store for Module<T: Trait> as MockForumUserRegistry {
 
-            pub ForumUserById get(forum_user_by_id) config(): map T::AccountId => Member<T::AccountId>;
+            pub ForumUserById get(forum_user_by_id) config(): map T::ForumUserId => Member<T::ForumUserId, T::AccountId>;
 
         }
     }
@@ -38,26 +43,77 @@ pub mod registry {
     }
 
     impl<T: Trait> Module<T> {
-        pub fn add_member(member: &Member<T::AccountId>) {
-            <ForumUserById<T>>::insert(member.id.clone(), member.clone());
+        pub fn add_member(member: &Member<T::ForumUserId, T::AccountId>) {
+            <ForumUserById<T>>::insert(member.id, member.clone());
+        }
+
+        pub fn set_controller_account(id: T::ForumUserId, controller_account: T::AccountId) {
+            <ForumUserById<T>>::mutate(id, |member| member.controller_account = controller_account);
+        }
+
+        pub fn suspend_member(id: T::ForumUserId) {
+            <ForumUserById<T>>::mutate(id, |member| member.suspended = true);
         }
     }
 
-    impl<T: Trait> ForumUserRegistry<T::AccountId> for Module<T> {
-        fn get_forum_user(id: &T::AccountId) -> Option<ForumUser<T::AccountId>> {
+    impl<T: Trait> ForumUserRegistry<T::ForumUserId> for Module<T> {
+        fn get_forum_user(id: &T::ForumUserId) -> Option<ForumUser<T::ForumUserId>> {
             if <ForumUserById<T>>::exists(id) {
                 let m = <ForumUserById<T>>::get(id);
 
-                Some(ForumUser { id: m.id })
+                if m.suspended {
+                    None
+                } else {
+                    Some(ForumUser { id: m.id })
+                }
             } else {
                 None
             }
         }
     }
 
+    impl<T: Trait> ActorOriginValidator<T::Origin, T::ForumUserId, T::AccountId> for Module<T> {
+        fn ensure_actor_origin(
+            origin: T::Origin,
+            forum_user_id: T::ForumUserId,
+        ) -> Result<T::AccountId, &'static str> {
+            let account_id = ensure_signed(origin)?;
+
+            if <ForumUserById<T>>::get(forum_user_id).controller_account == account_id {
+                Ok(account_id)
+            } else {
+                Err(ERROR_ORIGIN_NOT_FORUM_USER_CONTROLLER)
+            }
+        }
+    }
+
     pub type TestMembershipRegistryModule = Module<Runtime>;
 }
 
+/// Account of the forum lead.
+pub const FORUM_LEAD_ACCOUNT_ID: u64 = 33;
+
+/// Account of the forum worker, which is not the lead.
+pub const FORUM_MODERATOR_ACCOUNT_ID: u64 = 34;
+
+/// Mocks the forum working group with the lead and a single regular worker. The forum user ids
+/// of the workers match their accounts.
+pub struct MockForumModeratorRegistry;
+
+impl ForumModeratorRegistry<u64, u64> for MockForumModeratorRegistry {
+    fn is_forum_lead(id: &u64) -> bool {
+        *id == FORUM_LEAD_ACCOUNT_ID
+    }
+
+    fn get_forum_moderator(id: &u64) -> Option<u64> {
+        if *id == FORUM_LEAD_ACCOUNT_ID || *id == FORUM_MODERATOR_ACCOUNT_ID {
+            Some(*id)
+        } else {
+            None
+        }
+    }
+}
+
 impl_outer_origin! {
     pub enum Origin for Runtime {}
 }
@@ -100,7 +156,10 @@ impl timestamp::Trait for Runtime {
 
 impl Trait for Runtime {
     type Event = ();
+    type ForumUserId = u64;
+    type ForumUserOriginValidator = registry::TestMembershipRegistryModule;
     type MembershipRegistry = registry::TestMembershipRegistryModule;
+    type ModeratorRegistry = MockForumModeratorRegistry;
     type ThreadId = u64;
     type PostId = u64;
 }
@@ -120,10 +179,20 @@ pub fn mock_origin(origin: OriginType) -> mock::Origin {
     }
 }
 
-pub const NOT_FORUM_SUDO_ORIGIN: OriginType = OriginType::Signed(111);
+pub const FORUM_LEAD_ORIGIN: OriginType = OriginType::Signed(FORUM_LEAD_ACCOUNT_ID);
+
+pub const FORUM_MODERATOR_ORIGIN: OriginType = OriginType::Signed(FORUM_MODERATOR_ACCOUNT_ID);
+
+pub const NOT_FORUM_WORKER_ORIGIN: OriginType = OriginType::Signed(111);
+
+pub const FORUM_USER_ID: RuntimeForumUserId = 1;
+
+pub const FORUM_USER_ACCOUNT_ID: u64 = 123;
 
 pub const NOT_MEMBER_ORIGIN: OriginType = OriginType::Signed(222);
 
+pub const NOT_MEMBER_FORUM_USER_ID: RuntimeForumUserId = 222;
+
 pub const INVLAID_CATEGORY_ID: CategoryId = 333;
 
 pub const INVLAID_THREAD_ID: RuntimeThreadId = 444;
@@ -154,6 +223,13 @@ pub fn good_post_text() -> Vec<u8> {
     b"A response in the thread".to_vec()
 }
 
+pub fn good_poll() -> RuntimePollParameters {
+    PollParameters {
+        end_block: 10,
+        alternatives: vec![b"Yes".to_vec(), b"No".to_vec()],
+    }
+}
+
 pub fn good_rationale() -> Vec<u8> {
     b"This post violates our community rules".to_vec()
 }
@@ -209,9 +285,11 @@ impl UpdateCategoryFixture {
 
 pub struct CreateThreadFixture {
     pub origin: OriginType,
+    pub forum_user_id: RuntimeForumUserId,
     pub category_id: CategoryId,
     pub title: Vec<u8>,
     pub text: Vec<u8>,
+    pub poll: Option<RuntimePollParameters>,
     pub result: dispatch::Result,
 }
 
@@ -220,9 +298,11 @@ impl CreateThreadFixture {
         assert_eq!(
             TestForumModule::create_thread(
                 mock_origin(self.origin.clone()),
+                self.forum_user_id,
                 self.category_id,
                 self.title.clone(),
-                self.text.clone()
+                self.text.clone(),
+                self.poll.clone()
             ),
             self.result
         )
@@ -231,6 +311,7 @@ impl CreateThreadFixture {
 
 pub struct CreatePostFixture {
     pub origin: OriginType,
+    pub forum_user_id: RuntimeForumUserId,
     pub thread_id: RuntimeThreadId,
     pub text: Vec<u8>,
     pub result: dispatch::Result,
@@ -241,6 +322,7 @@ impl CreatePostFixture {
         assert_eq!(
             TestForumModule::add_post(
                 mock_origin(self.origin.clone()),
+                self.forum_user_id,
                 self.thread_id,
                 self.text.clone()
             ),
@@ -250,19 +332,22 @@ impl CreatePostFixture {
 }
 
 pub fn create_forum_member() -> OriginType {
-    let member_id = 123;
-    let new_member = registry::Member { id: member_id };
+    let new_member = registry::Member {
+        id: FORUM_USER_ID,
+        controller_account: FORUM_USER_ACCOUNT_ID,
+        suspended: false,
+    };
     registry::TestMembershipRegistryModule::add_member(&new_member);
-    OriginType::Signed(member_id)
+    OriginType::Signed(FORUM_USER_ACCOUNT_ID)
 }
 
 pub fn assert_create_category(
-    forum_sudo: OriginType,
+    forum_lead: OriginType,
     parent_category_id: Option<CategoryId>,
     expected_result: dispatch::Result,
 ) {
     CreateCategoryFixture {
-        origin: forum_sudo,
+        origin: forum_lead,
         parent: parent_category_id,
         title: good_category_title(),
         description: good_category_description(),
@@ -272,27 +357,30 @@ pub fn assert_create_category(
 }
 
 pub fn assert_create_thread(
-    forum_sudo: OriginType,
+    member_origin: OriginType,
     category_id: CategoryId,
     expected_result: dispatch::Result,
 ) {
     CreateThreadFixture {
-        origin: forum_sudo,
+        origin: member_origin,
+        forum_user_id: FORUM_USER_ID,
         category_id,
         title: good_thread_title(),
         text: good_thread_text(),
+        poll: None,
         result: expected_result,
     }
     .call_and_assert();
 }
 
 pub fn assert_create_post(
-    forum_sudo: OriginType,
+    member_origin: OriginType,
     thread_id: RuntimeThreadId,
     expected_result: dispatch::Result,
 ) {
     CreatePostFixture {
-        origin: forum_sudo,
+        origin: member_origin,
+        forum_user_id: FORUM_USER_ID,
         thread_id,
         text: good_thread_text(),
         result: expected_result,
@@ -301,30 +389,32 @@ pub fn assert_create_post(
 }
 
 pub fn create_category(
-    forum_sudo: OriginType,
+    forum_lead: OriginType,
     parent_category_id: Option<CategoryId>,
 ) -> CategoryId {
     let category_id = TestForumModule::next_category_id();
-    assert_create_category(forum_sudo, parent_category_id, Ok(()));
+    assert_create_category(forum_lead, parent_category_id, Ok(()));
     category_id
 }
 
-pub fn create_root_category(forum_sudo: OriginType) -> CategoryId {
-    create_category(forum_sudo, None)
+pub fn create_root_category(forum_lead: OriginType) -> CategoryId {
+    create_category(forum_lead, None)
 }
 
 pub fn create_root_category_and_thread(
-    forum_sudo: OriginType,
+    forum_lead: OriginType,
 ) -> (OriginType, CategoryId, RuntimeThreadId) {
     let member_origin = create_forum_member();
-    let category_id = create_root_category(forum_sudo);
+    let category_id = create_root_category(forum_lead);
     let thread_id = TestForumModule::next_thread_id();
 
     CreateThreadFixture {
         origin: member_origin.clone(),
+        forum_user_id: FORUM_USER_ID,
         category_id,
         title: good_thread_title(),
         text: good_thread_text(),
+        poll: None,
         result: Ok(()),
     }
     .call_and_assert();
@@ -333,13 +423,14 @@ pub fn create_root_category_and_thread(
 }
 
 pub fn create_root_category_and_thread_and_post(
-    forum_sudo: OriginType,
+    forum_lead: OriginType,
 ) -> (OriginType, CategoryId, RuntimeThreadId, RuntimePostId) {
-    let (member_origin, category_id, thread_id) = create_root_category_and_thread(forum_sudo);
+    let (member_origin, category_id, thread_id) = create_root_category_and_thread(forum_lead);
     let post_id = TestForumModule::next_post_id();
 
     CreatePostFixture {
         origin: member_origin.clone(),
+        forum_user_id: FORUM_USER_ID,
         thread_id: thread_id.clone(),
         text: good_post_text(),
         result: Ok(()),
@@ -350,48 +441,104 @@ pub fn create_root_category_and_thread_and_post(
 }
 
 pub fn moderate_thread(
-    forum_sudo: OriginType,
+    moderator: OriginType,
     thread_id: RuntimeThreadId,
     rationale: Vec<u8>,
 ) -> dispatch::Result {
-    TestForumModule::moderate_thread(mock_origin(forum_sudo), thread_id, rationale)
+    TestForumModule::moderate_thread(mock_origin(moderator), thread_id, rationale)
 }
 
 pub fn moderate_post(
-    forum_sudo: OriginType,
+    moderator: OriginType,
     post_id: RuntimePostId,
     rationale: Vec<u8>,
 ) -> dispatch::Result {
-    TestForumModule::moderate_post(mock_origin(forum_sudo), post_id, rationale)
+    TestForumModule::moderate_post(mock_origin(moderator), post_id, rationale)
 }
 
-pub fn archive_category(forum_sudo: OriginType, category_id: CategoryId) -> dispatch::Result {
-    TestForumModule::update_category(mock_origin(forum_sudo), category_id, Some(true), None)
+pub fn lock_thread(moderator: OriginType, thread_id: RuntimeThreadId) -> dispatch::Result {
+    TestForumModule::lock_thread(mock_origin(moderator), thread_id)
 }
 
-pub fn unarchive_category(forum_sudo: OriginType, category_id: CategoryId) -> dispatch::Result {
-    TestForumModule::update_category(mock_origin(forum_sudo), category_id, Some(false), None)
+pub fn unlock_thread(moderator: OriginType, thread_id: RuntimeThreadId) -> dispatch::Result {
+    TestForumModule::unlock_thread(mock_origin(moderator), thread_id)
 }
 
-pub fn delete_category(forum_sudo: OriginType, category_id: CategoryId) -> dispatch::Result {
-    TestForumModule::update_category(mock_origin(forum_sudo), category_id, None, Some(true))
+pub fn pin_thread(moderator: OriginType, thread_id: RuntimeThreadId) -> dispatch::Result {
+    TestForumModule::pin_thread(mock_origin(moderator), thread_id)
 }
 
-pub fn undelete_category(forum_sudo: OriginType, category_id: CategoryId) -> dispatch::Result {
-    TestForumModule::update_category(mock_origin(forum_sudo), category_id, None, Some(false))
+pub fn unpin_thread(moderator: OriginType, thread_id: RuntimeThreadId) -> dispatch::Result {
+    TestForumModule::unpin_thread(mock_origin(moderator), thread_id)
 }
 
-pub fn assert_not_forum_sudo_cannot_update_category(
+pub fn react_to_post(
+    member_origin: OriginType,
+    post_id: RuntimePostId,
+    reaction: Option<PostReaction>,
+) -> dispatch::Result {
+    TestForumModule::react_to_post(mock_origin(member_origin), FORUM_USER_ID, post_id, reaction)
+}
+
+pub fn vote_on_poll(
+    member_origin: OriginType,
+    thread_id: RuntimeThreadId,
+    alternative_index: u32,
+) -> dispatch::Result {
+    TestForumModule::vote_on_poll(
+        mock_origin(member_origin),
+        FORUM_USER_ID,
+        thread_id,
+        alternative_index,
+    )
+}
+
+pub fn move_thread(
+    moderator: OriginType,
+    thread_id: RuntimeThreadId,
+    new_category_id: CategoryId,
+) -> dispatch::Result {
+    TestForumModule::move_thread(mock_origin(moderator), thread_id, new_category_id)
+}
+
+pub fn move_category(
+    forum_lead: OriginType,
+    category_id: CategoryId,
+    new_parent: Option<CategoryId>,
+) -> dispatch::Result {
+    TestForumModule::move_category(mock_origin(forum_lead), category_id, new_parent)
+}
+
+pub fn archive_category(forum_lead: OriginType, category_id: CategoryId) -> dispatch::Result {
+    TestForumModule::update_category(mock_origin(forum_lead), category_id, Some(true), None)
+}
+
+pub fn unarchive_category(forum_lead: OriginType, category_id: CategoryId) -> dispatch::Result {
+    TestForumModule::update_category(mock_origin(forum_lead), category_id, Some(false), None)
+}
+
+pub fn delete_category(forum_lead: OriginType, category_id: CategoryId) -> dispatch::Result {
+    TestForumModule::update_category(mock_origin(forum_lead), category_id, None, Some(true))
+}
+
+pub fn undelete_category(forum_lead: OriginType, category_id: CategoryId) -> dispatch::Result {
+    TestForumModule::update_category(mock_origin(forum_lead), category_id, None, Some(false))
+}
+
+pub fn assert_not_forum_lead_cannot_update_category(
     update_operation: fn(OriginType, CategoryId) -> dispatch::Result,
 ) {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
 
     build_test_externalities(config).execute_with(|| {
-        let category_id = create_root_category(origin.clone());
+        let category_id = create_root_category(FORUM_LEAD_ORIGIN);
+        assert_eq!(
+            update_operation(NOT_FORUM_WORKER_ORIGIN, category_id),
+            Err(ERROR_ORIGIN_NOT_FORUM_LEAD)
+        );
         assert_eq!(
-            update_operation(NOT_FORUM_SUDO_ORIGIN, category_id),
-            Err(ERROR_ORIGIN_NOT_FORUM_SUDO)
+            update_operation(FORUM_MODERATOR_ORIGIN, category_id),
+            Err(ERROR_ORIGIN_NOT_FORUM_LEAD)
         );
     });
 }
@@ -412,8 +559,6 @@ pub fn default_genesis_config() -> GenesisConfig<Runtime> {
         post_by_id: vec![],
         next_post_id: 1,
 
-        forum_sudo: 33,
-
         category_title_constraint: InputValidationLengthConstraint {
             min: 10,
             max_min_diff: 140,
@@ -442,10 +587,12 @@ pub fn default_genesis_config() -> GenesisConfig<Runtime> {
         post_moderation_rationale_constraint: InputValidationLengthConstraint {
             min: 10,
             max_min_diff: 2000,
-        }, // JUST GIVING UP ON ALL THIS FOR NOW BECAUSE ITS TAKING TOO LONG
+        },
 
-           // Extra genesis fields
-           //initial_forum_sudo: Some(143)
+        poll_alternative_text_constraint: InputValidationLengthConstraint {
+            min: 1,
+            max_min_diff: 99,
+        }, // JUST GIVING UP ON ALL THIS FOR NOW BECAUSE ITS TAKING TOO LONG
     }
 }
 
@@ -458,21 +605,23 @@ pub type RuntimeCategory = Category<
 pub type RuntimeThread = Thread<
     <Runtime as system::Trait>::BlockNumber,
     <Runtime as timestamp::Trait>::Moment,
-    <Runtime as system::Trait>::AccountId,
+    RuntimeForumUserId,
     RuntimeThreadId,
 >;
 pub type RuntimePost = Post<
     <Runtime as system::Trait>::BlockNumber,
     <Runtime as timestamp::Trait>::Moment,
-    <Runtime as system::Trait>::AccountId,
+    RuntimeForumUserId,
     RuntimeThreadId,
     RuntimePostId,
 >;
+pub type RuntimePollParameters = PollParameters<<Runtime as system::Trait>::BlockNumber>;
 pub type RuntimeBlockchainTimestamp =
     BlockAndTime<<Runtime as system::Trait>::BlockNumber, <Runtime as timestamp::Trait>::Moment>;
 
 pub type RuntimeThreadId = <Runtime as Trait>::ThreadId;
 pub type RuntimePostId = <Runtime as Trait>::PostId;
+pub type RuntimeForumUserId = <Runtime as Trait>::ForumUserId;
 
 pub fn genesis_config(
     category_by_id: &RuntimeMap<CategoryId, RuntimeCategory>,
@@ -481,13 +630,13 @@ pub fn genesis_config(
     next_thread_id: u64,
     post_by_id: &RuntimeMap<RuntimePostId, RuntimePost>,
     next_post_id: u64,
-    forum_sudo: <Runtime as system::Trait>::AccountId,
     category_title_constraint: &InputValidationLengthConstraint,
     category_description_constraint: &InputValidationLengthConstraint,
     thread_title_constraint: &InputValidationLengthConstraint,
     post_text_constraint: &InputValidationLengthConstraint,
     thread_moderation_rationale_constraint: &InputValidationLengthConstraint,
     post_moderation_rationale_constraint: &InputValidationLengthConstraint,
+    poll_alternative_text_constraint: &InputValidationLengthConstraint,
 ) -> GenesisConfig<Runtime> {
     GenesisConfig::<Runtime> {
         category_by_id: category_by_id.clone(),
@@ -496,13 +645,13 @@ pub fn genesis_config(
         next_thread_id,
         post_by_id: post_by_id.clone(),
         next_post_id,
-        forum_sudo,
         category_title_constraint: category_title_constraint.clone(),
         category_description_constraint: category_description_constraint.clone(),
         thread_title_constraint: thread_title_constraint.clone(),
         post_text_constraint: post_text_constraint.clone(),
         thread_moderation_rationale_constraint: thread_moderation_rationale_constraint.clone(),
         post_moderation_rationale_constraint: post_moderation_rationale_constraint.clone(),
+        poll_alternative_text_constraint: poll_alternative_text_constraint.clone(),
     }
 }
 
@@ -9,64 +9,6 @@ use srml_support::{assert_err, assert_ok};
 * NB!: No test checks for event emission!!!!
 */
 
-/*
- * set_forum_sudo
- * ==============================================================================
- *
- * Missing cases
- *
- * set_forum_bad_origin
- *
- */
-
-#[test]
-fn set_forum_sudo_unset() {
-    let config = default_genesis_config();
-
-    build_test_externalities(config).execute_with(|| {
-        // Ensure that forum sudo is default
-        assert_eq!(TestForumModule::forum_sudo(), Some(33));
-
-        // Unset forum sudo
-        assert_ok!(TestForumModule::set_forum_sudo(
-            mock_origin(OriginType::Root),
-            None
-        ));
-
-        // Sudo no longer set
-        assert!(TestForumModule::forum_sudo().is_none());
-
-        // event emitted?!
-    });
-}
-
-#[test]
-fn set_forum_sudo_update() {
-    let config = default_genesis_config();
-
-    build_test_externalities(config).execute_with(|| {
-        // Ensure that forum sudo is default
-        assert_eq!(
-            TestForumModule::forum_sudo(),
-            Some(default_genesis_config().forum_sudo)
-        );
-
-        let new_forum_sudo_account_id = 780;
-
-        // Unset forum sudo
-        assert_ok!(TestForumModule::set_forum_sudo(
-            mock_origin(OriginType::Root),
-            Some(new_forum_sudo_account_id)
-        ));
-
-        // Sudo no longer set
-        assert_eq!(
-            TestForumModule::forum_sudo(),
-            Some(new_forum_sudo_account_id)
-        );
-    });
-}
-
 /*
  * create_category
  * ==============================================================================
@@ -74,13 +16,12 @@ fn set_forum_sudo_update() {
  * Missing cases
  *
  * create_category_bad_origin
- * create_category_forum_sudo_not_set
  */
 
 #[test]
 fn create_root_category_successfully() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
         assert_create_category(origin, None, Ok(()));
@@ -90,7 +31,7 @@ fn create_root_category_successfully() {
 #[test]
 fn create_subcategory_successfully() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
         let root_category_id = create_root_category(origin.clone());
@@ -101,7 +42,7 @@ fn create_subcategory_successfully() {
 #[test]
 fn create_category_title_too_short() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
     let min_len = config.category_title_constraint.min as usize;
 
     build_test_externalities(config).execute_with(|| {
@@ -119,7 +60,7 @@ fn create_category_title_too_short() {
 #[test]
 fn create_category_title_too_long() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
     let max_len = config.category_title_constraint.max() as usize;
 
     build_test_externalities(config).execute_with(|| {
@@ -137,7 +78,7 @@ fn create_category_title_too_long() {
 #[test]
 fn create_category_description_too_short() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
     let min_len = config.category_description_constraint.min as usize;
 
     build_test_externalities(config).execute_with(|| {
@@ -155,7 +96,7 @@ fn create_category_description_too_short() {
 #[test]
 fn create_category_description_too_long() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
     let max_len = config.category_description_constraint.max() as usize;
 
     build_test_externalities(config).execute_with(|| {
@@ -177,8 +118,6 @@ fn create_category_description_too_long() {
  * Missing cases
  *
  * create_category_bad_origin
- * create_category_forum_sudo_not_set
- * create_category_origin_not_forum_sudo
  * create_category_immutable_ancestor_category
  */
 
@@ -189,7 +128,7 @@ fn update_category_undelete_and_unarchive() {
      * leaf category is deleted, and then try to undelete.
      */
 
-    let forum_sudo = 32;
+    let forum_lead = FORUM_LEAD_ACCOUNT_ID;
 
     let created_at = RuntimeBlockchainTimestamp { block: 0, time: 0 };
 
@@ -208,7 +147,7 @@ fn update_category_undelete_and_unarchive() {
                 num_direct_unmoderated_threads: 0,
                 num_direct_moderated_threads: 0,
                 position_in_parent_category: None,
-                moderator_id: forum_sudo,
+                moderator_id: forum_lead,
             },
         ),
         // A subcategory of the one above
@@ -230,7 +169,7 @@ fn update_category_undelete_and_unarchive() {
                     parent_id: 1,
                     child_nr_in_parent_category: 1,
                 }),
-                moderator_id: forum_sudo,
+                moderator_id: forum_lead,
             },
         ),
     ];
@@ -248,7 +187,6 @@ fn update_category_undelete_and_unarchive() {
         1,                           // next_thread_id
         &vec![],                     // post_by_id
         1,                           // next_post_id
-        forum_sudo,
         &sloppy_constraint,
         &sloppy_constraint,
         &sloppy_constraint,
@@ -259,7 +197,7 @@ fn update_category_undelete_and_unarchive() {
 
     build_test_externalities(config).execute_with(|| {
         UpdateCategoryFixture {
-            origin: OriginType::Signed(forum_sudo),
+            origin: OriginType::Signed(forum_lead),
             category_id: 2,
             new_archival_status: None,        // same as before
             new_deletion_status: Some(false), // undelete
@@ -276,14 +214,13 @@ fn update_category_undelete_and_unarchive() {
  * Missing cases
  *
  * create_thread_bad_origin
- * create_thread_forum_sudo_not_set
  * ...
  */
 
 #[test]
 fn create_thread_successfully() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
         let category_id = create_root_category(origin);
@@ -291,9 +228,11 @@ fn create_thread_successfully() {
 
         CreateThreadFixture {
             origin: member_origin,
+            forum_user_id: FORUM_USER_ID,
             category_id,
             title: good_thread_title(),
             text: good_thread_text(),
+            poll: None,
             result: Ok(()),
         }
         .call_and_assert();
@@ -303,7 +242,7 @@ fn create_thread_successfully() {
 #[test]
 fn create_thread_title_too_short() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
     let min_len = config.thread_title_constraint.min as usize;
 
     build_test_externalities(config).execute_with(|| {
@@ -312,9 +251,11 @@ fn create_thread_title_too_short() {
 
         CreateThreadFixture {
             origin: member_origin,
+            forum_user_id: FORUM_USER_ID,
             category_id,
             title: generate_text(min_len - 1),
             text: good_thread_text(),
+            poll: None,
             result: Err(ERROR_THREAD_TITLE_TOO_SHORT),
         }
         .call_and_assert();
@@ -324,7 +265,7 @@ fn create_thread_title_too_short() {
 #[test]
 fn create_thread_title_too_long() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
     let max_len = config.thread_title_constraint.max() as usize;
 
     build_test_externalities(config).execute_with(|| {
@@ -333,9 +274,11 @@ fn create_thread_title_too_long() {
 
         CreateThreadFixture {
             origin: member_origin,
+            forum_user_id: FORUM_USER_ID,
             category_id,
             title: generate_text(max_len + 1),
             text: good_thread_text(),
+            poll: None,
             result: Err(ERROR_THREAD_TITLE_TOO_LONG),
         }
         .call_and_assert();
@@ -345,7 +288,7 @@ fn create_thread_title_too_long() {
 #[test]
 fn create_thread_text_too_short() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
     let min_len = config.post_text_constraint.min as usize;
 
     build_test_externalities(config).execute_with(|| {
@@ -354,9 +297,11 @@ fn create_thread_text_too_short() {
 
         CreateThreadFixture {
             origin: member_origin,
+            forum_user_id: FORUM_USER_ID,
             category_id,
             title: good_thread_title(),
             text: generate_text(min_len - 1),
+            poll: None,
             result: Err(ERROR_POST_TEXT_TOO_SHORT),
         }
         .call_and_assert();
@@ -366,7 +311,7 @@ fn create_thread_text_too_short() {
 #[test]
 fn create_thread_text_too_long() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
     let max_len = config.post_text_constraint.max() as usize;
 
     build_test_externalities(config).execute_with(|| {
@@ -375,9 +320,11 @@ fn create_thread_text_too_long() {
 
         CreateThreadFixture {
             origin: member_origin,
+            forum_user_id: FORUM_USER_ID,
             category_id,
             title: good_thread_title(),
             text: generate_text(max_len + 1),
+            poll: None,
             result: Err(ERROR_POST_TEXT_TOO_LONG),
         }
         .call_and_assert();
@@ -387,7 +334,7 @@ fn create_thread_text_too_long() {
 #[test]
 fn create_post_successfully() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
         let (_, _, _, _) = create_root_category_and_thread_and_post(origin);
@@ -397,7 +344,7 @@ fn create_post_successfully() {
 #[test]
 fn create_post_text_too_short() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
     let min_len = config.post_text_constraint.min as usize;
 
     build_test_externalities(config).execute_with(|| {
@@ -405,6 +352,7 @@ fn create_post_text_too_short() {
 
         CreatePostFixture {
             origin: member_origin,
+            forum_user_id: FORUM_USER_ID,
             thread_id,
             text: generate_text(min_len - 1),
             result: Err(ERROR_POST_TEXT_TOO_SHORT),
@@ -416,7 +364,7 @@ fn create_post_text_too_short() {
 #[test]
 fn create_post_text_too_long() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
     let max_len = config.post_text_constraint.max() as usize;
 
     build_test_externalities(config).execute_with(|| {
@@ -424,6 +372,7 @@ fn create_post_text_too_long() {
 
         CreatePostFixture {
             origin: member_origin,
+            forum_user_id: FORUM_USER_ID,
             thread_id,
             text: generate_text(max_len + 1),
             result: Err(ERROR_POST_TEXT_TOO_LONG),
@@ -438,7 +387,7 @@ fn create_post_text_too_long() {
 #[test]
 fn moderate_thread_successfully() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
         let (_, _, thread_id) = create_root_category_and_thread(origin.clone());
@@ -446,10 +395,23 @@ fn moderate_thread_successfully() {
     });
 }
 
+#[test]
+fn forum_moderator_can_moderate_thread() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread(FORUM_LEAD_ORIGIN);
+        assert_eq!(
+            moderate_thread(FORUM_MODERATOR_ORIGIN, thread_id, good_rationale()),
+            Ok(())
+        );
+    });
+}
+
 #[test]
 fn cannot_moderate_already_moderated_thread() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
         let (_, _, thread_id) = create_root_category_and_thread(origin.clone());
@@ -467,7 +429,7 @@ fn cannot_moderate_already_moderated_thread() {
 #[test]
 fn moderate_thread_rationale_too_short() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
     let min_len = config.thread_moderation_rationale_constraint.min as usize;
 
     build_test_externalities(config).execute_with(|| {
@@ -483,7 +445,7 @@ fn moderate_thread_rationale_too_short() {
 #[test]
 fn moderate_thread_rationale_too_long() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
     let max_len = config.thread_moderation_rationale_constraint.max() as usize;
 
     build_test_externalities(config).execute_with(|| {
@@ -499,7 +461,7 @@ fn moderate_thread_rationale_too_long() {
 #[test]
 fn moderate_post_successfully() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
         let (_, _, _, post_id) = create_root_category_and_thread_and_post(origin.clone());
@@ -507,10 +469,23 @@ fn moderate_post_successfully() {
     });
 }
 
+#[test]
+fn forum_moderator_can_moderate_post() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, _, post_id) = create_root_category_and_thread_and_post(FORUM_LEAD_ORIGIN);
+        assert_eq!(
+            moderate_post(FORUM_MODERATOR_ORIGIN, post_id, good_rationale()),
+            Ok(())
+        );
+    });
+}
+
 #[test]
 fn moderate_post_rationale_too_short() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
     let min_len = config.post_moderation_rationale_constraint.min as usize;
 
     build_test_externalities(config).execute_with(|| {
@@ -526,7 +501,7 @@ fn moderate_post_rationale_too_short() {
 #[test]
 fn moderate_post_rationale_too_long() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
     let max_len = config.post_moderation_rationale_constraint.max() as usize;
 
     build_test_externalities(config).execute_with(|| {
@@ -542,7 +517,7 @@ fn moderate_post_rationale_too_long() {
 #[test]
 fn cannot_moderate_already_moderated_post() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
         let (_, _, _, post_id) = create_root_category_and_thread_and_post(origin.clone());
@@ -557,81 +532,122 @@ fn cannot_moderate_already_moderated_post() {
     });
 }
 
-// Not a forum sudo:
+// Not a forum lead or moderator:
 // -----------------------------------------------------------------------------
 
 #[test]
-fn not_forum_sudo_cannot_create_root_category() {
+fn not_forum_lead_cannot_create_root_category() {
     let config = default_genesis_config();
 
     build_test_externalities(config).execute_with(|| {
         assert_create_category(
-            NOT_FORUM_SUDO_ORIGIN,
+            NOT_FORUM_WORKER_ORIGIN,
             None,
-            Err(ERROR_ORIGIN_NOT_FORUM_SUDO),
+            Err(ERROR_ORIGIN_NOT_FORUM_LEAD),
         );
     });
 }
 
 #[test]
-fn not_forum_sudo_cannot_create_subcategory() {
+fn not_forum_lead_cannot_create_subcategory() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
         let root_category_id = create_root_category(origin);
         assert_create_category(
-            NOT_FORUM_SUDO_ORIGIN,
+            NOT_FORUM_WORKER_ORIGIN,
             Some(root_category_id),
-            Err(ERROR_ORIGIN_NOT_FORUM_SUDO),
+            Err(ERROR_ORIGIN_NOT_FORUM_LEAD),
+        );
+    });
+}
+
+#[test]
+fn forum_moderator_cannot_create_category() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        assert_create_category(
+            FORUM_MODERATOR_ORIGIN,
+            None,
+            Err(ERROR_ORIGIN_NOT_FORUM_LEAD),
         );
     });
 }
 
 #[test]
-fn not_forum_sudo_cannot_archive_category() {
-    assert_not_forum_sudo_cannot_update_category(archive_category);
+fn not_forum_lead_cannot_archive_category() {
+    assert_not_forum_lead_cannot_update_category(archive_category);
 }
 
 #[test]
-fn not_forum_sudo_cannot_unarchive_category() {
-    assert_not_forum_sudo_cannot_update_category(unarchive_category);
+fn not_forum_lead_cannot_unarchive_category() {
+    assert_not_forum_lead_cannot_update_category(unarchive_category);
 }
 
 #[test]
-fn not_forum_sudo_cannot_delete_category() {
-    assert_not_forum_sudo_cannot_update_category(delete_category);
+fn not_forum_lead_cannot_delete_category() {
+    assert_not_forum_lead_cannot_update_category(delete_category);
 }
 
 #[test]
-fn not_forum_sudo_cannot_undelete_category() {
-    assert_not_forum_sudo_cannot_update_category(undelete_category);
+fn not_forum_lead_cannot_undelete_category() {
+    assert_not_forum_lead_cannot_update_category(undelete_category);
 }
 
 #[test]
-fn not_forum_sudo_cannot_moderate_thread() {
+fn not_forum_worker_cannot_moderate_thread() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
         let (_, _, thread_id) = create_root_category_and_thread(origin.clone());
         assert_eq!(
-            moderate_thread(NOT_FORUM_SUDO_ORIGIN, thread_id, good_rationale()),
-            Err(ERROR_ORIGIN_NOT_FORUM_SUDO)
+            moderate_thread(NOT_FORUM_WORKER_ORIGIN, thread_id, good_rationale()),
+            Err(ERROR_ORIGIN_NOT_FORUM_MODERATOR)
         );
     });
 }
 
 #[test]
-fn not_forum_sudo_cannot_moderate_post() {
+fn not_forum_worker_cannot_moderate_post() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
         let (_, _, _, post_id) = create_root_category_and_thread_and_post(origin.clone());
         assert_eq!(
-            moderate_post(NOT_FORUM_SUDO_ORIGIN, post_id, good_rationale()),
-            Err(ERROR_ORIGIN_NOT_FORUM_SUDO)
+            moderate_post(NOT_FORUM_WORKER_ORIGIN, post_id, good_rationale()),
+            Err(ERROR_ORIGIN_NOT_FORUM_MODERATOR)
+        );
+    });
+}
+
+#[test]
+fn not_forum_worker_cannot_lock_thread() {
+    let config = default_genesis_config();
+    let origin = FORUM_LEAD_ORIGIN;
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread(origin);
+        assert_eq!(
+            lock_thread(NOT_FORUM_WORKER_ORIGIN, thread_id),
+            Err(ERROR_ORIGIN_NOT_FORUM_MODERATOR)
+        );
+    });
+}
+
+#[test]
+fn not_forum_worker_cannot_pin_thread() {
+    let config = default_genesis_config();
+    let origin = FORUM_LEAD_ORIGIN;
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread(origin);
+        assert_eq!(
+            pin_thread(NOT_FORUM_WORKER_ORIGIN, thread_id),
+            Err(ERROR_ORIGIN_NOT_FORUM_MODERATOR)
         );
     });
 }
@@ -642,14 +658,16 @@ fn not_forum_sudo_cannot_moderate_post() {
 #[test]
 fn not_member_cannot_create_thread() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
         CreateThreadFixture {
             origin: NOT_MEMBER_ORIGIN,
+            forum_user_id: NOT_MEMBER_FORUM_USER_ID,
             category_id: create_root_category(origin),
             title: good_thread_title(),
             text: good_thread_text(),
+            poll: None,
             result: Err(ERROR_NOT_FORUM_USER),
         }
         .call_and_assert();
@@ -659,12 +677,13 @@ fn not_member_cannot_create_thread() {
 #[test]
 fn not_member_cannot_create_post() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
         let (_, _, thread_id) = create_root_category_and_thread(origin);
         CreatePostFixture {
             origin: NOT_MEMBER_ORIGIN,
+            forum_user_id: NOT_MEMBER_FORUM_USER_ID,
             thread_id,
             text: good_post_text(),
             result: Err(ERROR_NOT_FORUM_USER),
@@ -676,13 +695,14 @@ fn not_member_cannot_create_post() {
 #[test]
 fn not_member_cannot_edit_post() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
         let (_, _, _, post_id) = create_root_category_and_thread_and_post(origin);
         assert_err!(
             TestForumModule::edit_post_text(
                 mock_origin(NOT_MEMBER_ORIGIN),
+                NOT_MEMBER_FORUM_USER_ID,
                 post_id,
                 good_rationale()
             ),
@@ -691,264 +711,1179 @@ fn not_member_cannot_edit_post() {
     });
 }
 
-// Invalid id passed:
-// -----------------------------------------------------------------------------
-
 #[test]
-fn cannot_create_subcategory_with_invalid_parent_category_id() {
+fn not_member_cannot_react_to_post() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
-        assert_create_category(
-            origin,
-            Some(INVLAID_CATEGORY_ID),
-            Err(ERROR_CATEGORY_DOES_NOT_EXIST),
+        let (_, _, _, post_id) = create_root_category_and_thread_and_post(origin);
+        assert_err!(
+            TestForumModule::react_to_post(
+                mock_origin(NOT_MEMBER_ORIGIN),
+                NOT_MEMBER_FORUM_USER_ID,
+                post_id,
+                Some(PostReaction::Like)
+            ),
+            ERROR_NOT_FORUM_USER
         );
     });
 }
 
+// Forum user identity:
+// -----------------------------------------------------------------------------
+
 #[test]
-fn cannot_create_thread_with_invalid_category_id() {
+fn suspended_member_cannot_create_post() {
     let config = default_genesis_config();
+    let origin = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
-        CreateThreadFixture {
-            origin: create_forum_member(),
-            category_id: INVLAID_CATEGORY_ID,
-            title: good_thread_title(),
-            text: good_thread_text(),
-            result: Err(ERROR_CATEGORY_DOES_NOT_EXIST),
-        }
-        .call_and_assert();
+        let (member_origin, _, thread_id) = create_root_category_and_thread(origin);
+
+        registry::TestMembershipRegistryModule::suspend_member(FORUM_USER_ID);
+
+        assert_create_post(member_origin, thread_id, Err(ERROR_NOT_FORUM_USER));
     });
 }
 
 #[test]
-fn cannot_create_post_with_invalid_thread_id() {
+fn forum_user_cannot_post_with_other_account() {
     let config = default_genesis_config();
+    let origin = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
-        CreatePostFixture {
-            origin: create_forum_member(),
-            thread_id: INVLAID_THREAD_ID,
-            text: good_post_text(),
-            result: Err(ERROR_THREAD_DOES_NOT_EXIST),
-        }
-        .call_and_assert();
+        let (_, _, thread_id) = create_root_category_and_thread(origin);
+
+        assert_create_post(
+            NOT_MEMBER_ORIGIN,
+            thread_id,
+            Err(registry::ERROR_ORIGIN_NOT_FORUM_USER_CONTROLLER),
+        );
     });
 }
 
 #[test]
-fn cannot_moderate_thread_with_invalid_id() {
+fn forum_user_can_edit_post_after_controller_account_change() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
+        let (old_member_origin, _, _, post_id) = create_root_category_and_thread_and_post(origin);
+
+        let new_controller_account = 124;
+        registry::TestMembershipRegistryModule::set_controller_account(
+            FORUM_USER_ID,
+            new_controller_account,
+        );
+
+        assert_ok!(TestForumModule::edit_post_text(
+            mock_origin(OriginType::Signed(new_controller_account)),
+            FORUM_USER_ID,
+            post_id,
+            good_post_text()
+        ));
+
         assert_err!(
-            moderate_thread(origin, INVLAID_THREAD_ID, good_rationale()),
-            ERROR_THREAD_DOES_NOT_EXIST
+            TestForumModule::edit_post_text(
+                mock_origin(old_member_origin),
+                FORUM_USER_ID,
+                post_id,
+                good_post_text()
+            ),
+            registry::ERROR_ORIGIN_NOT_FORUM_USER_CONTROLLER
         );
     });
 }
 
 #[test]
-fn cannot_moderate_post_with_invalid_id() {
+fn forum_user_cannot_edit_post_of_other_forum_user() {
     let config = default_genesis_config();
-    let origin = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
+        let (_, _, _, post_id) = create_root_category_and_thread_and_post(origin);
+
+        let other_forum_user_id = 2;
+        let other_member_account = 125;
+        registry::TestMembershipRegistryModule::add_member(&registry::Member {
+            id: other_forum_user_id,
+            controller_account: other_member_account,
+            suspended: false,
+        });
+
         assert_err!(
-            moderate_post(origin, INVLAID_POST_ID, good_rationale()),
-            ERROR_POST_DOES_NOT_EXIST
+            TestForumModule::edit_post_text(
+                mock_origin(OriginType::Signed(other_member_account)),
+                other_forum_user_id,
+                post_id,
+                good_post_text()
+            ),
+            ERROR_FORUM_USER_DOES_NOT_MATCH_POST_AUTHOR
         );
     });
 }
 
-// Successfull extrinsics
-// -----------------------------------------------------------------------------
-
 #[test]
-fn archive_then_unarchive_category_successfully() {
+fn moderation_records_moderator_forum_user() {
     let config = default_genesis_config();
-    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let origin = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
-        let category_id = create_root_category(forum_sudo.clone());
-        assert_ok!(archive_category(forum_sudo.clone(), category_id.clone(),));
-        // TODO get category by id and assert archived == true.
+        let (_, _, thread_id, post_id) = create_root_category_and_thread_and_post(origin);
 
-        assert_ok!(unarchive_category(forum_sudo, category_id,));
-        // TODO get category by id and assert archived == false.
+        assert_eq!(
+            TestForumModule::thread_by_id(thread_id).author_id,
+            FORUM_USER_ID
+        );
+        assert_eq!(
+            TestForumModule::post_by_id(post_id).author_id,
+            FORUM_USER_ID
+        );
+
+        assert_ok!(moderate_post(
+            FORUM_MODERATOR_ORIGIN,
+            post_id,
+            good_rationale()
+        ));
+
+        let moderation = TestForumModule::post_by_id(post_id).moderation.unwrap();
+        assert_eq!(moderation.moderator_id, FORUM_MODERATOR_ACCOUNT_ID);
     });
 }
 
+// Thread locking and pinning:
+// -----------------------------------------------------------------------------
+
 #[test]
-fn delete_then_undelete_category_successfully() {
+fn forum_moderator_can_lock_and_unlock_thread() {
     let config = default_genesis_config();
-    let forum_sudo = OriginType::Signed(config.forum_sudo);
 
     build_test_externalities(config).execute_with(|| {
-        let category_id = create_root_category(forum_sudo.clone());
-        assert_ok!(delete_category(forum_sudo.clone(), category_id.clone(),));
-        // TODO get category by id and assert deleted == true.
+        let (_, _, thread_id) = create_root_category_and_thread(FORUM_LEAD_ORIGIN);
 
-        assert_ok!(undelete_category(forum_sudo.clone(), category_id.clone(),));
-        // TODO get category by id and assert deleted == false.
+        assert_ok!(lock_thread(FORUM_MODERATOR_ORIGIN, thread_id));
+        assert!(TestForumModule::thread_by_id(thread_id).locked);
+
+        assert_ok!(unlock_thread(FORUM_MODERATOR_ORIGIN, thread_id));
+        assert!(!TestForumModule::thread_by_id(thread_id).locked);
     });
 }
 
-// TODO Consider to fix the logic of the forum module
-// #[test]
-// fn cannot_unarchive_not_archived_category() {
-//     let config = default_genesis_config();
-//     let forum_sudo = OriginType::Signed(config.forum_sudo);
-
-//     build_test_externalities(config).execute_with(|| {
-//         let category_id = create_root_category(forum_sudo.clone());
-
-//         // TODO bug in a logic! it should not be possible. !!!
-
-//         assert_err!(
-//             archive_category(
-//                 forum_sudo.clone(),
-//                 category_id.clone(),
-//             ),
-//             "... TODO expect error ..."
-//         );
-//     });
-// }
-
-// TODO Consider to fix the logic of the forum module
-// #[test]
-// fn cannot_undelete_not_deleted_category() {
-//     let config = default_genesis_config();
-//     let forum_sudo = OriginType::Signed(config.forum_sudo);
-
-//     build_test_externalities(config).execute_with(|| {
-//         let category_id = create_root_category(forum_sudo.clone());
-//         assert_err!(
-//             delete_category(
-//                 forum_sudo.clone(),
-//                 category_id.clone(),
-//             ),
-//             "... TODO expect error ..."
-//         );
-//     });
-// }
-
-// With archived / deleted category, moderated thread
-// -----------------------------------------------------------------------------
-
 #[test]
-fn cannot_create_subcategory_in_archived_category() {
+fn cannot_add_post_to_locked_thread() {
     let config = default_genesis_config();
-    let forum_sudo = OriginType::Signed(config.forum_sudo);
 
     build_test_externalities(config).execute_with(|| {
-        let category_id = create_root_category(forum_sudo.clone());
-        assert_ok!(archive_category(forum_sudo.clone(), category_id.clone(),));
-        assert_create_category(
-            forum_sudo,
-            Some(category_id),
-            Err(ERROR_ANCESTOR_CATEGORY_IMMUTABLE),
-        );
+        let (member_origin, _, thread_id) = create_root_category_and_thread(FORUM_LEAD_ORIGIN);
+
+        assert_ok!(lock_thread(FORUM_MODERATOR_ORIGIN, thread_id));
+        assert_create_post(member_origin.clone(), thread_id, Err(ERROR_THREAD_LOCKED));
+
+        // Locking is not a moderation
+        let thread = TestForumModule::thread_by_id(thread_id);
+        assert!(thread.moderation.is_none());
+        assert!(!thread.locked);
+        assert!(thread.poll.is_none());
+
+        assert_ok!(unlock_thread(FORUM_MODERATOR_ORIGIN, thread_id));
+        assert_create_post(member_origin, thread_id, Ok(()));
     });
 }
 
 #[test]
-fn cannot_create_subcategory_in_deleted_category() {
+fn cannot_lock_already_locked_thread() {
     let config = default_genesis_config();
-    let forum_sudo = OriginType::Signed(config.forum_sudo);
 
     build_test_externalities(config).execute_with(|| {
-        let category_id = create_root_category(forum_sudo.clone());
-        assert_ok!(delete_category(forum_sudo.clone(), category_id.clone(),));
-        assert_create_category(
-            forum_sudo,
-            Some(category_id),
-            Err(ERROR_ANCESTOR_CATEGORY_IMMUTABLE),
+        let (_, _, thread_id) = create_root_category_and_thread(FORUM_LEAD_ORIGIN);
+
+        assert_ok!(lock_thread(FORUM_MODERATOR_ORIGIN, thread_id));
+        assert_eq!(
+            lock_thread(FORUM_MODERATOR_ORIGIN, thread_id),
+            Err(ERROR_THREAD_ALREADY_LOCKED)
         );
     });
 }
 
 #[test]
-fn cannot_create_thread_in_archived_category() {
+fn cannot_unlock_not_locked_thread() {
     let config = default_genesis_config();
-    let forum_sudo = OriginType::Signed(config.forum_sudo);
 
     build_test_externalities(config).execute_with(|| {
-        let category_id = create_root_category(forum_sudo.clone());
-        assert_ok!(archive_category(forum_sudo.clone(), category_id.clone(),));
-        assert_create_thread(
-            create_forum_member(),
-            category_id,
-            Err(ERROR_ANCESTOR_CATEGORY_IMMUTABLE),
+        let (_, _, thread_id) = create_root_category_and_thread(FORUM_LEAD_ORIGIN);
+
+        assert_eq!(
+            unlock_thread(FORUM_MODERATOR_ORIGIN, thread_id),
+            Err(ERROR_THREAD_NOT_LOCKED)
         );
     });
 }
 
 #[test]
-fn cannot_create_thread_in_deleted_category() {
+fn forum_moderator_can_pin_and_unpin_thread() {
     let config = default_genesis_config();
-    let forum_sudo = OriginType::Signed(config.forum_sudo);
 
     build_test_externalities(config).execute_with(|| {
-        let category_id = create_root_category(forum_sudo.clone());
-        assert_ok!(delete_category(forum_sudo.clone(), category_id.clone(),));
-        assert_create_thread(
-            create_forum_member(),
-            category_id,
-            Err(ERROR_ANCESTOR_CATEGORY_IMMUTABLE),
+        let (_, category_id, thread_id) = create_root_category_and_thread(FORUM_LEAD_ORIGIN);
+
+        assert_ok!(pin_thread(FORUM_MODERATOR_ORIGIN, thread_id));
+        assert_eq!(
+            TestForumModule::pinned_thread_ids_by_category(category_id),
+            vec![thread_id]
+        );
+        assert_eq!(
+            pin_thread(FORUM_MODERATOR_ORIGIN, thread_id),
+            Err(ERROR_THREAD_ALREADY_PINNED)
+        );
+
+        assert_ok!(unpin_thread(FORUM_MODERATOR_ORIGIN, thread_id));
+        assert!(TestForumModule::pinned_thread_ids_by_category(category_id).is_empty());
+        assert_eq!(
+            unpin_thread(FORUM_MODERATOR_ORIGIN, thread_id),
+            Err(ERROR_THREAD_NOT_PINNED)
         );
     });
 }
 
 #[test]
-fn cannot_create_post_in_thread_of_archived_category() {
+fn cannot_pin_more_threads_than_max_in_category() {
     let config = default_genesis_config();
-    let forum_sudo = OriginType::Signed(config.forum_sudo);
 
     build_test_externalities(config).execute_with(|| {
-        let category_id = create_root_category(forum_sudo.clone());
+        let (member_origin, category_id, _) = create_root_category_and_thread(FORUM_LEAD_ORIGIN);
+
+        for _ in 0..MAX_PINNED_THREADS_IN_CATEGORY {
+            let thread_id = TestForumModule::next_thread_id();
+            assert_create_thread(member_origin.clone(), category_id, Ok(()));
+            assert_ok!(pin_thread(FORUM_MODERATOR_ORIGIN, thread_id));
+        }
+
         let thread_id = TestForumModule::next_thread_id();
-        assert_create_thread(create_forum_member(), category_id, Ok(()));
-        assert_ok!(archive_category(forum_sudo.clone(), category_id.clone(),));
-        assert_create_post(
-            create_forum_member(),
-            thread_id,
-            Err(ERROR_ANCESTOR_CATEGORY_IMMUTABLE),
+        assert_create_thread(member_origin, category_id, Ok(()));
+        assert_eq!(
+            pin_thread(FORUM_MODERATOR_ORIGIN, thread_id),
+            Err(ERROR_MAX_PINNED_THREADS_EXCEEDED)
         );
     });
 }
 
 #[test]
-fn cannot_create_post_in_thread_of_deleted_category() {
+fn moderated_thread_is_unpinned() {
     let config = default_genesis_config();
-    let forum_sudo = OriginType::Signed(config.forum_sudo);
 
     build_test_externalities(config).execute_with(|| {
-        let category_id = create_root_category(forum_sudo.clone());
-        let thread_id = TestForumModule::next_thread_id();
-        assert_create_thread(create_forum_member(), category_id, Ok(()));
-        assert_ok!(delete_category(forum_sudo.clone(), category_id.clone(),));
-        assert_create_post(
-            create_forum_member(),
+        let (_, category_id, thread_id) = create_root_category_and_thread(FORUM_LEAD_ORIGIN);
+
+        assert_ok!(pin_thread(FORUM_MODERATOR_ORIGIN, thread_id));
+        assert_ok!(moderate_thread(
+            FORUM_MODERATOR_ORIGIN,
             thread_id,
-            Err(ERROR_ANCESTOR_CATEGORY_IMMUTABLE),
-        );
+            good_rationale()
+        ));
+        assert!(TestForumModule::pinned_thread_ids_by_category(category_id).is_empty());
+    });
+}
+
+#[test]
+fn thread_in_archived_category_can_be_unpinned() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, category_id, thread_id) = create_root_category_and_thread(FORUM_LEAD_ORIGIN);
+
+        assert_ok!(pin_thread(FORUM_MODERATOR_ORIGIN, thread_id));
+        assert_ok!(archive_category(FORUM_LEAD_ORIGIN, category_id));
+
+        assert_ok!(unpin_thread(FORUM_MODERATOR_ORIGIN, thread_id));
+        assert!(TestForumModule::pinned_thread_ids_by_category(category_id).is_empty());
+    });
+}
+
+// Thread polls:
+// -----------------------------------------------------------------------------
+
+fn create_root_category_and_thread_with_poll(
+    poll: RuntimePollParameters,
+    expected_result: dispatch::Result,
+) -> (OriginType, CategoryId, RuntimeThreadId) {
+    let member_origin = create_forum_member();
+    let category_id = create_root_category(FORUM_LEAD_ORIGIN);
+    let thread_id = TestForumModule::next_thread_id();
+
+    CreateThreadFixture {
+        origin: member_origin.clone(),
+        forum_user_id: FORUM_USER_ID,
+        category_id,
+        title: good_thread_title(),
+        text: good_thread_text(),
+        poll: Some(poll),
+        result: expected_result,
+    }
+    .call_and_assert();
+
+    (member_origin, category_id, thread_id)
+}
+
+fn poll_vote_counts(thread_id: RuntimeThreadId) -> Vec<u32> {
+    TestForumModule::thread_by_id(thread_id)
+        .poll
+        .unwrap()
+        .alternatives
+        .iter()
+        .map(|alternative| alternative.vote_count)
+        .collect()
+}
+
+#[test]
+fn create_thread_with_poll_successfully() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread_with_poll(good_poll(), Ok(()));
+
+        let poll = TestForumModule::thread_by_id(thread_id).poll.unwrap();
+        assert_eq!(poll.end_block, good_poll().end_block);
+        assert_eq!(poll.alternatives[0].text, good_poll().alternatives[0]);
+        assert_eq!(poll_vote_counts(thread_id), vec![0, 0]);
+    });
+}
+
+#[test]
+fn cannot_create_thread_with_poll_with_too_few_alternatives() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let poll = PollParameters {
+            alternatives: vec![b"Yes".to_vec()],
+            ..good_poll()
+        };
+        create_root_category_and_thread_with_poll(poll, Err(ERROR_POLL_ALTERNATIVES_TOO_FEW));
+    });
+}
+
+#[test]
+fn cannot_create_thread_with_poll_with_too_many_alternatives() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let poll = PollParameters {
+            alternatives: vec![b"Maybe".to_vec(); MAX_POLL_ALTERNATIVES + 1],
+            ..good_poll()
+        };
+        create_root_category_and_thread_with_poll(poll, Err(ERROR_POLL_ALTERNATIVES_TOO_MANY));
+    });
+}
+
+#[test]
+fn cannot_create_thread_with_poll_alternative_text_too_long() {
+    let config = default_genesis_config();
+    let max_len = config.poll_alternative_text_constraint.max() as usize;
+
+    build_test_externalities(config).execute_with(|| {
+        let poll = PollParameters {
+            alternatives: vec![b"Yes".to_vec(), generate_text(max_len + 1)],
+            ..good_poll()
+        };
+        create_root_category_and_thread_with_poll(poll, Err(ERROR_POLL_ALTERNATIVE_TEXT_TOO_LONG));
+    });
+}
+
+#[test]
+fn cannot_create_thread_with_poll_ending_in_past() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        system::Module::<Runtime>::set_block_number(good_poll().end_block);
+        create_root_category_and_thread_with_poll(
+            good_poll(),
+            Err(ERROR_POLL_END_BLOCK_NOT_IN_FUTURE),
+        );
+    });
+}
+
+#[test]
+fn forum_user_can_vote_and_change_vote_in_poll() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) =
+            create_root_category_and_thread_with_poll(good_poll(), Ok(()));
+
+        assert_ok!(vote_on_poll(member_origin.clone(), thread_id, 0));
+        assert_eq!(poll_vote_counts(thread_id), vec![1, 0]);
+        assert_eq!(
+            TestForumModule::poll_vote_by_forum_user(thread_id, FORUM_USER_ID),
+            Some(0)
+        );
+
+        assert_ok!(vote_on_poll(member_origin.clone(), thread_id, 1));
+        assert_eq!(poll_vote_counts(thread_id), vec![0, 1]);
+
+        assert_eq!(
+            vote_on_poll(member_origin, thread_id, 1),
+            Err(ERROR_POLL_VOTE_NOT_CHANGED)
+        );
+    });
+}
+
+#[test]
+fn cannot_vote_in_ended_poll() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) =
+            create_root_category_and_thread_with_poll(good_poll(), Ok(()));
+
+        system::Module::<Runtime>::set_block_number(good_poll().end_block);
+        assert_eq!(
+            vote_on_poll(member_origin, thread_id, 0),
+            Err(ERROR_POLL_ENDED)
+        );
+    });
+}
+
+#[test]
+fn cannot_vote_for_nonexistent_poll_alternative() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) =
+            create_root_category_and_thread_with_poll(good_poll(), Ok(()));
+
+        assert_eq!(
+            vote_on_poll(member_origin, thread_id, 2),
+            Err(ERROR_POLL_ALTERNATIVE_DOES_NOT_EXIST)
+        );
+    });
+}
+
+#[test]
+fn cannot_vote_in_thread_without_poll() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(FORUM_LEAD_ORIGIN);
+
+        assert_eq!(
+            vote_on_poll(member_origin, thread_id, 0),
+            Err(ERROR_THREAD_HAS_NO_POLL)
+        );
+    });
+}
+
+#[test]
+fn cannot_vote_in_poll_of_moderated_thread() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) =
+            create_root_category_and_thread_with_poll(good_poll(), Ok(()));
+
+        assert_ok!(moderate_thread(
+            FORUM_MODERATOR_ORIGIN,
+            thread_id,
+            good_rationale()
+        ));
+        assert_eq!(
+            vote_on_poll(member_origin, thread_id, 0),
+            Err(ERROR_THREAD_MODERATED)
+        );
+    });
+}
+
+#[test]
+fn cannot_vote_in_poll_in_archived_category() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, category_id, thread_id) =
+            create_root_category_and_thread_with_poll(good_poll(), Ok(()));
+
+        assert_ok!(archive_category(FORUM_LEAD_ORIGIN, category_id));
+        assert_eq!(
+            vote_on_poll(member_origin, thread_id, 0),
+            Err(ERROR_ANCESTOR_CATEGORY_IMMUTABLE)
+        );
+    });
+}
+
+#[test]
+fn cannot_vote_in_poll_in_archived_ancestor_category() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let member_origin = create_forum_member();
+        let root_category_id = create_root_category(FORUM_LEAD_ORIGIN);
+        let category_id = create_category(FORUM_LEAD_ORIGIN, Some(root_category_id));
+        let thread_id = TestForumModule::next_thread_id();
+
+        CreateThreadFixture {
+            origin: member_origin.clone(),
+            forum_user_id: FORUM_USER_ID,
+            category_id,
+            title: good_thread_title(),
+            text: good_thread_text(),
+            poll: Some(good_poll()),
+            result: Ok(()),
+        }
+        .call_and_assert();
+
+        assert_ok!(archive_category(FORUM_LEAD_ORIGIN, root_category_id));
+        assert_eq!(
+            vote_on_poll(member_origin, thread_id, 0),
+            Err(ERROR_ANCESTOR_CATEGORY_IMMUTABLE)
+        );
+    });
+}
+
+// Moving threads and categories:
+// -----------------------------------------------------------------------------
+
+#[test]
+fn forum_moderator_can_move_thread() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, old_category_id, thread_id) =
+            create_root_category_and_thread(FORUM_LEAD_ORIGIN);
+        let new_category_id = create_root_category(FORUM_LEAD_ORIGIN);
+        assert_create_thread(member_origin, new_category_id, Ok(()));
+
+        assert_ok!(move_thread(
+            FORUM_MODERATOR_ORIGIN,
+            thread_id,
+            new_category_id
+        ));
+
+        let thread = TestForumModule::thread_by_id(thread_id);
+        assert_eq!(thread.category_id, new_category_id);
+        assert_eq!(thread.nr_in_category, 2);
+
+        let old_category = TestForumModule::category_by_id(old_category_id);
+        assert_eq!(old_category.num_direct_unmoderated_threads, 0);
+        assert_eq!(old_category.num_direct_moved_threads, 1);
+        assert_eq!(old_category.num_threads_created(), 1);
+
+        let new_category = TestForumModule::category_by_id(new_category_id);
+        assert_eq!(new_category.num_direct_unmoderated_threads, 2);
+        assert_eq!(new_category.num_threads_created(), 2);
+    });
+}
+
+#[test]
+fn thread_created_after_move_gets_next_nr_in_category() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, old_category_id, thread_id) =
+            create_root_category_and_thread(FORUM_LEAD_ORIGIN);
+        let new_category_id = create_root_category(FORUM_LEAD_ORIGIN);
+
+        assert_ok!(move_thread(
+            FORUM_MODERATOR_ORIGIN,
+            thread_id,
+            new_category_id
+        ));
+
+        let next_thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin, old_category_id, Ok(()));
+        assert_eq!(
+            TestForumModule::thread_by_id(next_thread_id).nr_in_category,
+            2
+        );
+    });
+}
+
+#[test]
+fn moved_thread_is_unpinned() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, old_category_id, thread_id) = create_root_category_and_thread(FORUM_LEAD_ORIGIN);
+        let new_category_id = create_root_category(FORUM_LEAD_ORIGIN);
+
+        assert_ok!(pin_thread(FORUM_MODERATOR_ORIGIN, thread_id));
+        assert_ok!(move_thread(
+            FORUM_MODERATOR_ORIGIN,
+            thread_id,
+            new_category_id
+        ));
+        assert!(TestForumModule::pinned_thread_ids_by_category(old_category_id).is_empty());
+    });
+}
+
+#[test]
+fn not_forum_worker_cannot_move_thread() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread(FORUM_LEAD_ORIGIN);
+        let new_category_id = create_root_category(FORUM_LEAD_ORIGIN);
+
+        assert_eq!(
+            move_thread(NOT_FORUM_WORKER_ORIGIN, thread_id, new_category_id),
+            Err(ERROR_ORIGIN_NOT_FORUM_MODERATOR)
+        );
+    });
+}
+
+#[test]
+fn cannot_move_thread_to_its_category() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, category_id, thread_id) = create_root_category_and_thread(FORUM_LEAD_ORIGIN);
+
+        assert_eq!(
+            move_thread(FORUM_MODERATOR_ORIGIN, thread_id, category_id),
+            Err(ERROR_THREAD_ALREADY_IN_CATEGORY)
+        );
+    });
+}
+
+#[test]
+fn cannot_move_thread_to_archived_category() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread(FORUM_LEAD_ORIGIN);
+        let new_category_id = create_root_category(FORUM_LEAD_ORIGIN);
+        assert_ok!(archive_category(FORUM_LEAD_ORIGIN, new_category_id));
+
+        assert_eq!(
+            move_thread(FORUM_MODERATOR_ORIGIN, thread_id, new_category_id),
+            Err(ERROR_ANCESTOR_CATEGORY_IMMUTABLE)
+        );
+    });
+}
+
+#[test]
+fn cannot_move_moderated_thread() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread(FORUM_LEAD_ORIGIN);
+        let new_category_id = create_root_category(FORUM_LEAD_ORIGIN);
+        assert_ok!(moderate_thread(
+            FORUM_MODERATOR_ORIGIN,
+            thread_id,
+            good_rationale()
+        ));
+
+        assert_eq!(
+            move_thread(FORUM_MODERATOR_ORIGIN, thread_id, new_category_id),
+            Err(ERROR_THREAD_MODERATED)
+        );
+    });
+}
+
+#[test]
+fn forum_lead_can_move_category() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let old_parent_id = create_root_category(FORUM_LEAD_ORIGIN);
+        let category_id = create_category(FORUM_LEAD_ORIGIN, Some(old_parent_id));
+        let new_parent_id = create_root_category(FORUM_LEAD_ORIGIN);
+
+        assert_ok!(move_category(
+            FORUM_LEAD_ORIGIN,
+            category_id,
+            Some(new_parent_id)
+        ));
+
+        let position = TestForumModule::category_by_id(category_id)
+            .position_in_parent_category
+            .unwrap();
+        assert_eq!(position.parent_id, new_parent_id);
+        assert_eq!(position.child_nr_in_parent_category, 1);
+
+        let old_parent = TestForumModule::category_by_id(old_parent_id);
+        assert_eq!(old_parent.num_direct_subcategories, 1);
+        assert_eq!(old_parent.num_direct_moved_subcategories, 1);
+
+        let new_parent = TestForumModule::category_by_id(new_parent_id);
+        assert_eq!(new_parent.num_direct_subcategories, 1);
+    });
+}
+
+#[test]
+fn forum_lead_can_move_category_to_root() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let parent_id = create_root_category(FORUM_LEAD_ORIGIN);
+        let category_id = create_category(FORUM_LEAD_ORIGIN, Some(parent_id));
+
+        assert_ok!(move_category(FORUM_LEAD_ORIGIN, category_id, None));
+        assert!(TestForumModule::category_by_id(category_id)
+            .position_in_parent_category
+            .is_none());
+        assert_eq!(
+            move_category(FORUM_LEAD_ORIGIN, category_id, None),
+            Err(ERROR_CATEGORY_ALREADY_IN_PARENT)
+        );
+    });
+}
+
+#[test]
+fn not_forum_lead_cannot_move_category() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(FORUM_LEAD_ORIGIN);
+        let new_parent_id = create_root_category(FORUM_LEAD_ORIGIN);
+
+        assert_eq!(
+            move_category(NOT_FORUM_WORKER_ORIGIN, category_id, Some(new_parent_id)),
+            Err(ERROR_ORIGIN_NOT_FORUM_LEAD)
+        );
+    });
+}
+
+#[test]
+fn cannot_move_category_into_itself_or_its_subcategory() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(FORUM_LEAD_ORIGIN);
+        let subcategory_id = create_category(FORUM_LEAD_ORIGIN, Some(category_id));
+
+        assert_eq!(
+            move_category(FORUM_LEAD_ORIGIN, category_id, Some(category_id)),
+            Err(ERROR_CATEGORY_CANNOT_BE_MOVED_INTO_ITSELF)
+        );
+        assert_eq!(
+            move_category(FORUM_LEAD_ORIGIN, category_id, Some(subcategory_id)),
+            Err(ERROR_CATEGORY_CANNOT_BE_MOVED_INTO_ITSELF)
+        );
+    });
+}
+
+#[test]
+fn cannot_move_category_beyond_max_depth() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(FORUM_LEAD_ORIGIN);
+        create_category(FORUM_LEAD_ORIGIN, Some(category_id));
+        let new_parent_id = create_root_category(FORUM_LEAD_ORIGIN);
+
+        assert_eq!(
+            move_category(FORUM_LEAD_ORIGIN, category_id, Some(new_parent_id)),
+            Err(ERROR_MAX_VALID_CATEGORY_DEPTH_EXCEEDED)
+        );
+    });
+}
+
+#[test]
+fn subcategory_tree_depth_successfully() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(FORUM_LEAD_ORIGIN);
+        let subcategory_id = create_category(FORUM_LEAD_ORIGIN, Some(category_id));
+        create_category(FORUM_LEAD_ORIGIN, Some(category_id));
+        let other_category_id = create_root_category(FORUM_LEAD_ORIGIN);
+        create_category(FORUM_LEAD_ORIGIN, Some(other_category_id));
+
+        assert_eq!(TestForumModule::subcategory_tree_depth(category_id), 1);
+        assert_eq!(TestForumModule::subcategory_tree_depth(subcategory_id), 0);
+    });
+}
+
+#[test]
+fn cannot_move_category_to_archived_category() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(FORUM_LEAD_ORIGIN);
+        let new_parent_id = create_root_category(FORUM_LEAD_ORIGIN);
+        assert_ok!(archive_category(FORUM_LEAD_ORIGIN, new_parent_id));
+
+        assert_eq!(
+            move_category(FORUM_LEAD_ORIGIN, category_id, Some(new_parent_id)),
+            Err(ERROR_ANCESTOR_CATEGORY_IMMUTABLE)
+        );
+    });
+}
+
+// Post reactions:
+// -----------------------------------------------------------------------------
+
+#[test]
+fn forum_user_can_react_to_post() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) =
+            create_root_category_and_thread_and_post(FORUM_LEAD_ORIGIN);
+
+        assert_ok!(react_to_post(
+            member_origin,
+            post_id,
+            Some(PostReaction::Like)
+        ));
+
+        assert_eq!(
+            TestForumModule::post_reaction_by_forum_user(post_id, FORUM_USER_ID),
+            Some(PostReaction::Like)
+        );
+        assert_eq!(
+            TestForumModule::post_by_id(post_id).reaction_counts,
+            PostReactionCounts {
+                likes: 1,
+                ..PostReactionCounts::default()
+            }
+        );
+    });
+}
+
+#[test]
+fn forum_user_can_change_and_remove_post_reaction() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) =
+            create_root_category_and_thread_and_post(FORUM_LEAD_ORIGIN);
+
+        assert_ok!(react_to_post(
+            member_origin.clone(),
+            post_id,
+            Some(PostReaction::Like)
+        ));
+        assert_ok!(react_to_post(
+            member_origin.clone(),
+            post_id,
+            Some(PostReaction::Thanks)
+        ));
+        assert_eq!(
+            TestForumModule::post_by_id(post_id).reaction_counts,
+            PostReactionCounts {
+                thanks: 1,
+                ..PostReactionCounts::default()
+            }
+        );
+
+        assert_ok!(react_to_post(member_origin, post_id, None));
+        assert_eq!(
+            TestForumModule::post_reaction_by_forum_user(post_id, FORUM_USER_ID),
+            None
+        );
+        assert_eq!(
+            TestForumModule::post_by_id(post_id).reaction_counts,
+            PostReactionCounts::default()
+        );
+    });
+}
+
+#[test]
+fn cannot_react_to_post_with_same_reaction() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) =
+            create_root_category_and_thread_and_post(FORUM_LEAD_ORIGIN);
+
+        assert_eq!(
+            react_to_post(member_origin.clone(), post_id, None),
+            Err(ERROR_POST_REACTION_NOT_CHANGED)
+        );
+
+        assert_ok!(react_to_post(
+            member_origin.clone(),
+            post_id,
+            Some(PostReaction::Laugh)
+        ));
+        assert_eq!(
+            react_to_post(member_origin, post_id, Some(PostReaction::Laugh)),
+            Err(ERROR_POST_REACTION_NOT_CHANGED)
+        );
+    });
+}
+
+#[test]
+fn cannot_react_to_moderated_post() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) =
+            create_root_category_and_thread_and_post(FORUM_LEAD_ORIGIN);
+
+        assert_ok!(moderate_post(
+            FORUM_MODERATOR_ORIGIN,
+            post_id,
+            good_rationale()
+        ));
+        assert_eq!(
+            react_to_post(member_origin, post_id, Some(PostReaction::Dislike)),
+            Err(ERROR_POST_MODERATED)
+        );
+    });
+}
+
+// Invalid id passed:
+// -----------------------------------------------------------------------------
+
+#[test]
+fn cannot_create_subcategory_with_invalid_parent_category_id() {
+    let config = default_genesis_config();
+    let origin = FORUM_LEAD_ORIGIN;
+
+    build_test_externalities(config).execute_with(|| {
+        assert_create_category(
+            origin,
+            Some(INVLAID_CATEGORY_ID),
+            Err(ERROR_CATEGORY_DOES_NOT_EXIST),
+        );
+    });
+}
+
+#[test]
+fn cannot_create_thread_with_invalid_category_id() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        CreateThreadFixture {
+            origin: create_forum_member(),
+            forum_user_id: FORUM_USER_ID,
+            category_id: INVLAID_CATEGORY_ID,
+            title: good_thread_title(),
+            text: good_thread_text(),
+            poll: None,
+            result: Err(ERROR_CATEGORY_DOES_NOT_EXIST),
+        }
+        .call_and_assert();
+    });
+}
+
+#[test]
+fn cannot_create_post_with_invalid_thread_id() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        CreatePostFixture {
+            origin: create_forum_member(),
+            forum_user_id: FORUM_USER_ID,
+            thread_id: INVLAID_THREAD_ID,
+            text: good_post_text(),
+            result: Err(ERROR_THREAD_DOES_NOT_EXIST),
+        }
+        .call_and_assert();
+    });
+}
+
+#[test]
+fn cannot_moderate_thread_with_invalid_id() {
+    let config = default_genesis_config();
+    let origin = FORUM_LEAD_ORIGIN;
+
+    build_test_externalities(config).execute_with(|| {
+        assert_err!(
+            moderate_thread(origin, INVLAID_THREAD_ID, good_rationale()),
+            ERROR_THREAD_DOES_NOT_EXIST
+        );
+    });
+}
+
+#[test]
+fn cannot_moderate_post_with_invalid_id() {
+    let config = default_genesis_config();
+    let origin = FORUM_LEAD_ORIGIN;
+
+    build_test_externalities(config).execute_with(|| {
+        assert_err!(
+            moderate_post(origin, INVLAID_POST_ID, good_rationale()),
+            ERROR_POST_DOES_NOT_EXIST
+        );
+    });
+}
+
+// Successfull extrinsics
+// -----------------------------------------------------------------------------
+
+#[test]
+fn archive_then_unarchive_category_successfully() {
+    let config = default_genesis_config();
+    let forum_lead = FORUM_LEAD_ORIGIN;
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_lead.clone());
+        assert_ok!(archive_category(forum_lead.clone(), category_id.clone(),));
+        // TODO get category by id and assert archived == true.
+
+        assert_ok!(unarchive_category(forum_lead, category_id,));
+        // TODO get category by id and assert archived == false.
+    });
+}
+
+#[test]
+fn delete_then_undelete_category_successfully() {
+    let config = default_genesis_config();
+    let forum_lead = FORUM_LEAD_ORIGIN;
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_lead.clone());
+        assert_ok!(delete_category(forum_lead.clone(), category_id.clone(),));
+        // TODO get category by id and assert deleted == true.
+
+        assert_ok!(undelete_category(forum_lead.clone(), category_id.clone(),));
+        // TODO get category by id and assert deleted == false.
+    });
+}
+
+// TODO Consider to fix the logic of the forum module
+// #[test]
+// fn cannot_unarchive_not_archived_category() {
+//     let config = default_genesis_config();
+//     let forum_lead = FORUM_LEAD_ORIGIN;
+
+//     build_test_externalities(config).execute_with(|| {
+//         let category_id = create_root_category(forum_lead.clone());
+
+//         // TODO bug in a logic! it should not be possible. !!!
+
+//         assert_err!(
+//             archive_category(
+//                 forum_lead.clone(),
+//                 category_id.clone(),
+//             ),
+//             "... TODO expect error ..."
+//         );
+//     });
+// }
+
+// TODO Consider to fix the logic of the forum module
+// #[test]
+// fn cannot_undelete_not_deleted_category() {
+//     let config = default_genesis_config();
+//     let forum_lead = FORUM_LEAD_ORIGIN;
+
+//     build_test_externalities(config).execute_with(|| {
+//         let category_id = create_root_category(forum_lead.clone());
+//         assert_err!(
+//             delete_category(
+//                 forum_lead.clone(),
+//                 category_id.clone(),
+//             ),
+//             "... TODO expect error ..."
+//         );
+//     });
+// }
+
+// With archived / deleted category, moderated thread
+// -----------------------------------------------------------------------------
+
+#[test]
+fn cannot_create_subcategory_in_archived_category() {
+    let config = default_genesis_config();
+    let forum_lead = FORUM_LEAD_ORIGIN;
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_lead.clone());
+        assert_ok!(archive_category(forum_lead.clone(), category_id.clone(),));
+        assert_create_category(
+            forum_lead,
+            Some(category_id),
+            Err(ERROR_ANCESTOR_CATEGORY_IMMUTABLE),
+        );
+    });
+}
+
+#[test]
+fn cannot_create_subcategory_in_deleted_category() {
+    let config = default_genesis_config();
+    let forum_lead = FORUM_LEAD_ORIGIN;
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_lead.clone());
+        assert_ok!(delete_category(forum_lead.clone(), category_id.clone(),));
+        assert_create_category(
+            forum_lead,
+            Some(category_id),
+            Err(ERROR_ANCESTOR_CATEGORY_IMMUTABLE),
+        );
+    });
+}
+
+#[test]
+fn cannot_create_thread_in_archived_category() {
+    let config = default_genesis_config();
+    let forum_lead = FORUM_LEAD_ORIGIN;
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_lead.clone());
+        assert_ok!(archive_category(forum_lead.clone(), category_id.clone(),));
+        assert_create_thread(
+            create_forum_member(),
+            category_id,
+            Err(ERROR_ANCESTOR_CATEGORY_IMMUTABLE),
+        );
+    });
+}
+
+#[test]
+fn cannot_create_thread_in_deleted_category() {
+    let config = default_genesis_config();
+    let forum_lead = FORUM_LEAD_ORIGIN;
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_lead.clone());
+        assert_ok!(delete_category(forum_lead.clone(), category_id.clone(),));
+        assert_create_thread(
+            create_forum_member(),
+            category_id,
+            Err(ERROR_ANCESTOR_CATEGORY_IMMUTABLE),
+        );
+    });
+}
+
+#[test]
+fn cannot_create_post_in_thread_of_archived_category() {
+    let config = default_genesis_config();
+    let forum_lead = FORUM_LEAD_ORIGIN;
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_lead.clone());
+        let thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(create_forum_member(), category_id, Ok(()));
+        assert_ok!(archive_category(forum_lead.clone(), category_id.clone(),));
+        assert_create_post(
+            create_forum_member(),
+            thread_id,
+            Err(ERROR_ANCESTOR_CATEGORY_IMMUTABLE),
+        );
+    });
+}
+
+#[test]
+fn cannot_create_post_in_thread_of_deleted_category() {
+    let config = default_genesis_config();
+    let forum_lead = FORUM_LEAD_ORIGIN;
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_lead.clone());
+        let thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(create_forum_member(), category_id, Ok(()));
+        assert_ok!(delete_category(forum_lead.clone(), category_id.clone(),));
+        assert_create_post(
+            create_forum_member(),
+            thread_id,
+            Err(ERROR_ANCESTOR_CATEGORY_IMMUTABLE),
+        );
     });
 }
 
 #[test]
 fn cannot_create_post_in_moderated_thread() {
     let config = default_genesis_config();
-    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let forum_lead = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
-        let (_, _, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+        let (_, _, thread_id) = create_root_category_and_thread(forum_lead.clone());
         assert_ok!(moderate_thread(
-            forum_sudo,
+            forum_lead,
             thread_id.clone(),
             good_rationale()
         ));
@@ -963,14 +1898,19 @@ fn cannot_create_post_in_moderated_thread() {
 #[test]
 fn cannot_edit_post_in_moderated_thread() {
     let config = default_genesis_config();
-    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let forum_lead = FORUM_LEAD_ORIGIN;
 
     build_test_externalities(config).execute_with(|| {
         let (member_origin, _, thread_id, post_id) =
-            create_root_category_and_thread_and_post(forum_sudo.clone());
-        assert_ok!(moderate_thread(forum_sudo, thread_id, good_rationale()));
+            create_root_category_and_thread_and_post(forum_lead.clone());
+        assert_ok!(moderate_thread(forum_lead, thread_id, good_rationale()));
         assert_err!(
-            TestForumModule::edit_post_text(mock_origin(member_origin), post_id, good_rationale()),
+            TestForumModule::edit_post_text(
+                mock_origin(member_origin),
+                FORUM_USER_ID,
+                post_id,
+                good_rationale()
+            ),
             ERROR_THREAD_MODERATED
         );
     });
@@ -979,3 +1919,125 @@ fn cannot_edit_post_in_moderated_thread() {
 // TODO impl
 // #[test]
 // fn cannot_edit_moderated_post() {}
+
+/*
+ * migrate_forum_user_ids
+ * ==============================================================================
+ */
+
+#[test]
+fn migrate_forum_user_ids_successfully() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let author_account: <Runtime as system::Trait>::AccountId = 42;
+        let moderator_account: <Runtime as system::Trait>::AccountId = 43;
+        let thread_id: RuntimeThreadId = 1;
+        let post_id: RuntimePostId = 1;
+        let created_at = common::current_block_time::<Runtime>();
+
+        // Thread and post stored with the account authors and moderators
+        let thread_key = <ThreadById<Runtime> as generator::StorageMap<
+            RuntimeThreadId,
+            RuntimeThread,
+        >>::storage_map_final_key(thread_id);
+        unhashed::put(
+            thread_key.as_ref(),
+            &(
+                thread_id,
+                good_thread_title(),
+                1 as CategoryId,
+                1u32,
+                None::<(BlockAndTime<u64, u64>, u64, Vec<u8>)>,
+                1u32,
+                0u32,
+                created_at.clone(),
+                author_account,
+            ),
+        );
+
+        let post_key = <PostById<Runtime> as generator::StorageMap<
+            RuntimePostId,
+            RuntimePost,
+        >>::storage_map_final_key(post_id);
+        unhashed::put(
+            post_key.as_ref(),
+            &(
+                post_id,
+                thread_id,
+                1u32,
+                good_post_text(),
+                Some((created_at.clone(), moderator_account, good_rationale())),
+                Vec::<PostTextChange<u64, u64>>::new(),
+                created_at.clone(),
+                author_account,
+            ),
+        );
+
+        NextThreadId::<Runtime>::put(thread_id + 1);
+        NextPostId::<Runtime>::put(post_id + 1);
+
+        TestForumModule::migrate_forum_user_ids(|account_id| account_id + 100);
+
+        let thread = TestForumModule::thread_by_id(thread_id);
+        assert_eq!(thread.author_id, 142);
+        assert_eq!(thread.title, good_thread_title());
+        assert_eq!(thread.num_unmoderated_posts, 1);
+        assert!(thread.moderation.is_none());
+
+        let post = TestForumModule::post_by_id(post_id);
+        assert_eq!(post.author_id, 142);
+        assert_eq!(post.current_text, good_post_text());
+        assert_eq!(post.moderation.unwrap().moderator_id, 143);
+        assert_eq!(post.reaction_counts, PostReactionCounts::default());
+
+        // Already migrated threads and posts are left untouched
+        TestForumModule::migrate_forum_user_ids(|account_id| account_id + 200);
+
+        assert_eq!(TestForumModule::thread_by_id(thread_id).author_id, 142);
+        assert_eq!(TestForumModule::post_by_id(post_id).author_id, 142);
+    });
+}
+
+#[test]
+fn migrate_categories_successfully() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id: CategoryId = 1;
+
+        // Category stored without the moved out subcategories and threads counters
+        let key = <CategoryById<Runtime> as generator::StorageMap<
+            CategoryId,
+            RuntimeCategory,
+        >>::storage_map_final_key(category_id);
+        unhashed::put(
+            key.as_ref(),
+            &(
+                category_id,
+                good_category_title(),
+                good_category_description(),
+                common::current_block_time::<Runtime>(),
+                false,
+                true,
+                2u32,
+                3u32,
+                1u32,
+                None::<ChildPositionInParentCategory>,
+                FORUM_LEAD_ACCOUNT_ID,
+            ),
+        );
+
+        NextCategoryId::put(category_id + 1);
+
+        TestForumModule::migrate_categories();
+
+        let category = TestForumModule::category_by_id(category_id);
+        assert_eq!(category.title, good_category_title());
+        assert!(category.archived);
+        assert_eq!(category.num_direct_subcategories, 2);
+        assert_eq!(category.num_threads_created(), 4);
+        assert_eq!(category.num_direct_moved_threads, 0);
+        assert_eq!(category.moderator_id, FORUM_LEAD_ACCOUNT_ID);
+    });
+}
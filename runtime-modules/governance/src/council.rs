@@ -1,5 +1,6 @@
 use rstd::prelude::*;
 use sr_primitives::traits::{One, Zero};
+use srml_support::traits::ReservableCurrency;
 use srml_support::{debug, decl_event, decl_module, decl_storage, ensure};
 use system::{self, ensure_root};
 
@@ -21,10 +22,46 @@ impl<X: CouncilTermEnded> CouncilTermEnded for (X,) {
     }
 }
 
+// Hook for filling council seats vacated before the council term ends
+pub trait CouncilSeatsVacated {
+    fn council_seats_vacated(seats: u32);
+}
+
+impl CouncilSeatsVacated for () {
+    fn council_seats_vacated(_seats: u32) {}
+}
+
+impl<X: CouncilSeatsVacated> CouncilSeatsVacated for (X,) {
+    fn council_seats_vacated(seats: u32) {
+        X::council_seats_vacated(seats);
+    }
+}
+
+// Hook for checking whether the council election is running
+pub trait CouncilElectionStatus {
+    fn is_election_running() -> bool;
+}
+
+impl CouncilElectionStatus for () {
+    fn is_election_running() -> bool {
+        false
+    }
+}
+
+impl<X: CouncilElectionStatus> CouncilElectionStatus for (X,) {
+    fn is_election_running() -> bool {
+        X::is_election_running()
+    }
+}
+
 pub trait Trait: system::Trait + recurringrewards::Trait + GovernanceCurrency {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
     type CouncilTermEnded: CouncilTermEnded;
+
+    type CouncilSeatsVacated: CouncilSeatsVacated;
+
+    type CouncilElectionStatus: CouncilElectionStatus;
 }
 
 decl_storage! {
@@ -56,9 +93,11 @@ decl_storage! {
 
 // Event for this module.
 decl_event!(
-    pub enum Event<T> where <T as system::Trait>::BlockNumber {
+    pub enum Event<T> where <T as system::Trait>::BlockNumber, <T as system::Trait>::AccountId {
         CouncilTermEnded(BlockNumber),
         NewCouncilTermStarted(BlockNumber),
+        CouncilMemberRecalled(AccountId),
+        VacantCouncilSeatsFilled(u32),
     }
 );
 
@@ -126,6 +165,28 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    /// Adds seats elected to fill vacated council seats, the term of the council is not changed.
+    pub fn fill_vacant_seats(seats: Seats<T::AccountId, BalanceOf<T>>) {
+        if let Some(reward_source) = Self::council_mint() {
+            for seat in seats.iter() {
+                Self::add_reward_relationship(&seat.member, reward_source);
+            }
+        }
+
+        let filled = seats.len() as u32;
+
+        <ActiveCouncil<T>>::mutate(|council| council.extend(seats));
+
+        Self::deposit_event(RawEvent::VacantCouncilSeatsFilled(filled));
+    }
+
+    fn remove_reward_relationship(account: &T::AccountId) {
+        if RewardRelationships::<T>::exists(account) {
+            let relationship_id = Self::reward_relationships(account);
+            <recurringrewards::Module<T>>::remove_reward_relationship(relationship_id);
+        }
+    }
+
     fn remove_reward_relationships() {
         for seat in Self::active_council().into_iter() {
             if RewardRelationships::<T>::exists(&seat.member) {
@@ -214,10 +275,7 @@ decl_module! {
 
             ensure!(Self::is_councilor(&account_to_remove), "account is not a councilor");
 
-            if RewardRelationships::<T>::exists(&account_to_remove) {
-                let relationship_id = Self::reward_relationships(&account_to_remove);
-                <recurringrewards::Module<T>>::remove_reward_relationship(relationship_id);
-            }
+            Self::remove_reward_relationship(&account_to_remove);
 
             let filtered_council: Seats<T::AccountId, BalanceOf<T>> = Self::active_council()
                 .into_iter()
@@ -227,6 +285,41 @@ decl_module! {
             <ActiveCouncil<T>>::put(filtered_council);
         }
 
+        /// Recalls a council member, usually as the result of an approved no-confidence proposal.
+        /// Their reward is removed and the stakes of the seat and its backers are released, since
+        /// they are no longer transferable to the next election. Optionally starts a by-election
+        /// for the vacated seat. Council members can't be recalled while an election is running,
+        /// because the running election holds the council stakes as transferable.
+        pub fn recall_council_member(origin, account_to_recall: T::AccountId, start_by_election: bool) {
+            ensure_root(origin)?;
+
+            ensure!(
+                !T::CouncilElectionStatus::is_election_running(),
+                "cannot recall a council member while an election is running"
+            );
+
+            let seat = Self::active_council()
+                .into_iter()
+                .find(|seat| seat.member == account_to_recall)
+                .ok_or("account is not a councilor")?;
+
+            Self::remove_reward_relationship(&seat.member);
+            <RewardRelationships<T>>::remove(&seat.member);
+
+            <T as GovernanceCurrency>::Currency::unreserve(&seat.member, seat.stake);
+            for backer in seat.backers.iter() {
+                <T as GovernanceCurrency>::Currency::unreserve(&backer.member, backer.stake);
+            }
+
+            <ActiveCouncil<T>>::mutate(|council| council.retain(|c| c.member != account_to_recall));
+
+            Self::deposit_event(RawEvent::CouncilMemberRecalled(account_to_recall));
+
+            if start_by_election {
+                T::CouncilSeatsVacated::council_seats_vacated(1);
+            }
+        }
+
         /// Set blocknumber when council term will end
         fn set_term_ends_at(origin, ends_at: T::BlockNumber) {
             ensure_root(origin)?;
@@ -282,6 +375,7 @@ decl_module! {
 mod tests {
     use super::*;
     use crate::mock::*;
+    use srml_support::traits::Currency;
     use srml_support::*;
 
     fn add_council_member_as_root(account: <Test as system::Trait>::AccountId) -> dispatch::Result {
@@ -372,4 +466,118 @@ mod tests {
             assert!(RewardRelationships::<Test>::exists(&7));
         });
     }
+
+    #[test]
+    fn recall_council_member_test() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(Council::set_council_mint_capacity(
+                system::RawOrigin::Root.into(),
+                1000
+            ));
+
+            let _ = Balances::deposit_creating(&5, 100);
+            assert_ok!(Balances::reserve(&5, 50));
+            let _ = Balances::deposit_creating(&10, 100);
+            assert_ok!(Balances::reserve(&10, 30));
+
+            Council::council_elected(
+                vec![
+                    Seat {
+                        member: 5,
+                        stake: 50,
+                        backers: vec![election::Backer {
+                            member: 10,
+                            stake: 30,
+                        }],
+                    },
+                    Seat {
+                        member: 6,
+                        stake: 0,
+                        backers: vec![],
+                    },
+                ],
+                50 as u64,
+            );
+
+            assert_ok!(Council::recall_council_member(
+                system::RawOrigin::Root.into(),
+                5,
+                false
+            ));
+
+            assert!(!Council::is_councilor(&5));
+            assert!(Council::is_councilor(&6));
+            assert!(!RewardRelationships::<Test>::exists(&5));
+            assert!(RewardRelationships::<Test>::exists(&6));
+
+            // seat and backing stakes are released
+            assert_eq!(Balances::reserved_balance(&5), 0);
+            assert_eq!(Balances::reserved_balance(&10), 0);
+
+            // no by-election requested
+            assert!(Election::stage().is_none());
+
+            assert!(
+                Council::recall_council_member(system::RawOrigin::Root.into(), 5, false).is_err()
+            );
+        });
+    }
+
+    #[test]
+    fn recall_council_member_fails_during_election() {
+        initial_test_ext().execute_with(|| {
+            let _ = Balances::deposit_creating(&5, 100);
+            assert_ok!(Balances::reserve(&5, 50));
+            let _ = Balances::deposit_creating(&10, 100);
+            assert_ok!(Balances::reserve(&10, 30));
+
+            Council::council_elected(
+                vec![Seat {
+                    member: 5,
+                    stake: 50,
+                    backers: vec![election::Backer {
+                        member: 10,
+                        stake: 30,
+                    }],
+                }],
+                50 as u64,
+            );
+
+            assert_ok!(Election::force_start_election(
+                system::RawOrigin::Root.into()
+            ));
+            assert!(Election::is_election_running());
+
+            assert!(
+                Council::recall_council_member(system::RawOrigin::Root.into(), 5, false).is_err()
+            );
+
+            // the seat and its transferable stakes are kept for the running election
+            assert!(Council::is_councilor(&5));
+            assert_eq!(Balances::reserved_balance(&5), 50);
+            assert_eq!(Balances::reserved_balance(&10), 30);
+        });
+    }
+
+    #[test]
+    fn fill_vacant_seats_test() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(Council::set_council(
+                system::RawOrigin::Root.into(),
+                vec![4, 5]
+            ));
+            <TermEndsAt<Test>>::put(100);
+
+            Council::fill_vacant_seats(vec![Seat {
+                member: 6,
+                stake: 0,
+                backers: vec![],
+            }]);
+
+            assert!(Council::is_councilor(&4));
+            assert!(Council::is_councilor(&5));
+            assert!(Council::is_councilor(&6));
+            assert_eq!(Council::term_ends_at(), 100);
+        });
+    }
 }
@@ -19,7 +19,34 @@
 //!
 //! We only guard against these edge cases in the [`set_election_parameters`] call.
 //!
+//! # By-elections:
+//! When council seats are vacated before the end of the term, a by-election can be started to fill
+//! them. It runs through the same stages as a regular election, but only the vacant seats are
+//! elected and added to the active council without changing its term. Councilors can't apply and
+//! transferable stakes are not used. A by-election is not started if an election is already running
+//! or if it can't complete before the council term ends.
+//!
+//! # Tally Modes:
+//! - `SingleChoice` - a vote commits to a single applicant, each applicant elected is backed
+//! by the full stake of its voters and seats go to the applicants with the most total stake.
+//!
+//! - `Phragmen` - a vote commits to an ordered list of applicants, revealed with [`reveal_ranked`].
+//! Seats are filled one at a time with the sequential Phragmén method, which spreads the
+//! stake of voters over the applicants they rank rather than concentrating it on a few of them.
+//! Applicant stake counts as a vote for themselves. A plain [`reveal`] counts as a ranking of a single
+//! applicant. Each vote backs the highest ranked elected applicant with its full stake.
+//!
+//! # Slashing Council Stakes:
+//! The stake of a sitting councilor, and proportionally the stakes backing its seat, can be slashed
+//! with [`slash_council_member`]. Slashed funds are either burned or returned to the council
+//! budget by increasing the capacity of the council mint by the slashed amount. Slashing is not
+//! possible while an election is running, since the council stakes are then already taken into
+//! account as transferable stakes.
+//!
 //! [`set_election_parameters`]: struct.Module.html#method.set_election_parameters
+//! [`reveal`]: struct.Module.html#method.reveal
+//! [`reveal_ranked`]: struct.Module.html#method.reveal_ranked
+//! [`slash_council_member`]: struct.Module.html#method.slash_council_member
 
 // Clippy linter warning
 #![allow(clippy::type_complexity)]
@@ -30,22 +57,24 @@
 #![allow(clippy::redundant_closure_call)] // disable it because of the substrate lib design
 
 use rstd::prelude::*;
-use srml_support::traits::{Currency, ReservableCurrency};
+use srml_support::traits::{Currency, Imbalance, ReservableCurrency};
 use srml_support::{decl_event, decl_module, decl_storage, dispatch::Result, ensure};
 use system::{self, ensure_root, ensure_signed};
 
 use codec::{Decode, Encode};
 use rstd::collections::btree_map::BTreeMap;
+use rstd::collections::btree_set::BTreeSet;
 use rstd::ops::Add;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use sr_primitives::traits::{Hash, Zero};
+use sr_primitives::traits::{Hash, Saturating, UniqueSaturatedFrom, UniqueSaturatedInto, Zero};
+use sr_primitives::Perbill;
 
 use super::sealed_vote::SealedVote;
 use super::stake::Stake;
 
 use super::council;
-use crate::election_params::ElectionParameters;
+use crate::election_params::{ElectionParameters, TallyMode};
 pub use common::currency::{BalanceOf, GovernanceCurrency};
 
 pub trait Trait:
@@ -58,6 +87,12 @@ pub trait Trait:
 
 pub static MSG_CANNOT_CHANGE_PARAMS_DURING_ELECTION: &str = "CannotChangeParamsDuringElection";
 
+/// Fixed point scale of the voter loads computed by the Phragmén tally
+const PHRAGMEN_LOAD_SCALE: u128 = 1_000_000_000_000_000_000;
+
+/// Number of most recent election round results kept in the history
+pub const ELECTION_HISTORY_DEPTH: usize = 100;
+
 #[derive(Clone, Copy, Encode, Decode)]
 pub enum ElectionStage<BlockNumber> {
     Announcing(BlockNumber),
@@ -128,9 +163,60 @@ pub struct TransferableStake<Balance> {
     backing: Balance,
 }
 
+/// Where the funds slashed from council stakes go
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, Debug)]
+pub enum SlashedStakeDestination {
+    /// Slashed funds are burned
+    Burn,
+    /// Slashed funds are burned and the capacity of the council mint is increased by the
+    /// same amount
+    CouncilMint,
+}
+
+impl Default for SlashedStakeDestination {
+    fn default() -> Self {
+        SlashedStakeDestination::Burn
+    }
+}
+
 // can we use a type alias to overcome name clashes of public types with other modules?
 pub type ElectionStake<T> = Stake<BalanceOf<T>>;
 
+/// Outcome of an election round for an applicant that reached the voting stage
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct ApplicantResult<AccountId, Balance> {
+    pub applicant: AccountId,
+    /// Stake of the applicant itself
+    pub stake: Balance,
+    /// Total stake of the revealed votes for the applicant, in Phragmen tally mode a vote
+    /// counts for every applicant it ranks
+    pub backing: Balance,
+    /// Number of revealed votes for the applicant
+    pub votes: u32,
+}
+
+/// Archived outcome of an election round
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct ElectionResult<AccountId, Balance, BlockNumber> {
+    /// Block at which the round ended
+    pub ended_at: BlockNumber,
+    pub tally_mode: TallyMode,
+    pub applicants: Vec<ApplicantResult<AccountId, Balance>>,
+    pub revealed_votes: u32,
+    pub unrevealed_commitments: u32,
+    /// Elected council members, empty if the election was stopped
+    pub winners: Vec<AccountId>,
+}
+
+pub type ElectionResultOf<T> = ElectionResult<
+    <T as system::Trait>::AccountId,
+    BalanceOf<T>,
+    <T as system::Trait>::BlockNumber,
+>;
+
 decl_storage! {
     trait Store for Module<T: Trait> as CouncilElection {
         // Flag for wether to automatically start an election after a council term ends
@@ -153,6 +239,10 @@ decl_storage! {
         // TODO value type of this map looks scary, is there any way to simplify the notation?
         Votes get(votes): map T::Hash => SealedVote<T::AccountId, ElectionStake<T>, T::Hash, T::AccountId>;
 
+        // Applicant rankings of votes revealed with reveal_ranked(), the first ranked applicant
+        // is also recorded as the revealed vote in Votes.
+        RankedVotes get(ranked_votes): map T::Hash => Vec<T::AccountId>;
+
         // Current Election Parameters.
         // Should we replace all the individual values with a single ElectionParameters type?
         // Having them individually makes it more flexible to add and remove new parameters in future
@@ -165,6 +255,16 @@ decl_storage! {
         MinCouncilStake get(min_council_stake): BalanceOf<T>;
         NewTermDuration get(new_term_duration): T::BlockNumber;
         MinVotingStake get(min_voting_stake): BalanceOf<T>;
+        VoteTallyMode get(tally_mode): TallyMode;
+
+        // Number of vacant council seats being filled if the running election is a by-election,
+        // zero for regular elections.
+        ByElectionSeats get(by_election_seats): u32;
+
+        // Results of the election rounds which elected a council or were stopped, only the
+        // ELECTION_HISTORY_DEPTH most recent ones are kept.
+        pub ElectionResults get(election_results): map u32 => Option<ElectionResultOf<T>>;
+        pub ArchivedRounds get(archived_rounds): Vec<u32>;
     }
     add_extra_genesis {
         config(election_parameters): ElectionParameters<BalanceOf<T>, T::BlockNumber>;
@@ -180,7 +280,8 @@ decl_event!(
     pub enum Event<T> where
     <T as system::Trait>::BlockNumber,
     <T as system::Trait>::AccountId,
-    <T as system::Trait>::Hash  {
+    <T as system::Trait>::Hash,
+    Balance = BalanceOf<T>  {
         /// A new election started
         ElectionStarted(),
         AnnouncingStarted(u32),
@@ -192,7 +293,15 @@ decl_event!(
         CouncilElected(BlockNumber),
         Applied(AccountId),
         Voted(AccountId, Hash),
+        ByElectionStarted(u32),
+        /// Number of vacated seats a by-election could not be started for, either because an
+        /// election is running or the council term ends before a by-election would complete
+        ByElectionNotStarted(u32),
         Revealed(AccountId, Hash, AccountId),
+        RankedRevealed(AccountId, Hash, Vec<AccountId>),
+        /// Councilor, amount slashed from the seat stake, amount slashed from the backing stakes
+        /// and where the slashed funds went
+        CouncilMemberSlashed(AccountId, Balance, Balance, SlashedStakeDestination),
     }
 );
 
@@ -203,6 +312,15 @@ impl<T: Trait> Module<T> {
         Self::council_size() as usize
     }
 
+    /// Number of seats the running election fills, the council size unless it is a by-election.
+    fn seats_to_fill_usize() -> usize {
+        if Self::is_by_election() {
+            Self::by_election_seats() as usize
+        } else {
+            Self::council_size_usize()
+        }
+    }
+
     fn candidacy_limit_usize() -> usize {
         Self::candidacy_limit() as usize
     }
@@ -223,6 +341,11 @@ impl<T: Trait> Module<T> {
         Self::stage().is_some()
     }
 
+    /// Returns true if the running election only fills vacated council seats
+    pub fn is_by_election() -> bool {
+        Self::by_election_seats() > 0
+    }
+
     /// Returns block number at which current stage will end if an election is running.
     pub fn stage_ends_at() -> Option<T::BlockNumber> {
         if let Some(stage) = Self::stage() {
@@ -260,6 +383,46 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    /// Starts a by-election filling vacated council seats. The council keeps its stakes, so
+    /// there are no transferable stakes. Will fail if an election is already running or the
+    /// by-election can't complete before the council term ends.
+    fn start_by_election(seats: u32) -> Result {
+        let election_duration =
+            Self::announcing_period() + Self::voting_period() + Self::revealing_period();
+        ensure!(
+            Self::current_block_number_plus(election_duration)
+                < <council::Module<T>>::term_ends_at(),
+            "council term ends before by-election completes"
+        );
+
+        Self::start_election(Vec::new())?;
+
+        ByElectionSeats::put(seats);
+
+        Self::deposit_event(RawEvent::ByElectionStarted(seats));
+
+        Ok(())
+    }
+
+    /// Stops the running election without electing a council.
+    fn stop_election() {
+        let mut votes = Vec::new();
+        for commitment in Self::commitments() {
+            votes.push(Self::votes(commitment));
+        }
+
+        // no council gets elected
+        let empty_council = BTreeMap::new();
+
+        Self::archive_election_result(&votes, &empty_council);
+
+        Self::teardown_election(
+            &votes,
+            &empty_council,
+            false, /* do not unlock transferable stakes */
+        );
+    }
+
     /// Sets announcing stage. Can be called from any stage and assumes all preparatory work
     /// for entering the stage has been performed.
     /// Bumps the election round.
@@ -314,12 +477,12 @@ impl<T: Trait> Module<T> {
     fn on_announcing_ended() {
         let mut applicants = Self::applicants();
 
-        if applicants.len() < Self::council_size_usize() {
+        if applicants.len() < Self::seats_to_fill_usize() {
             // Not enough applicants announced candidacy
             Self::move_to_announcing_stage();
         } else {
             // upper limit on applicants that will move to voting stage
-            let limit = rstd::cmp::max(Self::council_size_usize(), Self::candidacy_limit_usize());
+            let limit = rstd::cmp::max(Self::seats_to_fill_usize(), Self::candidacy_limit_usize());
             let applicants_to_drop = Self::find_least_staked_applicants(&mut applicants, limit);
 
             Self::drop_applicants(applicants_to_drop);
@@ -340,7 +503,41 @@ impl<T: Trait> Module<T> {
             votes.push(Self::votes(commitment));
         }
 
-        let mut new_council = Self::tally_votes(&votes);
+        let new_council = match Self::tally_mode() {
+            TallyMode::SingleChoice => Self::elect_top_staked(&votes),
+            // Phragmén already accounts for applicant stake and fills the council size seats
+            TallyMode::Phragmen => Self::tally_votes_phragmen(&votes),
+        };
+
+        // unless we want to add more filtering criteria to what is considered a successful election
+        // other than just the minimum stake for candidacy, we have a new council!
+
+        Self::archive_election_result(&votes, &new_council);
+
+        let is_by_election = Self::is_by_election();
+
+        Self::teardown_election(
+            &votes,
+            &new_council,
+            true, /* unlock transferable stakes */
+        );
+
+        let new_council = new_council.into_iter().map(|(_, seat)| seat).collect();
+        if is_by_election {
+            <council::Module<T>>::fill_vacant_seats(new_council);
+        } else {
+            T::CouncilElected::council_elected(new_council, Self::new_term_duration());
+        }
+
+        Self::deposit_event(RawEvent::CouncilElected(<system::Module<T>>::block_number()));
+    }
+
+    /// Tallies single choice votes, adds applicants without votes and keeps the council size
+    /// applicants with the most total stake.
+    fn elect_top_staked(
+        votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>],
+    ) -> BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>> {
+        let mut new_council = Self::tally_votes(votes);
 
         // Note here that applicants with zero votes dont appear in the tally.
         // Is an applicant with some votes but less total stake than another applicant with zero votes
@@ -361,13 +558,13 @@ impl<T: Trait> Module<T> {
         }
 
         match new_council.len() {
-            ncl if ncl == Self::council_size_usize() => {
+            ncl if ncl == Self::seats_to_fill_usize() => {
                 // all applicants in the tally will form the new council
             }
-            ncl if ncl > Self::council_size_usize() => {
+            ncl if ncl > Self::seats_to_fill_usize() => {
                 // we have more than enough applicants to form the new council.
                 // select top staked
-                Self::filter_top_staked(&mut new_council, Self::council_size_usize());
+                Self::filter_top_staked(&mut new_council, Self::seats_to_fill_usize());
             }
             _ => {
                 // Not enough applicants with votes to form a council.
@@ -377,19 +574,66 @@ impl<T: Trait> Module<T> {
             }
         }
 
-        // unless we want to add more filtering criteria to what is considered a successful election
-        // other than just the minimum stake for candidacy, we have a new council!
+        new_council
+    }
 
-        Self::teardown_election(
-            &votes,
-            &new_council,
-            true, /* unlock transferable stakes */
+    /// Saves the outcome of the current round to the election history, dropping the oldest
+    /// result if the history is full. Must be called before the election is torn down.
+    fn archive_election_result(
+        votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>],
+        new_council: &BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>>,
+    ) {
+        let mut applicants: Vec<ApplicantResult<T::AccountId, BalanceOf<T>>> = Self::applicants()
+            .into_iter()
+            .map(|applicant| ApplicantResult {
+                stake: Self::applicant_stakes(&applicant).total(),
+                applicant,
+                backing: Zero::zero(),
+                votes: 0,
+            })
+            .collect();
+
+        let mut revealed_votes = 0;
+        let mut unrevealed_commitments = 0;
+
+        for sealed_vote in votes.iter() {
+            match Self::vote_ranking(sealed_vote) {
+                Some(ranking) => {
+                    revealed_votes += 1;
+
+                    for result in applicants
+                        .iter_mut()
+                        .filter(|result| ranking.contains(&result.applicant))
+                    {
+                        result.backing += sealed_vote.stake.total();
+                        result.votes += 1;
+                    }
+                }
+                None => unrevealed_commitments += 1,
+            }
+        }
+
+        let round = Self::round();
+
+        <ElectionResults<T>>::insert(
+            round,
+            ElectionResult {
+                ended_at: <system::Module<T>>::block_number(),
+                tally_mode: Self::tally_mode(),
+                applicants,
+                revealed_votes,
+                unrevealed_commitments,
+                winners: new_council.keys().cloned().collect(),
+            },
         );
 
-        let new_council = new_council.into_iter().map(|(_, seat)| seat).collect();
-        T::CouncilElected::council_elected(new_council, Self::new_term_duration());
+        ArchivedRounds::mutate(|rounds| {
+            rounds.push(round);
 
-        Self::deposit_event(RawEvent::CouncilElected(<system::Module<T>>::block_number()));
+            if rounds.len() > ELECTION_HISTORY_DEPTH {
+                <ElectionResults<T>>::remove(rounds.remove(0));
+            }
+        });
     }
 
     fn teardown_election(
@@ -409,6 +653,7 @@ impl<T: Trait> Module<T> {
 
         Self::clear_transferable_stakes();
 
+        ByElectionSeats::kill();
         <Stage<T>>::kill();
     }
 
@@ -490,8 +735,10 @@ impl<T: Trait> Module<T> {
             // not get elected to the council
             // TODO critical: shouldn't we slash the stake in such a case? This is the whole idea behid staking on something: people need to decide carefully and be responsible for their bahavior because they can loose their stake
             // See https://github.com/Joystream/substrate-node-joystream/issues/4
-            let do_refund = match sealed_vote.get_vote() {
-                Some(applicant) => !new_council.contains_key(&applicant),
+            let do_refund = match Self::vote_ranking(sealed_vote) {
+                Some(ranking) => !ranking
+                    .iter()
+                    .any(|applicant| new_council.contains_key(applicant)),
                 None => true,
             };
 
@@ -515,6 +762,7 @@ impl<T: Trait> Module<T> {
     fn clear_votes() {
         for commitment in Self::commitments() {
             <Votes<T>>::remove(commitment);
+            <RankedVotes<T>>::remove(commitment);
         }
         <Commitments<T>>::kill();
     }
@@ -550,6 +798,155 @@ impl<T: Trait> Module<T> {
         tally
     }
 
+    /// Returns the applicants a revealed vote ranks, most preferred first, or None if the vote
+    /// was not revealed.
+    fn vote_ranking(
+        sealed_vote: &SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>,
+    ) -> Option<Vec<T::AccountId>> {
+        sealed_vote.get_vote().as_ref().map(|applicant| {
+            if <RankedVotes<T>>::exists(&sealed_vote.commitment) {
+                Self::ranked_votes(&sealed_vote.commitment)
+            } else {
+                vec![applicant.clone()]
+            }
+        })
+    }
+
+    /// Elects up to council size applicants with the sequential Phragmén method.
+    ///
+    /// Each round elects the applicant with the lowest resulting load, where the load of an
+    /// applicant is what the voters ranking it would carry in total if it was elected, spread over
+    /// their stake. Ties are broken in favour of the applicant appearing first in `Applicants`.
+    /// Seats no staked applicant is left for go to the top staked remaining applicants.
+    fn tally_votes_phragmen(
+        sealed_votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>],
+    ) -> BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>> {
+        let applicants = Self::applicants();
+
+        let into_u128 = |stake: BalanceOf<T>| -> u128 { stake.unique_saturated_into() };
+
+        // Applicants back themselves with their own stake
+        let mut voters: Vec<(u128, Vec<T::AccountId>)> = applicants
+            .iter()
+            .map(|applicant| {
+                (
+                    into_u128(Self::applicant_stakes(applicant).total()),
+                    vec![applicant.clone()],
+                )
+            })
+            .collect();
+
+        let rankings: Vec<(&SealedVote<_, _, _, _>, Vec<T::AccountId>)> = sealed_votes
+            .iter()
+            .filter_map(|sealed_vote| {
+                Self::vote_ranking(sealed_vote).map(|ranking| (sealed_vote, ranking))
+            })
+            .collect();
+
+        voters.extend(
+            rankings.iter().map(|(sealed_vote, ranking)| {
+                (into_u128(sealed_vote.stake.total()), ranking.clone())
+            }),
+        );
+
+        let seats = if Self::seats_to_fill_usize() == 0 {
+            applicants.len()
+        } else {
+            Self::seats_to_fill_usize()
+        };
+
+        let mut loads = vec![0u128; voters.len()];
+        let mut elected: Vec<T::AccountId> = Vec::new();
+
+        while elected.len() < seats {
+            // (applicant, load) with the lowest load so far
+            let mut winner: Option<(&T::AccountId, u128)> = None;
+
+            for applicant in applicants.iter().filter(|id| !elected.contains(id)) {
+                let (approval, weighted_load) = voters
+                    .iter()
+                    .zip(loads.iter())
+                    .filter(|((_, ranking), _)| ranking.contains(applicant))
+                    .fold(
+                        (0u128, 0u128),
+                        |(approval, weighted_load), ((stake, _), load)| {
+                            (
+                                approval.saturating_add(*stake),
+                                weighted_load.saturating_add(stake.saturating_mul(*load)),
+                            )
+                        },
+                    );
+
+                // Applicants nobody staked on can't be elected
+                if approval == 0 {
+                    continue;
+                }
+
+                let load = PHRAGMEN_LOAD_SCALE.saturating_add(weighted_load) / approval;
+
+                if winner.map_or(true, |(_, lowest)| load < lowest) {
+                    winner = Some((applicant, load));
+                }
+            }
+
+            match winner {
+                Some((applicant, load)) => {
+                    for ((_, ranking), voter_load) in voters.iter().zip(loads.iter_mut()) {
+                        if ranking.contains(applicant) {
+                            *voter_load = load;
+                        }
+                    }
+                    elected.push(applicant.clone());
+                }
+                None => break,
+            }
+        }
+
+        // Like the single choice tally, fill the seats left with the top staked applicants
+        // nobody staked on.
+        if elected.len() < seats {
+            let mut unelected: Vec<T::AccountId> = applicants
+                .iter()
+                .filter(|id| !elected.contains(id))
+                .cloned()
+                .collect();
+
+            // stable sort keeps the `Applicants` order between equally staked applicants
+            unelected.sort_by_key(|applicant| {
+                rstd::cmp::Reverse(Self::applicant_stakes(applicant).total())
+            });
+
+            let seats_left = seats - elected.len();
+            elected.extend(unelected.into_iter().take(seats_left));
+        }
+
+        let mut tally: BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>> = elected
+            .into_iter()
+            .map(|applicant| {
+                let seat = Seat {
+                    member: applicant.clone(),
+                    stake: Self::applicant_stakes(&applicant).total(),
+                    backers: vec![],
+                };
+                (applicant, seat)
+            })
+            .collect();
+
+        // Votes back the highest ranked elected applicant with their full stake
+        for (sealed_vote, ranking) in rankings.iter() {
+            if let Some(applicant) = ranking.iter().find(|id| tally.contains_key(*id)) {
+                if let Some(seat) = tally.get_mut(applicant) {
+                    seat.backers.push(Backer {
+                        member: sealed_vote.voter.clone(),
+                        stake: sealed_vote.stake.total(),
+                    });
+                }
+            }
+        }
+
+        tally
+    }
+
     fn filter_top_staked(
         tally: &mut BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>>,
         limit: usize,
@@ -778,6 +1175,66 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    fn try_reveal_ranked_vote(
+        voter: T::AccountId,
+        commitment: T::Hash,
+        ranking: Vec<T::AccountId>,
+        salt: Vec<u8>,
+    ) -> Result {
+        ensure!(
+            Self::tally_mode() == TallyMode::Phragmen,
+            "ranked votes not allowed in single choice tally mode"
+        );
+        ensure!(<Votes<T>>::exists(&commitment), "commitment not found");
+
+        let sealed_vote = <Votes<T>>::get(&commitment);
+
+        ensure!(sealed_vote.is_not_revealed(), "vote already revealed");
+        // only voter can reveal their own votes
+        ensure!(sealed_vote.is_owned_by(voter), "only voter can reveal vote");
+        ensure!(!ranking.is_empty(), "ranking cannot be empty");
+        ensure!(
+            ranking
+                .iter()
+                .all(|applicant| <ApplicantStakes<T>>::exists(applicant)),
+            "vote for non-applicant not allowed"
+        );
+        ensure!(
+            ranking.iter().collect::<BTreeSet<_>>().len() == ranking.len(),
+            "ranking cannot contain duplicate applicants"
+        );
+
+        let mut salt = salt;
+
+        // The commitment is to the whole ranking, tries to unseal it and
+        // if salt is invalid will return error
+        let mut ranked_vote: SealedVote<
+            T::AccountId,
+            ElectionStake<T>,
+            T::Hash,
+            Vec<T::AccountId>,
+        > = SealedVote::new(sealed_vote.voter.clone(), sealed_vote.stake, commitment);
+        ranked_vote.unseal(
+            ranking.clone(),
+            &mut salt,
+            <T as system::Trait>::Hashing::hash,
+        )?;
+
+        // Update the revealed vote
+        <Votes<T>>::insert(
+            commitment,
+            SealedVote::new_unsealed(
+                sealed_vote.voter,
+                sealed_vote.stake,
+                commitment,
+                ranking[0].clone(),
+            ),
+        );
+        <RankedVotes<T>>::insert(commitment, ranking);
+
+        Ok(())
+    }
+
     fn set_verified_election_parameters(params: ElectionParameters<BalanceOf<T>, T::BlockNumber>) {
         <AnnouncingPeriod<T>>::put(params.announcing_period);
         <VotingPeriod<T>>::put(params.voting_period);
@@ -787,6 +1244,84 @@ impl<T: Trait> Module<T> {
         CouncilSize::put(params.council_size);
         CandidacyLimit::put(params.candidacy_limit);
         <MinVotingStake<T>>::put(params.min_voting_stake);
+        VoteTallyMode::put(params.tally_mode);
+    }
+
+    /// Slashes `amount` from the seat stake of a councilor and, if `slash_backers` is set, the same
+    /// proportion of each backing stake. Recorded seat stakes are reduced accordingly.
+    /// Returns the amounts actually slashed from the seat and backing stakes.
+    fn try_slash_council_member(
+        councilor: &T::AccountId,
+        amount: BalanceOf<T>,
+        slash_backers: bool,
+    ) -> rstd::result::Result<(BalanceOf<T>, BalanceOf<T>), &'static str> {
+        ensure!(
+            !Self::is_election_running(),
+            "cannot slash council stakes while an election is running"
+        );
+        ensure!(!amount.is_zero(), "slash amount must be greater than zero");
+
+        let mut council = <council::Module<T>>::active_council();
+        let seat = council
+            .iter_mut()
+            .find(|seat| seat.member == *councilor)
+            .ok_or("account is not a councilor")?;
+        ensure!(amount <= seat.stake, "slash amount exceeds seat stake");
+
+        let into_u128 = |stake: BalanceOf<T>| -> u128 { stake.unique_saturated_into() };
+        let backers_ratio =
+            Perbill::from_rational_approximation(into_u128(amount), into_u128(seat.stake));
+
+        let seat_slashed = Self::slash_reserved_stake(&seat.member, amount);
+        seat.stake -= seat_slashed;
+
+        let mut backing_slashed = BalanceOf::<T>::zero();
+        if slash_backers {
+            for backer in seat.backers.iter_mut() {
+                let backer_amount =
+                    BalanceOf::<T>::unique_saturated_from(backers_ratio * into_u128(backer.stake));
+                if backer_amount.is_zero() {
+                    continue;
+                }
+
+                let backer_slashed = Self::slash_reserved_stake(&backer.member, backer_amount);
+                backing_slashed += backer_slashed;
+                backer.stake -= backer_slashed;
+            }
+        }
+
+        <council::ActiveCouncil<T>>::put(council);
+
+        Ok((seat_slashed, backing_slashed))
+    }
+
+    /// Slashes reserved funds of an account, dropping the imbalance burns them.
+    fn slash_reserved_stake(who: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+        let (imbalance, _) = <T as GovernanceCurrency>::Currency::slash_reserved(who, amount);
+        imbalance.peek()
+    }
+
+    /// Returns the council mint id, failing if the council has no mint or the mint doesn't exist.
+    fn ensure_council_mint_exists(
+    ) -> rstd::result::Result<<T as minting::Trait>::MintId, &'static str> {
+        let mint_id = <council::Module<T>>::council_mint().ok_or("CouncilHasNoMint")?;
+        <minting::Module<T>>::get_mint_capacity(mint_id)?;
+
+        Ok(mint_id)
+    }
+
+    /// Increases the council mint capacity by the slashed amount.
+    fn return_slashed_stake_to_council_mint(
+        mint_id: <T as minting::Trait>::MintId,
+        slashed: BalanceOf<T>,
+    ) -> Result {
+        let capacity = <minting::Module<T>>::get_mint_capacity(mint_id)?;
+        let slashed: u128 = slashed.unique_saturated_into();
+        let increase = minting::BalanceOf::<T>::unique_saturated_from(slashed);
+
+        <minting::Module<T>>::set_mint_capacity(mint_id, capacity.saturating_add(increase))?;
+
+        Ok(())
     }
 }
 
@@ -814,6 +1349,11 @@ decl_module! {
             };
             ensure!(is_announcing, "election not in announcing stage");
 
+            ensure!(
+                !Self::is_by_election() || !<council::Module<T>>::is_councilor(&sender),
+                "councilors cannot apply in a by-election"
+            );
+
             // minimum stake on first attempt to apply
             if !<ApplicantStakes<T>>::exists(&sender) {
                 ensure!(stake >= Self::min_council_stake(), "minimum stake must be provided");
@@ -860,6 +1400,26 @@ decl_module! {
             Self::deposit_event(RawEvent::Revealed(sender, commitment, vote));
         }
 
+        // Reveals a vote committed to an ordered list of applicants, most preferred first.
+        // Only allowed when the election uses the Phragmen tally mode.
+        fn reveal_ranked(origin, commitment: T::Hash, ranking: Vec<T::AccountId>, salt: Vec<u8>) {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(salt.len() <= 32, "salt too large"); // at most 256 bits salt
+
+            let stage = Self::stage();
+            ensure!(Self::stage().is_some(), "election not running");
+
+            let is_revealing = match stage.unwrap() {
+                ElectionStage::Revealing(_) => true,
+                _ => false
+            };
+            ensure!(is_revealing, "election not in revealing stage");
+
+            Self::try_reveal_ranked_vote(sender.clone(), commitment, ranking.clone(), salt)?;
+            Self::deposit_event(RawEvent::RankedRevealed(sender, commitment, ranking));
+        }
+
         fn set_stage_announcing(origin, ends_at: T::BlockNumber) {
             ensure_root(origin)?;
             ensure!(ends_at > <system::Module<T>>::block_number(), "must end at future block number");
@@ -894,19 +1454,7 @@ decl_module! {
             ensure_root(origin)?;
             ensure!(Self::is_election_running(), "only running election can be stopped");
 
-            let mut votes = Vec::new();
-            for commitment in Self::commitments() {
-                votes.push(Self::votes(commitment));
-            }
-
-            // no council gets elected
-            let empty_council = BTreeMap::new();
-
-            Self::teardown_election (
-                &votes,
-                &empty_council,
-                false /* do not unlock transferable stakes */
-            );
+            Self::stop_election();
         }
 
         pub fn force_start_election(origin) {
@@ -914,6 +1462,35 @@ decl_module! {
             Self::start_election(<council::Module<T>>::active_council())?;
         }
 
+        /// Slashes the seat stake of a councilor by `amount`, optionally slashing its backers by the
+        /// same proportion of their stakes. Slashed funds are burned or returned to the council mint.
+        pub fn slash_council_member(
+            origin,
+            councilor: T::AccountId,
+            amount: BalanceOf<T>,
+            slash_backers: bool,
+            destination: SlashedStakeDestination
+        ) {
+            ensure_root(origin)?;
+
+            // Validate the mint before the stakes are slashed, returning the slashed funds can't
+            // fail afterwards.
+            let council_mint = if destination == SlashedStakeDestination::CouncilMint {
+                Some(Self::ensure_council_mint_exists()?)
+            } else {
+                None
+            };
+
+            let (seat_slashed, backing_slashed) =
+                Self::try_slash_council_member(&councilor, amount, slash_backers)?;
+
+            if let Some(mint_id) = council_mint {
+                Self::return_slashed_stake_to_council_mint(mint_id, seat_slashed + backing_slashed)?;
+            }
+
+            Self::deposit_event(RawEvent::CouncilMemberSlashed(councilor, seat_slashed, backing_slashed, destination));
+        }
+
         fn set_auto_start (origin, flag: bool) {
             ensure_root(origin)?;
             AutoStart::put(flag);
@@ -925,11 +1502,30 @@ decl_module! {
 impl<T: Trait> council::CouncilTermEnded for Module<T> {
     fn council_term_ended() {
         if Self::auto_start() {
+            // A by-election still running is superseded by the regular election
+            if Self::is_by_election() {
+                Self::stop_election();
+            }
+
             let _ = Self::start_election(<council::Module<T>>::active_council());
         }
     }
 }
 
+impl<T: Trait> council::CouncilElectionStatus for Module<T> {
+    fn is_election_running() -> bool {
+        Self::stage().is_some()
+    }
+}
+
+impl<T: Trait> council::CouncilSeatsVacated for Module<T> {
+    fn council_seats_vacated(seats: u32) {
+        if Self::start_by_election(seats).is_err() {
+            Self::deposit_event(RawEvent::ByElectionNotStarted(seats));
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1634,11 +2230,265 @@ mod tests {
         });
     }
 
-    pub fn mock_votes(
-        mock: Vec<(u64, u64, u64, u64)>,
-    ) -> Vec<SealedVote<u64, Stake<u64>, primitives::H256, u64>> {
-        let commitment = make_commitment_for_applicant(1, &mut vec![0u8]);
-
+    fn make_commitment_for_ranking(
+        ranking: &[<Test as system::Trait>::AccountId],
+        salt: &mut Vec<u8>,
+    ) -> <Test as system::Trait>::Hash {
+        let mut payload = ranking.encode();
+        payload.append(salt);
+        <Test as system::Trait>::Hashing::hash(&payload[..])
+    }
+
+    fn insert_sealed_vote(voter: u64, stake: u64, commitment: primitives::H256) {
+        <Votes<Test>>::insert(
+            &commitment,
+            SealedVote::new(
+                voter,
+                Stake {
+                    new: stake,
+                    transferred: 0,
+                },
+                commitment,
+            ),
+        );
+    }
+
+    #[test]
+    fn revealing_ranked_vote_works() {
+        initial_test_ext().execute_with(|| {
+            VoteTallyMode::put(TallyMode::Phragmen);
+
+            let ranking = vec![20 as u64, 30 as u64];
+            let salt = vec![128u8];
+            let commitment = make_commitment_for_ranking(&ranking, &mut salt.clone());
+            let voter = 10 as u64;
+
+            for applicant in ranking.iter() {
+                <ApplicantStakes<Test>>::insert(applicant, Stake::default());
+            }
+            insert_sealed_vote(voter, 100, commitment);
+
+            assert_ok!(Election::try_reveal_ranked_vote(
+                voter,
+                commitment,
+                ranking.clone(),
+                salt
+            ));
+            assert_eq!(<Votes<Test>>::get(commitment).get_vote().unwrap(), 20);
+            assert_eq!(Election::ranked_votes(commitment), ranking);
+        });
+    }
+
+    #[test]
+    fn revealing_ranked_vote_in_single_choice_mode_should_not_work() {
+        initial_test_ext().execute_with(|| {
+            let ranking = vec![20 as u64, 30 as u64];
+            let salt = vec![128u8];
+            let commitment = make_commitment_for_ranking(&ranking, &mut salt.clone());
+            let voter = 10 as u64;
+
+            for applicant in ranking.iter() {
+                <ApplicantStakes<Test>>::insert(applicant, Stake::default());
+            }
+            insert_sealed_vote(voter, 100, commitment);
+
+            assert!(Election::try_reveal_ranked_vote(voter, commitment, ranking, salt).is_err());
+            assert!(<Votes<Test>>::get(commitment).is_not_revealed());
+        });
+    }
+
+    #[test]
+    fn revealing_invalid_ranking_should_not_work() {
+        initial_test_ext().execute_with(|| {
+            VoteTallyMode::put(TallyMode::Phragmen);
+
+            let voter = 10 as u64;
+            let salt = vec![128u8];
+            <ApplicantStakes<Test>>::insert(20, Stake::default());
+            <ApplicantStakes<Test>>::insert(30, Stake::default());
+
+            for ranking in vec![vec![], vec![20, 20], vec![20, 40]] {
+                let commitment = make_commitment_for_ranking(&ranking, &mut salt.clone());
+                insert_sealed_vote(voter, 100, commitment);
+
+                assert!(Election::try_reveal_ranked_vote(
+                    voter,
+                    commitment,
+                    ranking.clone(),
+                    salt.clone()
+                )
+                .is_err());
+                assert!(<Votes<Test>>::get(commitment).is_not_revealed());
+                assert!(!<RankedVotes<Test>>::exists(commitment));
+            }
+
+            // commitment to a ranking can't be revealed with a different ranking
+            let commitment = make_commitment_for_ranking(&[20, 30], &mut salt.clone());
+            insert_sealed_vote(voter, 100, commitment);
+            assert!(
+                Election::try_reveal_ranked_vote(voter, commitment, vec![30, 20], salt).is_err()
+            );
+        });
+    }
+
+    fn mock_ranked_votes(
+        mock: Vec<(u64, u64, Vec<u64>)>,
+    ) -> Vec<SealedVote<u64, Stake<u64>, primitives::H256, u64>> {
+        mock.into_iter()
+            .enumerate()
+            .map(|(index, (voter, stake, ranking))| {
+                let commitment = make_commitment_for_ranking(&ranking, &mut vec![index as u8]);
+                <RankedVotes<Test>>::insert(commitment, ranking.clone());
+
+                SealedVote::new_unsealed(
+                    voter,
+                    Stake {
+                        new: stake,
+                        transferred: 0,
+                    },
+                    commitment,
+                    ranking[0],
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn phragmen_vote_tallying_should_work() {
+        initial_test_ext().execute_with(|| {
+            <Applicants<Test>>::put(vec![100, 200, 300]);
+            for applicant in &[100u64, 200, 300] {
+                <ApplicantStakes<Test>>::insert(applicant, Stake::default());
+            }
+            CouncilSize::put(2);
+
+            let votes = mock_ranked_votes(vec![
+                //  (voter, stake, ranking)
+                (10, 600, vec![100, 200]),
+                (20, 500, vec![100, 200]),
+                (30, 600, vec![300]),
+            ]);
+
+            // Most stake is behind 200, but it is shared with 100 which is elected first
+            let tally = Election::tally_votes_phragmen(&votes);
+
+            assert_eq!(tally.len(), 2);
+            assert_eq!(
+                tally.get(&100).unwrap().backers,
+                vec![
+                    Backer {
+                        member: 10 as u64,
+                        stake: 600 as u64,
+                    },
+                    Backer {
+                        member: 20 as u64,
+                        stake: 500 as u64,
+                    },
+                ]
+            );
+            assert_eq!(
+                tally.get(&300).unwrap().backers,
+                vec![Backer {
+                    member: 30 as u64,
+                    stake: 600 as u64,
+                }]
+            );
+        });
+    }
+
+    #[test]
+    fn phragmen_tally_counts_applicant_stake() {
+        initial_test_ext().execute_with(|| {
+            <Applicants<Test>>::put(vec![100, 200, 300]);
+            <ApplicantStakes<Test>>::insert(100, Stake::default());
+            <ApplicantStakes<Test>>::insert(
+                200,
+                Stake {
+                    new: 1000,
+                    transferred: 0,
+                },
+            );
+            <ApplicantStakes<Test>>::insert(300, Stake::default());
+            CouncilSize::put(2);
+
+            let votes = mock_ranked_votes(vec![
+                //  (voter, stake, ranking)
+                (10, 300, vec![300, 100]),
+                (20, 200, vec![100]),
+            ]);
+
+            let tally = Election::tally_votes_phragmen(&votes);
+
+            assert_eq!(tally.len(), 2);
+            assert_eq!(tally.get(&200).unwrap().stake, 1000);
+            assert!(tally.get(&200).unwrap().backers.is_empty());
+            assert!(tally.contains_key(&100));
+            assert!(!tally.contains_key(&300));
+            // voter 10 backs its highest ranked elected applicant
+            assert_eq!(tally.get(&100).unwrap().calc_total_stake(), 500);
+        });
+    }
+
+    #[test]
+    fn phragmen_tally_fills_seats_with_unbacked_applicants() {
+        initial_test_ext().execute_with(|| {
+            <Applicants<Test>>::put(vec![100, 200, 300]);
+            <ApplicantStakes<Test>>::insert(100, Stake::default());
+            <ApplicantStakes<Test>>::insert(200, Stake::default());
+            <ApplicantStakes<Test>>::insert(300, Stake::default());
+            CouncilSize::put(3);
+
+            let votes = mock_ranked_votes(vec![
+                //  (voter, stake, ranking)
+                (10, 300, vec![300]),
+            ]);
+
+            let tally = Election::tally_votes_phragmen(&votes);
+
+            assert_eq!(tally.len(), 3);
+            assert!(tally.get(&100).unwrap().backers.is_empty());
+            assert!(tally.get(&200).unwrap().backers.is_empty());
+            assert_eq!(tally.get(&300).unwrap().calc_total_stake(), 300);
+        });
+    }
+
+    #[test]
+    fn refunding_ranked_voting_stakes_should_work() {
+        initial_test_ext().execute_with(|| {
+            let _ = Balances::deposit_creating(&10, 1000);
+            let _ = Balances::reserve(&10, 1000);
+            let _ = Balances::deposit_creating(&20, 1000);
+            let _ = Balances::reserve(&20, 1000);
+
+            let votes = mock_ranked_votes(vec![
+                //  (voter, stake, ranking)
+                (10, 100, vec![100, 200]),
+                (20, 200, vec![100, 300]),
+            ]);
+
+            let mut new_council: BTreeMap<u64, Seat<u64, u64>> = BTreeMap::new();
+            new_council.insert(
+                200 as u64,
+                Seat {
+                    member: 200 as u64,
+                    stake: 0 as u64,
+                    backers: vec![],
+                },
+            );
+
+            Election::refund_voting_stakes(&votes, &new_council);
+
+            // Only votes which ranked no elected applicant are refunded
+            assert_eq!(Balances::free_balance(&10), 0);
+            assert_eq!(Balances::free_balance(&20), 200);
+        });
+    }
+
+    pub fn mock_votes(
+        mock: Vec<(u64, u64, u64, u64)>,
+    ) -> Vec<SealedVote<u64, Stake<u64>, primitives::H256, u64>> {
+        let commitment = make_commitment_for_applicant(1, &mut vec![0u8]);
+
         mock.into_iter()
             .map(|(voter, stake_ref, stake_tran, applicant)| {
                 SealedVote::new_unsealed(
@@ -1896,6 +2746,101 @@ mod tests {
         });
     }
 
+    #[test]
+    fn archiving_election_result_should_work() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(5);
+            Round::put(1);
+
+            <Applicants<Test>>::put(vec![100, 200]);
+            <ApplicantStakes<Test>>::insert(
+                100,
+                Stake {
+                    new: 10,
+                    transferred: 0,
+                },
+            );
+            <ApplicantStakes<Test>>::insert(
+                200,
+                Stake {
+                    new: 20,
+                    transferred: 0,
+                },
+            );
+
+            let mut votes = mock_votes(vec![
+                //  (voter, stake[new], stake[transferred], applicant)
+                (10, 100, 0, 100),
+                (20, 200, 50, 100),
+                (30, 300, 0, 200),
+            ]);
+            let commitment = make_commitment_for_applicant(200, &mut vec![1u8]);
+            votes.push(SealedVote::new(
+                40,
+                Stake {
+                    new: 400,
+                    transferred: 0,
+                },
+                commitment,
+            ));
+
+            let mut new_council: BTreeMap<u64, Seat<u64, u64>> = BTreeMap::new();
+            new_council.insert(
+                100 as u64,
+                Seat {
+                    member: 100 as u64,
+                    stake: 10 as u64,
+                    backers: vec![],
+                },
+            );
+
+            Election::archive_election_result(&votes, &new_council);
+
+            assert_eq!(
+                Election::election_results(1),
+                Some(ElectionResult {
+                    ended_at: 5,
+                    tally_mode: TallyMode::SingleChoice,
+                    applicants: vec![
+                        ApplicantResult {
+                            applicant: 100,
+                            stake: 10,
+                            backing: 350,
+                            votes: 2,
+                        },
+                        ApplicantResult {
+                            applicant: 200,
+                            stake: 20,
+                            backing: 300,
+                            votes: 1,
+                        },
+                    ],
+                    revealed_votes: 3,
+                    unrevealed_commitments: 1,
+                    winners: vec![100],
+                })
+            );
+            assert_eq!(Election::archived_rounds(), vec![1]);
+        });
+    }
+
+    #[test]
+    fn election_history_should_be_bounded() {
+        initial_test_ext().execute_with(|| {
+            let no_council = BTreeMap::new();
+
+            for round in 1..=(ELECTION_HISTORY_DEPTH as u32 + 1) {
+                Round::put(round);
+                Election::archive_election_result(&[], &no_council);
+            }
+
+            assert!(Election::election_results(1).is_none());
+            assert!(Election::election_results(2).is_some());
+            assert_eq!(Election::archived_rounds().len(), ELECTION_HISTORY_DEPTH);
+            assert_eq!(Election::archived_rounds()[0], 2);
+        });
+    }
+
     #[test]
     fn unlock_transferable_stakes_should_work() {
         initial_test_ext().execute_with(|| {
@@ -2073,11 +3018,98 @@ mod tests {
             }
             assert!(Election::stage().is_none());
 
+            let result = Election::election_results(Election::round()).unwrap();
+            assert_eq!(result.winners.len(), Election::council_size_usize());
+            assert_eq!(result.revealed_votes, 19);
+            assert_eq!(result.unrevealed_commitments, 38);
+
             // When council term ends.. start a new election.
             assert_ok!(Election::start_election(vec![]));
         });
     }
 
+    #[test]
+    fn by_election_should_fill_vacant_seat() {
+        initial_test_ext().execute_with(|| {
+            CouncilSize::put(3);
+            <MinCouncilStake<Test>>::put(50);
+            <AnnouncingPeriod<Test>>::put(10);
+            <VotingPeriod<Test>>::put(10);
+            <RevealingPeriod<Test>>::put(10);
+            CandidacyLimit::put(5);
+            <MinVotingStake<Test>>::put(10);
+
+            for i in 1..6 {
+                let _ = Balances::deposit_creating(&(i as u64), 1000);
+            }
+
+            assert_ok!(Council::set_council(Origin::ROOT, vec![1, 2]));
+            <council::TermEndsAt<Test>>::put(1000);
+
+            System::set_block_number(1);
+            assert_ok!(Election::start_by_election(1));
+            assert!(Election::is_by_election());
+
+            // councilors can't apply for the vacant seat
+            assert!(Election::apply(Origin::signed(1), 100).is_err());
+            assert_ok!(Election::apply(Origin::signed(3), 100));
+            assert_ok!(Election::apply(Origin::signed(4), 50));
+
+            let n = 1 + Election::announcing_period();
+            System::set_block_number(n);
+            Election::on_finalize(n);
+
+            let commitment = make_commitment_for_applicant(4, &mut vec![40u8]);
+            assert_ok!(Election::vote(Origin::signed(5), commitment, 200));
+
+            let n = n + Election::voting_period();
+            System::set_block_number(n);
+            Election::on_finalize(n);
+
+            assert_ok!(Election::reveal(
+                Origin::signed(5),
+                commitment,
+                4,
+                vec![40u8]
+            ));
+
+            let n = n + Election::revealing_period();
+            System::set_block_number(n);
+            Election::on_finalize(n);
+
+            assert!(Election::stage().is_none());
+            assert!(!Election::is_by_election());
+
+            let members: Vec<u64> = Council::active_council()
+                .into_iter()
+                .map(|seat| seat.member)
+                .collect();
+            assert_eq!(members, vec![1, 2, 4]);
+            assert_eq!(Council::term_ends_at(), 1000);
+
+            // unelected applicant is refunded
+            assert_eq!(Balances::reserved_balance(&3), 0);
+        });
+    }
+
+    #[test]
+    fn by_election_should_not_start_if_term_ends_first() {
+        initial_test_ext().execute_with(|| {
+            <AnnouncingPeriod<Test>>::put(10);
+            <VotingPeriod<Test>>::put(10);
+            <RevealingPeriod<Test>>::put(10);
+            <council::TermEndsAt<Test>>::put(20);
+
+            System::set_block_number(1);
+            assert!(Election::start_by_election(1).is_err());
+            assert!(Election::stage().is_none());
+
+            <Election as council::CouncilSeatsVacated>::council_seats_vacated(1);
+            assert!(Election::stage().is_none());
+            assert!(!Election::is_by_election());
+        });
+    }
+
     #[test]
     fn setting_election_parameters() {
         initial_test_ext().execute_with(|| {
@@ -2094,6 +3126,7 @@ mod tests {
                 min_voting_stake: 6,
                 min_council_stake: 7,
                 new_term_duration: 8,
+                tally_mode: TallyMode::Phragmen,
             };
 
             assert_ok!(Election::set_election_parameters(
@@ -2124,6 +3157,181 @@ mod tests {
                 <MinVotingStake<Test>>::get(),
                 new_parameters.min_voting_stake
             );
+            assert_eq!(VoteTallyMode::get(), new_parameters.tally_mode);
+        });
+    }
+
+    fn setup_slashable_council() {
+        for (account, stake) in &[(5u64, 50u64), (10, 30), (11, 20)] {
+            let _ = Balances::deposit_creating(account, 100);
+            assert_ok!(Balances::reserve(account, *stake));
+        }
+
+        <council::ActiveCouncil<Test>>::put(vec![
+            Seat {
+                member: 5,
+                stake: 50,
+                backers: vec![
+                    Backer {
+                        member: 10,
+                        stake: 30,
+                    },
+                    Backer {
+                        member: 11,
+                        stake: 20,
+                    },
+                ],
+            },
+            Seat {
+                member: 6,
+                stake: 0,
+                backers: vec![],
+            },
+        ]);
+    }
+
+    #[test]
+    fn slash_council_member_should_burn_seat_and_backing_stakes() {
+        initial_test_ext().execute_with(|| {
+            setup_slashable_council();
+            let issuance = Balances::total_issuance();
+
+            assert_ok!(Election::slash_council_member(
+                Origin::ROOT,
+                5,
+                20,
+                true,
+                SlashedStakeDestination::Burn
+            ));
+
+            assert_eq!(Balances::reserved_balance(&5), 30);
+            assert_eq!(Balances::reserved_balance(&10), 18);
+            assert_eq!(Balances::reserved_balance(&11), 12);
+            assert_eq!(Balances::total_issuance(), issuance - 40);
+
+            let seat = &Council::active_council()[0];
+            assert_eq!(seat.stake, 30);
+            assert_eq!(seat.backers[0].stake, 18);
+            assert_eq!(seat.backers[1].stake, 12);
+        });
+    }
+
+    #[test]
+    fn slash_council_member_without_backers_should_only_slash_seat_stake() {
+        initial_test_ext().execute_with(|| {
+            setup_slashable_council();
+
+            assert_ok!(Election::slash_council_member(
+                Origin::ROOT,
+                5,
+                50,
+                false,
+                SlashedStakeDestination::Burn
+            ));
+
+            assert_eq!(Balances::reserved_balance(&5), 0);
+            assert_eq!(Balances::reserved_balance(&10), 30);
+            assert_eq!(Balances::reserved_balance(&11), 20);
+            assert_eq!(Council::active_council()[0].stake, 0);
+            // the councilor keeps its seat
+            assert!(Council::is_councilor(&5));
+        });
+    }
+
+    #[test]
+    fn slash_council_member_should_return_slashed_stake_to_council_mint() {
+        initial_test_ext().execute_with(|| {
+            setup_slashable_council();
+
+            // no council mint to return the slashed stake to
+            assert!(Election::slash_council_member(
+                Origin::ROOT,
+                5,
+                20,
+                true,
+                SlashedStakeDestination::CouncilMint
+            )
+            .is_err());
+            assert_eq!(Balances::reserved_balance(&5), 50);
+
+            // council mint id of a mint that doesn't exist
+            <council::CouncilMint<Test>>::put(1000);
+            assert!(Election::slash_council_member(
+                Origin::ROOT,
+                5,
+                20,
+                true,
+                SlashedStakeDestination::CouncilMint
+            )
+            .is_err());
+            assert_eq!(Balances::reserved_balance(&5), 50);
+            assert_eq!(Council::active_council()[0].stake, 50);
+            <council::CouncilMint<Test>>::kill();
+
+            assert_ok!(Council::set_council_mint_capacity(Origin::ROOT, 1000));
+            assert_ok!(Election::slash_council_member(
+                Origin::ROOT,
+                5,
+                20,
+                true,
+                SlashedStakeDestination::CouncilMint
+            ));
+
+            let mint_id = Council::council_mint().unwrap();
+            assert_eq!(
+                <minting::Module<Test>>::get_mint_capacity(mint_id),
+                Ok(1040)
+            );
+        });
+    }
+
+    #[test]
+    fn slash_council_member_should_fail_with_invalid_parameters() {
+        initial_test_ext().execute_with(|| {
+            setup_slashable_council();
+
+            // not a councilor
+            assert!(Election::slash_council_member(
+                Origin::ROOT,
+                10,
+                10,
+                false,
+                SlashedStakeDestination::Burn
+            )
+            .is_err());
+
+            // more than the seat stake
+            assert!(Election::slash_council_member(
+                Origin::ROOT,
+                5,
+                51,
+                false,
+                SlashedStakeDestination::Burn
+            )
+            .is_err());
+
+            // zero amount
+            assert!(Election::slash_council_member(
+                Origin::ROOT,
+                6,
+                0,
+                false,
+                SlashedStakeDestination::Burn
+            )
+            .is_err());
+
+            // during an election
+            assert_ok!(Election::start_election(Council::active_council()));
+            assert!(Election::slash_council_member(
+                Origin::ROOT,
+                5,
+                10,
+                false,
+                SlashedStakeDestination::Burn
+            )
+            .is_err());
+
+            assert_eq!(Balances::reserved_balance(&5), 50);
         });
     }
 }
@@ -9,6 +9,24 @@ pub static MSG_COUNCIL_SIZE_CANNOT_BE_ZERO: &str = "CouncilSizeCannotBeZero";
 pub static MSG_CANDIDACY_LIMIT_WAS_LOWER_THAN_COUNCIL_SIZE: &str =
     "CandidacyWasLessThanCouncilSize";
 
+/// How revealed votes are counted at the end of the revealing stage
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, Debug)]
+pub enum TallyMode {
+    /// Each vote backs a single applicant, seats go to the applicants with the most
+    /// total stake.
+    SingleChoice,
+    /// Each vote ranks one or more applicants, seats are filled with the stake-weighted
+    /// sequential Phragmén method.
+    Phragmen,
+}
+
+impl Default for TallyMode {
+    fn default() -> Self {
+        TallyMode::SingleChoice
+    }
+}
+
 /// Combined Election parameters, as argument for set_election_parameters
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Copy, Encode, Decode, Default, PartialEq, Debug)]
@@ -21,6 +39,7 @@ pub struct ElectionParameters<Balance, BlockNumber> {
     pub new_term_duration: BlockNumber,
     pub min_council_stake: Balance,
     pub min_voting_stake: Balance,
+    pub tally_mode: TallyMode,
 }
 
 impl<Balance, BlockNumber: PartialOrd + Zero> ElectionParameters<Balance, BlockNumber> {
@@ -56,6 +56,8 @@ impl council::Trait for Test {
     type Event = ();
 
     type CouncilTermEnded = (Election,);
+    type CouncilSeatsVacated = (Election,);
+    type CouncilElectionStatus = (Election,);
 }
 impl election::Trait for Test {
     type Event = ();
@@ -16,6 +16,7 @@ pub enum Role {
     Curator,
 }
 
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
 pub struct ActorInRole<ActorId> {
     pub role: Role,
@@ -61,4 +62,8 @@ impl<ActorId: Ord + Copy> ActorInRoleSet<ActorId> {
     pub fn has_registered_role(&self, actor_in_role: &ActorInRole<ActorId>) -> bool {
         self.0.contains(actor_in_role)
     }
+
+    pub fn to_vec(&self) -> Vec<ActorInRole<ActorId>> {
+        self.0.iter().copied().collect()
+    }
 }
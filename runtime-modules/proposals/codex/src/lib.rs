@@ -20,6 +20,8 @@
 //! ### Council and election proposals
 //! - [create_set_election_parameters_proposal](./struct.Module.html#method.create_set_election_parameters_proposal)
 //! - [create_spending_proposal](./struct.Module.html#method.create_spending_proposal)
+//! - [create_recall_council_member_proposal](./struct.Module.html#method.create_recall_council_member_proposal)
+//! - [create_slash_council_member_proposal](./struct.Module.html#method.create_slash_council_member_proposal)
 //!
 //! ### Content working group proposals
 //! - [create_set_lead_proposal](./struct.Module.html#method.create_set_lead_proposal)
@@ -67,6 +69,7 @@ mod tests;
 
 use common::origin::ActorOriginValidator;
 use common::working_group::WorkingGroup;
+use governance::election::SlashedStakeDestination;
 use governance::election_params::ElectionParameters;
 use proposal_engine::ProposalParameters;
 use rstd::clone::Clone;
@@ -250,6 +253,15 @@ decl_error! {
 
         /// Invalid 'decrease stake proposal' parameter - cannot decrease by zero balance.
         DecreasingStakeIsZero,
+
+        /// Invalid 'recall council member proposal' parameter - account is not a councilor.
+        InvalidRecallCouncilMemberParameterNotCouncilor,
+
+        /// Invalid 'slash council member proposal' parameter - account is not a councilor.
+        InvalidSlashCouncilMemberParameterNotCouncilor,
+
+        /// Invalid 'slash council member proposal' parameter - slashing balance exceeds the seat stake.
+        InvalidSlashCouncilMemberParameterExceedsSeatStake,
     }
 }
 
@@ -403,6 +415,22 @@ decl_storage! {
         /// Grace period for the 'terminate working group leader role' proposal
         pub TerminateWorkingGroupLeaderRoleProposalGracePeriod get(terminate_working_group_leader_role_proposal_grace_period)
             config(): T::BlockNumber;
+
+        /// Voting period for the 'recall council member' proposal
+        pub RecallCouncilMemberProposalVotingPeriod get(recall_council_member_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'recall council member' proposal
+        pub RecallCouncilMemberProposalGracePeriod get(recall_council_member_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'slash council member' proposal
+        pub SlashCouncilMemberProposalVotingPeriod get(slash_council_member_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'slash council member' proposal
+        pub SlashCouncilMemberProposalGracePeriod get(slash_council_member_proposal_grace_period)
+            config(): T::BlockNumber;
     }
 }
 
@@ -877,6 +905,81 @@ decl_module! {
             Self::create_proposal(params)?;
         }
 
+        /// Create 'Recall council member' proposal type.
+        /// This proposal uses `recall_council_member()` extrinsic from the `governance::council`  module.
+        pub fn create_recall_council_member_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            councilor: T::AccountId,
+            start_by_election: bool,
+        ) {
+            ensure!(
+                <governance::council::Module<T>>::is_councilor(&councilor),
+                Error::InvalidRecallCouncilMemberParameterNotCouncilor
+            );
+
+            let proposal_details = ProposalDetails::RecallCouncilMember(councilor, start_by_election);
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::recall_council_member_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'Slash council member' proposal type.
+        /// This proposal uses `slash_council_member()` extrinsic from the `governance::election`  module.
+        pub fn create_slash_council_member_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            councilor: T::AccountId,
+            slashing_stake: BalanceOfGovernanceCurrency<T>,
+            slash_backers: bool,
+            destination: SlashedStakeDestination,
+        ) {
+            let seat = <governance::council::Module<T>>::active_council()
+                .into_iter()
+                .find(|seat| seat.member == councilor)
+                .ok_or(Error::InvalidSlashCouncilMemberParameterNotCouncilor)?;
+
+            ensure!(slashing_stake != Zero::zero(), Error::SlashingStakeIsZero);
+            ensure!(
+                slashing_stake <= seat.stake,
+                Error::InvalidSlashCouncilMemberParameterExceedsSeatStake
+            );
+
+            let proposal_details = ProposalDetails::SlashCouncilMember(
+                councilor,
+                slashing_stake,
+                slash_backers,
+                destination,
+            );
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::slash_council_member_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
 
 // *************** Extrinsic to execute
 
@@ -1146,5 +1249,17 @@ impl<T: Trait> Module<T> {
         <TerminateWorkingGroupLeaderRoleProposalGracePeriod<T>>::put(T::BlockNumber::from(
             p.terminate_working_group_leader_role_proposal_grace_period,
         ));
+        <RecallCouncilMemberProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.recall_council_member_proposal_voting_period,
+        ));
+        <RecallCouncilMemberProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.recall_council_member_proposal_grace_period,
+        ));
+        <SlashCouncilMemberProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.slash_council_member_proposal_voting_period,
+        ));
+        <SlashCouncilMemberProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.slash_council_member_proposal_grace_period,
+        ));
     }
 }
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::ElectionParameters;
 use common::working_group::WorkingGroup;
+use governance::election::SlashedStakeDestination;
 
 /// Encodes proposal using its details information.
 pub trait ProposalEncoder<T: crate::Trait> {
@@ -99,6 +100,13 @@ pub enum ProposalDetails<
 
     /// Fire the working group leader with possible slashing.
     TerminateWorkingGroupLeaderRole(TerminateRoleParameters<WorkerId>),
+
+    /// Councilor account to recall and whether to start a by-election for the vacated seat.
+    RecallCouncilMember(AccountId, bool),
+
+    /// Councilor account, balance to slash from its seat stake, whether to slash the backers by
+    /// the same proportion and where the slashed funds go.
+    SlashCouncilMember(AccountId, CurrencyBalance, bool, SlashedStakeDestination),
 }
 
 impl<
@@ -311,6 +319,18 @@ pub struct ProposalsConfigParameters {
 
     /// 'Terminate working group leader role' proposal grace period
     pub terminate_working_group_leader_role_proposal_grace_period: u32,
+
+    /// 'Recall council member' proposal voting period
+    pub recall_council_member_proposal_voting_period: u32,
+
+    /// 'Recall council member' proposal grace period
+    pub recall_council_member_proposal_grace_period: u32,
+
+    /// 'Slash council member' proposal voting period
+    pub slash_council_member_proposal_voting_period: u32,
+
+    /// 'Slash council member' proposal grace period
+    pub slash_council_member_proposal_grace_period: u32,
 }
 
 impl Default for ProposalsConfigParameters {
@@ -346,6 +366,10 @@ impl Default for ProposalsConfigParameters {
             set_working_group_leader_reward_proposal_grace_period: 0u32,
             terminate_working_group_leader_role_proposal_voting_period: 72200u32,
             terminate_working_group_leader_role_proposal_grace_period: 0u32,
+            recall_council_member_proposal_voting_period: 72000u32,
+            recall_council_member_proposal_grace_period: 0u32,
+            slash_council_member_proposal_voting_period: 72000u32,
+            slash_council_member_proposal_grace_period: 0u32,
         }
     }
 }
@@ -386,6 +410,10 @@ impl ProposalsConfigParameters {
             set_working_group_leader_reward_proposal_grace_period: 0,
             terminate_working_group_leader_role_proposal_voting_period: voting_period,
             terminate_working_group_leader_role_proposal_grace_period: 0,
+            recall_council_member_proposal_voting_period: voting_period,
+            recall_council_member_proposal_grace_period: 0,
+            slash_council_member_proposal_voting_period: voting_period,
+            slash_council_member_proposal_grace_period: 0,
         }
     }
 }
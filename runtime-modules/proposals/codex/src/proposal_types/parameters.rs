@@ -211,3 +211,31 @@ pub(crate) fn terminate_working_group_leader_role_proposal<T: crate::Trait>(
         required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
     }
 }
+
+// Proposal parameters for the 'Recall council member' proposal
+pub(crate) fn recall_council_member_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    ProposalParameters {
+        voting_period: <Module<T>>::recall_council_member_proposal_voting_period(),
+        grace_period: <Module<T>>::recall_council_member_proposal_grace_period(),
+        approval_quorum_percentage: 80,
+        approval_threshold_percentage: 80,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(<BalanceOf<T>>::from(200_000_u32)),
+    }
+}
+
+// Proposal parameters for the 'Slash council member' proposal
+pub(crate) fn slash_council_member_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    ProposalParameters {
+        voting_period: <Module<T>>::slash_council_member_proposal_voting_period(),
+        grace_period: <Module<T>>::slash_council_member_proposal_grace_period(),
+        approval_quorum_percentage: 80,
+        approval_threshold_percentage: 80,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(<BalanceOf<T>>::from(200_000_u32)),
+    }
+}
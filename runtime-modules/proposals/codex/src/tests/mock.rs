@@ -121,6 +121,8 @@ impl mint::Trait for Test {
 impl governance::council::Trait for Test {
     type Event = ();
     type CouncilTermEnded = ();
+    type CouncilSeatsVacated = ();
+    type CouncilElectionStatus = ();
 }
 
 impl common::origin::ActorOriginValidator<Origin, u64, u64> for () {
@@ -177,6 +179,7 @@ impl recurring_rewards::Trait for Test {
 }
 
 impl versioned_store_permissions::Trait for Test {
+    type Event = ();
     type Credential = u64;
     type CredentialChecker = ();
     type CreateClassPermissionsChecker = ();
@@ -184,6 +187,8 @@ impl versioned_store_permissions::Trait for Test {
 
 impl versioned_store::Trait for Test {
     type Event = ();
+    type ContentId = H256;
+    type DataObjectChecker = ();
 }
 
 impl hiring::Trait for Test {
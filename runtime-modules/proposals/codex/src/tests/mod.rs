@@ -1,8 +1,9 @@
 mod mock;
 
-use governance::election_params::ElectionParameters;
+use governance::election::{Seat, SlashedStakeDestination};
+use governance::election_params::{ElectionParameters, TallyMode};
 use srml_support::traits::Currency;
-use srml_support::StorageMap;
+use srml_support::{StorageMap, StorageValue};
 use system::RawOrigin;
 
 use crate::*;
@@ -348,6 +349,7 @@ fn get_valid_election_parameters() -> ElectionParameters<u64, u64> {
         new_term_duration: 14400,
         min_council_stake: 1,
         min_voting_stake: 1,
+        tally_mode: TallyMode::SingleChoice,
     }
 }
 
@@ -924,6 +926,22 @@ fn set_default_proposal_parameters_succeeded() {
             <TerminateWorkingGroupLeaderRoleProposalGracePeriod<Test>>::get(),
             p.terminate_working_group_leader_role_proposal_grace_period as u64
         );
+        assert_eq!(
+            <RecallCouncilMemberProposalVotingPeriod<Test>>::get(),
+            p.recall_council_member_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <RecallCouncilMemberProposalGracePeriod<Test>>::get(),
+            p.recall_council_member_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <SlashCouncilMemberProposalVotingPeriod<Test>>::get(),
+            p.slash_council_member_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SlashCouncilMemberProposalGracePeriod<Test>>::get(),
+            p.slash_council_member_proposal_grace_period as u64
+        );
     });
 }
 
@@ -1518,3 +1536,224 @@ fn create_terminate_working_group_leader_role_proposal_common_checks_succeed() {
         proposal_fixture.check_all();
     });
 }
+
+#[test]
+fn create_recall_council_member_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let councilor_account_id = 20;
+        <governance::council::Module<Test>>::set_council(
+            RawOrigin::Root.into(),
+            vec![councilor_account_id],
+        )
+        .unwrap();
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_recall_council_member_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    20,
+                    true,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_recall_council_member_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    20,
+                    true,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_recall_council_member_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    20,
+                    true,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_recall_council_member_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(200_000_u32)),
+                    20,
+                    true,
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::recall_council_member_proposal::<Test>(),
+            proposal_details: ProposalDetails::RecallCouncilMember(councilor_account_id, true),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_recall_council_member_proposal_fails_with_non_councilor() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        assert_eq!(
+            ProposalCodex::create_recall_council_member_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(200_000_u32)),
+                20,
+                false,
+            ),
+            Err(Error::InvalidRecallCouncilMemberParameterNotCouncilor)
+        );
+    });
+}
+
+fn setup_staked_councilor(councilor: u64, stake: u64) {
+    <governance::council::ActiveCouncil<Test>>::put(vec![Seat {
+        member: councilor,
+        stake,
+        backers: Vec::new(),
+    }]);
+}
+
+#[test]
+fn create_slash_council_member_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        setup_staked_councilor(20, 1000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_slash_council_member_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    20,
+                    100,
+                    true,
+                    SlashedStakeDestination::CouncilMint,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_slash_council_member_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    20,
+                    100,
+                    true,
+                    SlashedStakeDestination::CouncilMint,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_slash_council_member_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    20,
+                    100,
+                    true,
+                    SlashedStakeDestination::CouncilMint,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_slash_council_member_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(200_000_u32)),
+                    20,
+                    100,
+                    true,
+                    SlashedStakeDestination::CouncilMint,
+                )
+            },
+            proposal_parameters: crate::proposal_types::parameters::slash_council_member_proposal::<
+                Test,
+            >(),
+            proposal_details: ProposalDetails::SlashCouncilMember(
+                20,
+                100,
+                true,
+                SlashedStakeDestination::CouncilMint,
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_slash_council_member_proposal_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        setup_staked_councilor(20, 1000);
+
+        assert_eq!(
+            ProposalCodex::create_slash_council_member_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(200_000_u32)),
+                21,
+                100,
+                false,
+                SlashedStakeDestination::Burn,
+            ),
+            Err(Error::InvalidSlashCouncilMemberParameterNotCouncilor)
+        );
+
+        assert_eq!(
+            ProposalCodex::create_slash_council_member_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(200_000_u32)),
+                20,
+                0,
+                false,
+                SlashedStakeDestination::Burn,
+            ),
+            Err(Error::SlashingStakeIsZero)
+        );
+
+        assert_eq!(
+            ProposalCodex::create_slash_council_member_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(200_000_u32)),
+                20,
+                1001,
+                false,
+                SlashedStakeDestination::Burn,
+            ),
+            Err(Error::InvalidSlashCouncilMemberParameterExceedsSeatStake)
+        );
+    });
+}
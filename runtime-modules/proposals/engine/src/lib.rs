@@ -53,6 +53,7 @@
 //! - [ensure_create_proposal_parameters_are_valid](./struct.Module.html#method.ensure_create_proposal_parameters_are_valid) - ensures that we can create the proposal
 //! - [refund_proposal_stake](./struct.Module.html#method.refund_proposal_stake) - a callback for _StakingHandlerEvents_
 //! - [reset_active_proposals](./trait.Module.html#method.reset_active_proposals) - resets voting results for active proposals
+//! - [simulate_voting](./struct.Module.html#method.simulate_voting) - simulates voting outcome with additional votes
 //!
 //! ## Usage
 //!
@@ -113,7 +114,7 @@ use types::FinalizedProposalData;
 use types::ProposalStakeManager;
 pub use types::{
     ActiveStake, ApprovedProposalStatus, FinalizationData, Proposal, ProposalDecisionStatus,
-    ProposalParameters, ProposalStatus, VotingResults,
+    ProposalParameters, ProposalStatus, ProposalVotingOutcome, VotingResults,
 };
 pub use types::{BalanceOf, CurrencyOf, NegativeImbalance};
 pub use types::{DefaultStakeHandlerProvider, StakeHandler, StakeHandlerProvider};
@@ -571,6 +572,30 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    /// Simulates the voting outcome for the active proposal extended with additional
+    /// (hypothetical) votes. Uses the same rules as the proposal finalization on block finalization.
+    pub fn simulate_voting(
+        proposal_id: T::ProposalId,
+        additional_votes: VotingResults,
+    ) -> Result<ProposalVotingOutcome<T::BlockNumber>, Error> {
+        ensure!(
+            <Proposals<T>>::exists(proposal_id),
+            Error::ProposalNotFound
+        );
+        let proposal = Self::proposals(proposal_id);
+
+        ensure!(
+            matches!(proposal.status, ProposalStatus::Active { .. }),
+            Error::ProposalFinalized
+        );
+
+        Ok(proposal.simulate_voting(
+            additional_votes,
+            T::TotalVotersCounter::total_voters_count(),
+            Self::current_block(),
+        ))
+    }
+
     /// Resets voting results for active proposals.
     /// Possible application includes new council elections.
     pub fn reset_active_proposals() {
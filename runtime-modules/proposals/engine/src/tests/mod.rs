@@ -542,6 +542,73 @@ fn vote_fails_with_not_active_proposal() {
     });
 }
 
+#[test]
+fn simulate_voting_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        let expires_at = <crate::Proposals<Test>>::get(proposal_id).created_at + 3;
+
+        assert_eq!(
+            ProposalsEngine::simulate_voting(proposal_id, VotingResults::default()),
+            Ok(ProposalVotingOutcome {
+                decision_status: None,
+                remaining_approvals: Some(2),
+                remaining_slashes: Some(2),
+                expires_at,
+            })
+        );
+
+        let additional_votes = VotingResults {
+            approvals: 2,
+            ..VotingResults::default()
+        };
+        assert_eq!(
+            ProposalsEngine::simulate_voting(proposal_id, additional_votes),
+            Ok(ProposalVotingOutcome {
+                decision_status: Some(ProposalDecisionStatus::Approved(
+                    ApprovedProposalStatus::PendingExecution
+                )),
+                remaining_approvals: Some(0),
+                remaining_slashes: None,
+                expires_at,
+            })
+        );
+
+        // Simulation doesn't change the voting results.
+        assert_eq!(
+            <crate::Proposals<Test>>::get(proposal_id)
+                .voting_results
+                .votes_number(),
+            1
+        );
+    });
+}
+
+#[test]
+fn simulate_voting_fails_with_absent_or_finalized_proposal() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            ProposalsEngine::simulate_voting(1, VotingResults::default()),
+            Err(Error::ProposalNotFound)
+        );
+
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        CancelProposalFixture::new(proposal_id).cancel_and_assert(Ok(()));
+
+        assert_eq!(
+            ProposalsEngine::simulate_voting(proposal_id, VotingResults::default()),
+            Err(Error::ProposalFinalized)
+        );
+    });
+}
+
 #[test]
 fn vote_fails_with_absent_proposal() {
     initial_test_ext().execute_with(|| {
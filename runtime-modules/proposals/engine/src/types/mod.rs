@@ -110,7 +110,10 @@ impl VotingResults {
 
     /// Calculates number of votes so far
     pub fn votes_number(&self) -> u32 {
-        self.abstentions + self.approvals + self.rejections + self.slashes
+        self.abstentions
+            .saturating_add(self.approvals)
+            .saturating_add(self.rejections)
+            .saturating_add(self.slashes)
     }
 }
 
@@ -160,7 +163,7 @@ where
 {
     /// Returns whether voting period expired by now
     pub fn is_voting_period_expired(&self, now: BlockNumber) -> bool {
-        now >= self.created_at + self.parameters.voting_period
+        now >= self.voting_period_expires_at()
     }
 
     /// Returns whether grace period expired by now.
@@ -176,6 +179,11 @@ where
         false
     }
 
+    /// Returns the block number at which the voting period expires.
+    pub fn voting_period_expires_at(&self) -> BlockNumber {
+        self.created_at + self.parameters.voting_period
+    }
+
     /// Determines the finalized proposal status using voting results tally for current proposal.
     /// Calculates votes, takes in account voting period expiration.
     /// If voting process is in progress, then decision status is None.
@@ -185,13 +193,96 @@ where
         &self,
         total_voters_count: u32,
         now: BlockNumber,
+    ) -> Option<ProposalDecisionStatus> {
+        self.define_decision_status_for_votes(&self.voting_results, total_voters_count, now)
+    }
+
+    /// Simulates the voting outcome for the current voting results extended with the additional
+    /// (hypothetical) votes. Uses the same rules as the define_proposal_decision_status().
+    /// Parameters: additional votes, total voters number involved (council size), current time.
+    pub fn simulate_voting(
+        &self,
+        additional_votes: VotingResults,
+        total_voters_count: u32,
+        now: BlockNumber,
+    ) -> ProposalVotingOutcome<BlockNumber> {
+        let voting_results = VotingResults {
+            abstentions: self
+                .voting_results
+                .abstentions
+                .saturating_add(additional_votes.abstentions),
+            approvals: self
+                .voting_results
+                .approvals
+                .saturating_add(additional_votes.approvals),
+            rejections: self
+                .voting_results
+                .rejections
+                .saturating_add(additional_votes.rejections),
+            slashes: self
+                .voting_results
+                .slashes
+                .saturating_add(additional_votes.slashes),
+        };
+
+        let votes_count = voting_results.votes_number();
+        let remaining_voters_count = total_voters_count.saturating_sub(votes_count);
+
+        let remaining_approvals = (0..=remaining_voters_count).find(|additional_approvals| {
+            let proposal_status_resolution = ProposalStatusResolution {
+                proposal: self,
+                approvals: voting_results
+                    .approvals
+                    .saturating_add(*additional_approvals),
+                slashes: voting_results.slashes,
+                now,
+                votes_count: votes_count.saturating_add(*additional_approvals),
+                total_voters_count,
+            };
+
+            proposal_status_resolution.is_approval_quorum_reached()
+                && proposal_status_resolution.is_approval_threshold_reached()
+        });
+
+        let remaining_slashes = (0..=remaining_voters_count).find(|additional_slashes| {
+            let proposal_status_resolution = ProposalStatusResolution {
+                proposal: self,
+                approvals: voting_results.approvals,
+                slashes: voting_results.slashes.saturating_add(*additional_slashes),
+                now,
+                votes_count: votes_count.saturating_add(*additional_slashes),
+                total_voters_count,
+            };
+
+            proposal_status_resolution.is_slashing_quorum_reached()
+                && proposal_status_resolution.is_slashing_threshold_reached()
+        });
+
+        ProposalVotingOutcome {
+            decision_status: self.define_decision_status_for_votes(
+                &voting_results,
+                total_voters_count,
+                now,
+            ),
+            remaining_approvals,
+            remaining_slashes,
+            expires_at: self.voting_period_expires_at(),
+        }
+    }
+
+    // Determines the finalized proposal status using the provided voting results.
+    fn define_decision_status_for_votes(
+        &self,
+        voting_results: &VotingResults,
+        total_voters_count: u32,
+        now: BlockNumber,
     ) -> Option<ProposalDecisionStatus> {
         let proposal_status_resolution = ProposalStatusResolution {
             proposal: self,
-            approvals: self.voting_results.approvals,
-            slashes: self.voting_results.slashes,
+            approvals: voting_results.approvals,
+            slashes: voting_results.slashes,
             now,
-            votes_count: self.voting_results.votes_number(),
+            votes_count: voting_results.votes_number(),
             total_voters_count,
         };
 
@@ -223,6 +314,25 @@ where
     }
 }
 
+/// Simulated voting outcome for the proposal.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct ProposalVotingOutcome<BlockNumber> {
+    /// Proposal decision status after the voting. None if the voting is still in progress.
+    pub decision_status: Option<ProposalDecisionStatus>,
+
+    /// Additional 'Approve' votes required to approve the proposal.
+    /// None if the remaining voters cannot approve the proposal.
+    pub remaining_approvals: Option<u32>,
+
+    /// Additional 'Slash' votes required to slash the proposal.
+    /// None if the remaining voters cannot slash the proposal.
+    pub remaining_slashes: Option<u32>,
+
+    /// Block number at which the voting period expires.
+    pub expires_at: BlockNumber,
+}
+
 /// Provides data for the voting.
 pub trait VotersParameters {
     /// Defines maximum voters count for the proposal
@@ -675,6 +785,96 @@ mod tests {
         );
     }
 
+    #[test]
+    fn simulate_voting_returns_remaining_votes() {
+        let mut proposal = ProposalObject::default();
+        let now = 2;
+
+        proposal.created_at = 1;
+        proposal.parameters.voting_period = 3;
+        proposal.parameters.approval_quorum_percentage = 60;
+        proposal.parameters.approval_threshold_percentage = 60;
+        proposal.parameters.slashing_quorum_percentage = 60;
+        proposal.parameters.slashing_threshold_percentage = 60;
+
+        proposal.voting_results.add_vote(VoteKind::Approve);
+
+        let outcome = proposal.simulate_voting(VotingResults::default(), 5, now);
+        assert_eq!(
+            outcome,
+            ProposalVotingOutcome {
+                decision_status: None,
+                remaining_approvals: Some(2),
+                remaining_slashes: Some(2),
+                expires_at: 4,
+            }
+        );
+
+        let additional_votes = VotingResults {
+            approvals: 2,
+            ..VotingResults::default()
+        };
+
+        let outcome = proposal.simulate_voting(additional_votes, 5, now);
+        assert_eq!(
+            outcome,
+            ProposalVotingOutcome {
+                decision_status: Some(ProposalDecisionStatus::Approved(
+                    ApprovedProposalStatus::PendingExecution
+                )),
+                remaining_approvals: Some(0),
+                remaining_slashes: None,
+                expires_at: 4,
+            }
+        );
+
+        // The simulation doesn't change the proposal.
+        assert_eq!(proposal.voting_results.votes_number(), 1);
+    }
+
+    #[test]
+    fn simulate_voting_returns_no_remaining_votes_when_approval_is_impossible() {
+        let mut proposal = ProposalObject::default();
+        let now = 2;
+
+        proposal.created_at = 1;
+        proposal.parameters.voting_period = 3;
+        proposal.parameters.approval_quorum_percentage = 50;
+        proposal.parameters.approval_threshold_percentage = 75;
+        proposal.parameters.slashing_quorum_percentage = 50;
+        proposal.parameters.slashing_threshold_percentage = 50;
+
+        proposal.voting_results.add_vote(VoteKind::Reject);
+        proposal.voting_results.add_vote(VoteKind::Reject);
+
+        let outcome = proposal.simulate_voting(VotingResults::default(), 4, now);
+        assert_eq!(outcome.remaining_approvals, None);
+        assert_eq!(outcome.remaining_slashes, Some(2));
+        assert_eq!(outcome.decision_status, None);
+    }
+
+    #[test]
+    fn simulate_voting_saturates_additional_votes() {
+        let mut proposal = ProposalObject::default();
+        let now = 2;
+
+        proposal.created_at = 1;
+        proposal.parameters.voting_period = 3;
+        proposal.parameters.approval_quorum_percentage = 60;
+        proposal.parameters.approval_threshold_percentage = 60;
+
+        proposal.voting_results.add_vote(VoteKind::Approve);
+
+        let additional_votes = VotingResults {
+            approvals: u32::max_value(),
+            rejections: u32::max_value(),
+            ..VotingResults::default()
+        };
+
+        let outcome = proposal.simulate_voting(additional_votes, 5, now);
+        assert_eq!(outcome.remaining_approvals, Some(0));
+    }
+
     #[test]
     fn proposal_status_resolution_approval_quorum_works_correctly() {
         let no_approval_quorum_proposal: Proposal<u64, u64, u64, u64, u64> = Proposal {
@@ -14,6 +14,14 @@
 //! - [unset_ipns_id](./struct.Module.html#method.unset_ipns_id) - Deletes the AccountInfo with the IPNS identity for the storage provider.
 //! - [set_default_lifetime](./struct.Module.html#method.set_default_lifetime) - Sets default lifetime for storage providers accounts info.
 //! - [set_bootstrap_endpoints](./struct.Module.html#method.set_bootstrap_endpoints) - Sets bootstrap endpoints for the Colossus.
+//! - [set_endpoint_record](./struct.Module.html#method.set_endpoint_record) - Creates or refreshes the service endpoints record for the storage provider.
+//! - [unset_endpoint_record](./struct.Module.html#method.unset_endpoint_record) - Deletes the service endpoints record for the storage provider.
+//!
+//! ## Records pruning
+//!
+//! Expired AccountInfo and service endpoints records are removed in the _on_finalize_ of the
+//! block following their expiration. Records of the storage providers who left the working group
+//! or were terminated are removed immediately.
 //!
 
 // Ensure we're `no_std` when compiling for Wasm.
@@ -27,6 +35,7 @@ use rstd::prelude::*;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
+use sr_primitives::traits::{One, Zero};
 use srml_support::{decl_event, decl_module, decl_storage, ensure};
 use system::{self, ensure_root};
 /*
@@ -60,6 +69,15 @@ pub type StorageProviderId<T> = working_group::WorkerId<T>;
 pub(crate) const MINIMUM_LIFETIME: u32 = 600; // 1hr assuming 6s block times
 pub(crate) const DEFAULT_LIFETIME: u32 = MINIMUM_LIFETIME * 24; // 24hr
 
+/// Maximum number of the service endpoints in the endpoint record.
+pub const MAX_ENDPOINTS: usize = 10;
+
+/// Maximum length of the service endpoint url.
+pub const MAX_URL_LENGTH: usize = 256;
+
+/// Maximum length of the storage provider region.
+pub const MAX_REGION_LENGTH: usize = 64;
+
 /// Defines the expiration date for the storage provider.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
@@ -70,6 +88,78 @@ pub struct AccountInfo<BlockNumber> {
     pub expires_at: BlockNumber,
 }
 
+/// Protocol of the service endpoint.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EndpointProtocol {
+    /// HTTP(S) API endpoint.
+    Http,
+
+    /// IPFS HTTP(S) gateway.
+    IpfsGateway,
+
+    /// WebSocket endpoint.
+    WebSocket,
+}
+
+impl EndpointProtocol {
+    // Url schemes allowed for the protocol.
+    fn url_schemes(self) -> &'static [&'static [u8]] {
+        match self {
+            EndpointProtocol::Http | EndpointProtocol::IpfsGateway => &[b"http://", b"https://"],
+            EndpointProtocol::WebSocket => &[b"ws://", b"wss://"],
+        }
+    }
+}
+
+/// Service endpoint advertised by the storage provider.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct ServiceEndpoint {
+    /// Endpoint protocol.
+    pub protocol: EndpointProtocol,
+
+    /// Endpoint url. The url scheme should match the protocol.
+    pub url: Url,
+}
+
+impl ServiceEndpoint {
+    /// Verifies that the url is a bounded printable ASCII string with the host and the scheme
+    /// supported by the protocol.
+    pub fn is_valid(&self) -> bool {
+        if self.url.len() > MAX_URL_LENGTH || !self.url.iter().all(|c| c.is_ascii_graphic()) {
+            return false;
+        }
+
+        self.protocol.url_schemes().iter().any(|scheme| {
+            self.url.starts_with(scheme)
+                && self.url[scheme.len()..]
+                    .first()
+                    .map_or(false, |c| c.is_ascii_alphanumeric() || *c == b'[')
+        })
+    }
+}
+
+/// Service endpoints record of the storage provider.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct EndpointRecord<BlockNumber> {
+    /// Record version. Increases on every update.
+    pub version: u32,
+
+    /// Advertised service endpoints.
+    pub endpoints: Vec<ServiceEndpoint>,
+
+    /// Storage provider region.
+    pub region: Vec<u8>,
+
+    /// Storage provider capacity in bytes.
+    pub capacity: u64,
+
+    /// Block at which the record expires.
+    pub expires_at: BlockNumber,
+}
+
 /// The _Service discovery_ main _Trait_.
 pub trait Trait: system::Trait + working_group::Trait<StorageWorkingGroupInstance> {
     /// _Service discovery_ event type.
@@ -85,6 +175,14 @@ decl_storage! {
         pub AccountInfoByStorageProviderId get(account_info_by_storage_provider_id):
             map StorageProviderId<T> => AccountInfo<T::BlockNumber>;
 
+        /// Mapping of service providers' storage provider id to their service endpoints record
+        pub EndpointRecordByStorageProviderId get(endpoint_record_by_storage_provider_id):
+            linked_map StorageProviderId<T> => EndpointRecord<T::BlockNumber>;
+
+        /// Storage provider ids scheduled for the expired records pruning by the block number
+        pub ExpiringStorageProviderIds get(expiring_storage_provider_ids):
+            map T::BlockNumber => Vec<StorageProviderId<T>>;
+
         /// Lifetime of an AccountInfo record in AccountInfoByAccountId map
         pub DefaultLifetime get(default_lifetime) config():
             T::BlockNumber = T::BlockNumber::from(DEFAULT_LIFETIME);
@@ -106,6 +204,17 @@ decl_event! {
         /// Params:
         /// - Id of the storage provider.
         AccountInfoRemoved(StorageProviderId),
+
+        /// Emits on updating of the service endpoints record.
+        /// Params:
+        /// - Id of the storage provider.
+        /// - Version of the record.
+        EndpointRecordUpdated(StorageProviderId, u32),
+
+        /// Emits on removing of the service endpoints record.
+        /// Params:
+        /// - Id of the storage provider.
+        EndpointRecordRemoved(StorageProviderId),
     }
 }
 
@@ -115,6 +224,11 @@ decl_module! {
         /// Default deposit_event() handler
         fn deposit_event() = default;
 
+        /// Removes the records expired at the previous block.
+        fn on_finalize(now: T::BlockNumber) {
+            Self::remove_expired_records(now);
+        }
+
         /// Creates the AccountInfo to save an IPNS identity for the storage provider.
         /// Requires signed storage provider credentials.
         pub fn set_ipns_id(
@@ -130,11 +244,15 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
+            let expires_at = <system::Module<T>>::block_number() + Self::default_lifetime();
+
             <AccountInfoByStorageProviderId<T>>::insert(storage_provider_id, AccountInfo {
                 identity: id.clone(),
-                expires_at: <system::Module<T>>::block_number() + Self::default_lifetime(),
+                expires_at,
             });
 
+            Self::schedule_expiration(storage_provider_id, expires_at);
+
             Self::deposit_event(RawEvent::AccountInfoUpdated(storage_provider_id, id));
         }
 
@@ -146,8 +264,64 @@ decl_module! {
             // == MUTATION SAFE ==
 
             if <AccountInfoByStorageProviderId<T>>::exists(storage_provider_id) {
-                <AccountInfoByStorageProviderId<T>>::remove(storage_provider_id);
-                Self::deposit_event(RawEvent::AccountInfoRemoved(storage_provider_id));
+                Self::remove_account_info(storage_provider_id);
+            }
+        }
+
+        /// Creates or refreshes the service endpoints record for the storage provider. The record
+        /// expires after the default lifetime similar to the AccountInfo.
+        /// Requires signed storage provider credentials.
+        pub fn set_endpoint_record(
+            origin,
+            storage_provider_id: StorageProviderId<T>,
+            endpoints: Vec<ServiceEndpoint>,
+            region: Vec<u8>,
+            capacity: u64,
+        ) {
+            <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
+
+            ensure!(!endpoints.is_empty(), "discovery: endpoints cannot be empty");
+            ensure!(endpoints.len() <= MAX_ENDPOINTS, "discovery: too many endpoints");
+            ensure!(endpoints.iter().all(|endpoint| endpoint.is_valid()),
+                "discovery: invalid endpoint url");
+            ensure!(region.len() <= MAX_REGION_LENGTH, "discovery: region is too long");
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let version = if <EndpointRecordByStorageProviderId<T>>::exists(storage_provider_id) {
+                Self::endpoint_record_by_storage_provider_id(storage_provider_id)
+                    .version
+                    .saturating_add(1)
+            } else {
+                0
+            };
+
+            let expires_at = <system::Module<T>>::block_number() + Self::default_lifetime();
+
+            <EndpointRecordByStorageProviderId<T>>::insert(storage_provider_id, EndpointRecord {
+                version,
+                endpoints,
+                region,
+                capacity,
+                expires_at,
+            });
+
+            Self::schedule_expiration(storage_provider_id, expires_at);
+
+            Self::deposit_event(RawEvent::EndpointRecordUpdated(storage_provider_id, version));
+        }
+
+        /// Deletes the service endpoints record for the storage provider.
+        /// Requires signed storage provider credentials.
+        pub fn unset_endpoint_record(origin, storage_provider_id: StorageProviderId<T>) {
+            <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
+
+            // == MUTATION SAFE ==
+
+            if <EndpointRecordByStorageProviderId<T>>::exists(storage_provider_id) {
+                Self::remove_endpoint_record(storage_provider_id);
             }
         }
 
@@ -182,4 +356,109 @@ impl<T: Trait> Module<T> {
             || <system::Module<T>>::block_number()
                 > <AccountInfoByStorageProviderId<T>>::get(storage_provider_id).expires_at
     }
+
+    /// Verifies that the service endpoints record for the storage provider is still valid.
+    pub fn is_endpoint_record_expired(storage_provider_id: &StorageProviderId<T>) -> bool {
+        !<EndpointRecordByStorageProviderId<T>>::exists(storage_provider_id)
+            || <system::Module<T>>::block_number()
+                > <EndpointRecordByStorageProviderId<T>>::get(storage_provider_id).expires_at
+    }
+
+    /// Returns the service endpoints records of the active storage providers which are not
+    /// expired yet.
+    pub fn live_endpoint_records() -> Vec<(StorageProviderId<T>, EndpointRecord<T::BlockNumber>)> {
+        let now = <system::Module<T>>::block_number();
+
+        <EndpointRecordByStorageProviderId<T>>::enumerate()
+            .filter(|(storage_provider_id, record)| {
+                now <= record.expires_at
+                    && <working_group::WorkerById<T, StorageWorkingGroupInstance>>::exists(
+                        storage_provider_id,
+                    )
+            })
+            .collect()
+    }
+
+    /// Schedules the pruning of the records stored before the records pruning was introduced and
+    /// removes the records of the storage providers who already left the working group. Intended
+    /// for the runtime upgrade migration.
+    pub fn schedule_existing_records_pruning() {
+        let now = <system::Module<T>>::block_number();
+        let next_storage_provider_id =
+            <working_group::Module<T, StorageWorkingGroupInstance>>::next_worker_id();
+
+        let mut storage_provider_id = StorageProviderId::<T>::zero();
+        while storage_provider_id < next_storage_provider_id {
+            if !<working_group::WorkerById<T, StorageWorkingGroupInstance>>::exists(
+                storage_provider_id,
+            ) {
+                <Self as working_group::OnWorkerExit<StorageProviderId<T>>>::on_worker_exit(
+                    &storage_provider_id,
+                );
+            } else {
+                if <AccountInfoByStorageProviderId<T>>::exists(storage_provider_id) {
+                    let expires_at =
+                        <AccountInfoByStorageProviderId<T>>::get(storage_provider_id).expires_at;
+                    Self::schedule_expiration(storage_provider_id, expires_at.max(now));
+                }
+
+                if <EndpointRecordByStorageProviderId<T>>::exists(storage_provider_id) {
+                    let expires_at =
+                        <EndpointRecordByStorageProviderId<T>>::get(storage_provider_id).expires_at;
+                    Self::schedule_expiration(storage_provider_id, expires_at.max(now));
+                }
+            }
+
+            storage_provider_id += StorageProviderId::<T>::one();
+        }
+    }
+
+    // Schedules the records pruning for the block following the expiration.
+    fn schedule_expiration(storage_provider_id: StorageProviderId<T>, expires_at: T::BlockNumber) {
+        <ExpiringStorageProviderIds<T>>::mutate(expires_at + One::one(), |ids| {
+            if !ids.contains(&storage_provider_id) {
+                ids.push(storage_provider_id);
+            }
+        });
+    }
+
+    // Removes the records scheduled for the pruning at the provided block. Refreshed records
+    // are rescheduled on update and remain untouched.
+    fn remove_expired_records(now: T::BlockNumber) {
+        for storage_provider_id in <ExpiringStorageProviderIds<T>>::take(now) {
+            if <AccountInfoByStorageProviderId<T>>::exists(storage_provider_id)
+                && Self::is_account_info_expired(&storage_provider_id)
+            {
+                Self::remove_account_info(storage_provider_id);
+            }
+
+            if <EndpointRecordByStorageProviderId<T>>::exists(storage_provider_id)
+                && Self::is_endpoint_record_expired(&storage_provider_id)
+            {
+                Self::remove_endpoint_record(storage_provider_id);
+            }
+        }
+    }
+
+    fn remove_account_info(storage_provider_id: StorageProviderId<T>) {
+        <AccountInfoByStorageProviderId<T>>::remove(storage_provider_id);
+        Self::deposit_event(RawEvent::AccountInfoRemoved(storage_provider_id));
+    }
+
+    fn remove_endpoint_record(storage_provider_id: StorageProviderId<T>) {
+        <EndpointRecordByStorageProviderId<T>>::remove(storage_provider_id);
+        Self::deposit_event(RawEvent::EndpointRecordRemoved(storage_provider_id));
+    }
+}
+
+impl<T: Trait> working_group::OnWorkerExit<StorageProviderId<T>> for Module<T> {
+    fn on_worker_exit(storage_provider_id: &StorageProviderId<T>) {
+        if <AccountInfoByStorageProviderId<T>>::exists(storage_provider_id) {
+            Self::remove_account_info(*storage_provider_id);
+        }
+
+        if <EndpointRecordByStorageProviderId<T>>::exists(storage_provider_id) {
+            Self::remove_endpoint_record(*storage_provider_id);
+        }
+    }
 }
@@ -137,6 +137,7 @@ parameter_types! {
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = MetaEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type OnWorkerExit = Discovery;
 }
 
 impl timestamp::Trait for Test {
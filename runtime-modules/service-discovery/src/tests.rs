@@ -2,6 +2,7 @@
 
 use super::mock::*;
 
+use sr_primitives::traits::OnFinalize;
 use srml_support::*;
 use system::{self, EventRecord, Phase};
 
@@ -173,3 +174,399 @@ fn set_bootstrap_endpoints() {
         assert_eq!(Discovery::bootstrap_endpoints(), endpoints, "");
     });
 }
+
+fn test_endpoints() -> Vec<ServiceEndpoint> {
+    vec![
+        ServiceEndpoint {
+            protocol: EndpointProtocol::Http,
+            url: b"https://colossus.example.com/api".to_vec(),
+        },
+        ServiceEndpoint {
+            protocol: EndpointProtocol::WebSocket,
+            url: b"wss://colossus.example.com:9944".to_vec(),
+        },
+    ]
+}
+
+#[test]
+fn set_endpoint_record() {
+    initial_test_ext().execute_with(|| {
+        let current_block_number = 1000;
+        System::set_block_number(current_block_number);
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        let region = b"eu-west".to_vec();
+        let capacity = 1_000_000;
+        let ttl = <Test as system::Trait>::BlockNumber::from(DEFAULT_LIFETIME);
+        assert!(Discovery::set_endpoint_record(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            test_endpoints(),
+            region.clone(),
+            capacity,
+        )
+        .is_ok());
+
+        assert_eq!(
+            Discovery::endpoint_record_by_storage_provider_id(&storage_provider_id),
+            EndpointRecord {
+                version: 0,
+                endpoints: test_endpoints(),
+                region: region.clone(),
+                capacity,
+                expires_at: current_block_number + ttl,
+            }
+        );
+
+        assert_eq!(
+            *System::events().last().unwrap(),
+            EventRecord {
+                phase: Phase::ApplyExtrinsic(0),
+                event: MetaEvent::discovery(RawEvent::EndpointRecordUpdated(
+                    storage_provider_id,
+                    0
+                )),
+                topics: vec![]
+            }
+        );
+
+        // Refreshing the record increases the version
+        assert!(Discovery::set_endpoint_record(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            test_endpoints(),
+            region.clone(),
+            2 * capacity,
+        )
+        .is_ok());
+
+        let record = Discovery::endpoint_record_by_storage_provider_id(&storage_provider_id);
+        assert_eq!(record.version, 1);
+        assert_eq!(record.capacity, 2 * capacity);
+
+        // Invalid storage provider data
+        let invalid_storage_provider_id = 2;
+        let invalid_storage_provider_account_id = 2;
+        assert!(Discovery::set_endpoint_record(
+            Origin::signed(invalid_storage_provider_account_id),
+            invalid_storage_provider_id,
+            test_endpoints(),
+            region,
+            capacity,
+        )
+        .is_err());
+        assert!(!<EndpointRecordByStorageProviderId<Test>>::exists(
+            &invalid_storage_provider_id
+        ));
+    });
+}
+
+#[test]
+fn set_endpoint_record_fails_with_invalid_params() {
+    initial_test_ext().execute_with(|| {
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        let set_record = |endpoints: Vec<ServiceEndpoint>, region: Vec<u8>| {
+            Discovery::set_endpoint_record(
+                Origin::signed(storage_provider_account_id),
+                storage_provider_id,
+                endpoints,
+                region,
+                0,
+            )
+        };
+
+        assert_eq!(
+            set_record(Vec::new(), Vec::new()),
+            Err("discovery: endpoints cannot be empty")
+        );
+
+        let too_many_endpoints = test_endpoints()
+            .into_iter()
+            .cycle()
+            .take(MAX_ENDPOINTS + 1)
+            .collect();
+        assert_eq!(
+            set_record(too_many_endpoints, Vec::new()),
+            Err("discovery: too many endpoints")
+        );
+
+        assert_eq!(
+            set_record(test_endpoints(), vec![b'a'; MAX_REGION_LENGTH + 1]),
+            Err("discovery: region is too long")
+        );
+
+        let invalid_urls: Vec<(EndpointProtocol, &[u8])> = vec![
+            (EndpointProtocol::Http, b"ftp://colossus.example.com"),
+            (EndpointProtocol::Http, b"https://"),
+            (EndpointProtocol::Http, b"https://colossus example.com"),
+            (EndpointProtocol::IpfsGateway, b"wss://ipfs.example.com"),
+            (EndpointProtocol::WebSocket, b"https://colossus.example.com"),
+        ];
+
+        for (protocol, url) in invalid_urls {
+            let endpoint = ServiceEndpoint {
+                protocol,
+                url: url.to_vec(),
+            };
+
+            assert!(!endpoint.is_valid());
+            assert_eq!(
+                set_record(vec![endpoint], Vec::new()),
+                Err("discovery: invalid endpoint url")
+            );
+        }
+
+        assert!(!<EndpointRecordByStorageProviderId<Test>>::exists(
+            &storage_provider_id
+        ));
+    });
+}
+
+#[test]
+fn unset_endpoint_record() {
+    initial_test_ext().execute_with(|| {
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        <EndpointRecordByStorageProviderId<Test>>::insert(
+            &storage_provider_id,
+            EndpointRecord {
+                endpoints: test_endpoints(),
+                expires_at: 1000,
+                ..Default::default()
+            },
+        );
+
+        assert!(Discovery::unset_endpoint_record(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id
+        )
+        .is_ok());
+        assert!(!<EndpointRecordByStorageProviderId<Test>>::exists(
+            &storage_provider_id
+        ));
+
+        assert_eq!(
+            *System::events().last().unwrap(),
+            EventRecord {
+                phase: Phase::ApplyExtrinsic(0),
+                event: MetaEvent::discovery(RawEvent::EndpointRecordRemoved(storage_provider_id)),
+                topics: vec![]
+            }
+        );
+    });
+}
+
+#[test]
+fn live_endpoint_records() {
+    initial_test_ext().execute_with(|| {
+        let (_, storage_provider_id) = hire_storage_provider();
+        let departed_storage_provider_id = 2;
+        let expires_at = 1000;
+
+        let record = EndpointRecord {
+            endpoints: test_endpoints(),
+            expires_at,
+            ..Default::default()
+        };
+
+        <EndpointRecordByStorageProviderId<Test>>::insert(&storage_provider_id, record.clone());
+        <EndpointRecordByStorageProviderId<Test>>::insert(
+            &departed_storage_provider_id,
+            record.clone(),
+        );
+
+        System::set_block_number(expires_at - 10);
+        assert!(!Discovery::is_endpoint_record_expired(&storage_provider_id));
+        assert_eq!(
+            Discovery::live_endpoint_records(),
+            vec![(storage_provider_id, record)]
+        );
+
+        System::set_block_number(expires_at + 10);
+        assert!(Discovery::is_endpoint_record_expired(&storage_provider_id));
+        assert!(Discovery::live_endpoint_records().is_empty());
+    });
+}
+
+#[test]
+fn expired_records_are_removed_on_finalize() {
+    initial_test_ext().execute_with(|| {
+        let current_block_number = 1000;
+        System::set_block_number(current_block_number);
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        assert!(Discovery::set_ipns_id(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            "alice".as_bytes().to_vec(),
+        )
+        .is_ok());
+        assert!(Discovery::set_endpoint_record(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            test_endpoints(),
+            Vec::new(),
+            0,
+        )
+        .is_ok());
+
+        let expires_at = current_block_number + u64::from(DEFAULT_LIFETIME);
+        assert_eq!(
+            Discovery::expiring_storage_provider_ids(expires_at + 1),
+            vec![storage_provider_id]
+        );
+
+        System::set_block_number(expires_at);
+        Discovery::on_finalize(expires_at);
+        assert!(<AccountInfoByStorageProviderId<Test>>::exists(
+            &storage_provider_id
+        ));
+        assert!(<EndpointRecordByStorageProviderId<Test>>::exists(
+            &storage_provider_id
+        ));
+
+        System::set_block_number(expires_at + 1);
+        Discovery::on_finalize(expires_at + 1);
+        assert!(!<AccountInfoByStorageProviderId<Test>>::exists(
+            &storage_provider_id
+        ));
+        assert!(!<EndpointRecordByStorageProviderId<Test>>::exists(
+            &storage_provider_id
+        ));
+        assert!(!<ExpiringStorageProviderIds<Test>>::exists(expires_at + 1));
+
+        let events = System::events();
+        let removal_events: Vec<_> = events[events.len() - 2..]
+            .iter()
+            .map(|record| record.event.clone())
+            .collect();
+        assert_eq!(
+            removal_events,
+            vec![
+                MetaEvent::discovery(RawEvent::AccountInfoRemoved(storage_provider_id)),
+                MetaEvent::discovery(RawEvent::EndpointRecordRemoved(storage_provider_id)),
+            ]
+        );
+    });
+}
+
+#[test]
+fn refreshed_records_are_not_removed_on_finalize() {
+    initial_test_ext().execute_with(|| {
+        let current_block_number = 1000;
+        System::set_block_number(current_block_number);
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+        let identity = "alice".as_bytes().to_vec();
+
+        assert!(Discovery::set_ipns_id(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            identity.clone(),
+        )
+        .is_ok());
+
+        let refreshed_at = current_block_number + 100;
+        System::set_block_number(refreshed_at);
+        assert!(Discovery::set_ipns_id(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            identity,
+        )
+        .is_ok());
+
+        let first_expiration = current_block_number + u64::from(DEFAULT_LIFETIME) + 1;
+        System::set_block_number(first_expiration);
+        Discovery::on_finalize(first_expiration);
+        assert!(<AccountInfoByStorageProviderId<Test>>::exists(
+            &storage_provider_id
+        ));
+
+        let second_expiration = refreshed_at + u64::from(DEFAULT_LIFETIME) + 1;
+        System::set_block_number(second_expiration);
+        Discovery::on_finalize(second_expiration);
+        assert!(!<AccountInfoByStorageProviderId<Test>>::exists(
+            &storage_provider_id
+        ));
+    });
+}
+
+#[test]
+fn records_are_removed_on_storage_provider_exit() {
+    initial_test_ext().execute_with(|| {
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        assert!(Discovery::set_ipns_id(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            "alice".as_bytes().to_vec(),
+        )
+        .is_ok());
+        assert!(Discovery::set_endpoint_record(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            test_endpoints(),
+            Vec::new(),
+            0,
+        )
+        .is_ok());
+
+        assert!(<StorageWorkingGroup<Test>>::leave_role(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            Vec::new(),
+        )
+        .is_ok());
+
+        assert!(!<AccountInfoByStorageProviderId<Test>>::exists(
+            &storage_provider_id
+        ));
+        assert!(!<EndpointRecordByStorageProviderId<Test>>::exists(
+            &storage_provider_id
+        ));
+    });
+}
+
+#[test]
+fn existing_records_are_scheduled_for_pruning() {
+    initial_test_ext().execute_with(|| {
+        let current_block_number = 1000;
+        System::set_block_number(current_block_number);
+
+        let (_, storage_provider_id) = hire_storage_provider();
+        let departed_storage_provider_id = storage_provider_id + 1;
+        <working_group::NextWorkerId<Test, StorageWorkingGroupInstance>>::put(
+            departed_storage_provider_id + 1,
+        );
+
+        let expired_at = current_block_number - 1;
+        for id in &[storage_provider_id, departed_storage_provider_id] {
+            <AccountInfoByStorageProviderId<Test>>::insert(
+                id,
+                AccountInfo {
+                    identity: "alice".as_bytes().to_vec(),
+                    expires_at: expired_at,
+                },
+            );
+        }
+
+        Discovery::schedule_existing_records_pruning();
+
+        assert!(!<AccountInfoByStorageProviderId<Test>>::exists(
+            &departed_storage_provider_id
+        ));
+        assert_eq!(
+            Discovery::expiring_storage_provider_ids(current_block_number + 1),
+            vec![storage_provider_id]
+        );
+
+        System::set_block_number(current_block_number + 1);
+        Discovery::on_finalize(current_block_number + 1);
+        assert!(!<AccountInfoByStorageProviderId<Test>>::exists(
+            &storage_provider_id
+        ));
+    });
+}
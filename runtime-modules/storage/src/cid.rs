@@ -0,0 +1,226 @@
+//! # Content identifier
+//! Validated IPFS content identifiers (CID) and multihashes used by the data directory module.
+//!
+//! Supported CID formats:
+//! - CIDv0: base58btc encoded SHA2-256 multihash (e.g. "Qm...").
+//! - CIDv1: multibase encoded CID with the base32 ('b' prefix) or base58btc ('z' prefix) encoding.
+//!
+//! Only the multihashes with the known hash functions and digest sizes are accepted.
+
+use codec::{Decode, Encode};
+use rstd::prelude::*;
+
+/// Maximum length of the textual CID representation.
+pub const MAX_CID_LENGTH: usize = 128;
+
+/// SHA2-256 multihash code.
+pub const SHA2_256: u64 = 0x12;
+
+/// SHA2-512 multihash code.
+pub const SHA2_512: u64 = 0x13;
+
+/// SHA3-256 multihash code.
+pub const SHA3_256: u64 = 0x16;
+
+/// BLAKE2b-256 multihash code.
+pub const BLAKE2B_256: u64 = 0xb220;
+
+/// DAG-PB codec code (used by the CIDv0).
+pub const DAG_PB: u64 = 0x70;
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE32_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+// CIDv0 is always a base58btc encoded SHA2-256 multihash of 34 bytes.
+const CID_V0_LENGTH: usize = 46;
+const CID_V0_PREFIX: &[u8] = b"Qm";
+
+/// CID parsing errors.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CidError {
+    /// CID is empty or exceeds the maximum length.
+    InvalidLength,
+
+    /// Unsupported multibase prefix or invalid character for the encoding.
+    InvalidEncoding,
+
+    /// Unsupported CID version.
+    UnsupportedVersion,
+
+    /// Malformed unsigned varint.
+    InvalidVarint,
+
+    /// Unknown hash function or invalid digest size.
+    InvalidMultihash,
+}
+
+/// Self-describing content hash: hash function code and its digest.
+#[derive(Clone, Encode, Decode, Default, PartialEq, Eq, Debug)]
+pub struct Multihash {
+    /// Multihash code of the hash function.
+    pub code: u64,
+
+    /// Hash digest.
+    pub digest: Vec<u8>,
+}
+
+impl Multihash {
+    /// Parses the binary multihash. The input should contain the multihash only.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, CidError> {
+        let (code, rest) = read_varint(bytes)?;
+        let (length, digest) = read_varint(rest)?;
+
+        let expected_length = match code {
+            SHA2_256 | SHA3_256 | BLAKE2B_256 => 32,
+            SHA2_512 => 64,
+            _ => return Err(CidError::InvalidMultihash),
+        };
+
+        if length != expected_length || digest.len() as u64 != length {
+            return Err(CidError::InvalidMultihash);
+        }
+
+        Ok(Multihash {
+            code,
+            digest: digest.to_vec(),
+        })
+    }
+}
+
+/// Validated IPFS content identifier.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, Debug)]
+pub struct Cid {
+    /// CID version (0 or 1).
+    pub version: u8,
+
+    /// Multicodec code of the content.
+    pub codec: u64,
+
+    /// Content hash.
+    pub hash: Multihash,
+}
+
+impl Cid {
+    /// Parses and validates the textual CID representation.
+    pub fn parse(cid: &[u8]) -> Result<Self, CidError> {
+        if cid.is_empty() || cid.len() > MAX_CID_LENGTH {
+            return Err(CidError::InvalidLength);
+        }
+
+        if cid.len() == CID_V0_LENGTH && cid.starts_with(CID_V0_PREFIX) {
+            let bytes = decode_base58(cid)?;
+            let hash = Multihash::from_bytes(&bytes)?;
+
+            if hash.code != SHA2_256 {
+                return Err(CidError::InvalidMultihash);
+            }
+
+            return Ok(Cid {
+                version: 0,
+                codec: DAG_PB,
+                hash,
+            });
+        }
+
+        let bytes = match cid[0] {
+            b'b' => decode_base32(&cid[1..])?,
+            b'z' => decode_base58(&cid[1..])?,
+            _ => return Err(CidError::InvalidEncoding),
+        };
+
+        let (version, rest) = read_varint(&bytes)?;
+        if version != 1 {
+            return Err(CidError::UnsupportedVersion);
+        }
+
+        let (codec, multihash) = read_varint(rest)?;
+
+        Ok(Cid {
+            version: 1,
+            codec,
+            hash: Multihash::from_bytes(multihash)?,
+        })
+    }
+}
+
+// Reads the unsigned varint (up to 9 bytes) and returns it with the rest of the input.
+fn read_varint(bytes: &[u8]) -> Result<(u64, &[u8]), CidError> {
+    let mut value: u64 = 0;
+
+    for (index, byte) in bytes.iter().enumerate().take(9) {
+        value |= u64::from(byte & 0x7f) << (7 * index);
+
+        if byte & 0x80 == 0 {
+            // Reject the non-minimal encodings.
+            if index > 0 && *byte == 0 {
+                return Err(CidError::InvalidVarint);
+            }
+
+            return Ok((value, &bytes[index + 1..]));
+        }
+    }
+
+    Err(CidError::InvalidVarint)
+}
+
+fn decode_base58(input: &[u8]) -> Result<Vec<u8>, CidError> {
+    // Little-endian big number in base 256.
+    let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
+
+    for character in input {
+        let mut carry = BASE58_ALPHABET
+            .iter()
+            .position(|c| c == character)
+            .ok_or(CidError::InvalidEncoding)? as u32;
+
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+
+    // Leading '1' characters encode the leading zero bytes.
+    let leading_zeros = input
+        .iter()
+        .take_while(|c| **c == BASE58_ALPHABET[0])
+        .count();
+    bytes.extend(rstd::iter::repeat(0).take(leading_zeros));
+    bytes.reverse();
+
+    Ok(bytes)
+}
+
+fn decode_base32(input: &[u8]) -> Result<Vec<u8>, CidError> {
+    let mut bytes: Vec<u8> = Vec::with_capacity(input.len() * 5 / 8);
+    let mut buffer: u32 = 0;
+    let mut bits: u32 = 0;
+
+    for character in input {
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|c| c == character)
+            .ok_or(CidError::InvalidEncoding)? as u32;
+
+        buffer = (buffer << 5) | value;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    // Unpadded base32 can leave less than a byte of zero bits only.
+    if bits >= 5 || buffer != 0 {
+        return Err(CidError::InvalidEncoding);
+    }
+
+    Ok(bytes)
+}
@@ -10,6 +10,11 @@
 //!
 //! ### Public extrinsic
 //! - [add_content](./struct.Module.html#method.add_content) - Adds the content to the system.
+//! - [remove_content](./struct.Module.html#method.remove_content) - Removes the content from the system. Requires the content owner.
+//!
+//! ### Leader extrinsics
+//! - [set_member_quota](./struct.Module.html#method.set_member_quota) - Overrides the default storage quota for the member.
+//! - [set_data_object_type_quota](./struct.Module.html#method.set_data_object_type_quota) - Sets the per-member storage quota for the data object type.
 //!
 //! ### Private extrinsics
 //! - accept_content - Storage provider accepts a content.
@@ -17,6 +22,26 @@
 //! - remove_known_content_id - Removes the content id from the list of known content ids. Requires root privileges.
 //! - set_known_content_id - Sets the content id from the list of known content ids. Requires root privileges.
 //!
+//! ### Storage quotas
+//! Member uploads are limited by the total size and the number of the data objects. The default
+//! quota is set on genesis and can be overridden per member by the storage working group leader.
+//! The leader can also limit the member uploads of the particular data object type.
+//!
+//! ### Content identifiers
+//! IPFS content id of the added content should be a valid CID. The content hash from the CID is
+//! stored with the data object, so storage providers can verify the stored bytes.
+//! The uploader also commits the Merkle root of the content chunks, which the storage challenges
+//! use to verify that the storage providers still hold the content.
+//!
+//! ### Replication
+//! Each added content is assigned to the distinct storage providers according to the replication
+//! factor of its data object type. The first assigned storage provider acts as the liaison.
+//!
+//! ### Garbage collection
+//! Rejected content is removed automatically after the `RejectedContentRetentionPeriod`
+//! since its latest rejection. Content removal cascades to the storage relationships via the
+//! `ContentRemovalHandler`.
+//!
 
 // Do not delete! Cannot be uncommented by default, because of Parity decl_module! issue.
 //#![warn(missing_docs)]
@@ -24,6 +49,8 @@
 use codec::{Decode, Encode};
 use rstd::collections::btree_map::BTreeMap;
 use rstd::prelude::*;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sr_primitives::traits::{MaybeSerialize, Member};
 use srml_support::traits::Get;
 use srml_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
@@ -32,8 +59,9 @@ use system::{self, ensure_root};
 use common::origin::ActorOriginValidator;
 pub(crate) use common::BlockAndTime;
 
+use crate::cid::{Cid, Multihash};
 use crate::data_object_type_registry;
-use crate::data_object_type_registry::IsActiveDataObjectType;
+use crate::data_object_type_registry::{DataObjectTypeReplication, IsActiveDataObjectType};
 use crate::{MemberId, StorageProviderId, StorageWorkingGroup, StorageWorkingGroupInstance};
 
 /// The _Data directory_ main _Trait_.
@@ -56,10 +84,19 @@ pub trait Trait:
     ///Active data object type validator.
     type IsActiveDataObjectType: data_object_type_registry::IsActiveDataObjectType<Self>;
 
+    /// Provides the replication factor of the data object type.
+    type DataObjectTypeReplication: data_object_type_registry::DataObjectTypeReplication<Self>;
+
     /// Validates member id and origin combination.
     type MemberOriginValidator: ActorOriginValidator<Self::Origin, MemberId<Self>, Self::AccountId>;
 
     type MaxObjectsPerInjection: Get<u32>;
+
+    /// Number of blocks the rejected content is kept before its automatic removal.
+    type RejectedContentRetentionPeriod: Get<Self::BlockNumber>;
+
+    /// Handles the content removal (e.g.: removes the storage relationships).
+    type ContentRemovalHandler: ContentRemovalHandler<Self>;
 }
 
 decl_error! {
@@ -81,7 +118,22 @@ decl_error! {
         RequireRootOrigin,
 
         /// DataObject Injection Failed. Too Many DataObjects.
-        DataObjectsInjectionExceededLimit
+        DataObjectsInjectionExceededLimit,
+
+        /// Only the content owner may remove the content.
+        OwnerRequired,
+
+        /// Member storage quota exceeded.
+        QuotaExceeded,
+
+        /// Member storage quota for the data object type exceeded.
+        DataObjectTypeQuotaExceeded,
+
+        /// No storage provider available to assign the content.
+        NoStorageProviderAvailable,
+
+        /// IPFS content id is not a valid CID.
+        InvalidIpfsContentId,
     }
 }
 
@@ -130,11 +182,19 @@ pub type DataObject<T> = DataObjectInternal<
     <T as timestamp::Trait>::Moment,
     <T as data_object_type_registry::Trait>::DataObjectTypeId,
     StorageProviderId<T>,
+    <T as system::Trait>::Hash,
 >;
 
 /// Manages content ids, type and storage provider decision about it.
 #[derive(Clone, Encode, Decode, PartialEq, Debug)]
-pub struct DataObjectInternal<MemberId, BlockNumber, Moment, DataObjectTypeId, StorageProviderId> {
+pub struct DataObjectInternal<
+    MemberId,
+    BlockNumber,
+    Moment,
+    DataObjectTypeId,
+    StorageProviderId,
+    Hash,
+> {
     /// Content owner.
     pub owner: MemberId,
 
@@ -155,6 +215,64 @@ pub struct DataObjectInternal<MemberId, BlockNumber, Moment, DataObjectTypeId, S
 
     /// IPFS content id.
     pub ipfs_content_id: Vec<u8>,
+
+    /// Content hash from the IPFS content id.
+    pub content_hash: Multihash,
+
+    /// Merkle root of the content chunks committed on upload. Storage challenges use it to
+    /// verify the storage proofs. The content added before its introduction has no root.
+    pub chunks_root: Option<Hash>,
+}
+
+/// Storage limits for the member uploads.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, Default, PartialEq, Eq, Debug)]
+pub struct Quota {
+    /// Maximum total size of the data objects in bytes.
+    pub size_limit: u64,
+
+    /// Maximum number of the data objects.
+    pub objects_limit: u64,
+}
+
+/// Creates default storage quota of the member.
+pub fn default_member_quota() -> Quota {
+    Quota {
+        size_limit: 10 * 1024 * 1024 * 1024,
+        objects_limit: 1000,
+    }
+}
+
+/// Storage used by the member uploads.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, Default, PartialEq, Eq, Debug)]
+pub struct QuotaUsage {
+    /// Total size of the data objects in bytes.
+    pub size: u64,
+
+    /// Number of the data objects.
+    pub objects: u64,
+}
+
+impl QuotaUsage {
+    /// Verifies that the data object with provided size fits the quota.
+    pub fn can_add(&self, quota: &Quota, size: u64) -> bool {
+        self.objects < quota.objects_limit
+            && self
+                .size
+                .checked_add(size)
+                .map_or(false, |total_size| total_size <= quota.size_limit)
+    }
+
+    fn add(&mut self, size: u64) {
+        self.size = self.size.saturating_add(size);
+        self.objects = self.objects.saturating_add(1);
+    }
+
+    fn remove(&mut self, size: u64) {
+        self.size = self.size.saturating_sub(size);
+        self.objects = self.objects.saturating_sub(1);
+    }
 }
 
 /// A map collection of unique DataObjects keyed by the ContentId
@@ -168,6 +286,36 @@ decl_storage! {
         /// Maps data objects by their content id.
         pub DataObjectByContentId get(data_object_by_content_id):
             map T::ContentId => Option<DataObject<T>>;
+
+        /// Storage providers assigned to the content. The first one is the liaison.
+        pub AssignedStorageProviders get(assigned_storage_providers):
+            map T::ContentId => Vec<StorageProviderId<T>>;
+
+        /// Default storage quota of the member.
+        pub DefaultQuota get(default_quota) config(): Quota;
+
+        /// Storage quotas of the members overridden by the leader.
+        pub MemberQuotas get(member_quotas): map MemberId<T> => Option<Quota>;
+
+        /// Per-member storage quotas of the data object types.
+        pub DataObjectTypeQuotas get(data_object_type_quotas):
+            map <T as data_object_type_registry::Trait>::DataObjectTypeId => Option<Quota>;
+
+        /// Storage used by the member uploads.
+        pub MemberUsage get(member_usage): map MemberId<T> => QuotaUsage;
+
+        /// Storage used by the member uploads per data object type.
+        pub MemberUsageByDataObjectType get(member_usage_by_data_object_type):
+            double_map MemberId<T>, twox_128(<T as data_object_type_registry::Trait>::DataObjectTypeId) => QuotaUsage;
+
+        /// Rejected content ids scheduled for the removal by the block number.
+        pub RejectedContentRemovalSchedule get(rejected_content_removal_schedule):
+            map T::BlockNumber => Vec<T::ContentId>;
+
+        /// Removal block of the rejected content, set by its latest rejection. The content is
+        /// removed only at this block, the stale entries of the removal schedule are skipped.
+        pub RejectedContentRemovalBlock get(rejected_content_removal_block):
+            map T::ContentId => Option<T::BlockNumber>;
     }
 }
 
@@ -176,7 +324,8 @@ decl_event! {
     pub enum Event<T> where
         <T as Trait>::ContentId,
         MemberId = MemberId<T>,
-        StorageProviderId = StorageProviderId<T>
+        StorageProviderId = StorageProviderId<T>,
+        DataObjectTypeId = <T as data_object_type_registry::Trait>::DataObjectTypeId
     {
         /// Emits on adding of the content.
         /// Params:
@@ -195,6 +344,29 @@ decl_event! {
         /// - Id of the relationship.
         /// - Id of the storage provider.
         ContentRejected(ContentId, StorageProviderId),
+
+        /// Emits when the content owner removes a content.
+        /// Params:
+        /// - Id of the content.
+        /// - Id of the member.
+        /// - IPFS content id of the removed content.
+        ContentRemoved(ContentId, MemberId, Vec<u8>),
+
+        /// Emits when the rejected content is removed automatically.
+        /// Params:
+        /// - Id of the content.
+        /// - IPFS content id of the removed content.
+        RejectedContentRemoved(ContentId, Vec<u8>),
+
+        /// Emits when the leader updates the member storage quota.
+        /// Params:
+        /// - Id of the member.
+        MemberQuotaUpdated(MemberId),
+
+        /// Emits when the leader updates the storage quota of the data object type.
+        /// Params:
+        /// - Id of the data object type.
+        DataObjectTypeQuotaUpdated(DataObjectTypeId),
     }
 }
 
@@ -210,7 +382,16 @@ decl_module! {
         /// Maximum objects allowed per inject_data_objects() transaction
         const MaxObjectsPerInjection: u32 = T::MaxObjectsPerInjection::get();
 
-        /// Adds the content to the system. Member id should match its origin. The created DataObject
+        /// Number of blocks the rejected content is kept before its automatic removal.
+        const RejectedContentRetentionPeriod: T::BlockNumber = T::RejectedContentRetentionPeriod::get();
+
+        /// Removes the rejected content with the expired retention period.
+        fn on_finalize(now: T::BlockNumber) {
+            Self::remove_expired_rejected_content(now);
+        }
+
+        /// Adds the content to the system. Member id should match its origin. The chunks root is the
+        /// Merkle root of the content chunks used by the storage challenges. The created DataObject
         /// awaits liaison to accept or reject it.
         pub fn add_content(
             origin,
@@ -218,7 +399,8 @@ decl_module! {
             content_id: T::ContentId,
             type_id: <T as data_object_type_registry::Trait>::DataObjectTypeId,
             size: u64,
-            ipfs_content_id: Vec<u8>
+            ipfs_content_id: Vec<u8>,
+            chunks_root: T::Hash
         ) {
             T::MemberOriginValidator::ensure_actor_origin(
                 origin,
@@ -231,7 +413,14 @@ decl_module! {
             ensure!(!<DataObjectByContentId<T>>::exists(content_id),
                 Error::DataObjectAlreadyAdded);
 
-            let liaison = T::StorageProviderHelper::get_random_storage_provider()?;
+            let cid = Cid::parse(&ipfs_content_id).map_err(|_| Error::InvalidIpfsContentId)?;
+
+            Self::ensure_quota_allows_upload(member_id, type_id, size)?;
+
+            let replication_factor = T::DataObjectTypeReplication::replication_factor(&type_id);
+            let storage_providers =
+                T::StorageProviderHelper::get_random_storage_providers(replication_factor)?;
+            let liaison = *storage_providers.first().ok_or(Error::NoStorageProviderAvailable)?;
 
             // Let's create the entry then
             let data: DataObject<T> = DataObjectInternal {
@@ -242,16 +431,44 @@ decl_module! {
                 liaison,
                 liaison_judgement: LiaisonJudgement::Pending,
                 ipfs_content_id,
+                content_hash: cid.hash,
+                chunks_root: Some(chunks_root),
             };
 
             //
             // == MUTATION SAFE ==
             //
 
+            Self::increase_usage(member_id, type_id, size);
+            <AssignedStorageProviders<T>>::insert(&content_id, storage_providers);
             <DataObjectByContentId<T>>::insert(&content_id, data);
             Self::deposit_event(RawEvent::ContentAdded(content_id, member_id));
         }
 
+        /// Removes the content from the system. Member id should match its origin and
+        /// the content owner. Storage relationships of the content are removed as well.
+        pub fn remove_content(
+            origin,
+            member_id: MemberId<T>,
+            content_id: T::ContentId
+        ) {
+            T::MemberOriginValidator::ensure_actor_origin(
+                origin,
+                member_id,
+            )?;
+
+            let data = Self::data_object_by_content_id(&content_id).ok_or(Error::CidNotFound)?;
+
+            ensure!(data.owner == member_id, Error::OwnerRequired);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::remove_data_object(content_id, &data);
+            Self::deposit_event(RawEvent::ContentRemoved(content_id, member_id, data.ipfs_content_id));
+        }
+
         /// Storage provider accepts a content. Requires signed storage provider account and its id.
         /// The LiaisonJudgement can be updated, but only by the liaison.
         pub(crate) fn accept_content(
@@ -266,6 +483,7 @@ decl_module! {
             Self::update_content_judgement(&storage_provider_id, content_id, LiaisonJudgement::Accepted)?;
 
             <KnownContentIds<T>>::mutate(|ids| ids.push(content_id));
+            <RejectedContentRemovalBlock<T>>::remove(content_id);
 
             Self::deposit_event(RawEvent::ContentAccepted(content_id, storage_provider_id));
         }
@@ -282,9 +500,52 @@ decl_module! {
             // == MUTATION SAFE ==
 
             Self::update_content_judgement(&storage_provider_id, content_id, LiaisonJudgement::Rejected)?;
+
+            let removal_block = <system::Module<T>>::block_number() + T::RejectedContentRetentionPeriod::get();
+            <RejectedContentRemovalSchedule<T>>::mutate(removal_block, |ids| ids.push(content_id));
+            <RejectedContentRemovalBlock<T>>::insert(content_id, removal_block);
+
             Self::deposit_event(RawEvent::ContentRejected(content_id, storage_provider_id));
         }
 
+        /// Overrides the default storage quota for the member. None value restores the default
+        /// quota. Requires leader privileges.
+        pub fn set_member_quota(origin, member_id: MemberId<T>, quota: Option<Quota>) {
+            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            match quota {
+                Some(quota) => <MemberQuotas<T>>::insert(member_id, quota),
+                None => <MemberQuotas<T>>::remove(member_id),
+            }
+
+            Self::deposit_event(RawEvent::MemberQuotaUpdated(member_id));
+        }
+
+        /// Sets the per-member storage quota for the data object type. None value removes the
+        /// data object type limits. Requires leader privileges.
+        pub fn set_data_object_type_quota(
+            origin,
+            type_id: <T as data_object_type_registry::Trait>::DataObjectTypeId,
+            quota: Option<Quota>
+        ) {
+            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            match quota {
+                Some(quota) => <DataObjectTypeQuotas<T>>::insert(type_id, quota),
+                None => <DataObjectTypeQuotas<T>>::remove(type_id),
+            }
+
+            Self::deposit_event(RawEvent::DataObjectTypeQuotaUpdated(type_id));
+        }
+
         // Sudo methods
 
         /// Removes the content id from the list of known content ids. Requires root privileges.
@@ -312,6 +573,12 @@ decl_module! {
             ensure!(objects.len() <= T::MaxObjectsPerInjection::get() as usize, Error::DataObjectsInjectionExceededLimit);
 
             for (id, object) in objects.into_iter() {
+                // keep the storage usage consistent for the overwritten objects
+                if let Some(old_object) = Self::data_object_by_content_id(id) {
+                    Self::decrease_usage(old_object.owner, old_object.type_id, old_object.size);
+                }
+                Self::increase_usage(object.owner, object.type_id, object.size);
+
                 // append to known content ids
                 // duplicates will be removed at the end
                 <KnownContentIds<T>>::mutate(|ids| ids.push(id));
@@ -343,12 +610,103 @@ impl<T: Trait> Module<T> {
 
         Ok(())
     }
+
+    /// Returns the storage quota of the member.
+    pub fn member_quota(member_id: MemberId<T>) -> Quota {
+        Self::member_quotas(member_id).unwrap_or_else(Self::default_quota)
+    }
+
+    // Ensures that the member quotas allow to upload the data object.
+    fn ensure_quota_allows_upload(
+        member_id: MemberId<T>,
+        type_id: <T as data_object_type_registry::Trait>::DataObjectTypeId,
+        size: u64,
+    ) -> Result<(), Error> {
+        ensure!(
+            Self::member_usage(member_id).can_add(&Self::member_quota(member_id), size),
+            Error::QuotaExceeded
+        );
+
+        if let Some(type_quota) = Self::data_object_type_quotas(type_id) {
+            ensure!(
+                Self::member_usage_by_data_object_type(member_id, type_id)
+                    .can_add(&type_quota, size),
+                Error::DataObjectTypeQuotaExceeded
+            );
+        }
+
+        Ok(())
+    }
+
+    fn increase_usage(
+        member_id: MemberId<T>,
+        type_id: <T as data_object_type_registry::Trait>::DataObjectTypeId,
+        size: u64,
+    ) {
+        <MemberUsage<T>>::mutate(member_id, |usage| usage.add(size));
+        <MemberUsageByDataObjectType<T>>::mutate(member_id, type_id, |usage| usage.add(size));
+    }
+
+    fn decrease_usage(
+        member_id: MemberId<T>,
+        type_id: <T as data_object_type_registry::Trait>::DataObjectTypeId,
+        size: u64,
+    ) {
+        <MemberUsage<T>>::mutate(member_id, |usage| usage.remove(size));
+        <MemberUsageByDataObjectType<T>>::mutate(member_id, type_id, |usage| usage.remove(size));
+    }
+
+    // Removes the data object with its known content id, releases the owner storage usage
+    // and notifies the removal handler.
+    fn remove_data_object(content_id: T::ContentId, data: &DataObject<T>) {
+        Self::decrease_usage(data.owner, data.type_id, data.size);
+        <DataObjectByContentId<T>>::remove(content_id);
+        <AssignedStorageProviders<T>>::remove(content_id);
+        <RejectedContentRemovalBlock<T>>::remove(content_id);
+        <KnownContentIds<T>>::mutate(|ids| ids.retain(|&id| id != content_id));
+
+        T::ContentRemovalHandler::on_content_removed(&content_id);
+    }
+
+    // Removes the content scheduled for the removal at the provided block if it is still rejected.
+    fn remove_expired_rejected_content(now: T::BlockNumber) {
+        for content_id in <RejectedContentRemovalSchedule<T>>::take(now) {
+            // The content could be rejected again later with the new retention period.
+            if Self::rejected_content_removal_block(&content_id) != Some(now) {
+                continue;
+            }
+
+            // The liaison could change its judgement after the rejection.
+            if let Some(data) = Self::data_object_by_content_id(&content_id) {
+                if data.liaison_judgement == LiaisonJudgement::Rejected {
+                    Self::remove_data_object(content_id, &data);
+                    Self::deposit_event(RawEvent::RejectedContentRemoved(
+                        content_id,
+                        data.ipfs_content_id,
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Handles the content removal in the dependent modules.
+pub trait ContentRemovalHandler<T: Trait> {
+    /// Notifies about the removed content.
+    fn on_content_removed(content_id: &T::ContentId);
+}
+
+impl<T: Trait> ContentRemovalHandler<T> for () {
+    fn on_content_removed(_content_id: &T::ContentId) {}
 }
 
 /// Provides random storage provider id. We use it when assign the content to the storage provider.
 pub trait StorageProviderHelper<T: Trait> {
     /// Provides random storage provider id.
     fn get_random_storage_provider() -> Result<StorageProviderId<T>, &'static str>;
+
+    /// Provides up to the requested number of distinct random storage provider ids.
+    fn get_random_storage_providers(count: u32) -> Result<Vec<StorageProviderId<T>>, &'static str>;
 }
 
 /// Content access helper.
@@ -358,6 +716,12 @@ pub trait ContentIdExists<T: Trait> {
 
     /// Returns the data object for the provided content id.
     fn get_data_object(id: &T::ContentId) -> Result<DataObject<T>, &'static str>;
+
+    /// Verifies that the storage provider is assigned to store the content.
+    fn is_assigned_storage_provider(
+        id: &T::ContentId,
+        storage_provider_id: &StorageProviderId<T>,
+    ) -> bool;
 }
 
 impl<T: Trait> ContentIdExists<T> for Module<T> {
@@ -371,4 +735,11 @@ impl<T: Trait> ContentIdExists<T> for Module<T> {
             None => Err(Error::LiaisonRequired.into()),
         }
     }
+
+    fn is_assigned_storage_provider(
+        content_id: &T::ContentId,
+        storage_provider_id: &StorageProviderId<T>,
+    ) -> bool {
+        Self::assigned_storage_providers(*content_id).contains(storage_provider_id)
+    }
 }
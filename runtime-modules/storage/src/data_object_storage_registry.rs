@@ -13,6 +13,12 @@
 //! - [set_relationship_ready](./struct.Module.html#method.set_relationship_ready)- Activates storage provider-to-content relationship.
 //! - [unset_relationship_ready](./struct.Module.html#method.unset_relationship_ready) - Deactivates storage provider-to-content relationship.
 //!
+//! Storage relationships are removed with their content by the data directory module.
+//!
+//! The module tracks the number of the ready storage relationships per content. The content with
+//! less ready relationships than the replication factor of its data object type is considered
+//! under-replicated.
+//!
 
 // Clippy linter requirement.
 // Disable it because of the substrate lib design. Example:
@@ -27,7 +33,8 @@ use rstd::prelude::*;
 use sr_primitives::traits::{MaybeSerialize, Member, SimpleArithmetic};
 use srml_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
 
-use crate::data_directory::{self, ContentIdExists};
+use crate::data_directory::{self, ContentIdExists, ContentRemovalHandler};
+use crate::data_object_type_registry::DataObjectTypeReplication;
 use crate::{StorageProviderId, StorageWorkingGroup, StorageWorkingGroupInstance};
 
 const DEFAULT_FIRST_RELATIONSHIP_ID: u32 = 1;
@@ -62,9 +69,15 @@ decl_error! {
         /// Content with this ID not found.
         CidNotFound,
 
+        /// The storage provider is not assigned to store the content.
+        StorageProviderNotAssigned,
+
         /// No data object storage relationship found for this ID.
         DataObjectStorageRelationshipNotFound,
 
+        /// The storage provider already has a relationship with the content.
+        RelationshipAlreadyExists,
+
         /// Only the storage provider in a DOSR can decide whether they're ready.
         OnlyStorageProviderMayClaimReady,
 
@@ -120,6 +133,9 @@ decl_storage! {
 
         /// Keeps a list of storage relationships per content id.
         pub RelationshipsByContentId get(relationships_by_content_id): map T::ContentId => Vec<T::DataObjectStorageRelationshipId>;
+
+        /// Number of the ready storage relationships per content id.
+        pub ReadyRelationshipsCount get(ready_relationships_count): map T::ContentId => u32;
     }
 }
 
@@ -142,6 +158,13 @@ decl_event! {
         /// - Id of the relationship.
         /// - Current state of the relationship (True=Active).
         DataObjectStorageRelationshipReadyUpdated(DataObjectStorageRelationshipId, bool),
+
+        /// Emits on removing of the data object storage relationship with its content.
+        /// Params:
+        /// - Id of the relationship.
+        /// - Id of the content.
+        /// - Id of the storage provider.
+        DataObjectStorageRelationshipRemoved(DataObjectStorageRelationshipId, ContentId, StorageProviderId),
     }
 }
 
@@ -163,6 +186,18 @@ decl_module! {
             // Content ID must exist
             ensure!(T::ContentIdExists::has_content(&cid), Error::CidNotFound);
 
+            // Storage provider must be assigned to the content
+            ensure!(
+                T::ContentIdExists::is_assigned_storage_provider(&cid, &storage_provider_id),
+                Error::StorageProviderNotAssigned
+            );
+
+            // Storage provider may have only one relationship per content
+            ensure!(
+                !Self::has_relationship(&cid, &storage_provider_id),
+                Error::RelationshipAlreadyExists
+            );
+
             // Create new ID, data.
             let new_id = Self::next_relationship_id();
             let dosr: DataObjectStorageRelationship<T> = DataObjectStorageRelationship {
@@ -181,7 +216,7 @@ decl_module! {
             });
 
             // Also add the DOSR to the list of DOSRs for the CID. Uniqueness is guaranteed
-            // by the map, so we can just append the new_id to the list.
+            // by the map and the check above, so we can just append the new_id to the list.
             let mut dosr_list = Self::relationships_by_content_id(cid);
             dosr_list.push(new_id);
             <RelationshipsByContentId<T>>::insert(cid, dosr_list);
@@ -232,6 +267,17 @@ impl<T: Trait> Module<T> {
             Error::OnlyStorageProviderMayClaimReady
         );
 
+        // Keep the ready relationships counter in sync
+        if dosr.ready != ready {
+            <ReadyRelationshipsCount<T>>::mutate(dosr.content_id, |count| {
+                *count = if ready {
+                    count.saturating_add(1)
+                } else {
+                    count.saturating_sub(1)
+                };
+            });
+        }
+
         // Flip to ready
         dosr.ready = ready;
 
@@ -243,4 +289,51 @@ impl<T: Trait> Module<T> {
 
         Ok(())
     }
+
+    /// Checks whether the storage provider already has a relationship with the content.
+    fn has_relationship(
+        content_id: &T::ContentId,
+        storage_provider_id: &StorageProviderId<T>,
+    ) -> bool {
+        Self::relationships_by_content_id(content_id)
+            .into_iter()
+            .filter_map(Self::relationships)
+            .any(|dosr| dosr.storage_provider_id == *storage_provider_id)
+    }
+
+    /// Returns known content ids with less ready storage relationships than the replication
+    /// factor of their data object type.
+    pub fn under_replicated_content_ids() -> Vec<T::ContentId> {
+        <data_directory::Module<T>>::known_content_ids()
+            .into_iter()
+            .filter(|content_id| {
+                T::ContentIdExists::get_data_object(content_id)
+                    .map(|data| {
+                        let replication_factor =
+                            <T as data_directory::Trait>::DataObjectTypeReplication::replication_factor(
+                                &data.type_id,
+                            );
+
+                        Self::ready_relationships_count(content_id) < replication_factor
+                    })
+                    .unwrap_or(false)
+            })
+            .collect()
+    }
+}
+
+impl<T: Trait> ContentRemovalHandler<T> for Module<T> {
+    fn on_content_removed(content_id: &T::ContentId) {
+        <ReadyRelationshipsCount<T>>::remove(content_id);
+
+        for id in <RelationshipsByContentId<T>>::take(content_id) {
+            if let Some(dosr) = <Relationships<T>>::take(id) {
+                Self::deposit_event(RawEvent::DataObjectStorageRelationshipRemoved(
+                    id,
+                    dosr.content_id,
+                    dosr.storage_provider_id,
+                ));
+            }
+        }
+    }
 }
@@ -26,10 +26,11 @@ use crate::{StorageWorkingGroup, StorageWorkingGroupInstance};
 use codec::{Codec, Decode, Encode};
 use rstd::prelude::*;
 use sr_primitives::traits::{MaybeSerialize, Member, SimpleArithmetic};
-use srml_support::{decl_error, decl_event, decl_module, decl_storage, Parameter};
+use srml_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
 
 const DEFAULT_TYPE_DESCRIPTION: &str = "Default data object type for audio and video content.";
 const DEFAULT_FIRST_DATA_OBJECT_TYPE_ID: u32 = 1;
+const DEFAULT_REPLICATION_FACTOR: u32 = 1;
 
 /// The _Data object type registry_ main _Trait_.
 pub trait Trait: system::Trait + working_group::Trait<StorageWorkingGroupInstance> {
@@ -55,6 +56,9 @@ decl_error! {
 
         /// Require root origin in extrinsics
         RequireRootOrigin,
+
+        /// Data object type replication factor should be positive.
+        InvalidReplicationFactor,
     }
 }
 
@@ -85,6 +89,9 @@ pub struct DataObjectType {
 
     /// Active/Disabled flag.
     pub active: bool,
+
+    /// Number of the distinct storage providers which should store the data object.
+    pub replication_factor: u32,
 }
 
 impl Default for DataObjectType {
@@ -92,6 +99,7 @@ impl Default for DataObjectType {
         DataObjectType {
             description: DEFAULT_TYPE_DESCRIPTION.as_bytes().to_vec(),
             active: true,
+            replication_factor: DEFAULT_REPLICATION_FACTOR,
         }
     }
 }
@@ -151,10 +159,13 @@ decl_module! {
         pub fn register_data_object_type(origin, data_object_type: DataObjectType) {
             <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
 
+            Self::ensure_replication_factor_is_valid(data_object_type.replication_factor)?;
+
             let new_do_type_id = Self::next_data_object_type_id();
             let do_type: DataObjectType = DataObjectType {
                 description: data_object_type.description.clone(),
                 active: data_object_type.active,
+                replication_factor: data_object_type.replication_factor,
             };
 
             //
@@ -173,8 +184,11 @@ decl_module! {
 
             let mut do_type = Self::ensure_data_object_type(id)?;
 
+            Self::ensure_replication_factor_is_valid(data_object_type.replication_factor)?;
+
             do_type.description = data_object_type.description.clone();
             do_type.active = data_object_type.active;
+            do_type.replication_factor = data_object_type.replication_factor;
 
             //
             // == MUTATION SAFE ==
@@ -225,6 +239,12 @@ impl<T: Trait> Module<T> {
     fn ensure_data_object_type(id: T::DataObjectTypeId) -> Result<DataObjectType, Error> {
         Self::data_object_types(&id).ok_or(Error::DataObjectTypeNotFound)
     }
+
+    fn ensure_replication_factor_is_valid(replication_factor: u32) -> Result<(), Error> {
+        ensure!(replication_factor > 0, Error::InvalidReplicationFactor);
+
+        Ok(())
+    }
 }
 
 /// Active data object type validator trait.
@@ -241,3 +261,18 @@ impl<T: Trait> IsActiveDataObjectType<T> for Module<T> {
         }
     }
 }
+
+/// Data object type replication factor provider trait.
+pub trait DataObjectTypeReplication<T: Trait> {
+    /// Returns the number of the distinct storage providers which should store the data object
+    /// of the given type.
+    fn replication_factor(id: &T::DataObjectTypeId) -> u32;
+}
+
+impl<T: Trait> DataObjectTypeReplication<T> for Module<T> {
+    fn replication_factor(id: &T::DataObjectTypeId) -> u32 {
+        Self::data_object_types(id).map_or(DEFAULT_REPLICATION_FACTOR, |do_type| {
+            do_type.replication_factor
+        })
+    }
+}
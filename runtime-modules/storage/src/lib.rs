@@ -1,9 +1,11 @@
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod cid;
 pub mod data_directory;
 pub mod data_object_storage_registry;
 pub mod data_object_type_registry;
+pub mod storage_challenge;
 
 mod tests;
 
@@ -0,0 +1,631 @@
+//! # Storage challenge module
+//! Storage challenge module for the Joystream platform verifies that the storage providers still
+//! hold the content of their ready storage relationships.
+//!
+//! ## Comments
+//!
+//! Every `ChallengePeriod` the module randomly picks up to `ChallengesPerPeriod` ready storage
+//! relationships and challenges their storage providers. The storage provider should respond
+//! with a proof before the `ChallengeResponsePeriod` expires, otherwise the challenge fails.
+//! Challenge results are recorded per storage provider, so the storage working group leader can
+//! slash the unreliable storage providers using the working group `slash_stake` extrinsic.
+//! The challenge is cancelled instead of failed when its storage relationship was removed with the
+//! content or the storage provider left the working group before the deadline.
+//! Resolved challenges are removed from the storage `ChallengePeriod` blocks after their deadline.
+//!
+//! The runtime verifies the proofs with the `MerkleStorageProofVerifier`. The content is split into
+//! `STORAGE_PROOF_CHUNK_SIZE` byte chunks and the uploader commits the Merkle root of the chunks
+//! with the data object. The challenge seed selects the chunk, and the proof contains the chunk
+//! bytes with its Merkle path to the committed root. The content without the committed root is
+//! not challenged.
+//!
+//! ## Supported extrinsics
+//!
+//! - [respond_to_challenge](./struct.Module.html#method.respond_to_challenge) - Responds to the storage challenge with a proof.
+//!
+
+// Do not delete! Cannot be uncommented by default, because of Parity decl_module! issue.
+//#![warn(missing_docs)]
+
+use codec::{Codec, Decode, Encode};
+use rstd::prelude::*;
+use sr_primitives::traits::{
+    Hash, MaybeSerialize, Member, SimpleArithmetic, UniqueSaturatedFrom, UniqueSaturatedInto, Zero,
+};
+use srml_support::traits::{Get, Randomness};
+use srml_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
+
+use crate::data_directory::{self, ContentIdExists};
+use crate::data_object_storage_registry;
+use crate::{StorageProviderId, StorageWorkingGroup, StorageWorkingGroupInstance};
+
+// Random subject prefix for the challenge selection.
+const CHALLENGE_RANDOM_SUBJECT: &[u8] = b"storage_challenge";
+
+/// Size of the content chunks of the storage proofs in bytes.
+pub const STORAGE_PROOF_CHUNK_SIZE: u64 = 1024;
+
+// Hash prefixes of the Merkle tree leaves and nodes.
+const MERKLE_LEAF_PREFIX: u8 = 0;
+const MERKLE_NODE_PREFIX: u8 = 1;
+
+/// The _Storage challenge_ main _Trait_.
+pub trait Trait: data_object_storage_registry::Trait {
+    /// _Storage challenge_ event type.
+    type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+    /// Storage challenge id.
+    type ChallengeId: Parameter
+        + Member
+        + SimpleArithmetic
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerialize
+        + PartialEq;
+
+    /// Randomness source for the challenge selection.
+    type Randomness: Randomness<Self::Hash>;
+
+    /// Verifies the storage provider proofs.
+    type StorageProofVerifier: StorageProofVerifier<Self>;
+
+    /// Number of blocks between the challenge rounds.
+    type ChallengePeriod: Get<Self::BlockNumber>;
+
+    /// Number of blocks the storage provider has to respond to the challenge.
+    type ChallengeResponsePeriod: Get<Self::BlockNumber>;
+
+    /// Maximum number of the challenges per challenge round.
+    type ChallengesPerPeriod: Get<u32>;
+}
+
+decl_error! {
+    /// _Storage challenge_ module predefined errors
+    pub enum Error {
+        /// Storage challenge with this ID not found.
+        ChallengeNotFound,
+
+        /// Only the challenged storage provider may respond to the challenge.
+        OnlyChallengedStorageProviderMayRespond,
+
+        /// Storage challenge is not pending anymore.
+        ChallengeNotPending,
+
+        /// Storage challenge response deadline expired.
+        ChallengeExpired,
+
+        /// Storage proof verification failed.
+        InvalidStorageProof,
+    }
+}
+
+impl From<working_group::Error> for Error {
+    fn from(error: working_group::Error) -> Self {
+        match error {
+            working_group::Error::Other(msg) => Error::Other(msg),
+            _ => Error::Other(error.into()),
+        }
+    }
+}
+
+/// Storage challenge status.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, Debug)]
+pub enum ChallengeStatus {
+    /// Challenge awaits for the storage provider response.
+    Pending,
+
+    /// Storage provider responded with a valid proof.
+    Passed,
+
+    /// Storage provider didn't respond in time.
+    Failed,
+
+    /// Challenged storage relationship or storage provider was removed before the deadline.
+    Cancelled,
+}
+
+impl Default for ChallengeStatus {
+    fn default() -> Self {
+        ChallengeStatus::Pending
+    }
+}
+
+/// Alias for the StorageChallengeInternal
+pub type StorageChallenge<T> = StorageChallengeInternal<
+    StorageProviderId<T>,
+    <T as data_directory::Trait>::ContentId,
+    <T as data_object_storage_registry::Trait>::DataObjectStorageRelationshipId,
+    <T as system::Trait>::Hash,
+    <T as system::Trait>::BlockNumber,
+>;
+
+/// Challenge of the storage provider to prove that it holds the content.
+#[derive(Clone, Encode, Decode, PartialEq, Debug)]
+pub struct StorageChallengeInternal<StorageProviderId, ContentId, RelationshipId, Hash, BlockNumber>
+{
+    /// Challenged storage provider id.
+    pub storage_provider_id: StorageProviderId,
+
+    /// Challenged content id.
+    pub content_id: ContentId,
+
+    /// Challenged storage relationship id.
+    pub relationship_id: RelationshipId,
+
+    /// Random seed the proof should be based on.
+    pub seed: Hash,
+
+    /// Challenge creation block.
+    pub created_at: BlockNumber,
+
+    /// The last block to respond to the challenge.
+    pub deadline: BlockNumber,
+
+    /// Current challenge status.
+    pub status: ChallengeStatus,
+}
+
+/// Storage challenge results of the storage provider.
+#[derive(Clone, Copy, Encode, Decode, Default, PartialEq, Eq, Debug)]
+pub struct ChallengeStats {
+    /// Number of the passed challenges.
+    pub passed: u32,
+
+    /// Number of the failed challenges.
+    pub failed: u32,
+}
+
+decl_storage! {
+    trait Store for Module<T: Trait> as StorageChallenge {
+        /// Next storage challenge id.
+        pub NextChallengeId get(next_challenge_id): T::ChallengeId;
+
+        /// Storage challenges by their ids.
+        pub Challenges get(challenges): map T::ChallengeId => Option<StorageChallenge<T>>;
+
+        /// Storage challenge ids by their deadline block. Used to fail the pending challenges at
+        /// the deadline and to prune the resolved challenges afterwards.
+        pub ChallengesByDeadline get(challenges_by_deadline):
+            map T::BlockNumber => Vec<T::ChallengeId>;
+
+        /// Storage challenge results per storage provider.
+        pub ChallengeStatsByStorageProvider get(challenge_stats_by_storage_provider):
+            map StorageProviderId<T> => ChallengeStats;
+    }
+}
+
+decl_event! {
+    /// _Storage challenge_ events
+    pub enum Event<T> where
+        <T as Trait>::ChallengeId,
+        <T as data_directory::Trait>::ContentId,
+        StorageProviderId = StorageProviderId<T>
+    {
+        /// Emits on the storage provider challenge.
+        /// Params:
+        /// - Id of the challenge.
+        /// - Id of the storage provider.
+        /// - Id of the content.
+        ChallengeCreated(ChallengeId, StorageProviderId, ContentId),
+
+        /// Emits on the valid storage provider response.
+        /// Params:
+        /// - Id of the challenge.
+        /// - Id of the storage provider.
+        ChallengePassed(ChallengeId, StorageProviderId),
+
+        /// Emits when the storage provider didn't respond in time.
+        /// Params:
+        /// - Id of the challenge.
+        /// - Id of the storage provider.
+        ChallengeFailed(ChallengeId, StorageProviderId),
+
+        /// Emits when the challenged storage relationship or storage provider was removed
+        /// before the deadline.
+        /// Params:
+        /// - Id of the challenge.
+        /// - Id of the storage provider.
+        ChallengeCancelled(ChallengeId, StorageProviderId),
+    }
+}
+
+decl_module! {
+    /// _Storage challenge_ substrate module.
+    pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        /// Default deposit_event() handler.
+        fn deposit_event() = default;
+
+        /// Predefined errors.
+        type Error = Error;
+
+        /// Number of blocks between the challenge rounds.
+        const ChallengePeriod: T::BlockNumber = T::ChallengePeriod::get();
+
+        /// Number of blocks the storage provider has to respond to the challenge.
+        const ChallengeResponsePeriod: T::BlockNumber = T::ChallengeResponsePeriod::get();
+
+        /// Maximum number of the challenges per challenge round.
+        const ChallengesPerPeriod: u32 = T::ChallengesPerPeriod::get();
+
+        /// Fails or cancels the expired challenges, prunes the old resolved challenges and creates the new
+        /// ones at the challenge round.
+        fn on_finalize(now: T::BlockNumber) {
+            Self::fail_expired_challenges(now);
+            Self::prune_resolved_challenges(now);
+
+            let challenge_period = T::ChallengePeriod::get();
+            if !challenge_period.is_zero() && (now % challenge_period).is_zero() {
+                Self::create_challenges(now);
+            }
+        }
+
+        /// Responds to the storage challenge with a proof. The storage provider should be
+        /// registered in the storage working group and match the challenge.
+        pub fn respond_to_challenge(
+            origin,
+            storage_provider_id: StorageProviderId<T>,
+            challenge_id: T::ChallengeId,
+            proof: Vec<u8>
+        ) {
+            <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
+
+            let mut challenge = Self::challenges(challenge_id).ok_or(Error::ChallengeNotFound)?;
+
+            ensure!(
+                challenge.storage_provider_id == storage_provider_id,
+                Error::OnlyChallengedStorageProviderMayRespond
+            );
+            ensure!(challenge.status == ChallengeStatus::Pending, Error::ChallengeNotPending);
+            ensure!(
+                <system::Module<T>>::block_number() <= challenge.deadline,
+                Error::ChallengeExpired
+            );
+            ensure!(
+                T::StorageProofVerifier::verify_storage_proof(&challenge, &proof),
+                Error::InvalidStorageProof
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            challenge.status = ChallengeStatus::Passed;
+            <Challenges<T>>::insert(challenge_id, challenge);
+            <ChallengeStatsByStorageProvider<T>>::mutate(storage_provider_id, |stats| {
+                stats.passed = stats.passed.saturating_add(1);
+            });
+
+            Self::deposit_event(RawEvent::ChallengePassed(challenge_id, storage_provider_id));
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    // Randomly picks the ready storage relationships and challenges their storage providers.
+    fn create_challenges(now: T::BlockNumber) {
+        let first_id: u64 = <data_object_storage_registry::Module<T>>::first_relationship_id()
+            .unique_saturated_into();
+        let next_id: u64 = <data_object_storage_registry::Module<T>>::next_relationship_id()
+            .unique_saturated_into();
+
+        if next_id <= first_id {
+            return;
+        }
+
+        let mut challenged_ids = Vec::new();
+        for index in 0..T::ChallengesPerPeriod::get() {
+            let seed = T::Randomness::random(&(CHALLENGE_RANDOM_SUBJECT, index).encode());
+            let relationship_id = T::DataObjectStorageRelationshipId::unique_saturated_from(
+                first_id + Self::random_number(&seed) % (next_id - first_id),
+            );
+
+            if challenged_ids.contains(&relationship_id) {
+                continue;
+            }
+
+            if let Some(relationship) =
+                <data_object_storage_registry::Module<T>>::relationships(relationship_id)
+            {
+                if relationship.ready && Self::has_chunks_root(&relationship.content_id) {
+                    challenged_ids.push(relationship_id);
+
+                    Self::create_challenge(
+                        relationship_id,
+                        relationship.storage_provider_id,
+                        relationship.content_id,
+                        seed,
+                        now,
+                    );
+                }
+            }
+        }
+    }
+
+    fn create_challenge(
+        relationship_id: T::DataObjectStorageRelationshipId,
+        storage_provider_id: StorageProviderId<T>,
+        content_id: T::ContentId,
+        seed: T::Hash,
+        now: T::BlockNumber,
+    ) {
+        let challenge_id = Self::next_challenge_id();
+        let deadline = now + T::ChallengeResponsePeriod::get();
+
+        let challenge: StorageChallenge<T> = StorageChallengeInternal {
+            storage_provider_id,
+            content_id,
+            relationship_id,
+            seed,
+            created_at: now,
+            deadline,
+            status: ChallengeStatus::Pending,
+        };
+
+        <Challenges<T>>::insert(challenge_id, challenge);
+        <ChallengesByDeadline<T>>::mutate(deadline, |ids| ids.push(challenge_id));
+        <NextChallengeId<T>>::mutate(|id| *id += T::ChallengeId::from(1));
+
+        Self::deposit_event(RawEvent::ChallengeCreated(
+            challenge_id,
+            storage_provider_id,
+            content_id,
+        ));
+    }
+
+    // Fails the pending challenges with the response deadline at the current block. Finalization
+    // happens after the block extrinsics, so the responses of the deadline block are accepted.
+    // The challenges of the removed content or storage providers are cancelled instead.
+    fn fail_expired_challenges(now: T::BlockNumber) {
+        for challenge_id in Self::challenges_by_deadline(now) {
+            if let Some(mut challenge) = Self::challenges(challenge_id) {
+                if challenge.status == ChallengeStatus::Pending {
+                    let storage_provider_id = challenge.storage_provider_id;
+
+                    if Self::is_challenge_subject_removed(&challenge) {
+                        challenge.status = ChallengeStatus::Cancelled;
+                        <Challenges<T>>::insert(challenge_id, challenge);
+
+                        Self::deposit_event(RawEvent::ChallengeCancelled(
+                            challenge_id,
+                            storage_provider_id,
+                        ));
+                    } else {
+                        challenge.status = ChallengeStatus::Failed;
+                        <Challenges<T>>::insert(challenge_id, challenge);
+                        <ChallengeStatsByStorageProvider<T>>::mutate(
+                            storage_provider_id,
+                            |stats| {
+                                stats.failed = stats.failed.saturating_add(1);
+                            },
+                        );
+
+                        Self::deposit_event(RawEvent::ChallengeFailed(
+                            challenge_id,
+                            storage_provider_id,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    // Checks whether the challenged storage relationship was removed with its content or the
+    // challenged storage provider left the storage working group.
+    fn is_challenge_subject_removed(challenge: &StorageChallenge<T>) -> bool {
+        let relationship_exists =
+            <data_object_storage_registry::Module<T>>::relationships(challenge.relationship_id)
+                .map_or(false, |relationship| {
+                    relationship.storage_provider_id == challenge.storage_provider_id
+                });
+
+        let storage_provider_exists =
+            <working_group::WorkerById<T, StorageWorkingGroupInstance>>::exists(
+                challenge.storage_provider_id,
+            );
+
+        !relationship_exists || !storage_provider_exists
+    }
+
+    // Checks whether the uploader committed the Merkle root of the content chunks.
+    fn has_chunks_root(content_id: &T::ContentId) -> bool {
+        <T as data_object_storage_registry::Trait>::ContentIdExists::get_data_object(content_id)
+            .map_or(false, |data_object| data_object.chunks_root.is_some())
+    }
+
+    // Removes the challenges resolved at least `ChallengePeriod` blocks ago.
+    fn prune_resolved_challenges(now: T::BlockNumber) {
+        let challenge_period = T::ChallengePeriod::get();
+        if now < challenge_period {
+            return;
+        }
+
+        for challenge_id in <ChallengesByDeadline<T>>::take(now - challenge_period) {
+            <Challenges<T>>::remove(challenge_id);
+        }
+    }
+
+    // Converts the first bytes of the random hash to the number.
+    fn random_number(seed: &T::Hash) -> u64 {
+        seed.as_ref()
+            .iter()
+            .take(8)
+            .enumerate()
+            .fold(0u64, |number, (offset, byte)| {
+                number | (u64::from(*byte) << (8 * offset))
+            })
+    }
+}
+
+/// Storage proof verifier. Verifies the storage provider response to the challenge.
+pub trait StorageProofVerifier<T: Trait> {
+    /// Verifies the storage proof for the challenge.
+    fn verify_storage_proof(challenge: &StorageChallenge<T>, proof: &[u8]) -> bool;
+}
+
+/// Storage proof of the challenged content chunk.
+#[derive(Clone, Encode, Decode, Default, PartialEq, Debug)]
+pub struct StorageProof<Hash> {
+    /// Bytes of the challenged content chunk.
+    pub chunk: Vec<u8>,
+
+    /// Sibling hashes from the chunk leaf up to the Merkle root.
+    pub merkle_path: Vec<Hash>,
+}
+
+/// Verifies the SCALE encoded `StorageProof` of the content chunk selected by the challenge seed
+/// against the Merkle root of the content chunks committed on upload. The proof can't be built
+/// without the challenged chunk bytes, and the seed binds the proof to the challenge.
+///
+/// The Merkle tree leaves are the hashes of the content chunks. The last node of the level with
+/// an odd number of nodes is paired with itself.
+pub struct MerkleStorageProofVerifier;
+
+impl MerkleStorageProofVerifier {
+    /// Returns the number of the content chunks. The empty content has a single empty chunk.
+    pub fn chunks_count(size: u64) -> u64 {
+        let count = size / STORAGE_PROOF_CHUNK_SIZE;
+
+        if size % STORAGE_PROOF_CHUNK_SIZE != 0 || count == 0 {
+            count + 1
+        } else {
+            count
+        }
+    }
+
+    /// Returns the index of the content chunk selected by the challenge seed.
+    pub fn challenged_chunk_index<T: Trait>(seed: &T::Hash, size: u64) -> u64 {
+        let chunk_seed = T::Hashing::hash(seed.as_ref());
+
+        <Module<T>>::random_number(&chunk_seed) % Self::chunks_count(size)
+    }
+
+    /// Builds the Merkle root of the content chunks.
+    pub fn chunks_root<T: Trait>(content: &[u8]) -> T::Hash {
+        let mut level = Self::chunk_leaves::<T>(content);
+        while level.len() > 1 {
+            level = Self::next_level::<T>(&level);
+        }
+
+        level[0]
+    }
+
+    /// Builds the storage proof of the content chunk.
+    pub fn build_storage_proof<T: Trait>(content: &[u8], index: u64) -> StorageProof<T::Hash> {
+        let chunk = content
+            .chunks(STORAGE_PROOF_CHUNK_SIZE as usize)
+            .nth(index as usize)
+            .map(|chunk| chunk.to_vec())
+            .unwrap_or_default();
+
+        let mut merkle_path = Vec::new();
+        let mut level = Self::chunk_leaves::<T>(content);
+        let mut index = index as usize;
+        while level.len() > 1 {
+            let sibling = level.get(index ^ 1).or_else(|| level.get(index));
+            if let Some(sibling) = sibling {
+                merkle_path.push(*sibling);
+            }
+
+            level = Self::next_level::<T>(&level);
+            index /= 2;
+        }
+
+        StorageProof { chunk, merkle_path }
+    }
+
+    // Returns the size of the content chunk.
+    fn chunk_size(size: u64, index: u64) -> u64 {
+        let offset = index.saturating_mul(STORAGE_PROOF_CHUNK_SIZE);
+
+        size.saturating_sub(offset).min(STORAGE_PROOF_CHUNK_SIZE)
+    }
+
+    // Returns the number of the Merkle tree levels above the leaves.
+    fn merkle_depth(chunks_count: u64) -> usize {
+        let mut depth = 0;
+        let mut count = chunks_count;
+        while count > 1 {
+            count = (count + 1) / 2;
+            depth += 1;
+        }
+
+        depth
+    }
+
+    fn chunk_leaves<T: Trait>(content: &[u8]) -> Vec<T::Hash> {
+        let mut leaves: Vec<T::Hash> = content
+            .chunks(STORAGE_PROOF_CHUNK_SIZE as usize)
+            .map(Self::leaf_hash::<T>)
+            .collect();
+
+        if leaves.is_empty() {
+            leaves.push(Self::leaf_hash::<T>(&[]));
+        }
+
+        leaves
+    }
+
+    fn next_level<T: Trait>(level: &[T::Hash]) -> Vec<T::Hash> {
+        level
+            .chunks(2)
+            .map(|pair| Self::node_hash::<T>(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+            .collect()
+    }
+
+    fn leaf_hash<T: Trait>(chunk: &[u8]) -> T::Hash {
+        T::Hashing::hash(&[&[MERKLE_LEAF_PREFIX][..], chunk].concat())
+    }
+
+    fn node_hash<T: Trait>(left: &T::Hash, right: &T::Hash) -> T::Hash {
+        T::Hashing::hash(&[&[MERKLE_NODE_PREFIX][..], left.as_ref(), right.as_ref()].concat())
+    }
+}
+
+impl<T: Trait> StorageProofVerifier<T> for MerkleStorageProofVerifier {
+    fn verify_storage_proof(challenge: &StorageChallenge<T>, proof: &[u8]) -> bool {
+        let data_object =
+            match <T as data_object_storage_registry::Trait>::ContentIdExists::get_data_object(
+                &challenge.content_id,
+            ) {
+                Ok(data_object) => data_object,
+                Err(_) => return false,
+            };
+
+        let chunks_root = match data_object.chunks_root {
+            Some(chunks_root) => chunks_root,
+            None => return false,
+        };
+
+        let proof = match StorageProof::<T::Hash>::decode(&mut &proof[..]) {
+            Ok(proof) => proof,
+            Err(_) => return false,
+        };
+
+        let index = Self::challenged_chunk_index::<T>(&challenge.seed, data_object.size);
+
+        if proof.chunk.len() as u64 != Self::chunk_size(data_object.size, index)
+            || proof.merkle_path.len() != Self::merkle_depth(Self::chunks_count(data_object.size))
+        {
+            return false;
+        }
+
+        let mut node_index = index;
+        let root =
+            proof
+                .merkle_path
+                .iter()
+                .fold(Self::leaf_hash::<T>(&proof.chunk), |node, sibling| {
+                    let parent = if node_index % 2 == 0 {
+                        Self::node_hash::<T>(&node, sibling)
+                    } else {
+                        Self::node_hash::<T>(sibling, &node)
+                    };
+                    node_index /= 2;
+
+                    parent
+                });
+
+        root == chunks_root
+    }
+}
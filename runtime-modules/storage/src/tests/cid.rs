@@ -0,0 +1,102 @@
+#![cfg(test)]
+
+use crate::cid::{Cid, CidError, Multihash, BLAKE2B_256, DAG_PB, SHA2_256};
+
+const CID_V0: &[u8] = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const CID_V1_BASE32: &[u8] = b"bafybeie5nqv6kd3qnfjupgvz34woh3oksc3iau6abmyajn7qvtf6d2ho34";
+const CID_V1_BASE58: &[u8] = b"zdj7Wg2Qkk4mYgAkVU1kppfQ2sMGz5zPwERVpeWmxCQLDxVoC";
+const CID_V1_BLAKE2B: &[u8] = b"bafk2bzaceaze3tycpxkkgcutfrcb6ns2exugwfz556slrzmjjasti4nydnzm6";
+
+fn sha2_256_digest() -> Vec<u8> {
+    vec![
+        0x9d, 0x6c, 0x2b, 0xe5, 0x0f, 0x70, 0x69, 0x53, 0x47, 0x9a, 0xb9, 0xdf, 0x2c, 0xe3, 0xed,
+        0xca, 0x90, 0xb6, 0x80, 0x53, 0xc0, 0x0b, 0x30, 0x04, 0xb7, 0xf0, 0xac, 0xcb, 0xe1, 0xe8,
+        0xee, 0xdf,
+    ]
+}
+
+#[test]
+fn parse_cid_v0_succeeds() {
+    assert_eq!(
+        Cid::parse(CID_V0),
+        Ok(Cid {
+            version: 0,
+            codec: DAG_PB,
+            hash: Multihash {
+                code: SHA2_256,
+                digest: sha2_256_digest(),
+            },
+        })
+    );
+}
+
+#[test]
+fn parse_cid_v1_succeeds() {
+    let expected_cid = Cid {
+        version: 1,
+        codec: DAG_PB,
+        hash: Multihash {
+            code: SHA2_256,
+            digest: sha2_256_digest(),
+        },
+    };
+
+    assert_eq!(Cid::parse(CID_V1_BASE32), Ok(expected_cid.clone()));
+    assert_eq!(Cid::parse(CID_V1_BASE58), Ok(expected_cid));
+
+    let blake2b_cid = Cid::parse(CID_V1_BLAKE2B).unwrap();
+    assert_eq!(blake2b_cid.hash.code, BLAKE2B_256);
+    assert_eq!(blake2b_cid.hash.digest.len(), 32);
+}
+
+#[test]
+fn parse_cid_fails_with_malformed_input() {
+    assert_eq!(Cid::parse(b""), Err(CidError::InvalidLength));
+    assert_eq!(Cid::parse(&[b'b'; 129]), Err(CidError::InvalidLength));
+    assert_eq!(Cid::parse(&[1, 2, 3, 4]), Err(CidError::InvalidEncoding));
+
+    // Invalid base58 character ('0').
+    assert_eq!(
+        Cid::parse(b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd0"),
+        Err(CidError::InvalidEncoding)
+    );
+
+    // Truncated digest.
+    assert_eq!(
+        Cid::parse(b"bafybeie5nqv6kd3qnfjupgvz34woh3oksc3iau6abmyajn7qvtf6d2ho"),
+        Err(CidError::InvalidMultihash)
+    );
+
+    // Non-zero trailing bits.
+    assert_eq!(
+        Cid::parse(&CID_V1_BASE32[..CID_V1_BASE32.len() - 8]),
+        Err(CidError::InvalidEncoding)
+    );
+
+    // CIDv1 encoding of the CIDv0 is not a valid CIDv1.
+    let mut v0_as_v1 = b"z".to_vec();
+    v0_as_v1.extend_from_slice(&CID_V0[..]);
+    assert_eq!(Cid::parse(&v0_as_v1), Err(CidError::UnsupportedVersion));
+}
+
+#[test]
+fn multihash_fails_with_unknown_code_or_invalid_length() {
+    let mut bytes = vec![0x12, 0x20];
+    bytes.extend(sha2_256_digest());
+    assert!(Multihash::from_bytes(&bytes).is_ok());
+
+    // Unknown hash function.
+    bytes[0] = 0x11;
+    assert_eq!(
+        Multihash::from_bytes(&bytes),
+        Err(CidError::InvalidMultihash)
+    );
+
+    // Wrong digest length for the SHA2-256.
+    bytes[0] = 0x12;
+    bytes[1] = 0x1f;
+    assert_eq!(
+        Multihash::from_bytes(&bytes),
+        Err(CidError::InvalidMultihash)
+    );
+}
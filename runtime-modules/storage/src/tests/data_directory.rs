@@ -1,8 +1,11 @@
 #![cfg(test)]
 
 use super::mock::*;
+use crate::cid::{self, Multihash};
 use crate::data_directory::Error;
 use rstd::collections::btree_map::BTreeMap;
+use srml_support::traits::Get;
+use srml_support::{StorageMap, StorageValue};
 use system::RawOrigin;
 
 #[test]
@@ -17,7 +20,8 @@ fn succeed_adding_content() {
             1,
             1234,
             0,
-            vec![1, 3, 3, 7],
+            TEST_IPFS_CONTENT_ID.to_vec(),
+            TEST_CHUNKS_ROOT,
         );
         assert!(res.is_ok());
     });
@@ -34,7 +38,8 @@ fn add_content_fails_with_invalid_origin() {
             1,
             1234,
             0,
-            vec![1, 3, 3, 7],
+            TEST_IPFS_CONTENT_ID.to_vec(),
+            TEST_CHUNKS_ROOT,
         );
         assert_eq!(res, Err(Error::Other("RequireSignedOrigin")));
     });
@@ -52,7 +57,8 @@ fn accept_and_reject_content_fail_with_invalid_storage_provider() {
             1,
             1234,
             0,
-            vec![1, 2, 3, 4],
+            TEST_IPFS_CONTENT_ID.to_vec(),
+            TEST_CHUNKS_ROOT,
         );
         assert!(res.is_ok());
 
@@ -95,7 +101,8 @@ fn accept_content_as_liaison() {
             1,
             1234,
             0,
-            vec![1, 2, 3, 4],
+            TEST_IPFS_CONTENT_ID.to_vec(),
+            TEST_CHUNKS_ROOT,
         );
         assert!(res.is_ok());
 
@@ -139,7 +146,8 @@ fn reject_content_as_liaison() {
             1,
             1234,
             0,
-            vec![1, 2, 3, 4],
+            TEST_IPFS_CONTENT_ID.to_vec(),
+            TEST_CHUNKS_ROOT,
         );
         assert!(res.is_ok());
 
@@ -191,6 +199,8 @@ fn data_object_injection_works() {
             liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
             liaison_judgement: data_directory::LiaisonJudgement::Pending,
             ipfs_content_id: vec![],
+            content_hash: Multihash::default(),
+            chunks_root: None,
         };
 
         let content_id_1 = 1;
@@ -235,7 +245,8 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
             content_id_1,
             1,
             10,
-            vec![8, 8, 8, 8],
+            TEST_IPFS_CONTENT_ID.to_vec(),
+            TEST_CHUNKS_ROOT,
         );
         assert!(res.is_ok());
         let res = TestDataDirectory::add_content(
@@ -244,7 +255,8 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
             content_id_2,
             2,
             20,
-            vec![9, 9, 9, 9],
+            TEST_IPFS_CONTENT_ID.to_vec(),
+            TEST_CHUNKS_ROOT,
         );
         assert!(res.is_ok());
 
@@ -261,6 +273,8 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
             liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
             liaison_judgement: data_directory::LiaisonJudgement::Pending,
             ipfs_content_id: vec![5, 6, 7],
+            content_hash: Multihash::default(),
+            chunks_root: None,
         };
 
         let object2 = data_directory::DataObjectInternal {
@@ -274,6 +288,8 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
             liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
             liaison_judgement: data_directory::LiaisonJudgement::Pending,
             ipfs_content_id: vec![5, 6, 7],
+            content_hash: Multihash::default(),
+            chunks_root: None,
         };
 
         objects.insert(content_id_1, object1.clone());
@@ -298,3 +314,531 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
         );
     });
 }
+
+#[test]
+fn remove_content_succeeds_and_removes_storage_relationships() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let member_id = 1u64;
+        let content_id = TEST_MOCK_EXISTING_CID;
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            content_id,
+            1,
+            1234,
+            TEST_IPFS_CONTENT_ID.to_vec(),
+            TEST_CHUNKS_ROOT,
+        );
+        assert!(res.is_ok());
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        let res = TestDataDirectory::accept_content(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            content_id,
+        );
+        assert!(res.is_ok());
+
+        let res = TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            content_id,
+        );
+        assert!(res.is_ok());
+        let relationship_id = TEST_FIRST_RELATIONSHIP_ID;
+
+        let res = TestDataDirectory::remove_content(Origin::signed(sender), member_id, content_id);
+        assert_eq!(res, Ok(()));
+
+        assert_eq!(
+            TestDataDirectory::data_object_by_content_id(content_id),
+            None
+        );
+        assert_eq!(TestDataDirectory::known_content_ids(), vec![]);
+        assert_eq!(
+            TestDataObjectStorageRegistry::relationships(relationship_id),
+            None
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::relationships_by_content_id(content_id),
+            vec![]
+        );
+
+        let events: Vec<MetaEvent> = System::events()
+            .into_iter()
+            .map(|record| record.event)
+            .collect();
+        assert!(events.contains(&MetaEvent::data_object_storage_registry(
+            data_object_storage_registry::RawEvent::DataObjectStorageRelationshipRemoved(
+                relationship_id,
+                content_id,
+                storage_provider_id,
+            )
+        )));
+        assert_eq!(
+            events.last(),
+            Some(&MetaEvent::data_directory(
+                data_directory::RawEvent::ContentRemoved(
+                    content_id,
+                    member_id,
+                    TEST_IPFS_CONTENT_ID.to_vec()
+                )
+            ))
+        );
+    });
+}
+
+#[test]
+fn remove_content_fails_with_invalid_owner_or_missing_content() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let member_id = 1u64;
+        let content_id = 1;
+
+        let res = TestDataDirectory::remove_content(Origin::signed(sender), member_id, content_id);
+        assert_eq!(res, Err(Error::CidNotFound));
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            content_id,
+            1,
+            1234,
+            TEST_IPFS_CONTENT_ID.to_vec(),
+            TEST_CHUNKS_ROOT,
+        );
+        assert!(res.is_ok());
+
+        let another_member_id = 2u64;
+        let res = TestDataDirectory::remove_content(
+            Origin::signed(another_member_id),
+            another_member_id,
+            content_id,
+        );
+        assert_eq!(res, Err(Error::OwnerRequired));
+        assert!(TestDataDirectory::data_object_by_content_id(content_id).is_some());
+    });
+}
+
+#[test]
+fn rejected_content_is_removed_after_retention_period() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let member_id = 1u64;
+        let rejected_content_id = 1;
+        let accepted_content_id = 2;
+
+        System::set_block_number(1);
+
+        for content_id in vec![rejected_content_id, accepted_content_id] {
+            let res = TestDataDirectory::add_content(
+                Origin::signed(sender),
+                member_id,
+                content_id,
+                1,
+                1234,
+                TEST_IPFS_CONTENT_ID.to_vec(),
+                TEST_CHUNKS_ROOT,
+            );
+            assert!(res.is_ok());
+        }
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        for content_id in vec![rejected_content_id, accepted_content_id] {
+            let res = TestDataDirectory::reject_content(
+                Origin::signed(storage_provider_account_id),
+                storage_provider_id,
+                content_id,
+            );
+            assert!(res.is_ok());
+        }
+
+        // The liaison changes its mind about the second content.
+        let res = TestDataDirectory::accept_content(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            accepted_content_id,
+        );
+        assert!(res.is_ok());
+
+        let removal_block = 1 + RejectedContentRetentionPeriod::get();
+
+        TestDataDirectory::on_finalize(removal_block - 1);
+        assert!(TestDataDirectory::data_object_by_content_id(rejected_content_id).is_some());
+
+        TestDataDirectory::on_finalize(removal_block);
+        assert_eq!(
+            TestDataDirectory::data_object_by_content_id(rejected_content_id),
+            None
+        );
+        assert!(TestDataDirectory::data_object_by_content_id(accepted_content_id).is_some());
+        assert_eq!(
+            TestDataDirectory::rejected_content_removal_schedule(removal_block),
+            vec![]
+        );
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_directory(data_directory::RawEvent::RejectedContentRemoved(
+                rejected_content_id,
+                TEST_IPFS_CONTENT_ID.to_vec()
+            ))
+        );
+    });
+}
+
+#[test]
+fn rejected_again_content_is_removed_after_latest_retention_period() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let member_id = 1u64;
+        let content_id = 1;
+
+        System::set_block_number(1);
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            content_id,
+            1,
+            1234,
+            TEST_IPFS_CONTENT_ID.to_vec(),
+            TEST_CHUNKS_ROOT,
+        );
+        assert!(res.is_ok());
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        let res = TestDataDirectory::reject_content(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            content_id,
+        );
+        assert!(res.is_ok());
+
+        let res = TestDataDirectory::accept_content(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            content_id,
+        );
+        assert!(res.is_ok());
+        assert_eq!(
+            TestDataDirectory::rejected_content_removal_block(content_id),
+            None
+        );
+
+        System::set_block_number(2);
+
+        let res = TestDataDirectory::reject_content(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            content_id,
+        );
+        assert!(res.is_ok());
+
+        let first_removal_block = 1 + RejectedContentRetentionPeriod::get();
+        let latest_removal_block = 2 + RejectedContentRetentionPeriod::get();
+
+        TestDataDirectory::on_finalize(first_removal_block);
+        assert!(TestDataDirectory::data_object_by_content_id(content_id).is_some());
+
+        TestDataDirectory::on_finalize(latest_removal_block);
+        assert_eq!(
+            TestDataDirectory::data_object_by_content_id(content_id),
+            None
+        );
+        assert_eq!(
+            TestDataDirectory::rejected_content_removal_block(content_id),
+            None
+        );
+    });
+}
+
+// Hires the storage provider and sets it as the storage working group leader.
+fn set_storage_lead() -> u64 {
+    let (lead_account_id, lead_worker_id) = hire_storage_provider();
+
+    <working_group::CurrentLead<Test, StorageWorkingGroupInstance>>::put(lead_worker_id);
+
+    lead_account_id
+}
+
+#[test]
+fn add_content_fails_when_member_quota_exceeded() {
+    ExtBuilder::default()
+        .default_quota(data_directory::Quota {
+            size_limit: 2000,
+            objects_limit: 2,
+        })
+        .build()
+        .execute_with(|| {
+            let sender = 1u64;
+            let member_id = 1u64;
+
+            let res = TestDataDirectory::add_content(
+                Origin::signed(sender),
+                member_id,
+                1,
+                1,
+                1234,
+                TEST_IPFS_CONTENT_ID.to_vec(),
+                TEST_CHUNKS_ROOT,
+            );
+            assert!(res.is_ok());
+
+            // Size limit exceeded.
+            let res = TestDataDirectory::add_content(
+                Origin::signed(sender),
+                member_id,
+                2,
+                1,
+                1000,
+                TEST_IPFS_CONTENT_ID.to_vec(),
+                TEST_CHUNKS_ROOT,
+            );
+            assert_eq!(res, Err(Error::QuotaExceeded));
+
+            let res = TestDataDirectory::add_content(
+                Origin::signed(sender),
+                member_id,
+                2,
+                1,
+                700,
+                TEST_IPFS_CONTENT_ID.to_vec(),
+                TEST_CHUNKS_ROOT,
+            );
+            assert!(res.is_ok());
+
+            assert_eq!(
+                TestDataDirectory::member_usage(member_id),
+                data_directory::QuotaUsage {
+                    size: 1934,
+                    objects: 2,
+                }
+            );
+
+            // Objects limit exceeded.
+            let res = TestDataDirectory::add_content(
+                Origin::signed(sender),
+                member_id,
+                3,
+                1,
+                1,
+                TEST_IPFS_CONTENT_ID.to_vec(),
+                TEST_CHUNKS_ROOT,
+            );
+            assert_eq!(res, Err(Error::QuotaExceeded));
+
+            // Removal releases the quota.
+            let res = TestDataDirectory::remove_content(Origin::signed(sender), member_id, 1);
+            assert!(res.is_ok());
+
+            assert_eq!(
+                TestDataDirectory::member_usage(member_id),
+                data_directory::QuotaUsage {
+                    size: 700,
+                    objects: 1,
+                }
+            );
+            assert_eq!(
+                TestDataDirectory::member_usage_by_data_object_type(member_id, 1),
+                data_directory::QuotaUsage {
+                    size: 700,
+                    objects: 1,
+                }
+            );
+
+            let res = TestDataDirectory::add_content(
+                Origin::signed(sender),
+                member_id,
+                3,
+                1,
+                1,
+                TEST_IPFS_CONTENT_ID.to_vec(),
+                TEST_CHUNKS_ROOT,
+            );
+            assert!(res.is_ok());
+        });
+}
+
+#[test]
+fn set_member_quota_succeeds() {
+    with_default_mock_builder(|| {
+        let lead_account_id = set_storage_lead();
+        let member_id = 1u64;
+        let quota = data_directory::Quota {
+            size_limit: 10,
+            objects_limit: 1,
+        };
+
+        let res = TestDataDirectory::set_member_quota(Origin::signed(55), member_id, Some(quota));
+        assert_eq!(res, Err(working_group::Error::IsNotLeadAccount.into()));
+
+        let res = TestDataDirectory::set_member_quota(
+            Origin::signed(lead_account_id),
+            member_id,
+            Some(quota),
+        );
+        assert_eq!(res, Ok(()));
+        assert_eq!(TestDataDirectory::member_quota(member_id), quota);
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(member_id),
+            member_id,
+            1,
+            1,
+            11,
+            TEST_IPFS_CONTENT_ID.to_vec(),
+            TEST_CHUNKS_ROOT,
+        );
+        assert_eq!(res, Err(Error::QuotaExceeded));
+
+        // Restore the default quota.
+        let res =
+            TestDataDirectory::set_member_quota(Origin::signed(lead_account_id), member_id, None);
+        assert_eq!(res, Ok(()));
+        assert_eq!(
+            TestDataDirectory::member_quota(member_id),
+            TestDataDirectory::default_quota()
+        );
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_directory(data_directory::RawEvent::MemberQuotaUpdated(member_id))
+        );
+    });
+}
+
+#[test]
+fn add_content_fails_when_data_object_type_quota_exceeded() {
+    with_default_mock_builder(|| {
+        let lead_account_id = set_storage_lead();
+        let member_id = 1u64;
+        let video_type_id = 1u64;
+        let image_type_id = 2u64;
+
+        let res = TestDataDirectory::set_data_object_type_quota(
+            Origin::signed(lead_account_id),
+            image_type_id,
+            Some(data_directory::Quota {
+                size_limit: 100,
+                objects_limit: 5,
+            }),
+        );
+        assert_eq!(res, Ok(()));
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(member_id),
+            member_id,
+            1,
+            image_type_id,
+            101,
+            TEST_IPFS_CONTENT_ID.to_vec(),
+            TEST_CHUNKS_ROOT,
+        );
+        assert_eq!(res, Err(Error::DataObjectTypeQuotaExceeded));
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(member_id),
+            member_id,
+            1,
+            video_type_id,
+            101,
+            TEST_IPFS_CONTENT_ID.to_vec(),
+            TEST_CHUNKS_ROOT,
+        );
+        assert!(res.is_ok());
+    });
+}
+
+#[test]
+fn add_content_assigns_storage_providers_by_replication_factor() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let member_id = 1u64;
+        let content_id = 1;
+        let type_id = 1;
+
+        <data_object_type_registry::DataObjectTypes<Test>>::insert(
+            type_id,
+            TestDataObjectType {
+                description: b"video".to_vec(),
+                active: true,
+                replication_factor: 3,
+            },
+        );
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            content_id,
+            type_id,
+            1234,
+            TEST_IPFS_CONTENT_ID.to_vec(),
+            TEST_CHUNKS_ROOT,
+        );
+        assert!(res.is_ok());
+
+        assert_eq!(
+            TestDataDirectory::assigned_storage_providers(content_id),
+            vec![1, 2, 3]
+        );
+        assert_eq!(
+            TestDataDirectory::data_object_by_content_id(content_id)
+                .unwrap()
+                .liaison,
+            1
+        );
+
+        let res = TestDataDirectory::remove_content(Origin::signed(sender), member_id, content_id);
+        assert!(res.is_ok());
+
+        assert_eq!(
+            TestDataDirectory::assigned_storage_providers(content_id),
+            vec![]
+        );
+    });
+}
+
+#[test]
+fn add_content_validates_ipfs_content_id() {
+    with_default_mock_builder(|| {
+        let sender = 1u64;
+        let member_id = 1u64;
+        let content_id = 1;
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            content_id,
+            1,
+            1234,
+            b"QmGarbage".to_vec(),
+            TEST_CHUNKS_ROOT,
+        );
+        assert_eq!(res, Err(Error::InvalidIpfsContentId));
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            content_id,
+            1,
+            1234,
+            TEST_IPFS_CONTENT_ID.to_vec(),
+            TEST_CHUNKS_ROOT,
+        );
+        assert!(res.is_ok());
+
+        let data = TestDataDirectory::data_object_by_content_id(content_id).unwrap();
+        assert_eq!(data.content_hash.code, cid::SHA2_256);
+        assert_eq!(
+            data.content_hash,
+            cid::Cid::parse(TEST_IPFS_CONTENT_ID).unwrap().hash
+        );
+        assert_eq!(data.chunks_root, Some(TEST_CHUNKS_ROOT));
+    });
+}
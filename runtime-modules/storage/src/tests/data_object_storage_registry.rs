@@ -1,6 +1,7 @@
 #![cfg(test)]
 
 use super::mock::*;
+use srml_support::{StorageLinkedMap, StorageValue};
 
 #[test]
 fn initial_state() {
@@ -84,6 +85,34 @@ fn test_add_relationship() {
     });
 }
 
+#[test]
+fn test_fail_adding_duplicate_relationship() {
+    with_default_mock_builder(|| {
+        let (account_id, storage_provider_id) = hire_storage_provider();
+        let res = TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(account_id),
+            storage_provider_id,
+            TEST_MOCK_EXISTING_CID,
+        );
+        assert_eq!(res, Ok(()));
+
+        let res = TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(account_id),
+            storage_provider_id,
+            TEST_MOCK_EXISTING_CID,
+        );
+        assert_eq!(
+            res,
+            Err(data_object_storage_registry::Error::RelationshipAlreadyExists)
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::relationships_by_content_id(TEST_MOCK_EXISTING_CID)
+                .len(),
+            1
+        );
+    });
+}
+
 #[test]
 fn test_fail_adding_relationship_with_bad_content() {
     with_default_mock_builder(|| {
@@ -97,6 +126,33 @@ fn test_fail_adding_relationship_with_bad_content() {
     });
 }
 
+#[test]
+fn test_fail_adding_relationship_with_not_assigned_storage_provider() {
+    with_default_mock_builder(|| {
+        let (account_id, storage_provider_id) = (2, 2);
+        let storage_provider = working_group::Worker {
+            member_id: 2,
+            role_account_id: account_id,
+            reward_relationship: None,
+            role_stake_profile: None,
+        };
+        <working_group::WorkerById<Test, StorageWorkingGroupInstance>>::insert(
+            storage_provider_id,
+            storage_provider,
+        );
+
+        let res = TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(account_id),
+            storage_provider_id,
+            TEST_MOCK_EXISTING_CID,
+        );
+        assert_eq!(
+            res,
+            Err(data_object_storage_registry::Error::StorageProviderNotAssigned)
+        );
+    });
+}
+
 #[test]
 fn test_toggle_ready() {
     with_default_mock_builder(|| {
@@ -155,3 +211,60 @@ fn test_toggle_ready() {
         );
     });
 }
+
+#[test]
+fn under_replicated_content_ids_tracks_ready_relationships() {
+    with_default_mock_builder(|| {
+        let (account_id, storage_provider_id) = hire_storage_provider();
+
+        <data_directory::KnownContentIds<Test>>::put(vec![TEST_MOCK_EXISTING_CID]);
+
+        assert_eq!(
+            TestDataObjectStorageRegistry::under_replicated_content_ids(),
+            vec![TEST_MOCK_EXISTING_CID]
+        );
+
+        let res = TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(account_id),
+            storage_provider_id,
+            TEST_MOCK_EXISTING_CID,
+        );
+        assert!(res.is_ok());
+        let relationship_id = TEST_FIRST_RELATIONSHIP_ID;
+
+        // Setting the relationship ready twice counts only once.
+        for _ in 0..2 {
+            let res = TestDataObjectStorageRegistry::set_relationship_ready(
+                Origin::signed(account_id),
+                storage_provider_id,
+                relationship_id,
+            );
+            assert!(res.is_ok());
+        }
+
+        assert_eq!(
+            TestDataObjectStorageRegistry::ready_relationships_count(TEST_MOCK_EXISTING_CID),
+            1
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::under_replicated_content_ids(),
+            vec![]
+        );
+
+        let res = TestDataObjectStorageRegistry::unset_relationship_ready(
+            Origin::signed(account_id),
+            storage_provider_id,
+            relationship_id,
+        );
+        assert!(res.is_ok());
+
+        assert_eq!(
+            TestDataObjectStorageRegistry::ready_relationships_count(TEST_MOCK_EXISTING_CID),
+            0
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::under_replicated_content_ids(),
+            vec![TEST_MOCK_EXISTING_CID]
+        );
+    });
+}
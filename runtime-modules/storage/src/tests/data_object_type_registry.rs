@@ -1,6 +1,7 @@
 #![cfg(test)]
 
 use super::mock::*;
+use crate::data_object_type_registry::DataObjectTypeReplication;
 use srml_support::{StorageLinkedMap, StorageValue};
 use system::{self, EventRecord, Phase, RawOrigin};
 
@@ -61,6 +62,7 @@ fn succeed_register() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
         };
         let res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -79,6 +81,7 @@ fn activate_data_object_type_fails_with_invalid_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -106,6 +109,7 @@ fn deactivate_data_object_type_fails_with_invalid_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: true,
+            replication_factor: 1,
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -133,6 +137,7 @@ fn update_data_object_type_fails_with_invalid_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -144,6 +149,7 @@ fn update_data_object_type_fails_with_invalid_lead() {
         let updated1: TestDataObjectType = TestDataObjectType {
             description: "bar".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
         };
 
         let invalid_leader_account_id = 2;
@@ -165,6 +171,7 @@ fn update_existing() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -179,6 +186,7 @@ fn update_existing() {
         let updated1: TestDataObjectType = TestDataObjectType {
             description: "bar".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
         };
         let res = TestDataObjectTypeRegistry::update_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -191,6 +199,7 @@ fn update_existing() {
         let updated3: TestDataObjectType = TestDataObjectType {
             description: "bar".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
         };
         let res = TestDataObjectTypeRegistry::update_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -218,6 +227,7 @@ fn register_data_object_type_failed_with_no_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -236,6 +246,7 @@ fn activate_existing() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -295,3 +306,59 @@ fn activate_existing() {
         assert!(!data.unwrap().active);
     });
 }
+
+#[test]
+fn register_data_object_type_fails_with_invalid_replication_factor() {
+    with_default_mock_builder(|| {
+        SetLeadFixture::set_default_lead();
+
+        let data: TestDataObjectType = TestDataObjectType {
+            description: "foo".as_bytes().to_vec(),
+            active: true,
+            replication_factor: 0,
+        };
+        let res = TestDataObjectTypeRegistry::register_data_object_type(
+            RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
+            data,
+        );
+        assert_eq!(
+            res,
+            Err(data_object_type_registry::Error::InvalidReplicationFactor)
+        );
+    });
+}
+
+#[test]
+fn replication_factor_provided_by_data_object_type() {
+    with_default_mock_builder(|| {
+        SetLeadFixture::set_default_lead();
+
+        let data: TestDataObjectType = TestDataObjectType {
+            description: "foo".as_bytes().to_vec(),
+            active: true,
+            replication_factor: 3,
+        };
+        let res = TestDataObjectTypeRegistry::register_data_object_type(
+            RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
+            data,
+        );
+        assert!(res.is_ok());
+
+        let dot_id = get_last_data_object_type_id();
+
+        assert_eq!(
+            <TestDataObjectTypeRegistry as DataObjectTypeReplication<Test>>::replication_factor(
+                &dot_id
+            ),
+            3
+        );
+
+        // Missing data object types fall back to the single storage provider.
+        assert_eq!(
+            <TestDataObjectTypeRegistry as DataObjectTypeReplication<Test>>::replication_factor(
+                &(dot_id + 1)
+            ),
+            1
+        );
+    });
+}
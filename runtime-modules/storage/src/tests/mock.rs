@@ -1,6 +1,8 @@
 #![cfg(test)]
 
-pub use crate::{data_directory, data_object_storage_registry, data_object_type_registry};
+pub use crate::{
+    data_directory, data_object_storage_registry, data_object_type_registry, storage_challenge,
+};
 pub use common::currency::GovernanceCurrency;
 use membership::members;
 pub use system;
@@ -8,7 +10,7 @@ pub use system;
 pub use primitives::{Blake2Hasher, H256};
 pub use sr_primitives::{
     testing::{Digest, DigestItem, Header, UintAuthorityId},
-    traits::{BlakeTwo256, Convert, IdentityLookup, OnFinalize},
+    traits::{BlakeTwo256, Convert, Hash, IdentityLookup, OnFinalize},
     weights::Weight,
     BuildStorage, Perbill,
 };
@@ -16,6 +18,7 @@ pub use sr_primitives::{
 use crate::data_directory::ContentIdExists;
 use crate::data_object_type_registry::IsActiveDataObjectType;
 pub use crate::StorageWorkingGroupInstance;
+use srml_support::traits::Randomness;
 use srml_support::{impl_outer_event, impl_outer_origin, parameter_types, StorageLinkedMap};
 
 mod working_group_mod {
@@ -32,6 +35,7 @@ impl_outer_event! {
         data_object_type_registry<T>,
         data_directory<T>,
         data_object_storage_registry<T>,
+        storage_challenge<T>,
         balances<T>,
         members<T>,
         working_group_mod StorageWorkingGroupInstance <T>,
@@ -45,6 +49,17 @@ pub const TEST_FIRST_METADATA_ID: u64 = 4000;
 
 pub const TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID: u32 = 1;
 pub const TEST_MOCK_EXISTING_CID: u64 = 42;
+pub const TEST_MOCK_VALID_STORAGE_PROOF: &[u8] = b"valid proof";
+pub const TEST_IPFS_CONTENT_ID: &[u8] = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+pub const TEST_CHUNKS_ROOT: H256 = H256([1; 32]);
+pub const TEST_MOCK_CONTENT_SIZE: u64 = 1234;
+
+// Content bytes of the TEST_MOCK_EXISTING_CID data object.
+pub fn mock_content() -> Vec<u8> {
+    (0..TEST_MOCK_CONTENT_SIZE)
+        .map(|index| index as u8)
+        .collect()
+}
 
 pub struct AnyDataObjectTypeIsActive {}
 impl<T: data_object_type_registry::Trait> IsActiveDataObjectType<T> for AnyDataObjectTypeIsActive {
@@ -65,7 +80,7 @@ impl ContentIdExists<Test> for MockContent {
         match *which {
             TEST_MOCK_EXISTING_CID => Ok(data_directory::DataObjectInternal {
                 type_id: 1,
-                size: 1234,
+                size: TEST_MOCK_CONTENT_SIZE,
                 added_at: data_directory::BlockAndTime {
                     block: 10,
                     time: 1024,
@@ -74,10 +89,41 @@ impl ContentIdExists<Test> for MockContent {
                 liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
                 liaison_judgement: data_directory::LiaisonJudgement::Pending,
                 ipfs_content_id: vec![],
+                content_hash: Default::default(),
+                chunks_root: Some(
+                    storage_challenge::MerkleStorageProofVerifier::chunks_root::<Test>(
+                        &mock_content(),
+                    ),
+                ),
             }),
             _ => Err("nope, missing"),
         }
     }
+
+    fn is_assigned_storage_provider(
+        which: &<Test as data_directory::Trait>::ContentId,
+        storage_provider_id: &crate::StorageProviderId<Test>,
+    ) -> bool {
+        *which == TEST_MOCK_EXISTING_CID
+            && *storage_provider_id == TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID
+    }
+}
+
+pub struct MockRandomness {}
+impl Randomness<H256> for MockRandomness {
+    fn random(subject: &[u8]) -> H256 {
+        BlakeTwo256::hash(subject)
+    }
+}
+
+pub struct MockStorageProofVerifier {}
+impl storage_challenge::StorageProofVerifier<Test> for MockStorageProofVerifier {
+    fn verify_storage_proof(
+        _challenge: &storage_challenge::StorageChallenge<Test>,
+        proof: &[u8],
+    ) -> bool {
+        proof == TEST_MOCK_VALID_STORAGE_PROOF
+    }
 }
 
 // Workaround for https://github.com/rust-lang/rust/issues/26925 . Remove when sorted.
@@ -90,6 +136,10 @@ parameter_types! {
     pub const AvailableBlockRatio: Perbill = Perbill::one();
     pub const MinimumPeriod: u64 = 5;
     pub const MaxObjectsPerInjection: u32 = 5;
+    pub const RejectedContentRetentionPeriod: u64 = 10;
+    pub const ChallengePeriod: u64 = 10;
+    pub const ChallengeResponsePeriod: u64 = 3;
+    pub const ChallengesPerPeriod: u32 = 2;
 }
 
 impl system::Trait for Test {
@@ -154,6 +204,7 @@ parameter_types! {
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = MetaEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type OnWorkerExit = ();
 }
 
 impl data_object_type_registry::Trait for Test {
@@ -166,14 +217,21 @@ impl data_directory::Trait for Test {
     type ContentId = u64;
     type StorageProviderHelper = ();
     type IsActiveDataObjectType = AnyDataObjectTypeIsActive;
+    type DataObjectTypeReplication = TestDataObjectTypeRegistry;
     type MemberOriginValidator = ();
     type MaxObjectsPerInjection = MaxObjectsPerInjection;
+    type RejectedContentRetentionPeriod = RejectedContentRetentionPeriod;
+    type ContentRemovalHandler = TestDataObjectStorageRegistry;
 }
 
 impl crate::data_directory::StorageProviderHelper<Test> for () {
     fn get_random_storage_provider() -> Result<u32, &'static str> {
         Ok(1)
     }
+
+    fn get_random_storage_providers(count: u32) -> Result<Vec<u32>, &'static str> {
+        Ok((1..=count).collect())
+    }
 }
 
 impl common::origin::ActorOriginValidator<Origin, u64, u64> for () {
@@ -190,6 +248,16 @@ impl data_object_storage_registry::Trait for Test {
     type ContentIdExists = MockContent;
 }
 
+impl storage_challenge::Trait for Test {
+    type Event = MetaEvent;
+    type ChallengeId = u64;
+    type Randomness = MockRandomness;
+    type StorageProofVerifier = MockStorageProofVerifier;
+    type ChallengePeriod = ChallengePeriod;
+    type ChallengeResponsePeriod = ChallengeResponsePeriod;
+    type ChallengesPerPeriod = ChallengesPerPeriod;
+}
+
 impl members::Trait for Test {
     type Event = MetaEvent;
     type MemberId = u64;
@@ -230,6 +298,7 @@ pub struct ExtBuilder {
     first_content_id: u64,
     first_relationship_id: u64,
     first_metadata_id: u64,
+    default_quota: data_directory::Quota,
 }
 
 impl Default for ExtBuilder {
@@ -239,6 +308,10 @@ impl Default for ExtBuilder {
             first_content_id: 2,
             first_relationship_id: 3,
             first_metadata_id: 4,
+            default_quota: data_directory::Quota {
+                size_limit: 100_000,
+                objects_limit: 10,
+            },
         }
     }
 }
@@ -260,6 +333,10 @@ impl ExtBuilder {
         self.first_metadata_id = first_metadata_id;
         self
     }
+    pub fn default_quota(mut self, default_quota: data_directory::Quota) -> Self {
+        self.default_quota = default_quota;
+        self
+    }
     pub fn build(self) -> runtime_io::TestExternalities {
         let mut t = system::GenesisConfig::default()
             .build_storage::<Test>()
@@ -271,6 +348,12 @@ impl ExtBuilder {
         .assimilate_storage(&mut t)
         .unwrap();
 
+        data_directory::GenesisConfig {
+            default_quota: self.default_quota,
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
         data_object_storage_registry::GenesisConfig::<Test> {
             first_relationship_id: self.first_relationship_id,
         }
@@ -294,6 +377,7 @@ pub type TestDataObjectTypeRegistry = data_object_type_registry::Module<Test>;
 pub type TestDataObjectType = data_object_type_registry::DataObjectType;
 pub type TestDataDirectory = data_directory::Module<Test>;
 pub type TestDataObjectStorageRegistry = data_object_storage_registry::Module<Test>;
+pub type TestStorageChallenge = storage_challenge::Module<Test>;
 
 pub fn with_default_mock_builder<R, F: FnOnce() -> R>(f: F) -> R {
     ExtBuilder::default()
@@ -1,6 +1,8 @@
 #![cfg(test)]
 
+mod cid;
 mod data_directory;
 mod data_object_storage_registry;
 mod data_object_type_registry;
 mod mock;
+mod storage_challenge;
@@ -0,0 +1,309 @@
+#![cfg(test)]
+
+use super::mock::*;
+use crate::data_directory::ContentRemovalHandler;
+use crate::storage_challenge::{
+    ChallengeStats, ChallengeStatus, Error, MerkleStorageProofVerifier, StorageProof,
+    StorageProofVerifier,
+};
+use codec::Encode;
+use srml_support::traits::Get;
+use srml_support::StorageLinkedMap;
+
+// Adds the ready storage relationship for the mock content.
+fn add_ready_relationship() -> (u64, u32) {
+    let (account_id, storage_provider_id) = hire_storage_provider();
+
+    let res = TestDataObjectStorageRegistry::add_relationship(
+        Origin::signed(account_id),
+        storage_provider_id,
+        TEST_MOCK_EXISTING_CID,
+    );
+    assert!(res.is_ok());
+
+    let res = TestDataObjectStorageRegistry::set_relationship_ready(
+        Origin::signed(account_id),
+        storage_provider_id,
+        TEST_FIRST_RELATIONSHIP_ID,
+    );
+    assert!(res.is_ok());
+
+    (account_id, storage_provider_id)
+}
+
+#[test]
+fn challenges_are_created_for_ready_relationships() {
+    with_default_mock_builder(|| {
+        // No relationships - no challenges.
+        TestStorageChallenge::on_finalize(ChallengePeriod::get());
+        assert_eq!(TestStorageChallenge::next_challenge_id(), 0);
+
+        let (_, storage_provider_id) = add_ready_relationship();
+
+        // Not a challenge round.
+        TestStorageChallenge::on_finalize(ChallengePeriod::get() + 1);
+        assert_eq!(TestStorageChallenge::next_challenge_id(), 0);
+
+        let now = 2 * ChallengePeriod::get();
+        TestStorageChallenge::on_finalize(now);
+
+        // The only relationship is challenged once per round.
+        assert_eq!(TestStorageChallenge::next_challenge_id(), 1);
+
+        let challenge = TestStorageChallenge::challenges(0).unwrap();
+        assert_eq!(challenge.storage_provider_id, storage_provider_id);
+        assert_eq!(challenge.content_id, TEST_MOCK_EXISTING_CID);
+        assert_eq!(challenge.relationship_id, TEST_FIRST_RELATIONSHIP_ID);
+        assert_eq!(challenge.deadline, now + ChallengeResponsePeriod::get());
+        assert_eq!(challenge.status, ChallengeStatus::Pending);
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::storage_challenge(storage_challenge::RawEvent::ChallengeCreated(
+                0,
+                storage_provider_id,
+                TEST_MOCK_EXISTING_CID
+            ))
+        );
+    });
+}
+
+#[test]
+fn respond_to_challenge_succeeds() {
+    with_default_mock_builder(|| {
+        let (account_id, storage_provider_id) = add_ready_relationship();
+        TestStorageChallenge::on_finalize(ChallengePeriod::get());
+        let challenge_id = 0;
+
+        let res = TestStorageChallenge::respond_to_challenge(
+            Origin::signed(account_id),
+            storage_provider_id,
+            challenge_id + 1,
+            TEST_MOCK_VALID_STORAGE_PROOF.to_vec(),
+        );
+        assert_eq!(res, Err(Error::ChallengeNotFound));
+
+        let res = TestStorageChallenge::respond_to_challenge(
+            Origin::signed(account_id),
+            storage_provider_id,
+            challenge_id,
+            b"invalid proof".to_vec(),
+        );
+        assert_eq!(res, Err(Error::InvalidStorageProof));
+
+        let res = TestStorageChallenge::respond_to_challenge(
+            Origin::signed(account_id),
+            storage_provider_id,
+            challenge_id,
+            TEST_MOCK_VALID_STORAGE_PROOF.to_vec(),
+        );
+        assert_eq!(res, Ok(()));
+
+        assert_eq!(
+            TestStorageChallenge::challenges(challenge_id)
+                .unwrap()
+                .status,
+            ChallengeStatus::Passed
+        );
+        assert_eq!(
+            TestStorageChallenge::challenge_stats_by_storage_provider(storage_provider_id),
+            ChallengeStats {
+                passed: 1,
+                failed: 0
+            }
+        );
+
+        let res = TestStorageChallenge::respond_to_challenge(
+            Origin::signed(account_id),
+            storage_provider_id,
+            challenge_id,
+            TEST_MOCK_VALID_STORAGE_PROOF.to_vec(),
+        );
+        assert_eq!(res, Err(Error::ChallengeNotPending));
+    });
+}
+
+#[test]
+fn respond_to_challenge_fails_with_invalid_storage_provider() {
+    with_default_mock_builder(|| {
+        add_ready_relationship();
+        TestStorageChallenge::on_finalize(ChallengePeriod::get());
+
+        let res = TestStorageChallenge::respond_to_challenge(
+            Origin::signed(2),
+            2,
+            0,
+            TEST_MOCK_VALID_STORAGE_PROOF.to_vec(),
+        );
+        assert_eq!(res, Err(working_group::Error::WorkerDoesNotExist.into()));
+    });
+}
+
+#[test]
+fn unanswered_challenge_fails_after_deadline() {
+    with_default_mock_builder(|| {
+        let (account_id, storage_provider_id) = add_ready_relationship();
+        let now = ChallengePeriod::get();
+        TestStorageChallenge::on_finalize(now);
+        let challenge_id = 0;
+        let deadline = now + ChallengeResponsePeriod::get();
+
+        TestStorageChallenge::on_finalize(deadline - 1);
+        assert_eq!(
+            TestStorageChallenge::challenges(challenge_id)
+                .unwrap()
+                .status,
+            ChallengeStatus::Pending
+        );
+
+        TestStorageChallenge::on_finalize(deadline);
+        assert_eq!(
+            TestStorageChallenge::challenges(challenge_id)
+                .unwrap()
+                .status,
+            ChallengeStatus::Failed
+        );
+        assert_eq!(
+            TestStorageChallenge::challenge_stats_by_storage_provider(storage_provider_id),
+            ChallengeStats {
+                passed: 0,
+                failed: 1
+            }
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::storage_challenge(storage_challenge::RawEvent::ChallengeFailed(
+                challenge_id,
+                storage_provider_id
+            ))
+        );
+
+        System::set_block_number(deadline + 1);
+        let res = TestStorageChallenge::respond_to_challenge(
+            Origin::signed(account_id),
+            storage_provider_id,
+            challenge_id,
+            TEST_MOCK_VALID_STORAGE_PROOF.to_vec(),
+        );
+        assert_eq!(res, Err(Error::ChallengeNotPending));
+    });
+}
+
+#[test]
+fn resolved_challenges_are_pruned() {
+    with_default_mock_builder(|| {
+        add_ready_relationship();
+        let now = ChallengePeriod::get();
+        TestStorageChallenge::on_finalize(now);
+        let challenge_id = 0;
+        let deadline = now + ChallengeResponsePeriod::get();
+
+        TestStorageChallenge::on_finalize(deadline);
+        assert!(TestStorageChallenge::challenges(challenge_id).is_some());
+
+        TestStorageChallenge::on_finalize(deadline + ChallengePeriod::get());
+        assert!(TestStorageChallenge::challenges(challenge_id).is_none());
+        assert!(TestStorageChallenge::challenges_by_deadline(deadline).is_empty());
+    });
+}
+
+#[test]
+fn merkle_storage_proof_verifies_the_challenged_chunk() {
+    with_default_mock_builder(|| {
+        add_ready_relationship();
+        TestStorageChallenge::on_finalize(ChallengePeriod::get());
+
+        let challenge = TestStorageChallenge::challenges(0).unwrap();
+        let content = mock_content();
+        let verify_storage_proof = |proof: &StorageProof<H256>| {
+            <MerkleStorageProofVerifier as StorageProofVerifier<Test>>::verify_storage_proof(
+                &challenge,
+                &proof.encode(),
+            )
+        };
+
+        let index = MerkleStorageProofVerifier::challenged_chunk_index::<Test>(
+            &challenge.seed,
+            TEST_MOCK_CONTENT_SIZE,
+        );
+        let proof = MerkleStorageProofVerifier::build_storage_proof::<Test>(&content, index);
+        assert!(verify_storage_proof(&proof));
+
+        // The proof of the other chunk doesn't match the challenge seed.
+        let other_chunk_proof =
+            MerkleStorageProofVerifier::build_storage_proof::<Test>(&content, index ^ 1);
+        assert!(!verify_storage_proof(&other_chunk_proof));
+
+        // The proof can't be built without the chunk bytes.
+        let mut forged_proof = proof.clone();
+        forged_proof.chunk = vec![0; forged_proof.chunk.len()];
+        assert!(!verify_storage_proof(&forged_proof));
+
+        let mut short_path_proof = proof;
+        short_path_proof.merkle_path.pop();
+        assert!(!verify_storage_proof(&short_path_proof));
+
+        assert!(!<MerkleStorageProofVerifier as StorageProofVerifier<
+            Test,
+        >>::verify_storage_proof(
+            &challenge, TEST_MOCK_VALID_STORAGE_PROOF,
+        ));
+    });
+}
+
+#[test]
+fn challenges_of_removed_content_are_cancelled() {
+    with_default_mock_builder(|| {
+        let (_, storage_provider_id) = add_ready_relationship();
+        let now = ChallengePeriod::get();
+        TestStorageChallenge::on_finalize(now);
+        let challenge_id = 0;
+        let deadline = now + ChallengeResponsePeriod::get();
+
+        TestDataObjectStorageRegistry::on_content_removed(&TEST_MOCK_EXISTING_CID);
+
+        TestStorageChallenge::on_finalize(deadline);
+        assert_eq!(
+            TestStorageChallenge::challenges(challenge_id)
+                .unwrap()
+                .status,
+            ChallengeStatus::Cancelled
+        );
+        assert_eq!(
+            TestStorageChallenge::challenge_stats_by_storage_provider(storage_provider_id),
+            ChallengeStats::default()
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::storage_challenge(storage_challenge::RawEvent::ChallengeCancelled(
+                challenge_id,
+                storage_provider_id
+            ))
+        );
+    });
+}
+
+#[test]
+fn challenges_of_exited_storage_providers_are_cancelled() {
+    with_default_mock_builder(|| {
+        let (_, storage_provider_id) = add_ready_relationship();
+        let now = ChallengePeriod::get();
+        TestStorageChallenge::on_finalize(now);
+        let challenge_id = 0;
+        let deadline = now + ChallengeResponsePeriod::get();
+
+        <working_group::WorkerById<Test, StorageWorkingGroupInstance>>::remove(storage_provider_id);
+
+        TestStorageChallenge::on_finalize(deadline);
+        assert_eq!(
+            TestStorageChallenge::challenges(challenge_id)
+                .unwrap()
+                .status,
+            ChallengeStatus::Cancelled
+        );
+        assert_eq!(
+            TestStorageChallenge::challenge_stats_by_storage_provider(storage_provider_id),
+            ChallengeStats::default()
+        );
+    });
+}
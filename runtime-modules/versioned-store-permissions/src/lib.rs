@@ -5,10 +5,13 @@ use codec::Codec;
 use rstd::collections::btree_map::BTreeMap;
 use rstd::prelude::*;
 use runtime_primitives::traits::{MaybeSerialize, Member, SimpleArithmetic};
-use srml_support::{decl_module, decl_storage, dispatch, ensure, Parameter};
+use srml_support::{decl_event, decl_module, decl_storage, dispatch, ensure, Parameter};
 
 // EntityId, ClassId -> should be configured on versioned_store::Trait
-pub use versioned_store::{ClassId, ClassPropertyValue, EntityId, Property, PropertyValue};
+pub use versioned_store::{
+    ClassId, ClassPropertyValue, ClassPropertyValueOf, Entity, EntityDeletionMode, EntityId,
+    EntityOf, Property, PropertyValue,
+};
 
 mod constraint;
 mod credentials;
@@ -60,8 +63,7 @@ pub type ClassPermissionsType<T> =
     ClassPermissions<ClassId, <T as Trait>::Credential, u16, <T as system::Trait>::BlockNumber>;
 
 pub trait Trait: system::Trait + versioned_store::Trait {
-    // type Event: ...
-    // Do we need Events?
+    type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
     /// Type that represents an actor or group of actors in the system.
     type Credential: Parameter
@@ -90,11 +92,59 @@ decl_storage! {
 
       /// Owner of an entity in the versioned store. If it is None then it is owned by the system.
       pub EntityMaintainerByEntityId get(entity_maintainer_by_entity_id): linked_map EntityId => Option<T::Credential>;
+
+      /// Entity permissions overriding the class level entity permissions for the corresponding entity.
+      pub EntityPermissionsOverrideByEntityId get(entity_permissions_override_by_entity_id): map EntityId => Option<EntityPermissions<T::Credential>>;
+
+      /// Ids of the entities maintained by the credential.
+      pub EntitiesByMaintainer get(entities_by_maintainer): map T::Credential => Vec<EntityId>;
     }
 }
 
+decl_event!(
+    pub enum Event<T>
+    where
+        Credential = <T as Trait>::Credential,
+        CredentialSetOf = CredentialSet<<T as Trait>::Credential>,
+        EntityPermissionsOf = EntityPermissions<<T as Trait>::Credential>,
+        ClassPermissionsOf = ClassPermissionsType<T>,
+    {
+        /// Class was created with the permissions. Params:
+        /// - Id of the class.
+        /// - Initial permissions of the class.
+        ClassPermissionsCreated(ClassId, ClassPermissionsOf),
+        ClassAdminsUpdated(ClassId, CredentialSetOf),
+        ClassEntityPermissionsUpdated(ClassId, EntityPermissionsOf),
+        ClassEntitiesCanBeCreatedUpdated(ClassId, bool),
+        ClassAddSchemasSetUpdated(ClassId, CredentialSetOf),
+        ClassCreateEntitiesSetUpdated(ClassId, CredentialSetOf),
+        ClassReferenceConstraintUpdated(ClassId, ReferenceConstraint<ClassId, u16>),
+
+        /// Entity was created. Params:
+        /// - Id of the entity.
+        /// - Maintainer of the entity, None if maintained by the system.
+        EntityCreated(EntityId, Option<Credential>),
+
+        /// Entity maintainer was changed. None if the entity is maintained by the system.
+        EntityMaintainerUpdated(EntityId, Option<Credential>),
+
+        /// Entity permissions override was changed. None if the class level entity permissions apply.
+        EntityPermissionsOverrideUpdated(EntityId, Option<EntityPermissionsOf>),
+
+        /// Batch transaction was applied. Params:
+        /// - Number of the applied operations.
+        TransactionCompleted(u32),
+
+        /// Batch transaction was rolled back. Params:
+        /// - Index of the failed operation.
+        /// - Error of the failed operation.
+        TransactionFailed(u32, Vec<u8>),
+    }
+);
+
 decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        fn deposit_event() = default;
 
         /// Sets the admins for a class
         fn set_class_admins(
@@ -110,10 +160,13 @@ decl_module! {
                 Self::is_system, // root origin
                 class_id,
                 |class_permissions| {
-                    class_permissions.admins = admins;
+                    class_permissions.admins = admins.clone();
                     Ok(())
                 }
-            )
+            )?;
+
+            Self::deposit_event(RawEvent::ClassAdminsUpdated(class_id, admins));
+            Ok(())
         }
 
         // Methods for updating concrete permissions
@@ -132,10 +185,13 @@ decl_module! {
                 ClassPermissions::is_admin,
                 class_id,
                 |class_permissions| {
-                    class_permissions.entity_permissions = entity_permissions;
+                    class_permissions.entity_permissions = entity_permissions.clone();
                     Ok(())
                 }
-            )
+            )?;
+
+            Self::deposit_event(RawEvent::ClassEntityPermissionsUpdated(class_id, entity_permissions));
+            Ok(())
         }
 
         fn set_class_entities_can_be_created(
@@ -155,7 +211,10 @@ decl_module! {
                     class_permissions.entities_can_be_created = can_be_created;
                     Ok(())
                 }
-            )
+            )?;
+
+            Self::deposit_event(RawEvent::ClassEntitiesCanBeCreatedUpdated(class_id, can_be_created));
+            Ok(())
         }
 
         fn set_class_add_schemas_set(
@@ -172,10 +231,13 @@ decl_module! {
                 ClassPermissions::is_admin,
                 class_id,
                 |class_permissions| {
-                    class_permissions.add_schemas = credential_set;
+                    class_permissions.add_schemas = credential_set.clone();
                     Ok(())
                 }
-            )
+            )?;
+
+            Self::deposit_event(RawEvent::ClassAddSchemasSetUpdated(class_id, credential_set));
+            Ok(())
         }
 
         fn set_class_create_entities_set(
@@ -192,10 +254,13 @@ decl_module! {
                 ClassPermissions::is_admin,
                 class_id,
                 |class_permissions| {
-                    class_permissions.create_entities = credential_set;
+                    class_permissions.create_entities = credential_set.clone();
                     Ok(())
                 }
-            )
+            )?;
+
+            Self::deposit_event(RawEvent::ClassCreateEntitiesSetUpdated(class_id, credential_set));
+            Ok(())
         }
 
         fn set_class_reference_constraint(
@@ -212,30 +277,76 @@ decl_module! {
                 ClassPermissions::is_admin,
                 class_id,
                 |class_permissions| {
-                    class_permissions.reference_constraint = constraint;
+                    class_permissions.reference_constraint = constraint.clone();
                     Ok(())
                 }
-            )
+            )?;
+
+            Self::deposit_event(RawEvent::ClassReferenceConstraintUpdated(class_id, constraint));
+            Ok(())
+        }
+
+        /// Transfers the entity to a new maintainer. Setting None makes the entity maintained by
+        /// the system. Can be performed by the root origin, class admins or the current maintainer.
+        pub fn set_entity_maintainer(
+            origin,
+            with_credential: Option<T::Credential>,
+            entity_id: EntityId,
+            new_maintainer: Option<T::Credential>
+        ) -> dispatch::Result {
+            let raw_origin = Self::ensure_root_or_signed(origin)?;
+
+            let class_id = Self::get_class_id_by_entity_id(entity_id)?;
+            let class_permissions = Self::ensure_class_permissions(class_id)?;
+            let access_level = Self::derive_access_level(&raw_origin, with_credential, None)?;
+
+            let is_current_maintainer = match access_level {
+                AccessLevel::Credential(credential) => {
+                    Self::entity_maintainer_by_entity_id(entity_id) == Some(credential)
+                }
+                _ => false,
+            };
+
+            if !is_current_maintainer {
+                ClassPermissions::is_admin(&class_permissions, &access_level)?;
+            }
+
+            Self::update_entity_maintainer(entity_id, new_maintainer);
+
+            Self::deposit_event(RawEvent::EntityMaintainerUpdated(entity_id, new_maintainer));
+            Ok(())
         }
 
-        // Setting a new maintainer for an entity may require having additional constraints.
-        // So for now it is disabled.
-        // pub fn set_entity_maintainer(
-        //     origin,
-        //     entity_id: EntityId,
-        //     new_maintainer: Option<T::Credential>
-        // ) -> dispatch::Result {
-        //     ensure_root(origin)?;
+        /// Sets the entity permissions applied to the entity instead of the class level entity
+        /// permissions. Setting None restores the class level entity permissions.
+        /// Can be performed by the root origin or class admins.
+        pub fn set_entity_permissions_override(
+            origin,
+            with_credential: Option<T::Credential>,
+            entity_id: EntityId,
+            entity_permissions: Option<EntityPermissions<T::Credential>>
+        ) -> dispatch::Result {
+            let raw_origin = Self::ensure_root_or_signed(origin)?;
 
-        //     // ensure entity exists in the versioned store
-        //     let _ = Self::get_class_id_by_entity_id(entity_id)?;
+            let class_id = Self::get_class_id_by_entity_id(entity_id)?;
 
-        //     <EntityMaintainerByEntityId<T>>::mutate(entity_id, |maintainer| {
-        //         *maintainer = new_maintainer;
-        //     });
+            Self::if_class_permissions_satisfied(
+                &raw_origin,
+                with_credential,
+                None,
+                ClassPermissions::is_admin,
+                class_id,
+                |_class_permissions, _access_level| {
+                    <EntityPermissionsOverrideByEntityId<T>>::mutate(entity_id, |permissions| {
+                        *permissions = entity_permissions.clone();
+                    });
+                    Ok(())
+                }
+            )?;
 
-        //     Ok(())
-        // }
+            Self::deposit_event(RawEvent::EntityPermissionsOverrideUpdated(entity_id, entity_permissions));
+            Ok(())
+        }
 
         // Permissioned proxy calls to versioned store
 
@@ -260,8 +371,9 @@ decl_module! {
 
                 // is there a need to assert class_id is unique?
 
-                <ClassPermissionsByClassId<T>>::insert(&class_id, class_permissions);
+                <ClassPermissionsByClassId<T>>::insert(&class_id, class_permissions.clone());
 
+                Self::deposit_event(RawEvent::ClassPermissionsCreated(class_id, class_permissions));
                 Ok(())
             } else {
                 Err("NotPermittedToCreateClass")
@@ -302,6 +414,60 @@ decl_module! {
             )
         }
 
+        /// Deprecates the class schema, so it cannot be added to entities anymore.
+        pub fn deprecate_class_schema(
+            origin,
+            with_credential: Option<T::Credential>,
+            class_id: ClassId,
+            schema_id: u16
+        ) -> dispatch::Result {
+            let raw_origin = Self::ensure_root_or_signed(origin)?;
+
+            Self::if_class_permissions_satisfied(
+                &raw_origin,
+                with_credential,
+                None,
+                ClassPermissions::can_add_class_schema,
+                class_id,
+                |_class_permissions, _access_level| {
+                    <versioned_store::Module<T>>::deprecate_class_schema(class_id, schema_id)
+                }
+            )
+        }
+
+        /// Migrates the entities of the class from one class schema to another.
+        /// Only the system or the class admins can migrate entities.
+        pub fn migrate_entities(
+            origin,
+            with_credential: Option<T::Credential>,
+            class_id: ClassId,
+            from_schema_id: u16,
+            to_schema_id: u16,
+            entity_ids: Vec<EntityId>,
+            default_values: Vec<ClassPropertyValueOf<T>>
+        ) -> dispatch::Result {
+            let raw_origin = Self::ensure_root_or_signed(origin)?;
+
+            Self::ensure_internal_property_values_permitted(class_id, &default_values)?;
+
+            Self::if_class_permissions_satisfied(
+                &raw_origin,
+                with_credential,
+                None,
+                ClassPermissions::is_admin,
+                class_id,
+                |_class_permissions, _access_level| {
+                    <versioned_store::Module<T>>::migrate_entities(
+                        class_id,
+                        from_schema_id,
+                        to_schema_id,
+                        entity_ids,
+                        default_values
+                    )
+                }
+            )
+        }
+
         /// Creates a new entity of type class_id. The maintainer is set to be either None if the origin is root, or the provided credential
         /// associated with signer.
         pub fn create_entity(
@@ -320,7 +486,7 @@ decl_module! {
             as_entity_maintainer: bool,
             entity_id: EntityId,
             schema_id: u16, // Do not type alias u16!! - u16,
-            property_values: Vec<ClassPropertyValue>
+            property_values: Vec<ClassPropertyValueOf<T>>
         ) -> dispatch::Result {
             let raw_origin = Self::ensure_root_or_signed(origin)?;
             Self::do_add_schema_support_to_entity(&raw_origin, with_credential, as_entity_maintainer, entity_id, schema_id, property_values)
@@ -331,39 +497,61 @@ decl_module! {
             with_credential: Option<T::Credential>,
             as_entity_maintainer: bool,
             entity_id: EntityId,
-            property_values: Vec<ClassPropertyValue>
+            property_values: Vec<ClassPropertyValueOf<T>>
         ) -> dispatch::Result {
             let raw_origin = Self::ensure_root_or_signed(origin)?;
             Self::do_update_entity_property_values(&raw_origin, with_credential, as_entity_maintainer, entity_id, property_values)
         }
 
-        pub fn transaction(origin, operations: Vec<Operation<T::Credential>>) -> dispatch::Result {
+        /// Deletes the entity. Only the system or the entity maintainer (if permitted by the class
+        /// entity permissions) can delete it. References from other entities are handled according
+        /// to the deletion mode.
+        pub fn delete_entity(
+            origin,
+            with_credential: Option<T::Credential>,
+            entity_id: EntityId,
+            mode: EntityDeletionMode
+        ) -> dispatch::Result {
+            let raw_origin = Self::ensure_root_or_signed(origin)?;
+            Self::do_delete_entity(&raw_origin, with_credential, entity_id, mode)
+        }
+
+        /// Applies the operations in order. The batch is atomic: if any operation fails, all entities
+        /// created and updated by the previous operations are restored and the TransactionFailed
+        /// event with the index of the failed operation is deposited.
+        /// Note that the events already deposited for the previous operations (e.g. EntityCreated)
+        /// are not reverted by the rollback.
+        pub fn transaction(origin, operations: Vec<Operation<T::Credential, T::ContentId>>) -> dispatch::Result {
             // This map holds the EntityId of the entity created as a result of executing a CreateEntity Operation
             // keyed by the indexed of the operation, in the operations vector.
             let mut entity_created_in_operation: BTreeMap<usize, EntityId> = BTreeMap::new();
 
+            // State of the entities before they were touched by the transaction,
+            // None for the entities created by the transaction.
+            let mut entity_snapshots: BTreeMap<EntityId, Option<EntityOf<T>>> = BTreeMap::new();
+            let next_entity_id = <versioned_store::Module<T>>::next_entity_id();
+
             let raw_origin = Self::ensure_root_or_signed(origin)?;
 
+            let operations_count = operations.len() as u32;
+
             for (op_index, operation) in operations.into_iter().enumerate() {
-                match operation.operation_type {
-                    OperationType::CreateEntity(create_entity_operation) => {
-                        let entity_id = Self::do_create_entity(&raw_origin, operation.with_credential, create_entity_operation.class_id)?;
-                        entity_created_in_operation.insert(op_index, entity_id);
-                    },
-                    OperationType::UpdatePropertyValues(update_property_values_operation) => {
-                        let entity_id = operations::parametrized_entity_to_entity_id(&entity_created_in_operation, update_property_values_operation.entity_id)?;
-                        let property_values = operations::parametrized_property_values_to_property_values(&entity_created_in_operation, update_property_values_operation.new_parametrized_property_values)?;
-                        Self::do_update_entity_property_values(&raw_origin, operation.with_credential, operation.as_entity_maintainer, entity_id, property_values)?;
-                    },
-                    OperationType::AddSchemaSupportToEntity(add_schema_support_to_entity_operation) => {
-                        let entity_id = operations::parametrized_entity_to_entity_id(&entity_created_in_operation, add_schema_support_to_entity_operation.entity_id)?;
-                        let schema_id = add_schema_support_to_entity_operation.schema_id;
-                        let property_values = operations::parametrized_property_values_to_property_values(&entity_created_in_operation, add_schema_support_to_entity_operation.parametrized_property_values)?;
-                        Self::do_add_schema_support_to_entity(&raw_origin, operation.with_credential, operation.as_entity_maintainer, entity_id, schema_id, property_values)?;
-                    }
+                let result = Self::do_operation(
+                    &raw_origin,
+                    op_index,
+                    operation,
+                    &mut entity_created_in_operation,
+                    &mut entity_snapshots,
+                );
+
+                if let Err(error) = result {
+                    Self::rollback_transaction(next_entity_id, entity_snapshots);
+                    Self::deposit_event(RawEvent::TransactionFailed(op_index as u32, error.as_bytes().to_vec()));
+                    return Err(error);
                 }
             }
 
+            Self::deposit_event(RawEvent::TransactionCompleted(operations_count));
             Ok(())
         }
     }
@@ -394,16 +582,14 @@ impl<T: Trait> Module<T> {
             |_class_permissions, access_level| {
                 let entity_id = <versioned_store::Module<T>>::create_entity(class_id)?;
 
-                // Note: mutating value to None is equivalient to removing the value from storage map
-                <EntityMaintainerByEntityId<T>>::mutate(
-                    entity_id,
-                    |maintainer| match access_level {
-                        AccessLevel::System => *maintainer = None,
-                        AccessLevel::Credential(credential) => *maintainer = Some(*credential),
-                        _ => *maintainer = None,
-                    },
-                );
+                let maintainer = match access_level {
+                    AccessLevel::Credential(credential) => Some(*credential),
+                    _ => None,
+                };
 
+                Self::update_entity_maintainer(entity_id, maintainer);
+
+                Self::deposit_event(RawEvent::EntityCreated(entity_id, maintainer));
                 Ok(entity_id)
             },
         )
@@ -414,24 +600,18 @@ impl<T: Trait> Module<T> {
         with_credential: Option<T::Credential>,
         as_entity_maintainer: bool,
         entity_id: EntityId,
-        property_values: Vec<ClassPropertyValue>,
+        property_values: Vec<ClassPropertyValueOf<T>>,
     ) -> dispatch::Result {
         let class_id = Self::get_class_id_by_entity_id(entity_id)?;
 
         Self::ensure_internal_property_values_permitted(class_id, &property_values)?;
 
-        let as_entity_maintainer = if as_entity_maintainer {
-            Some(entity_id)
-        } else {
-            None
-        };
-
-        Self::if_class_permissions_satisfied(
+        Self::if_entity_permissions_satisfied(
             raw_origin,
             with_credential,
             as_entity_maintainer,
             ClassPermissions::can_update_entity,
-            class_id,
+            entity_id,
             |_class_permissions, _access_level| {
                 <versioned_store::Module<T>>::update_entity_property_values(
                     entity_id,
@@ -447,25 +627,19 @@ impl<T: Trait> Module<T> {
         as_entity_maintainer: bool,
         entity_id: EntityId,
         schema_id: u16,
-        property_values: Vec<ClassPropertyValue>,
+        property_values: Vec<ClassPropertyValueOf<T>>,
     ) -> dispatch::Result {
         // class id of the entity being updated
         let class_id = Self::get_class_id_by_entity_id(entity_id)?;
 
         Self::ensure_internal_property_values_permitted(class_id, &property_values)?;
 
-        let as_entity_maintainer = if as_entity_maintainer {
-            Some(entity_id)
-        } else {
-            None
-        };
-
-        Self::if_class_permissions_satisfied(
+        Self::if_entity_permissions_satisfied(
             raw_origin,
             with_credential,
             as_entity_maintainer,
             ClassPermissions::can_update_entity,
-            class_id,
+            entity_id,
             |_class_permissions, _access_level| {
                 <versioned_store::Module<T>>::add_schema_support_to_entity(
                     entity_id,
@@ -476,6 +650,168 @@ impl<T: Trait> Module<T> {
         )
     }
 
+    fn do_operation(
+        raw_origin: &system::RawOrigin<T::AccountId>,
+        op_index: usize,
+        operation: Operation<T::Credential, T::ContentId>,
+        entity_created_in_operation: &mut BTreeMap<usize, EntityId>,
+        entity_snapshots: &mut BTreeMap<EntityId, Option<EntityOf<T>>>,
+    ) -> dispatch::Result {
+        match operation.operation_type {
+            OperationType::CreateEntity(create_entity_operation) => {
+                let entity_id = Self::do_create_entity(
+                    raw_origin,
+                    operation.with_credential,
+                    create_entity_operation.class_id,
+                )?;
+                entity_created_in_operation.insert(op_index, entity_id);
+                entity_snapshots.insert(entity_id, None);
+            }
+            OperationType::UpdatePropertyValues(update_property_values_operation) => {
+                let entity_id = operations::parametrized_entity_to_entity_id(
+                    entity_created_in_operation,
+                    update_property_values_operation.entity_id,
+                )?;
+                let property_values = operations::parametrized_property_values_to_property_values(
+                    entity_created_in_operation,
+                    update_property_values_operation.new_parametrized_property_values,
+                )?;
+                Self::snapshot_entity(entity_snapshots, entity_id);
+                Self::do_update_entity_property_values(
+                    raw_origin,
+                    operation.with_credential,
+                    operation.as_entity_maintainer,
+                    entity_id,
+                    property_values,
+                )?;
+            }
+            OperationType::AddSchemaSupportToEntity(add_schema_support_to_entity_operation) => {
+                let entity_id = operations::parametrized_entity_to_entity_id(
+                    entity_created_in_operation,
+                    add_schema_support_to_entity_operation.entity_id,
+                )?;
+                let schema_id = add_schema_support_to_entity_operation.schema_id;
+                let property_values = operations::parametrized_property_values_to_property_values(
+                    entity_created_in_operation,
+                    add_schema_support_to_entity_operation.parametrized_property_values,
+                )?;
+                Self::snapshot_entity(entity_snapshots, entity_id);
+                Self::do_add_schema_support_to_entity(
+                    raw_origin,
+                    operation.with_credential,
+                    operation.as_entity_maintainer,
+                    entity_id,
+                    schema_id,
+                    property_values,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Saves the entity state before it is first mutated by the transaction.
+    fn snapshot_entity(
+        entity_snapshots: &mut BTreeMap<EntityId, Option<EntityOf<T>>>,
+        entity_id: EntityId,
+    ) {
+        if !entity_snapshots.contains_key(&entity_id)
+            && <versioned_store::EntityById<T>>::exists(entity_id)
+        {
+            let entity = <versioned_store::Module<T>>::entity_by_id(entity_id);
+            entity_snapshots.insert(entity_id, Some(entity));
+        }
+    }
+
+    // Restores the entities touched by the transaction and removes the created ones
+    // with their maintainers.
+    fn rollback_transaction(
+        next_entity_id: EntityId,
+        entity_snapshots: BTreeMap<EntityId, Option<EntityOf<T>>>,
+    ) {
+        for (entity_id, snapshot) in entity_snapshots.into_iter() {
+            if snapshot.is_none() {
+                Self::update_entity_maintainer(entity_id, None);
+            }
+
+            <versioned_store::Module<T>>::restore_entity(entity_id, snapshot);
+        }
+
+        versioned_store::NextEntityId::put(next_entity_id);
+    }
+
+    fn do_delete_entity(
+        raw_origin: &system::RawOrigin<T::AccountId>,
+        with_credential: Option<T::Credential>,
+        entity_id: EntityId,
+        mode: EntityDeletionMode,
+    ) -> dispatch::Result {
+        // Only the system can act without the entity maintainer access level.
+        let as_entity_maintainer = *raw_origin != system::RawOrigin::Root;
+
+        Self::if_entity_permissions_satisfied(
+            raw_origin,
+            with_credential,
+            as_entity_maintainer,
+            ClassPermissions::can_delete_entity,
+            entity_id,
+            |_class_permissions, _access_level| {
+                <versioned_store::Module<T>>::delete_entity(entity_id, mode)?;
+                Self::update_entity_maintainer(entity_id, None);
+                <EntityPermissionsOverrideByEntityId<T>>::remove(entity_id);
+                Ok(())
+            },
+        )
+    }
+
+    /// Transfers all entities maintained by the `from` credential to the `to` credential.
+    /// Used by the modules issuing the credentials when their holder changes.
+    pub fn transfer_maintained_entities(from: T::Credential, to: T::Credential) {
+        for entity_id in <EntitiesByMaintainer<T>>::take(from) {
+            Self::update_entity_maintainer(entity_id, Some(to));
+            Self::deposit_event(RawEvent::EntityMaintainerUpdated(entity_id, Some(to)));
+        }
+    }
+
+    /// Rebuilds the entities by maintainer index from the entity maintainers. Intended for the
+    /// runtime upgrade migration of the entities created before the index was introduced.
+    pub fn rebuild_entities_by_maintainer() {
+        for (_, maintainer) in <EntityMaintainerByEntityId<T>>::enumerate() {
+            if let Some(credential) = maintainer {
+                <EntitiesByMaintainer<T>>::remove(credential);
+            }
+        }
+
+        for (entity_id, maintainer) in <EntityMaintainerByEntityId<T>>::enumerate() {
+            if let Some(credential) = maintainer {
+                <EntitiesByMaintainer<T>>::mutate(credential, |ids| ids.push(entity_id));
+            }
+        }
+    }
+
+    // Sets the entity maintainer and keeps the entities by maintainer index in sync.
+    fn update_entity_maintainer(entity_id: EntityId, new_maintainer: Option<T::Credential>) {
+        if let Some(current_maintainer) = Self::entity_maintainer_by_entity_id(entity_id) {
+            let mut entity_ids = Self::entities_by_maintainer(current_maintainer);
+            entity_ids.retain(|id| *id != entity_id);
+
+            if entity_ids.is_empty() {
+                <EntitiesByMaintainer<T>>::remove(current_maintainer);
+            } else {
+                <EntitiesByMaintainer<T>>::insert(current_maintainer, entity_ids);
+            }
+        }
+
+        if let Some(maintainer) = new_maintainer {
+            <EntitiesByMaintainer<T>>::mutate(maintainer, |ids| ids.push(entity_id));
+        }
+
+        // Note: mutating value to None is equivalient to removing the value from storage map
+        <EntityMaintainerByEntityId<T>>::mutate(entity_id, |current_maintainer| {
+            *current_maintainer = new_maintainer;
+        });
+    }
+
     /// Derives the AccessLevel the caller is attempting to act with.
     /// It expects only signed or root origin.
     fn derive_access_level(
@@ -596,10 +932,54 @@ impl<T: Trait> Module<T> {
         callback(&class_permissions, &access_level)
     }
 
+    /// Derives the access level of the caller acting on the entity. The entity permissions
+    /// override (if set) replaces the class level entity permissions.
+    /// If the predicate passes the callback is invoked. Returns result of the callback
+    /// or error from failed predicate.
+    fn if_entity_permissions_satisfied<Predicate, Callback, R>(
+        raw_origin: &system::RawOrigin<T::AccountId>,
+        with_credential: Option<T::Credential>,
+        as_entity_maintainer: bool,
+        // predicate to test
+        predicate: Predicate,
+        // entity to act on
+        entity_id: EntityId,
+        // callback to invoke if predicate passes
+        callback: Callback,
+    ) -> Result<R, &'static str>
+    where
+        Predicate:
+            FnOnce(&ClassPermissionsType<T>, &AccessLevel<T::Credential>) -> dispatch::Result,
+        Callback: FnOnce(
+            &ClassPermissionsType<T>,
+            &AccessLevel<T::Credential>,
+        ) -> Result<R, &'static str>,
+    {
+        let class_id = Self::get_class_id_by_entity_id(entity_id)?;
+
+        let as_entity_maintainer = if as_entity_maintainer {
+            Some(entity_id)
+        } else {
+            None
+        };
+
+        let access_level =
+            Self::derive_access_level(raw_origin, with_credential, as_entity_maintainer)?;
+        let mut class_permissions = Self::ensure_class_permissions(class_id)?;
+
+        if let Some(entity_permissions) = Self::entity_permissions_override_by_entity_id(entity_id)
+        {
+            class_permissions.entity_permissions = entity_permissions;
+        }
+
+        predicate(&class_permissions, &access_level)?;
+        callback(&class_permissions, &access_level)
+    }
+
     fn get_class_id_by_entity_id(entity_id: EntityId) -> Result<ClassId, &'static str> {
         // use a utility method on versioned_store module
         ensure!(
-            versioned_store::EntityById::exists(entity_id),
+            <versioned_store::EntityById<T>>::exists(entity_id),
             "EntityNotFound"
         );
         let entity = <versioned_store::Module<T>>::entity_by_id(entity_id);
@@ -610,7 +990,7 @@ impl<T: Trait> Module<T> {
     // the target entity and class exists and constraint allows it.
     fn ensure_internal_property_values_permitted(
         source_class_id: ClassId,
-        property_values: &[ClassPropertyValue],
+        property_values: &[ClassPropertyValueOf<T>],
     ) -> dispatch::Result {
         for property_value in property_values.iter() {
             if let PropertyValue::Internal(ref target_entity_id) = property_value.value {
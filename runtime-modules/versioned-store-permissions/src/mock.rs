@@ -9,13 +9,24 @@ use runtime_primitives::{
     traits::{BlakeTwo256, IdentityLookup},
     Perbill,
 };
-use srml_support::{impl_outer_origin, parameter_types};
+use srml_support::{impl_outer_event, impl_outer_origin, parameter_types};
 use versioned_store::InputValidationLengthConstraint;
 
 impl_outer_origin! {
     pub enum Origin for Runtime {}
 }
 
+mod lib {
+    pub use crate::Event;
+}
+
+impl_outer_event! {
+    pub enum TestEvent for Runtime {
+        versioned_store<T>,
+        lib<T>,
+    }
+}
+
 // Workaround for https://github.com/rust-lang/rust/issues/26925 . Remove when sorted.
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Runtime;
@@ -37,7 +48,7 @@ impl system::Trait for Runtime {
     type AccountId = u64;
     type Lookup = IdentityLookup<Self::AccountId>;
     type Header = Header;
-    type Event = ();
+    type Event = TestEvent;
     type BlockHashCount = BlockHashCount;
     type MaximumBlockWeight = MaximumBlockWeight;
     type MaximumBlockLength = MaximumBlockLength;
@@ -52,10 +63,13 @@ impl timestamp::Trait for Runtime {
 }
 
 impl versioned_store::Trait for Runtime {
-    type Event = ();
+    type Event = TestEvent;
+    type ContentId = H256;
+    type DataObjectChecker = ();
 }
 
 impl Trait for Runtime {
+    type Event = TestEvent;
     type Credential = u64;
     type CredentialChecker = MockCredentialChecker;
     type CreateClassPermissionsChecker = MockCreateClassPermissionsChecker;
@@ -116,7 +130,7 @@ impl CreateClassPermissionsChecker<Runtime> for MockCreateClassPermissionsChecke
 // This function basically just builds a genesis storage key/value store according to
 // our desired mockup.
 
-fn default_versioned_store_genesis_config() -> versioned_store::GenesisConfig {
+fn default_versioned_store_genesis_config() -> versioned_store::GenesisConfig<Runtime> {
     versioned_store::GenesisConfig {
         class_by_id: vec![],
         entity_by_id: vec![],
@@ -142,7 +156,7 @@ fn default_versioned_store_genesis_config() -> versioned_store::GenesisConfig {
 }
 
 fn build_test_externalities(
-    config: versioned_store::GenesisConfig,
+    config: versioned_store::GenesisConfig<Runtime>,
 ) -> runtime_io::TestExternalities {
     let mut t = system::GenesisConfig::default()
         .build_storage::<Runtime>()
@@ -158,7 +172,7 @@ pub fn with_test_externalities<R, F: FnOnce() -> R>(f: F) -> R {
     build_test_externalities(versioned_store_config).execute_with(f)
 }
 
-// pub type System = system::Module;
+pub type System = system::Module<Runtime>;
 
 /// Export module on a test runtime
 pub type Permissions = Module<Runtime>;
@@ -4,9 +4,9 @@ use rstd::prelude::*;
 use versioned_store::{ClassId, ClassPropertyValue, EntityId, PropertyValue};
 
 #[derive(Encode, Decode, Eq, PartialEq, Clone, Debug)]
-pub enum ParametrizedPropertyValue {
+pub enum ParametrizedPropertyValue<ContentId> {
     /// Same fields as normal PropertyValue
-    PropertyValue(PropertyValue),
+    PropertyValue(PropertyValue<ContentId>),
 
     /// This is the index of an operation creating an entity in the transaction/batch operations
     InternalEntityJustAdded(u32), // should really be usize but it doesn't have Encode/Decode support
@@ -22,12 +22,12 @@ pub enum ParameterizedEntity {
 }
 
 #[derive(Encode, Decode, Eq, PartialEq, Clone, Debug)]
-pub struct ParametrizedClassPropertyValue {
+pub struct ParametrizedClassPropertyValue<ContentId> {
     /// Index is into properties vector of class.
     pub in_class_index: u16,
 
     /// Value of property with index `in_class_index` in a given class.
-    pub value: ParametrizedPropertyValue,
+    pub value: ParametrizedPropertyValue<ContentId>,
 }
 
 #[derive(Encode, Decode, Eq, PartialEq, Clone, Debug)]
@@ -36,30 +36,30 @@ pub struct CreateEntityOperation {
 }
 
 #[derive(Encode, Decode, Eq, PartialEq, Clone, Debug)]
-pub struct UpdatePropertyValuesOperation {
+pub struct UpdatePropertyValuesOperation<ContentId> {
     pub entity_id: ParameterizedEntity,
-    pub new_parametrized_property_values: Vec<ParametrizedClassPropertyValue>,
+    pub new_parametrized_property_values: Vec<ParametrizedClassPropertyValue<ContentId>>,
 }
 
 #[derive(Encode, Decode, Eq, PartialEq, Clone, Debug)]
-pub struct AddSchemaSupportToEntityOperation {
+pub struct AddSchemaSupportToEntityOperation<ContentId> {
     pub entity_id: ParameterizedEntity,
     pub schema_id: u16,
-    pub parametrized_property_values: Vec<ParametrizedClassPropertyValue>,
+    pub parametrized_property_values: Vec<ParametrizedClassPropertyValue<ContentId>>,
 }
 
 #[derive(Encode, Decode, Eq, PartialEq, Clone, Debug)]
-pub enum OperationType {
+pub enum OperationType<ContentId> {
     CreateEntity(CreateEntityOperation),
-    UpdatePropertyValues(UpdatePropertyValuesOperation),
-    AddSchemaSupportToEntity(AddSchemaSupportToEntityOperation),
+    UpdatePropertyValues(UpdatePropertyValuesOperation<ContentId>),
+    AddSchemaSupportToEntity(AddSchemaSupportToEntityOperation<ContentId>),
 }
 
 #[derive(Encode, Decode, Eq, PartialEq, Clone, Debug)]
-pub struct Operation<Credential> {
+pub struct Operation<Credential, ContentId> {
     pub with_credential: Option<Credential>,
     pub as_entity_maintainer: bool,
-    pub operation_type: OperationType,
+    pub operation_type: OperationType<ContentId>,
 }
 
 pub fn parametrized_entity_to_entity_id(
@@ -80,11 +80,11 @@ pub fn parametrized_entity_to_entity_id(
     }
 }
 
-pub fn parametrized_property_values_to_property_values(
+pub fn parametrized_property_values_to_property_values<ContentId>(
     created_entities: &BTreeMap<usize, EntityId>,
-    parametrized_property_values: Vec<ParametrizedClassPropertyValue>,
-) -> Result<Vec<ClassPropertyValue>, &'static str> {
-    let mut class_property_values: Vec<ClassPropertyValue> = vec![];
+    parametrized_property_values: Vec<ParametrizedClassPropertyValue<ContentId>>,
+) -> Result<Vec<ClassPropertyValue<ContentId>>, &'static str> {
+    let mut class_property_values: Vec<ClassPropertyValue<ContentId>> = vec![];
 
     for parametrized_class_property_value in parametrized_property_values.into_iter() {
         let property_value = match parametrized_class_property_value.value {
@@ -131,6 +131,24 @@ where
             _ => Err("UnknownActor"),
         }
     }
+
+    pub fn can_delete_entity(
+        class_permissions: &Self,
+        access_level: &AccessLevel<Credential>,
+    ) -> dispatch::Result {
+        match access_level {
+            AccessLevel::System => Ok(()),
+            AccessLevel::EntityMaintainer => {
+                if class_permissions.entity_permissions.maintainer_can_delete {
+                    Ok(())
+                } else {
+                    Err("MaintainerCannotDeleteEntity")
+                }
+            }
+            AccessLevel::Credential(_) => Err("OnlyMaintainerCanDeleteEntity"),
+            _ => Err("UnknownActor"),
+        }
+    }
 }
 
 #[derive(Encode, Decode, Clone, Debug, Eq, PartialEq)]
@@ -142,12 +160,15 @@ where
     pub update: CredentialSet<Credential>,
     /// Wether the designated maintainer (if set) of an entity has permission to update it.
     pub maintainer_has_all_permissions: bool,
+    /// Wether the designated maintainer (if set) of an entity has permission to delete it.
+    pub maintainer_can_delete: bool,
 }
 
 impl<Credential: Ord> Default for EntityPermissions<Credential> {
     fn default() -> Self {
         EntityPermissions {
             maintainer_has_all_permissions: true,
+            maintainer_can_delete: false,
             update: CredentialSet::new(),
         }
     }
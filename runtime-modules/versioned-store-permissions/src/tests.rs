@@ -13,10 +13,11 @@ fn simple_test_schema() -> Vec<Property> {
         required: false,
         name: b"field1".to_vec(),
         description: b"Description field1".to_vec(),
+        unique: false,
     }]
 }
 
-fn simple_test_entity_property_values() -> Vec<ClassPropertyValue> {
+fn simple_test_entity_property_values() -> Vec<ClassPropertyValueOf<Runtime>> {
     vec![ClassPropertyValue {
         in_class_index: 0,
         value: PropertyValue::Int64(1337),
@@ -383,6 +384,7 @@ fn class_permissions_set_class_entity_permissions() {
         let entity_permissions1 = EntityPermissions {
             update: CredentialSet::from(vec![1]),
             maintainer_has_all_permissions: true,
+            maintainer_can_delete: false,
         };
 
         //root
@@ -398,6 +400,7 @@ fn class_permissions_set_class_entity_permissions() {
         let entity_permissions2 = EntityPermissions {
             update: CredentialSet::from(vec![4]),
             maintainer_has_all_permissions: true,
+            maintainer_can_delete: true,
         };
         //admins
         assert_ok!(Permissions::set_class_entity_permissions(
@@ -485,6 +488,298 @@ fn class_permissions_set_class_reference_constraint() {
     })
 }
 
+#[test]
+fn delete_entity_by_maintainer_or_system() {
+    with_test_externalities(|| {
+        let class_id = create_simple_class(ClassPermissions {
+            entities_can_be_created: true,
+            create_entities: CredentialSet::from(vec![1]),
+            ..Default::default()
+        });
+
+        let entity_id = next_entity_id();
+        assert_ok!(Permissions::create_entity(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+            Some(1),
+            class_id,
+        ));
+
+        // default permissions don't allow entity maintainer to delete the entity
+        assert_err!(
+            Permissions::delete_entity(
+                Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+                Some(1),
+                entity_id,
+                EntityDeletionMode::FailIfReferenced
+            ),
+            "MaintainerCannotDeleteEntity"
+        );
+
+        assert_ok!(Permissions::set_class_entity_permissions(
+            Origin::ROOT,
+            None,
+            class_id,
+            EntityPermissions {
+                maintainer_can_delete: true,
+                ..Default::default()
+            }
+        ));
+
+        // only the maintainer can delete the entity
+        assert_err!(
+            Permissions::delete_entity(
+                Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ZERO),
+                Some(0),
+                entity_id,
+                EntityDeletionMode::FailIfReferenced
+            ),
+            "NotEnityMaintainer"
+        );
+
+        assert_ok!(Permissions::delete_entity(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+            Some(1),
+            entity_id,
+            EntityDeletionMode::FailIfReferenced
+        ));
+        assert!(!<versioned_store::EntityById<Runtime>>::exists(entity_id));
+        assert!(!<EntityMaintainerByEntityId<Runtime>>::exists(entity_id));
+
+        // system can always delete entities
+        let entity_id = next_entity_id();
+        assert_ok!(Permissions::create_entity(Origin::ROOT, None, class_id));
+        assert_ok!(Permissions::delete_entity(
+            Origin::ROOT,
+            None,
+            entity_id,
+            EntityDeletionMode::FailIfReferenced
+        ));
+        assert!(!<versioned_store::EntityById<Runtime>>::exists(entity_id));
+    })
+}
+
+fn create_entity_maintained_by_credential_one() -> (ClassId, EntityId) {
+    let class_id = create_simple_class(ClassPermissions {
+        entities_can_be_created: true,
+        create_entities: CredentialSet::from(vec![1]),
+        admins: CredentialSet::from(vec![0]),
+        ..Default::default()
+    });
+
+    assert_ok!(Permissions::add_class_schema(
+        Origin::ROOT,
+        None,
+        class_id,
+        vec![],
+        simple_test_schema()
+    ));
+
+    let entity_id = next_entity_id();
+    assert_ok!(Permissions::create_entity(
+        Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+        Some(1),
+        class_id,
+    ));
+
+    (class_id, entity_id)
+}
+
+#[test]
+fn set_entity_maintainer() {
+    with_test_externalities(|| {
+        let (_, entity_id) = create_entity_maintained_by_credential_one();
+
+        // entity must exist
+        assert_err!(
+            Permissions::set_entity_maintainer(
+                Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+                Some(1),
+                entity_id + 1,
+                Some(0)
+            ),
+            "EntityNotFound"
+        );
+
+        // current maintainer transfers the entity to the credential zero
+        assert_ok!(Permissions::set_entity_maintainer(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+            Some(1),
+            entity_id,
+            Some(0)
+        ));
+        assert_eq!(
+            Permissions::entity_maintainer_by_entity_id(entity_id),
+            Some(0)
+        );
+
+        // previous maintainer cannot update the entity or transfer it back anymore
+        assert_err!(
+            Permissions::add_schema_support_to_entity(
+                Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+                Some(1),
+                true,
+                entity_id,
+                0,
+                simple_test_entity_property_values()
+            ),
+            "NotEnityMaintainer"
+        );
+        assert_err!(
+            Permissions::set_entity_maintainer(
+                Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+                Some(1),
+                entity_id,
+                Some(1)
+            ),
+            "NotInAdminsSet"
+        );
+
+        // new maintainer can update the entity
+        assert_ok!(Permissions::add_schema_support_to_entity(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ZERO),
+            Some(0),
+            true,
+            entity_id,
+            0,
+            simple_test_entity_property_values()
+        ));
+
+        // root makes the entity maintained by the system
+        assert_ok!(Permissions::set_entity_maintainer(
+            Origin::ROOT,
+            None,
+            entity_id,
+            None
+        ));
+        assert!(!<EntityMaintainerByEntityId<Runtime>>::exists(entity_id));
+    })
+}
+
+fn assert_last_event(expected_event: Event<Runtime>) {
+    assert_eq!(
+        System::events().last().unwrap().event,
+        TestEvent::lib(expected_event)
+    );
+}
+
+#[test]
+fn set_entity_maintainer_by_class_admin() {
+    with_test_externalities(|| {
+        let (_, entity_id) = create_entity_maintained_by_credential_one();
+
+        // credential zero is in the class admins set
+        assert_ok!(Permissions::set_entity_maintainer(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ZERO),
+            Some(0),
+            entity_id,
+            Some(0)
+        ));
+        assert_eq!(
+            Permissions::entity_maintainer_by_entity_id(entity_id),
+            Some(0)
+        );
+        assert_last_event(RawEvent::EntityMaintainerUpdated(entity_id, Some(0)));
+        assert_eq!(Permissions::entities_by_maintainer(0), vec![entity_id]);
+        assert!(Permissions::entities_by_maintainer(1).is_empty());
+    })
+}
+
+#[test]
+fn transfer_maintained_entities() {
+    with_test_externalities(|| {
+        let (_, entity_id) = create_entity_maintained_by_credential_one();
+        assert_eq!(Permissions::entities_by_maintainer(1), vec![entity_id]);
+
+        Permissions::transfer_maintained_entities(1, 2);
+
+        assert_eq!(
+            Permissions::entity_maintainer_by_entity_id(entity_id),
+            Some(2)
+        );
+        assert_eq!(Permissions::entities_by_maintainer(2), vec![entity_id]);
+        assert!(!<EntitiesByMaintainer<Runtime>>::exists(1));
+        assert_last_event(RawEvent::EntityMaintainerUpdated(entity_id, Some(2)));
+    })
+}
+
+#[test]
+fn entity_permissions_override() {
+    with_test_externalities(|| {
+        let (_, entity_id) = create_entity_maintained_by_credential_one();
+
+        let entity_permissions = EntityPermissions {
+            update: CredentialSet::from(vec![0]),
+            maintainer_has_all_permissions: false,
+            maintainer_can_delete: false,
+        };
+
+        // only root and class admins can override entity permissions
+        assert_err!(
+            Permissions::set_entity_permissions_override(
+                Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+                Some(1),
+                entity_id,
+                Some(entity_permissions.clone())
+            ),
+            "NotInAdminsSet"
+        );
+        assert_ok!(Permissions::set_entity_permissions_override(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ZERO),
+            Some(0),
+            entity_id,
+            Some(entity_permissions.clone())
+        ));
+        assert_eq!(
+            Permissions::entity_permissions_override_by_entity_id(entity_id),
+            Some(entity_permissions.clone())
+        );
+        assert_last_event(RawEvent::EntityPermissionsOverrideUpdated(
+            entity_id,
+            Some(entity_permissions),
+        ));
+
+        // maintainer lost its permissions, while credential zero can update the entity
+        assert_err!(
+            Permissions::add_schema_support_to_entity(
+                Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+                Some(1),
+                true,
+                entity_id,
+                0,
+                simple_test_entity_property_values()
+            ),
+            "MaintainerNotGivenAllPermissions"
+        );
+        assert_ok!(Permissions::add_schema_support_to_entity(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ZERO),
+            Some(0),
+            false,
+            entity_id,
+            0,
+            simple_test_entity_property_values()
+        ));
+
+        // removing the override restores the class level entity permissions
+        assert_ok!(Permissions::set_entity_permissions_override(
+            Origin::ROOT,
+            None,
+            entity_id,
+            None
+        ));
+        assert!(!<EntityPermissionsOverrideByEntityId<Runtime>>::exists(
+            entity_id
+        ));
+        assert_last_event(RawEvent::EntityPermissionsOverrideUpdated(entity_id, None));
+        assert_ok!(Permissions::update_entity_property_values(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+            Some(1),
+            true,
+            entity_id,
+            simple_test_entity_property_values()
+        ));
+    })
+}
+
 #[test]
 fn batch_transaction_simple() {
     with_test_externalities(|| {
@@ -502,6 +797,7 @@ fn batch_transaction_simple() {
             required: true,
             name: b"entity".to_vec(),
             description: b"another entity of same class".to_vec(),
+            unique: false,
         }];
 
         assert_ok!(Permissions::add_class_schema(
@@ -564,8 +860,10 @@ fn batch_transaction_simple() {
         ));
 
         // two entities created
-        assert!(versioned_store::EntityById::exists(entity_id));
-        assert!(versioned_store::EntityById::exists(entity_id + 1));
+        assert!(<versioned_store::EntityById<Runtime>>::exists(entity_id));
+        assert!(<versioned_store::EntityById<Runtime>>::exists(
+            entity_id + 1
+        ));
     })
 }
 
@@ -586,6 +884,7 @@ fn batch_transaction_vector_of_entities() {
             required: true,
             name: b"entities".to_vec(),
             description: b"vector of entities of same class".to_vec(),
+            unique: false,
         }];
 
         assert_ok!(Permissions::add_class_schema(
@@ -645,12 +944,16 @@ fn batch_transaction_vector_of_entities() {
         ));
 
         // three entities created
-        assert!(versioned_store::EntityById::exists(entity_id));
-        assert!(versioned_store::EntityById::exists(entity_id + 1));
-        assert!(versioned_store::EntityById::exists(entity_id + 2));
+        assert!(<versioned_store::EntityById<Runtime>>::exists(entity_id));
+        assert!(<versioned_store::EntityById<Runtime>>::exists(
+            entity_id + 1
+        ));
+        assert!(<versioned_store::EntityById<Runtime>>::exists(
+            entity_id + 2
+        ));
 
         assert_eq!(
-            versioned_store::EntityById::get(entity_id),
+            <versioned_store::EntityById<Runtime>>::get(entity_id),
             versioned_store::Entity {
                 class_id: new_class_id,
                 id: entity_id,
@@ -663,3 +966,326 @@ fn batch_transaction_vector_of_entities() {
         );
     })
 }
+
+#[test]
+fn batch_transaction_is_rolled_back_on_failed_operation() {
+    with_test_externalities(|| {
+        let new_class_id = create_simple_class(ClassPermissions {
+            reference_constraint: ReferenceConstraint::NoConstraint,
+            ..Default::default()
+        });
+
+        let new_properties = vec![Property {
+            prop_type: PropertyType::Internal(new_class_id),
+            required: true,
+            name: b"entity".to_vec(),
+            description: b"another entity of same class".to_vec(),
+            unique: false,
+        }];
+
+        assert_ok!(Permissions::add_class_schema(
+            Origin::ROOT,
+            None,
+            new_class_id,
+            vec![],
+            new_properties
+        ));
+
+        let existing_entity_id = next_entity_id();
+        assert_ok!(Permissions::create_entity(Origin::ROOT, None, new_class_id));
+        assert_ok!(Permissions::add_schema_support_to_entity(
+            Origin::ROOT,
+            None,
+            false,
+            existing_entity_id,
+            0,
+            vec![ClassPropertyValue {
+                in_class_index: 0,
+                value: PropertyValue::Internal(existing_entity_id),
+            }]
+        ));
+        let existing_entity = <versioned_store::EntityById<Runtime>>::get(existing_entity_id);
+
+        let update_existing_entity = |value| Operation {
+            with_credential: None,
+            as_entity_maintainer: false,
+            operation_type: OperationType::UpdatePropertyValues(UpdatePropertyValuesOperation {
+                entity_id: ParameterizedEntity::ExistingEntity(existing_entity_id),
+                new_parametrized_property_values: vec![ParametrizedClassPropertyValue {
+                    in_class_index: 0,
+                    value,
+                }],
+            }),
+        };
+
+        let operations = vec![
+            Operation {
+                with_credential: None,
+                as_entity_maintainer: false,
+                operation_type: OperationType::CreateEntity(CreateEntityOperation {
+                    class_id: new_class_id,
+                }),
+            },
+            Operation {
+                with_credential: None,
+                as_entity_maintainer: false,
+                operation_type: OperationType::AddSchemaSupportToEntity(
+                    AddSchemaSupportToEntityOperation {
+                        entity_id: ParameterizedEntity::InternalEntityJustAdded(0),
+                        schema_id: 0,
+                        parametrized_property_values: vec![ParametrizedClassPropertyValue {
+                            in_class_index: 0,
+                            value: ParametrizedPropertyValue::InternalEntityJustAdded(0),
+                        }],
+                    },
+                ),
+            },
+            update_existing_entity(ParametrizedPropertyValue::InternalEntityJustAdded(0)),
+            // refers to an operation which didn't create an entity
+            update_existing_entity(ParametrizedPropertyValue::InternalEntityJustAdded(2)),
+        ];
+
+        let entity_id = next_entity_id();
+
+        assert_err!(
+            Permissions::transaction(Origin::ROOT, operations),
+            "EntityNotCreatedByOperation"
+        );
+
+        // created entity is removed and the existing entity is restored
+        assert!(!<versioned_store::EntityById<Runtime>>::exists(entity_id));
+        assert_eq!(next_entity_id(), entity_id);
+        assert_eq!(
+            <versioned_store::EntityById<Runtime>>::get(existing_entity_id),
+            existing_entity
+        );
+        assert!(!versioned_store::EntityReferencedBy::exists(entity_id));
+        assert_eq!(
+            versioned_store::EntityReferencedBy::get(existing_entity_id),
+            vec![existing_entity_id]
+        );
+    })
+}
+
+#[test]
+fn batch_transaction_rollback_removes_entity_maintainers() {
+    with_test_externalities(|| {
+        const CREDENTIAL_ONE: u64 = 1;
+
+        let new_class_id = create_simple_class(ClassPermissions {
+            entities_can_be_created: true,
+            create_entities: vec![CREDENTIAL_ONE].into(),
+            ..Default::default()
+        });
+
+        assert_ok!(Permissions::add_class_schema(
+            Origin::ROOT,
+            None,
+            new_class_id,
+            vec![],
+            simple_test_schema()
+        ));
+
+        let operations = vec![
+            Operation {
+                with_credential: Some(CREDENTIAL_ONE),
+                as_entity_maintainer: false,
+                operation_type: OperationType::CreateEntity(CreateEntityOperation {
+                    class_id: new_class_id,
+                }),
+            },
+            Operation {
+                with_credential: Some(CREDENTIAL_ONE),
+                as_entity_maintainer: true,
+                operation_type: OperationType::AddSchemaSupportToEntity(
+                    AddSchemaSupportToEntityOperation {
+                        entity_id: ParameterizedEntity::InternalEntityJustAdded(0),
+                        // unknown schema
+                        schema_id: 1,
+                        parametrized_property_values: vec![],
+                    },
+                ),
+            },
+        ];
+
+        let entity_id = next_entity_id();
+
+        assert!(Permissions::transaction(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+            operations
+        )
+        .is_err());
+
+        assert!(!<versioned_store::EntityById<Runtime>>::exists(entity_id));
+        assert!(!<EntityMaintainerByEntityId<Runtime>>::exists(entity_id));
+        assert_eq!(next_entity_id(), entity_id);
+    })
+}
+
+#[test]
+fn deprecate_class_schema_and_migrate_entities() {
+    with_test_externalities(|| {
+        let (class_id, entity_id) = create_entity_maintained_by_credential_one();
+
+        assert_ok!(Permissions::add_schema_support_to_entity(
+            Origin::ROOT,
+            None,
+            false,
+            entity_id,
+            0,
+            simple_test_entity_property_values()
+        ));
+
+        // new schema keeps the first property and adds a required one
+        assert_ok!(Permissions::add_class_schema(
+            Origin::ROOT,
+            None,
+            class_id,
+            vec![0],
+            vec![Property {
+                prop_type: PropertyType::Bool,
+                required: true,
+                name: b"field2".to_vec(),
+                description: b"Description field2".to_vec(),
+                unique: false,
+            }]
+        ));
+
+        // only credentials in the add schemas set can deprecate schemas
+        assert_err!(
+            Permissions::deprecate_class_schema(
+                Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+                Some(1),
+                class_id,
+                0
+            ),
+            "NotInAddSchemasSet"
+        );
+        assert_ok!(Permissions::deprecate_class_schema(
+            Origin::ROOT,
+            None,
+            class_id,
+            0
+        ));
+
+        let default_values = vec![ClassPropertyValue {
+            in_class_index: 1,
+            value: PropertyValue::Bool(true),
+        }];
+
+        // entity maintainer is not a class admin
+        assert_err!(
+            Permissions::migrate_entities(
+                Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+                Some(1),
+                class_id,
+                0,
+                1,
+                vec![entity_id],
+                default_values.clone()
+            ),
+            "NotInAdminsSet"
+        );
+
+        assert_ok!(Permissions::migrate_entities(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ZERO),
+            Some(0),
+            class_id,
+            0,
+            1,
+            vec![entity_id],
+            default_values.clone()
+        ));
+
+        let entity = <versioned_store::Module<Runtime>>::entity_by_id(entity_id);
+        assert_eq!(entity.in_class_schema_indexes, vec![1]);
+        assert_eq!(
+            entity.values,
+            [simple_test_entity_property_values(), default_values].concat()
+        );
+    })
+}
+
+#[test]
+fn class_permissions_changes_emit_events() {
+    with_test_externalities(|| {
+        let class_permissions = class_permissions_minimal();
+        let class_id = create_simple_class(class_permissions.clone());
+        assert_last_event(RawEvent::ClassPermissionsCreated(
+            class_id,
+            class_permissions,
+        ));
+
+        let admins = CredentialSet::from(vec![0]);
+        assert_ok!(Permissions::set_class_admins(
+            Origin::ROOT,
+            class_id,
+            admins.clone()
+        ));
+        assert_last_event(RawEvent::ClassAdminsUpdated(class_id, admins));
+
+        let create_entities_set = CredentialSet::from(vec![1]);
+        assert_ok!(Permissions::set_class_create_entities_set(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ZERO),
+            Some(0),
+            class_id,
+            create_entities_set.clone()
+        ));
+        assert_last_event(RawEvent::ClassCreateEntitiesSetUpdated(
+            class_id,
+            create_entities_set,
+        ));
+
+        assert_ok!(Permissions::set_class_entities_can_be_created(
+            Origin::ROOT,
+            None,
+            class_id,
+            true
+        ));
+        assert_last_event(RawEvent::ClassEntitiesCanBeCreatedUpdated(class_id, true));
+
+        assert_ok!(Permissions::set_class_reference_constraint(
+            Origin::ROOT,
+            None,
+            class_id,
+            ReferenceConstraint::NoConstraint
+        ));
+        assert_last_event(RawEvent::ClassReferenceConstraintUpdated(
+            class_id,
+            ReferenceConstraint::NoConstraint,
+        ));
+
+        let entity_id = next_entity_id();
+        assert_ok!(Permissions::create_entity(
+            Origin::signed(MEMBER_ONE_WITH_CREDENTIAL_ONE),
+            Some(1),
+            class_id
+        ));
+        assert_last_event(RawEvent::EntityCreated(entity_id, Some(1)));
+
+        assert_ok!(Permissions::set_entity_maintainer(
+            Origin::ROOT,
+            None,
+            entity_id,
+            None
+        ));
+        assert_last_event(RawEvent::EntityMaintainerUpdated(entity_id, None));
+    })
+}
+
+#[test]
+fn batch_transaction_emits_completed_event() {
+    with_test_externalities(|| {
+        let class_id = create_simple_class_with_default_permissions();
+
+        assert_ok!(Permissions::transaction(
+            Origin::ROOT,
+            vec![Operation {
+                with_credential: None,
+                as_entity_maintainer: false,
+                operation_type: OperationType::CreateEntity(CreateEntityOperation { class_id }),
+            }]
+        ));
+        assert_last_event(RawEvent::TransactionCompleted(1));
+    })
+}
@@ -30,6 +30,7 @@ fn create_podcast_class_schema() {
                 required: true,
                 name: b"atom:link".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 1
             Property {
@@ -37,6 +38,7 @@ fn create_podcast_class_schema() {
                 required: true,
                 name: b"title".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 2
             Property {
@@ -44,6 +46,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"pubDate".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 3
             Property {
@@ -51,6 +54,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"lastBuildDate".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 4
             Property {
@@ -58,6 +62,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"generator".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 5
             Property {
@@ -65,6 +70,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"link".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 6
             // Example: en-us
@@ -73,6 +79,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"language".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 7
             Property {
@@ -80,6 +87,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"copyright".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 8
             Property {
@@ -87,6 +95,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"docs".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 9
             Property {
@@ -94,6 +103,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"managingEditor".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 10
             Property {
@@ -101,6 +111,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"image/url".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 11
             Property {
@@ -108,6 +119,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"image/title".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 12
             Property {
@@ -115,6 +127,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"image/link".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 13
             Property {
@@ -122,6 +135,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:summary".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 14
             // TODO this could be Internal prop.
@@ -130,6 +144,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:author".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 15
             // TODO make this as a text vec?
@@ -138,6 +153,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:keywords".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 16
             Property {
@@ -145,6 +161,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:category".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 17
             Property {
@@ -152,6 +169,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:image".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 18
             Property {
@@ -159,6 +177,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:explicit".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 19
             Property {
@@ -166,6 +185,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:owner/itunes:name".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 20
             Property {
@@ -173,6 +193,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:owner/itunes:email".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 21
             Property {
@@ -180,6 +201,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"description".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 22
             Property {
@@ -187,6 +209,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:subtitle".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 23
             Property {
@@ -194,6 +217,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:type".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
         ];
 
@@ -207,6 +231,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"title".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 1
             Property {
@@ -214,6 +239,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:title".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 2
             Property {
@@ -221,6 +247,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"pubDate".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 3
             Property {
@@ -228,6 +255,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"guid".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 4
             Property {
@@ -235,6 +263,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"link".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 5
             Property {
@@ -242,6 +271,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:image".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 6
             Property {
@@ -249,6 +279,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"description".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 7
             Property {
@@ -256,6 +287,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"content:encoded".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 8
             Property {
@@ -263,6 +295,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"enclosure/length".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 9
             Property {
@@ -270,6 +303,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"enclosure/type".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 10
             Property {
@@ -277,6 +311,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"enclosure/url".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 11
             Property {
@@ -284,6 +319,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:duration".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 12
             Property {
@@ -291,6 +327,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:explicit".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 13
             // TODO make this as a text vec?
@@ -299,6 +336,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:keywords".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 14
             Property {
@@ -306,6 +344,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:subtitle".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 15
             Property {
@@ -313,6 +352,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:summary".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 16
             Property {
@@ -320,6 +360,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:season".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 17
             Property {
@@ -327,6 +368,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:episode".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 18
             Property {
@@ -334,6 +376,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:episodeType".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
             // 19
             // TODO this could be Internal prop.
@@ -342,6 +385,7 @@ fn create_podcast_class_schema() {
                 required: false,
                 name: b"itunes:author".to_vec(),
                 description: b"".to_vec(),
+                unique: false,
             },
         ];
 
@@ -513,7 +557,7 @@ impl PropHelper {
         PropHelper { prop_idx: 0 }
     }
 
-    fn next_value(&mut self, value: PropertyValue) -> ClassPropertyValue {
+    fn next_value(&mut self, value: PropertyValueOf<Runtime>) -> ClassPropertyValueOf<Runtime> {
         let value = ClassPropertyValue {
             in_class_index: self.prop_idx,
             value,
@@ -522,7 +566,7 @@ impl PropHelper {
         value
     }
 
-    fn next_text_value(&mut self, text: Vec<u8>) -> ClassPropertyValue {
+    fn next_text_value(&mut self, text: Vec<u8>) -> ClassPropertyValueOf<Runtime> {
         self.next_value(PropertyValue::Text(text))
     }
 }
@@ -14,10 +14,11 @@
 #[cfg(feature = "std")]
 use serde_derive::{Deserialize, Serialize};
 
-use codec::{Decode, Encode};
+use codec::{Codec, Decode, Encode};
 use rstd::collections::btree_set::BTreeSet;
 use rstd::prelude::*;
-use srml_support::{decl_event, decl_module, decl_storage, dispatch, ensure};
+use runtime_primitives::traits::{BlakeTwo256, Hash, MaybeSerializeDeserialize, Member};
+use srml_support::{decl_event, decl_module, decl_storage, dispatch, ensure, Parameter};
 
 mod example;
 mod mock;
@@ -48,7 +49,13 @@ const ERROR_CLASS_SCHEMA_REFERS_UNKNOWN_INTERNAL_ID: &str =
 const ERROR_NO_PROPS_IN_CLASS_SCHEMA: &str =
     "Cannot add a class schema with an empty list of properties";
 const ERROR_ENTITY_NOT_FOUND: &str = "Entity was not found by id";
-// const ERROR_ENTITY_ALREADY_DELETED: &str = "Entity is already deleted";
+const ERROR_ENTITY_IS_REFERENCED: &str = "Entity is referenced by other entities";
+const ERROR_REQUIRED_REFERENCE_CANNOT_BE_CLEARED: &str =
+    "Entity is referenced by a required property of another entity";
+const ERROR_CLASS_SCHEMA_IS_DEPRECATED: &str = "Class schema is deprecated";
+const ERROR_CLASS_SCHEMA_ALREADY_DEPRECATED: &str = "Class schema is already deprecated";
+const ERROR_ENTITY_CLASS_MISMATCH: &str = "Entity does not belong to the class";
+const ERROR_ENTITY_DOES_NOT_SUPPORT_SCHEMA: &str = "Entity does not support the class schema";
 const ERROR_SCHEMA_ALREADY_ADDED_TO_ENTITY: &str =
     "Cannot add a schema that is already added to this entity";
 const ERROR_PROP_VALUE_DONT_MATCH_TYPE: &str =
@@ -61,6 +68,23 @@ const ERROR_TEXT_PROP_IS_TOO_LONG: &str = "Text propery is too long";
 const ERROR_VEC_PROP_IS_TOO_LONG: &str = "Vector propery is too long";
 const ERROR_INTERNAL_RPOP_DOES_NOT_MATCH_ITS_CLASS: &str =
     "Internal property does not match its class";
+const ERROR_FIXED_BYTES_PROP_HAS_ZERO_LEN: &str = "Fixed bytes property length cannot be zero";
+const ERROR_FIXED_BYTES_PROP_LEN_MISMATCH: &str =
+    "Fixed bytes property value length does not match its type";
+const ERROR_ENUM_PROP_HAS_NO_VARIANTS: &str = "Enum property has no variants";
+const ERROR_ENUM_PROP_HAS_TOO_MANY_VARIANTS: &str = "Enum property has too many variants";
+const ERROR_ENUM_VARIANT_NAME_INVALID: &str = "Enum variant name is empty or too long";
+const ERROR_ENUM_VARIANT_NAMES_NOT_UNIQUE: &str = "Enum variant names are not unique";
+const ERROR_UNKNOWN_ENUM_VARIANT: &str = "Enum property value refers to unknown variant";
+const ERROR_DATA_OBJECT_NOT_FOUND: &str = "Data object was not found by content id";
+const ERROR_PROP_VALUE_NOT_UNIQUE: &str =
+    "Property value is already used by another entity of the class";
+
+/// Max number of the named variants of the Enum property type.
+pub const MAX_ENUM_VARIANTS: usize = 256;
+
+/// Max length of the name of the Enum property type variant.
+pub const MAX_ENUM_VARIANT_NAME_LENGTH: usize = 64;
 
 /// Length constraint for input validation
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -121,7 +145,7 @@ pub struct Class {
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
-pub struct Entity {
+pub struct Entity<ContentId> {
     pub id: EntityId,
 
     /// The class id of this entity.
@@ -134,8 +158,26 @@ pub struct Entity {
 
     /// Values for properties on class that are used by some schema used by this entity!
     /// Length is no more than Class.properties.
-    pub values: Vec<ClassPropertyValue>,
-    // pub deleted: bool,
+    pub values: Vec<ClassPropertyValue<ContentId>>,
+}
+
+/// Defines how references from other entities are handled on the entity deletion.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EntityDeletionMode {
+    /// Deletion fails if any other entity references the entity.
+    FailIfReferenced,
+
+    /// Internal references to the entity are set to None and the entity is removed from
+    /// the internal vectors of the referencing entities. Deletion fails if the entity is
+    /// referenced by a required Internal property.
+    ClearReferences,
+}
+
+impl Default for EntityDeletionMode {
+    fn default() -> Self {
+        EntityDeletionMode::FailIfReferenced
+    }
 }
 
 /// A schema defines what properties describe an entity
@@ -144,6 +186,10 @@ pub struct Entity {
 pub struct ClassSchema {
     /// Indices into properties vector for the corresponding class.
     pub properties: Vec<u16>,
+
+    /// Deprecated schemas cannot be added to entities anymore.
+    /// Entities can be migrated from them to the newer schemas.
+    pub deprecated: bool,
 }
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -153,6 +199,10 @@ pub struct Property {
     pub required: bool,
     pub name: Vec<u8>,
     pub description: Vec<u8>,
+
+    /// Values of the unique property cannot be shared by the entities of the class.
+    /// Entities can be looked up by the values of their unique properties.
+    pub unique: bool,
 }
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -189,8 +239,18 @@ pub enum PropertyType {
     /// The second ClassId value tells that an every element of this vector
     /// should be of a specific ClassId.
     InternalVec(u16, ClassId),
-    // External(ExternalProperty),
-    // ExternalVec(u16, ExternalProperty),
+
+    /// Reference to a data object known to the data directory.
+    DataObject,
+
+    /// Byte array of the exact length, e.g. a hash. The u16 value is the length of the array.
+    FixedBytes(u16),
+
+    /// Index of one of the named variants.
+    Enum(Vec<Vec<u8>>),
+
+    /// Unix timestamp in milliseconds.
+    Timestamp,
 }
 
 impl Default for PropertyType {
@@ -201,7 +261,7 @@ impl Default for PropertyType {
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
-pub enum PropertyValue {
+pub enum PropertyValue<ContentId> {
     None,
 
     // Single value:
@@ -225,11 +285,15 @@ pub enum PropertyValue {
     Int64Vec(Vec<i64>),
     TextVec(Vec<Vec<u8>>),
     InternalVec(Vec<EntityId>),
-    // External(ExternalPropertyType),
-    // ExternalVec(Vec<ExternalPropertyType>),
+
+    // Other values:
+    DataObject(ContentId),
+    FixedBytes(Vec<u8>),
+    Enum(u16),
+    Timestamp(u64),
 }
 
-impl Default for PropertyValue {
+impl<ContentId> Default for PropertyValue<ContentId> {
     fn default() -> Self {
         PropertyValue::None
     }
@@ -237,16 +301,100 @@ impl Default for PropertyValue {
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
-pub struct ClassPropertyValue {
+pub struct ClassPropertyValue<ContentId> {
     /// Index is into properties vector of class.
     pub in_class_index: u16,
 
     /// Value of property with index `in_class_index` in a given class.
-    pub value: PropertyValue,
+    pub value: PropertyValue<ContentId>,
+}
+
+/// Alias for the Entity with the content id type of the module.
+pub type EntityOf<T> = Entity<<T as Trait>::ContentId>;
+
+/// Alias for the PropertyValue with the content id type of the module.
+pub type PropertyValueOf<T> = PropertyValue<<T as Trait>::ContentId>;
+
+/// Alias for the ClassPropertyValue with the content id type of the module.
+pub type ClassPropertyValueOf<T> = ClassPropertyValue<<T as Trait>::ContentId>;
+
+/// Returns ids of the entities referenced by the Internal and InternalVec property values.
+pub fn referenced_entity_ids<ContentId>(
+    values: &[ClassPropertyValue<ContentId>],
+) -> BTreeSet<EntityId> {
+    let mut entity_ids = BTreeSet::new();
+
+    for property_value in values.iter() {
+        match property_value.value {
+            PV::Internal(entity_id) => {
+                entity_ids.insert(entity_id);
+            }
+            PV::InternalVec(ref vec) => entity_ids.extend(vec.iter().cloned()),
+            _ => (),
+        }
+    }
+
+    entity_ids
+}
+
+/// Hash of the property value. Used in the unique property values index.
+pub type PropertyValueHash = <BlakeTwo256 as Hash>::Output;
+
+/// Key of the unique property values index: class id, in-class property index
+/// and the property value hash.
+pub type UniqueValueKey = (ClassId, u16, PropertyValueHash);
+
+pub fn property_value_hash<ContentId: Encode>(
+    value: &PropertyValue<ContentId>,
+) -> PropertyValueHash {
+    BlakeTwo256::hash_of(value)
+}
+
+/// Returns the unique property values index keys of the entity property values.
+/// None values are not indexed.
+pub fn unique_value_keys<ContentId: Encode + PartialEq>(
+    class: &Class,
+    values: &[ClassPropertyValue<ContentId>],
+) -> Vec<UniqueValueKey> {
+    values
+        .iter()
+        .filter(|property_value| property_value.value != PV::None)
+        .filter(|property_value| {
+            class
+                .properties
+                .get(property_value.in_class_index as usize)
+                .map_or(false, |prop| prop.unique)
+        })
+        .map(|property_value| {
+            (
+                class.id,
+                property_value.in_class_index,
+                property_value_hash(&property_value.value),
+            )
+        })
+        .collect()
+}
+
+/// Checks the data objects referenced by the entities.
+pub trait DataObjectChecker<T: Trait> {
+    fn data_object_exists(content_id: &T::ContentId) -> bool;
+}
+
+/// An implementation where no data object exists. Effectively
+/// no DataObject property value can be set.
+impl<T: Trait> DataObjectChecker<T> for () {
+    fn data_object_exists(_content_id: &T::ContentId) -> bool {
+        false
+    }
 }
 
 pub trait Trait: system::Trait + Sized {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+    /// Content id of the data object referenced by the DataObject property values.
+    type ContentId: Parameter + Member + Codec + Default + Copy + MaybeSerializeDeserialize;
+
+    type DataObjectChecker: DataObjectChecker<Self>;
 }
 
 decl_storage! {
@@ -255,7 +403,14 @@ decl_storage! {
 
         pub ClassById get(class_by_id) config(): map ClassId => Class;
 
-        pub EntityById get(entity_by_id) config(): map EntityId => Entity;
+        pub EntityById get(entity_by_id) config(): map EntityId => EntityOf<T>;
+
+        /// Ids of the entities which reference the entity by the Internal or InternalVec property values.
+        pub EntityReferencedBy get(entity_referenced_by): map EntityId => Vec<EntityId>;
+
+        /// Entity ids by the values of the unique class properties.
+        pub EntityIdByUniqueValue get(entity_id_by_unique_value):
+            map UniqueValueKey => Option<EntityId>;
 
         pub NextClassId get(next_class_id) config(): ClassId;
 
@@ -273,6 +428,25 @@ decl_storage! {
         pub ClassDescriptionConstraint get(class_description_constraint)
             config(): InputValidationLengthConstraint;
     }
+    add_extra_genesis {
+        build(|config: &GenesisConfig<T>| {
+            for (entity_id, entity) in config.entity_by_id.iter() {
+                for referenced_entity_id in referenced_entity_ids(&entity.values) {
+                    EntityReferencedBy::mutate(referenced_entity_id, |ids| ids.push(*entity_id));
+                }
+
+                let class = config
+                    .class_by_id
+                    .iter()
+                    .find(|(class_id, _)| *class_id == entity.class_id);
+                if let Some((_, class)) = class {
+                    for key in unique_value_keys(class, &entity.values) {
+                        EntityIdByUniqueValue::insert(key, *entity_id);
+                    }
+                }
+            }
+        });
+    }
 }
 
 decl_event!(
@@ -282,12 +456,19 @@ decl_event!(
     {
         ClassCreated(ClassId),
         ClassSchemaAdded(ClassId, u16),
+        ClassSchemaDeprecated(ClassId, u16),
 
         EntityCreated(EntityId),
-        // EntityDeleted(EntityId),
+        EntityDeleted(EntityId),
         EntityPropertiesUpdated(EntityId),
         EntitySchemaAdded(EntityId, u16),
 
+        /// Entity was migrated from one class schema to another. Params:
+        /// - Id of the entity.
+        /// - Index of the previous class schema.
+        /// - Index of the new class schema.
+        EntityMigrated(EntityId, u16, u16),
+
         /// This is a fake event that uses AccountId type just to make Rust compiler happy to compile this module.
         FixCompilation(AccountId),
     }
@@ -355,6 +536,7 @@ impl<T: Trait> Module<T> {
         for prop in new_properties.iter() {
             Self::ensure_property_name_is_valid(&prop.name)?;
             Self::ensure_property_description_is_valid(&prop.description)?;
+            Self::ensure_property_type_is_valid(&prop.prop_type)?;
 
             // Check that the name of a new property is unique within its class.
             ensure!(
@@ -389,6 +571,7 @@ impl<T: Trait> Module<T> {
 
         let mut schema = ClassSchema {
             properties: existing_properties,
+            deprecated: false,
         };
 
         let mut updated_class_props = class.properties;
@@ -407,6 +590,114 @@ impl<T: Trait> Module<T> {
         Ok(schema_idx)
     }
 
+    pub fn deprecate_class_schema(class_id: ClassId, schema_id: u16) -> dispatch::Result {
+        Self::ensure_known_class_id(class_id)?;
+
+        let class = ClassById::get(class_id);
+        let schema = class
+            .schemas
+            .get(schema_id as usize)
+            .ok_or(ERROR_UNKNOWN_CLASS_SCHEMA_ID)?;
+        ensure!(!schema.deprecated, ERROR_CLASS_SCHEMA_ALREADY_DEPRECATED);
+
+        ClassById::mutate(class_id, |class| {
+            class.schemas[schema_id as usize].deprecated = true;
+        });
+
+        Self::deposit_event(RawEvent::ClassSchemaDeprecated(class_id, schema_id));
+        Ok(())
+    }
+
+    /// Moves the entities of the class from one class schema to another. Entity values of the
+    /// properties not used by the new schema (or other schemas supported by the entity) are dropped.
+    /// Missing values of the new schema properties are taken from the provided default values,
+    /// or set to None if the property is not required.
+    /// Either all entities are migrated or none of them.
+    pub fn migrate_entities(
+        class_id: ClassId,
+        from_schema_id: u16,
+        to_schema_id: u16,
+        entity_ids: Vec<EntityId>,
+        default_values: Vec<ClassPropertyValueOf<T>>,
+    ) -> dispatch::Result {
+        Self::ensure_known_class_id(class_id)?;
+
+        let class = ClassById::get(class_id);
+
+        let from_schema_known = from_schema_id < class.schemas.len() as u16;
+        ensure!(from_schema_known, ERROR_UNKNOWN_CLASS_SCHEMA_ID);
+
+        let to_schema = class
+            .schemas
+            .get(to_schema_id as usize)
+            .ok_or(ERROR_UNKNOWN_CLASS_SCHEMA_ID)?;
+        ensure!(!to_schema.deprecated, ERROR_CLASS_SCHEMA_IS_DEPRECATED);
+
+        // Validate the default values against the target schema properties once for all entities.
+        for default_value in default_values.iter() {
+            let class_prop = class
+                .properties
+                .get(default_value.in_class_index as usize)
+                .ok_or(ERROR_UNKNOWN_ENTITY_PROP_ID)?;
+            Self::ensure_property_value_is_valid(default_value.value.clone(), class_prop.clone())?;
+        }
+
+        // Compute the migrated entities first, so no storage is mutated if any of them is invalid.
+        let mut migrated_entities = Vec::with_capacity(entity_ids.len());
+        // Unique values of the migrated entities, so they cannot share the default values.
+        let mut migrated_unique_value_keys = BTreeSet::new();
+        for entity_id in entity_ids {
+            Self::ensure_known_entity_id(entity_id)?;
+
+            let entity = <EntityById<T>>::get(entity_id);
+            ensure!(entity.class_id == class_id, ERROR_ENTITY_CLASS_MISMATCH);
+            ensure!(
+                entity.in_class_schema_indexes.contains(&from_schema_id),
+                ERROR_ENTITY_DOES_NOT_SUPPORT_SCHEMA
+            );
+
+            let migrated_entity = Self::migrate_entity(
+                entity,
+                &class,
+                from_schema_id,
+                to_schema_id,
+                &default_values,
+            )?;
+
+            Self::ensure_unique_values_available(entity_id, &class, &migrated_entity.values)?;
+            for key in unique_value_keys(&class, &migrated_entity.values) {
+                ensure!(
+                    migrated_unique_value_keys.insert(key),
+                    ERROR_PROP_VALUE_NOT_UNIQUE
+                );
+            }
+
+            migrated_entities.push(migrated_entity);
+        }
+
+        for migrated_entity in migrated_entities {
+            let entity_id = migrated_entity.id;
+            let current_values = <EntityById<T>>::get(entity_id).values;
+
+            Self::update_entity_references(entity_id, &current_values, &migrated_entity.values);
+            Self::update_unique_value_index(
+                entity_id,
+                &class,
+                &current_values,
+                &migrated_entity.values,
+            );
+            <EntityById<T>>::insert(entity_id, migrated_entity);
+
+            Self::deposit_event(RawEvent::EntityMigrated(
+                entity_id,
+                from_schema_id,
+                to_schema_id,
+            ));
+        }
+
+        Ok(())
+    }
+
     pub fn create_entity(class_id: ClassId) -> Result<EntityId, &'static str> {
         Self::ensure_known_class_id(class_id)?;
 
@@ -417,11 +708,10 @@ impl<T: Trait> Module<T> {
             class_id,
             in_class_schema_indexes: vec![],
             values: vec![],
-            // deleted: false,
         };
 
         // Save newly created entity:
-        EntityById::insert(entity_id, new_entity);
+        <EntityById<T>>::insert(entity_id, new_entity);
 
         // Increment the next entity id:
         NextEntityId::mutate(|n| *n += 1);
@@ -433,7 +723,7 @@ impl<T: Trait> Module<T> {
     pub fn add_schema_support_to_entity(
         entity_id: EntityId,
         schema_id: u16,
-        property_values: Vec<ClassPropertyValue>,
+        property_values: Vec<ClassPropertyValueOf<T>>,
     ) -> dispatch::Result {
         Self::ensure_known_entity_id(entity_id)?;
 
@@ -443,6 +733,9 @@ impl<T: Trait> Module<T> {
         let known_schema_id = schema_id < class.schemas.len() as u16;
         ensure!(known_schema_id, ERROR_UNKNOWN_CLASS_SCHEMA_ID);
 
+        let schema_deprecated = class.schemas[schema_id as usize].deprecated;
+        ensure!(!schema_deprecated, ERROR_CLASS_SCHEMA_IS_DEPRECATED);
+
         // Check that schema id is not yet added to this entity:
         let schema_not_added = entity
             .in_class_schema_indexes
@@ -504,7 +797,17 @@ impl<T: Trait> Module<T> {
             }
         }
 
-        EntityById::mutate(entity_id, |entity| {
+        Self::ensure_unique_values_available(entity_id, &class, &appended_entity_values)?;
+
+        Self::update_entity_references(entity_id, &current_entity_values, &appended_entity_values);
+        Self::update_unique_value_index(
+            entity_id,
+            &class,
+            &current_entity_values,
+            &appended_entity_values,
+        );
+
+        <EntityById<T>>::mutate(entity_id, |entity| {
             // Add a new schema to the list of schemas supported by this entity.
             entity.in_class_schema_indexes.push(schema_id);
 
@@ -520,7 +823,7 @@ impl<T: Trait> Module<T> {
 
     pub fn update_entity_property_values(
         entity_id: EntityId,
-        new_property_values: Vec<ClassPropertyValue>,
+        new_property_values: Vec<ClassPropertyValueOf<T>>,
     ) -> dispatch::Result {
         Self::ensure_known_entity_id(entity_id)?;
 
@@ -528,6 +831,7 @@ impl<T: Trait> Module<T> {
 
         // Get current property values of an entity as a mutable vector,
         // so we can update them if new values provided present in new_property_values.
+        let current_values = entity.values.clone();
         let mut updated_values = entity.values;
         let mut updates_count = 0;
 
@@ -570,7 +874,12 @@ impl<T: Trait> Module<T> {
 
         // If at least one of the entity property values should be update:
         if updates_count > 0 {
-            EntityById::mutate(entity_id, |entity| {
+            Self::ensure_unique_values_available(entity_id, &class, &updated_values)?;
+
+            Self::update_entity_references(entity_id, &current_values, &updated_values);
+            Self::update_unique_value_index(entity_id, &class, &current_values, &updated_values);
+
+            <EntityById<T>>::mutate(entity_id, |entity| {
                 entity.values = updated_values;
             });
             Self::deposit_event(RawEvent::EntityPropertiesUpdated(entity_id));
@@ -579,20 +888,85 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
-    // Commented out for now <- requested by Bedeho.
-    // pub fn delete_entity(entity_id: EntityId) -> dispatch::Result {
-    //     Self::ensure_known_entity_id(entity_id)?;
+    pub fn delete_entity(entity_id: EntityId, mode: EntityDeletionMode) -> dispatch::Result {
+        Self::ensure_known_entity_id(entity_id)?;
 
-    //     let is_deleted = EntityById::get(entity_id).deleted;
-    //     ensure!(!is_deleted, ERROR_ENTITY_ALREADY_DELETED);
+        // Self references don't prevent the deletion.
+        let referencing_entity_ids: Vec<EntityId> = Self::entity_referenced_by(entity_id)
+            .into_iter()
+            .filter(|id| *id != entity_id)
+            .collect();
 
-    //     EntityById::mutate(entity_id, |x| {
-    //         x.deleted = true;
-    //     });
+        // Compute the updated property values of the referencing entities first,
+        // so that no storage is mutated if any of them cannot be updated.
+        let mut cleared_entities = Vec::with_capacity(referencing_entity_ids.len());
+        for referencing_entity_id in referencing_entity_ids {
+            ensure!(
+                mode == EntityDeletionMode::ClearReferences,
+                ERROR_ENTITY_IS_REFERENCED
+            );
 
-    //     Self::deposit_event(RawEvent::EntityDeleted(entity_id));
-    //     Ok(())
-    // }
+            let (referencing_entity, class) = Self::get_entity_and_class(referencing_entity_id);
+            let cleared_values =
+                Self::clear_references(entity_id, referencing_entity.values.clone(), &class)?;
+            Self::ensure_unique_values_available(referencing_entity_id, &class, &cleared_values)?;
+            cleared_entities.push((referencing_entity, class, cleared_values));
+        }
+
+        for (referencing_entity, class, cleared_values) in cleared_entities {
+            let referencing_entity_id = referencing_entity.id;
+            Self::update_unique_value_index(
+                referencing_entity_id,
+                &class,
+                &referencing_entity.values,
+                &cleared_values,
+            );
+            <EntityById<T>>::mutate(referencing_entity_id, |entity| {
+                entity.values = cleared_values;
+            });
+            Self::deposit_event(RawEvent::EntityPropertiesUpdated(referencing_entity_id));
+        }
+
+        let entity = <EntityById<T>>::take(entity_id);
+        Self::update_entity_references(entity_id, &entity.values, &[]);
+        Self::update_unique_value_index(
+            entity_id,
+            &ClassById::get(entity.class_id),
+            &entity.values,
+            &[],
+        );
+        EntityReferencedBy::remove(entity_id);
+
+        Self::deposit_event(RawEvent::EntityDeleted(entity_id));
+        Ok(())
+    }
+
+    /// Restores the entity to the provided state or removes it if the state is None, keeping
+    /// the entity references consistent. No events are deposited. Used to roll back partially
+    /// applied batches of entity operations.
+    pub fn restore_entity(entity_id: EntityId, entity: Option<EntityOf<T>>) {
+        let current_entity = if <EntityById<T>>::exists(entity_id) {
+            Some(<EntityById<T>>::get(entity_id))
+        } else {
+            None
+        };
+
+        if let Some(ref current_entity) = current_entity {
+            let class = ClassById::get(current_entity.class_id);
+            Self::update_entity_references(entity_id, &current_entity.values, &[]);
+            Self::update_unique_value_index(entity_id, &class, &current_entity.values, &[]);
+        }
+
+        match entity {
+            Some(entity) => {
+                let class = ClassById::get(entity.class_id);
+                Self::update_entity_references(entity_id, &[], &entity.values);
+                Self::update_unique_value_index(entity_id, &class, &[], &entity.values);
+                <EntityById<T>>::insert(entity_id, entity);
+            }
+            None => <EntityById<T>>::remove(entity_id),
+        }
+    }
 
     // Helper functions:
     // ----------------------------------------------------------------
@@ -603,11 +977,14 @@ impl<T: Trait> Module<T> {
     }
 
     pub fn ensure_known_entity_id(entity_id: EntityId) -> dispatch::Result {
-        ensure!(EntityById::exists(entity_id), ERROR_ENTITY_NOT_FOUND);
+        ensure!(<EntityById<T>>::exists(entity_id), ERROR_ENTITY_NOT_FOUND);
         Ok(())
     }
 
-    pub fn ensure_valid_internal_prop(value: PropertyValue, prop: Property) -> dispatch::Result {
+    pub fn ensure_valid_internal_prop(
+        value: PropertyValueOf<T>,
+        prop: Property,
+    ) -> dispatch::Result {
         match (value, prop.prop_type) {
             (PV::Internal(entity_id), PT::Internal(class_id)) => {
                 Self::ensure_known_class_id(class_id)?;
@@ -623,32 +1000,283 @@ impl<T: Trait> Module<T> {
         }
     }
 
-    pub fn is_unknown_internal_entity_id(id: PropertyValue) -> bool {
+    pub fn is_unknown_internal_entity_id(id: PropertyValueOf<T>) -> bool {
         if let PropertyValue::Internal(entity_id) = id {
-            !EntityById::exists(entity_id)
+            !<EntityById<T>>::exists(entity_id)
         } else {
             false
         }
     }
 
-    pub fn get_entity_and_class(entity_id: EntityId) -> (Entity, Class) {
-        let entity = EntityById::get(entity_id);
+    // Updates the reverse references index for the entity with the changed property values.
+    fn update_entity_references(
+        entity_id: EntityId,
+        old_values: &[ClassPropertyValueOf<T>],
+        new_values: &[ClassPropertyValueOf<T>],
+    ) {
+        let old_references = referenced_entity_ids(old_values);
+        let new_references = referenced_entity_ids(new_values);
+
+        for removed_reference in old_references.difference(&new_references) {
+            let mut referencing_entity_ids = EntityReferencedBy::get(removed_reference);
+            referencing_entity_ids.retain(|id| *id != entity_id);
+
+            if referencing_entity_ids.is_empty() {
+                EntityReferencedBy::remove(removed_reference);
+            } else {
+                EntityReferencedBy::insert(removed_reference, referencing_entity_ids);
+            }
+        }
+
+        for added_reference in new_references.difference(&old_references) {
+            EntityReferencedBy::mutate(added_reference, |ids| ids.push(entity_id));
+        }
+    }
+
+    // Ensures the unique property values are not used by the other entities of the class.
+    fn ensure_unique_values_available(
+        entity_id: EntityId,
+        class: &Class,
+        values: &[ClassPropertyValueOf<T>],
+    ) -> dispatch::Result {
+        for key in unique_value_keys(class, values) {
+            if let Some(other_entity_id) = EntityIdByUniqueValue::get(key) {
+                ensure!(other_entity_id == entity_id, ERROR_PROP_VALUE_NOT_UNIQUE);
+            }
+        }
+        Ok(())
+    }
+
+    // Updates the unique property values index for the entity with the changed property values.
+    fn update_unique_value_index(
+        entity_id: EntityId,
+        class: &Class,
+        old_values: &[ClassPropertyValueOf<T>],
+        new_values: &[ClassPropertyValueOf<T>],
+    ) {
+        for key in unique_value_keys(class, old_values) {
+            if EntityIdByUniqueValue::get(key) == Some(entity_id) {
+                EntityIdByUniqueValue::remove(key);
+            }
+        }
+
+        for key in unique_value_keys(class, new_values) {
+            EntityIdByUniqueValue::insert(key, entity_id);
+        }
+    }
+
+    // Returns the entity moved from one class schema to another.
+    fn migrate_entity(
+        mut entity: EntityOf<T>,
+        class: &Class,
+        from_schema_id: u16,
+        to_schema_id: u16,
+        default_values: &[ClassPropertyValueOf<T>],
+    ) -> Result<EntityOf<T>, &'static str> {
+        entity
+            .in_class_schema_indexes
+            .retain(|schema_id| *schema_id != from_schema_id && *schema_id != to_schema_id);
+        entity.in_class_schema_indexes.push(to_schema_id);
+
+        // Properties used by the schemas supported by the entity after the migration.
+        let mut used_prop_ids = BTreeSet::new();
+        for schema_id in entity.in_class_schema_indexes.iter() {
+            if let Some(schema) = class.schemas.get(*schema_id as usize) {
+                used_prop_ids.extend(schema.properties.iter().cloned());
+            }
+        }
+
+        // Drop the values of the properties which are not used anymore.
+        entity
+            .values
+            .retain(|value| used_prop_ids.contains(&value.in_class_index));
+
+        let to_schema = class
+            .schemas
+            .get(to_schema_id as usize)
+            .ok_or(ERROR_UNKNOWN_CLASS_SCHEMA_ID)?;
+
+        // Add the missing values of the new schema properties.
+        for prop_id in to_schema.properties.iter() {
+            let prop_already_added = entity
+                .values
+                .iter()
+                .any(|value| value.in_class_index == *prop_id);
+
+            if prop_already_added {
+                continue;
+            }
+
+            let class_prop = class
+                .properties
+                .get(*prop_id as usize)
+                .ok_or(ERROR_CLASS_SCHEMA_REFERS_UNKNOWN_PROP_INDEX)?;
+
+            let value = match default_values
+                .iter()
+                .find(|value| value.in_class_index == *prop_id)
+            {
+                Some(default_value) => default_value.value.clone(),
+                None => {
+                    ensure!(!class_prop.required, ERROR_MISSING_REQUIRED_PROP);
+                    PropertyValue::None
+                }
+            };
+
+            entity.values.push(ClassPropertyValue {
+                in_class_index: *prop_id,
+                value,
+            });
+        }
+
+        Ok(entity)
+    }
+
+    // Returns property values with the references to the deleted entity removed.
+    fn clear_references(
+        deleted_entity_id: EntityId,
+        mut values: Vec<ClassPropertyValueOf<T>>,
+        class: &Class,
+    ) -> Result<Vec<ClassPropertyValueOf<T>>, &'static str> {
+        for property_value in values.iter_mut() {
+            match property_value.value {
+                PV::Internal(entity_id) if entity_id == deleted_entity_id => {
+                    let required = class
+                        .properties
+                        .get(property_value.in_class_index as usize)
+                        .map_or(false, |prop| prop.required);
+                    ensure!(!required, ERROR_REQUIRED_REFERENCE_CANNOT_BE_CLEARED);
+
+                    property_value.value = PV::None;
+                }
+                PV::InternalVec(ref mut vec) => vec.retain(|id| *id != deleted_entity_id),
+                _ => (),
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// Rebuilds the reverse references index from the stored entities. Intended for the runtime
+    /// upgrade migration of the entities created before the index was introduced.
+    pub fn rebuild_entity_references() {
+        let next_entity_id = NextEntityId::get();
+
+        for entity_id in 0..next_entity_id {
+            EntityReferencedBy::remove(entity_id);
+        }
+
+        for entity_id in 0..next_entity_id {
+            if <EntityById<T>>::exists(entity_id) {
+                let entity = <EntityById<T>>::get(entity_id);
+                for referenced_entity_id in referenced_entity_ids(&entity.values) {
+                    EntityReferencedBy::mutate(referenced_entity_id, |ids| ids.push(entity_id));
+                }
+            }
+        }
+    }
+
+    pub fn get_entity_and_class(entity_id: EntityId) -> (EntityOf<T>, Class) {
+        let entity = <EntityById<T>>::get(entity_id);
         let class = ClassById::get(entity.class_id);
         (entity, class)
     }
 
     pub fn ensure_property_value_is_valid(
-        value: PropertyValue,
+        value: PropertyValueOf<T>,
         prop: Property,
     ) -> dispatch::Result {
         Self::ensure_prop_value_matches_its_type(value.clone(), prop.clone())?;
         Self::ensure_valid_internal_prop(value.clone(), prop.clone())?;
         Self::validate_max_len_if_text_prop(value.clone(), prop.clone())?;
-        Self::validate_max_len_if_vec_prop(value, prop)?;
+        Self::validate_max_len_if_vec_prop(value.clone(), prop.clone())?;
+        Self::validate_len_if_fixed_bytes_prop(value.clone(), prop.clone())?;
+        Self::validate_variant_if_enum_prop(value.clone(), prop)?;
+        Self::ensure_known_data_object_if_data_object_prop(value)?;
         Ok(())
     }
 
-    pub fn validate_max_len_if_text_prop(value: PropertyValue, prop: Property) -> dispatch::Result {
+    pub fn validate_len_if_fixed_bytes_prop(
+        value: PropertyValueOf<T>,
+        prop: Property,
+    ) -> dispatch::Result {
+        match (value, prop.prop_type) {
+            (PV::FixedBytes(bytes), PT::FixedBytes(len)) => {
+                ensure!(
+                    bytes.len() == len as usize,
+                    ERROR_FIXED_BYTES_PROP_LEN_MISMATCH
+                );
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn validate_variant_if_enum_prop(
+        value: PropertyValueOf<T>,
+        prop: Property,
+    ) -> dispatch::Result {
+        match (value, prop.prop_type) {
+            (PV::Enum(variant), PT::Enum(variants)) => {
+                ensure!(
+                    (variant as usize) < variants.len(),
+                    ERROR_UNKNOWN_ENUM_VARIANT
+                );
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn ensure_known_data_object_if_data_object_prop(
+        value: PropertyValueOf<T>,
+    ) -> dispatch::Result {
+        match value {
+            PV::DataObject(content_id) => {
+                ensure!(
+                    T::DataObjectChecker::data_object_exists(&content_id),
+                    ERROR_DATA_OBJECT_NOT_FOUND
+                );
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn ensure_property_type_is_valid(prop_type: &PropertyType) -> dispatch::Result {
+        match prop_type {
+            PT::FixedBytes(len) => {
+                ensure!(*len > 0, ERROR_FIXED_BYTES_PROP_HAS_ZERO_LEN);
+                Ok(())
+            }
+            PT::Enum(variants) => {
+                ensure!(!variants.is_empty(), ERROR_ENUM_PROP_HAS_NO_VARIANTS);
+                ensure!(
+                    variants.len() <= MAX_ENUM_VARIANTS,
+                    ERROR_ENUM_PROP_HAS_TOO_MANY_VARIANTS
+                );
+
+                let mut unique_variant_names = BTreeSet::new();
+                for variant in variants.iter() {
+                    ensure!(
+                        !variant.is_empty() && variant.len() <= MAX_ENUM_VARIANT_NAME_LENGTH,
+                        ERROR_ENUM_VARIANT_NAME_INVALID
+                    );
+                    ensure!(
+                        unique_variant_names.insert(variant),
+                        ERROR_ENUM_VARIANT_NAMES_NOT_UNIQUE
+                    );
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn validate_max_len_if_text_prop(
+        value: PropertyValueOf<T>,
+        prop: Property,
+    ) -> dispatch::Result {
         match (value, prop.prop_type) {
             (PV::Text(text), PT::Text(max_len)) => Self::validate_max_len_of_text(text, max_len),
             _ => Ok(()),
@@ -665,7 +1293,7 @@ impl<T: Trait> Module<T> {
 
     #[rustfmt::skip]
     pub fn validate_max_len_if_vec_prop(
-        value: PropertyValue,
+        value: PropertyValueOf<T>,
         prop: Property,
     ) -> dispatch::Result {
 
@@ -722,7 +1350,7 @@ impl<T: Trait> Module<T> {
     }
 
     pub fn ensure_prop_value_matches_its_type(
-        value: PropertyValue,
+        value: PropertyValueOf<T>,
         prop: Property,
     ) -> dispatch::Result {
         if Self::does_prop_value_match_type(value, prop) {
@@ -734,7 +1362,7 @@ impl<T: Trait> Module<T> {
 
     #[rustfmt::skip]
     pub fn does_prop_value_match_type(
-        value: PropertyValue,
+        value: PropertyValueOf<T>,
         prop: Property,
     ) -> bool {
 
@@ -766,10 +1394,14 @@ impl<T: Trait> Module<T> {
             (PV::Int32Vec(_),    PT::Int32Vec(_)) |
             (PV::Int64Vec(_),    PT::Int64Vec(_)) |
             (PV::TextVec(_),     PT::TextVec(_, _)) |
-            (PV::InternalVec(_), PT::InternalVec(_, _)) => true,
+            (PV::InternalVec(_), PT::InternalVec(_, _)) |
+
+            // Other values:
+            (PV::DataObject(_),  PT::DataObject) |
+            (PV::FixedBytes(_),  PT::FixedBytes(_)) |
+            (PV::Enum(_),        PT::Enum(_)) |
+            (PV::Timestamp(_),   PT::Timestamp) => true,
 
-            // (PV::External(_), PT::External(_)) => true,
-            // (PV::ExternalVec(_), PT::ExternalVec(_, _)) => true,
             _ => false,
         }
     }
@@ -52,6 +52,22 @@ impl timestamp::Trait for Runtime {
 
 impl Trait for Runtime {
     type Event = ();
+    type ContentId = ContentId;
+    type DataObjectChecker = MockDataObjectChecker;
+}
+
+pub type ContentId = H256;
+
+pub const KNOWN_CONTENT_ID: ContentId = H256([1; 32]);
+
+pub const UNKNOWN_CONTENT_ID: ContentId = H256([2; 32]);
+
+pub struct MockDataObjectChecker;
+
+impl DataObjectChecker<Runtime> for MockDataObjectChecker {
+    fn data_object_exists(content_id: &ContentId) -> bool {
+        *content_id == KNOWN_CONTENT_ID
+    }
 }
 
 pub const UNKNOWN_CLASS_ID: ClassId = 111;
@@ -91,6 +107,7 @@ pub fn good_prop_bool() -> Property {
         required: false,
         name: b"Name of a bool property".to_vec(),
         description: b"Description of a bool property".to_vec(),
+        unique: false,
     }
 }
 
@@ -100,6 +117,7 @@ pub fn good_prop_u32() -> Property {
         required: false,
         name: b"Name of a u32 property".to_vec(),
         description: b"Description of a u32 property".to_vec(),
+        unique: false,
     }
 }
 
@@ -109,6 +127,7 @@ pub fn good_prop_text() -> Property {
         required: false,
         name: b"Name of a text property".to_vec(),
         description: b"Description of a text property".to_vec(),
+        unique: false,
     }
 }
 
@@ -118,6 +137,7 @@ pub fn new_internal_class_prop(class_id: ClassId) -> Property {
         required: false,
         name: b"Name of a internal property".to_vec(),
         description: b"Description of a internal property".to_vec(),
+        unique: false,
     }
 }
 
@@ -138,14 +158,14 @@ pub fn create_class() -> ClassId {
     class_id
 }
 
-pub fn bool_prop_value() -> ClassPropertyValue {
+pub fn bool_prop_value() -> ClassPropertyValueOf<Runtime> {
     ClassPropertyValue {
         in_class_index: 0,
         value: PropertyValue::Bool(true),
     }
 }
 
-pub fn prop_value(index: u16, value: PropertyValue) -> ClassPropertyValue {
+pub fn prop_value(index: u16, value: PropertyValueOf<Runtime>) -> ClassPropertyValueOf<Runtime> {
     ClassPropertyValue {
         in_class_index: index,
         value,
@@ -201,6 +221,7 @@ pub fn assert_class_schemas(class_id: ClassId, expected_schema_prop_ids: Vec<Vec
         .iter()
         .map(|prop_ids| ClassSchema {
             properties: prop_ids.clone(),
+            deprecated: false,
         })
         .collect();
     assert_eq!(class.schemas, schemas);
@@ -213,7 +234,7 @@ pub fn assert_entity_not_found(result: dispatch::Result) {
 // This function basically just builds a genesis storage key/value store according to
 // our desired mockup.
 
-pub fn default_genesis_config() -> GenesisConfig {
+pub fn default_genesis_config() -> GenesisConfig<Runtime> {
     GenesisConfig {
         class_by_id: vec![],
         entity_by_id: vec![],
@@ -238,7 +259,7 @@ pub fn default_genesis_config() -> GenesisConfig {
     }
 }
 
-fn build_test_externalities(config: GenesisConfig) -> runtime_io::TestExternalities {
+fn build_test_externalities(config: GenesisConfig<Runtime>) -> runtime_io::TestExternalities {
     let mut t = system::GenesisConfig::default()
         .build_storage::<Runtime>()
         .unwrap();
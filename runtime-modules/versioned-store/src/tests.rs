@@ -470,34 +470,562 @@ fn update_entity_props_successfully() {
 // Delete entity
 // --------------------------------------
 
-// #[test]
-// fn delete_entity_successfully() {
-//     with_test_externalities(|| {
-//         let entity_id = create_entity();
-//         assert_ok!(
-//             TestModule::delete_entity(entity_id),
-//             ()
-//         );
-//     })
-// }
-
-// #[test]
-// fn cannot_delete_entity_when_entity_not_found() {
-//     with_test_externalities(|| {
-//         assert_entity_not_found(
-//             TestModule::delete_entity(UNKNOWN_ENTITY_ID)
-//         );
-//     })
-// }
-
-// #[test]
-// fn cannot_delete_already_deleted_entity() {
-//     with_test_externalities(|| {
-//         let entity_id = create_entity();
-//         let _ok = TestModule::delete_entity(entity_id);
-//         assert_err!(
-//             TestModule::delete_entity(entity_id),
-//             ERROR_ENTITY_ALREADY_DELETED
-//         );
-//     })
-// }
+fn create_entity_referencing(target_entity_id: EntityId) -> EntityId {
+    let class_id = TestModule::entity_by_id(target_entity_id).class_id;
+    let entity_id = create_entity_of_class(class_id);
+    assert_ok!(TestModule::add_schema_support_to_entity(
+        entity_id,
+        SCHEMA_ID_0,
+        vec![
+            bool_prop_value(),
+            prop_value(PROP_ID_INTERNAL, PropertyValue::Internal(target_entity_id)),
+        ]
+    ));
+    entity_id
+}
+
+#[test]
+fn delete_entity_successfully() {
+    with_test_externalities(|| {
+        let entity_id = create_entity_with_schema_support();
+        assert_ok!(TestModule::delete_entity(
+            entity_id,
+            EntityDeletionMode::FailIfReferenced
+        ));
+        assert!(!<EntityById<Runtime>>::exists(entity_id));
+    })
+}
+
+#[test]
+fn cannot_delete_entity_when_entity_not_found() {
+    with_test_externalities(|| {
+        assert_entity_not_found(TestModule::delete_entity(
+            UNKNOWN_ENTITY_ID,
+            EntityDeletionMode::FailIfReferenced,
+        ));
+    })
+}
+
+#[test]
+fn delete_self_referencing_entity_successfully() {
+    with_test_externalities(|| {
+        let entity_id = create_entity_with_schema_support();
+        assert_ok!(TestModule::update_entity_property_values(
+            entity_id,
+            vec![prop_value(
+                PROP_ID_INTERNAL,
+                PropertyValue::Internal(entity_id)
+            )]
+        ));
+        assert_eq!(TestModule::entity_referenced_by(entity_id), vec![entity_id]);
+
+        assert_ok!(TestModule::delete_entity(
+            entity_id,
+            EntityDeletionMode::FailIfReferenced
+        ));
+        assert!(!<EntityById<Runtime>>::exists(entity_id));
+        assert!(!EntityReferencedBy::exists(entity_id));
+    })
+}
+
+#[test]
+fn entity_references_are_updated_with_property_values() {
+    with_test_externalities(|| {
+        let target_entity_id = create_entity_with_schema_support();
+        let entity_id = create_entity_referencing(target_entity_id);
+        assert_eq!(
+            TestModule::entity_referenced_by(target_entity_id),
+            vec![entity_id]
+        );
+
+        assert_ok!(TestModule::update_entity_property_values(
+            entity_id,
+            vec![prop_value(PROP_ID_INTERNAL, PropertyValue::None)]
+        ));
+        assert!(TestModule::entity_referenced_by(target_entity_id).is_empty());
+    })
+}
+
+#[test]
+fn rebuild_entity_references_successfully() {
+    with_test_externalities(|| {
+        let target_entity_id = create_entity_with_schema_support();
+        let entity_id = create_entity_referencing(target_entity_id);
+
+        EntityReferencedBy::remove(target_entity_id);
+        EntityReferencedBy::insert(entity_id, vec![UNKNOWN_ENTITY_ID]);
+
+        TestModule::rebuild_entity_references();
+        assert_eq!(
+            TestModule::entity_referenced_by(target_entity_id),
+            vec![entity_id]
+        );
+        assert!(!EntityReferencedBy::exists(entity_id));
+    })
+}
+
+#[test]
+fn cannot_delete_referenced_entity() {
+    with_test_externalities(|| {
+        let target_entity_id = create_entity_with_schema_support();
+        let _ = create_entity_referencing(target_entity_id);
+
+        assert_err!(
+            TestModule::delete_entity(target_entity_id, EntityDeletionMode::FailIfReferenced),
+            ERROR_ENTITY_IS_REFERENCED
+        );
+        assert!(<EntityById<Runtime>>::exists(target_entity_id));
+    })
+}
+
+#[test]
+fn delete_referenced_entity_with_clearing_references() {
+    with_test_externalities(|| {
+        let target_entity_id = create_entity_with_schema_support();
+        let entity_id = create_entity_referencing(target_entity_id);
+
+        assert_ok!(TestModule::delete_entity(
+            target_entity_id,
+            EntityDeletionMode::ClearReferences
+        ));
+        assert!(!<EntityById<Runtime>>::exists(target_entity_id));
+        assert!(!EntityReferencedBy::exists(target_entity_id));
+        assert_eq!(
+            TestModule::entity_by_id(entity_id).values,
+            vec![
+                bool_prop_value(),
+                prop_value(PROP_ID_U32, PropertyValue::None),
+                prop_value(PROP_ID_INTERNAL, PropertyValue::None),
+            ]
+        );
+    })
+}
+
+#[test]
+fn cannot_clear_required_reference_to_deleted_entity() {
+    with_test_externalities(|| {
+        let target_entity_id = create_entity_with_schema_support();
+        let target_class_id = TestModule::entity_by_id(target_entity_id).class_id;
+
+        let class_id = create_class();
+        let required_internal_prop = Property {
+            required: true,
+            ..new_internal_class_prop(target_class_id)
+        };
+        assert_ok!(TestModule::add_class_schema(
+            class_id,
+            vec![],
+            vec![required_internal_prop]
+        ));
+
+        let entity_id = create_entity_of_class(class_id);
+        assert_ok!(TestModule::add_schema_support_to_entity(
+            entity_id,
+            SCHEMA_ID_0,
+            vec![prop_value(0, PropertyValue::Internal(target_entity_id))]
+        ));
+
+        assert_err!(
+            TestModule::delete_entity(target_entity_id, EntityDeletionMode::ClearReferences),
+            ERROR_REQUIRED_REFERENCE_CANNOT_BE_CLEARED
+        );
+        assert!(<EntityById<Runtime>>::exists(target_entity_id));
+    })
+}
+
+// Schema deprecation and entity migration
+// --------------------------------------
+
+fn add_schema_replacing_optional_props_with_required_text(class_id: ClassId) -> u16 {
+    let schema_id = TestModule::add_class_schema(
+        class_id,
+        vec![PROP_ID_BOOL],
+        vec![good_prop_text().required()],
+    )
+    .unwrap();
+    assert_eq!(schema_id, SCHEMA_ID_1);
+    schema_id
+}
+
+const PROP_ID_TEXT: u16 = 3;
+
+fn text_prop_value() -> ClassPropertyValueOf<Runtime> {
+    prop_value(PROP_ID_TEXT, PropertyValue::Text(b"default".to_vec()))
+}
+
+#[test]
+fn deprecate_class_schema_successfully() {
+    with_test_externalities(|| {
+        let (class_id, schema_id, entity_id) = create_class_with_schema_and_entity();
+        assert_ok!(TestModule::deprecate_class_schema(class_id, schema_id));
+        assert!(TestModule::class_by_id(class_id).schemas[schema_id as usize].deprecated);
+
+        assert_err!(
+            TestModule::add_schema_support_to_entity(entity_id, schema_id, vec![bool_prop_value()]),
+            ERROR_CLASS_SCHEMA_IS_DEPRECATED
+        );
+    })
+}
+
+#[test]
+fn cannot_deprecate_class_schema_twice() {
+    with_test_externalities(|| {
+        let (class_id, schema_id, _) = create_class_with_schema_and_entity();
+        assert_ok!(TestModule::deprecate_class_schema(class_id, schema_id));
+        assert_err!(
+            TestModule::deprecate_class_schema(class_id, schema_id),
+            ERROR_CLASS_SCHEMA_ALREADY_DEPRECATED
+        );
+    })
+}
+
+#[test]
+fn cannot_deprecate_unknown_class_schema() {
+    with_test_externalities(|| {
+        let (class_id, _, _) = create_class_with_schema_and_entity();
+        assert_err!(
+            TestModule::deprecate_class_schema(class_id, SCHEMA_ID_1),
+            ERROR_UNKNOWN_CLASS_SCHEMA_ID
+        );
+    })
+}
+
+#[test]
+fn migrate_entities_successfully() {
+    with_test_externalities(|| {
+        let entity_id = create_entity_with_schema_support();
+        let class_id = TestModule::entity_by_id(entity_id).class_id;
+        let schema_id = add_schema_replacing_optional_props_with_required_text(class_id);
+        assert_ok!(TestModule::deprecate_class_schema(class_id, SCHEMA_ID_0));
+
+        assert_ok!(TestModule::migrate_entities(
+            class_id,
+            SCHEMA_ID_0,
+            schema_id,
+            vec![entity_id],
+            vec![text_prop_value()]
+        ));
+
+        let entity = TestModule::entity_by_id(entity_id);
+        assert_eq!(entity.in_class_schema_indexes, [schema_id]);
+        assert_eq!(
+            entity.values,
+            vec![
+                prop_value(PROP_ID_BOOL, PropertyValue::Bool(true)),
+                text_prop_value()
+            ]
+        );
+    })
+}
+
+#[test]
+fn cannot_migrate_entities_when_missing_required_prop() {
+    with_test_externalities(|| {
+        let entity_id = create_entity_with_schema_support();
+        let class_id = TestModule::entity_by_id(entity_id).class_id;
+        let schema_id = add_schema_replacing_optional_props_with_required_text(class_id);
+        let entity_before = TestModule::entity_by_id(entity_id);
+
+        assert_err!(
+            TestModule::migrate_entities(class_id, SCHEMA_ID_0, schema_id, vec![entity_id], vec![]),
+            ERROR_MISSING_REQUIRED_PROP
+        );
+        assert_eq!(TestModule::entity_by_id(entity_id), entity_before);
+    })
+}
+
+#[test]
+fn cannot_migrate_entities_to_deprecated_schema() {
+    with_test_externalities(|| {
+        let entity_id = create_entity_with_schema_support();
+        let class_id = TestModule::entity_by_id(entity_id).class_id;
+        let schema_id = add_schema_replacing_optional_props_with_required_text(class_id);
+        assert_ok!(TestModule::deprecate_class_schema(class_id, schema_id));
+
+        assert_err!(
+            TestModule::migrate_entities(
+                class_id,
+                SCHEMA_ID_0,
+                schema_id,
+                vec![entity_id],
+                vec![text_prop_value()]
+            ),
+            ERROR_CLASS_SCHEMA_IS_DEPRECATED
+        );
+    })
+}
+
+#[test]
+fn cannot_migrate_entities_not_supporting_source_schema() {
+    with_test_externalities(|| {
+        let (class_id, _, entity_id) = create_class_with_schema_and_entity();
+        let schema_id = add_schema_replacing_optional_props_with_required_text(class_id);
+
+        assert_err!(
+            TestModule::migrate_entities(
+                class_id,
+                SCHEMA_ID_0,
+                schema_id,
+                vec![entity_id],
+                vec![text_prop_value()]
+            ),
+            ERROR_ENTITY_DOES_NOT_SUPPORT_SCHEMA
+        );
+    })
+}
+
+// Data object, fixed bytes, enum and timestamp properties
+// --------------------------------------
+
+fn new_prop(name: &[u8], prop_type: PropertyType) -> Property {
+    Property {
+        prop_type,
+        required: false,
+        name: name.to_vec(),
+        description: b"Description of a property".to_vec(),
+        unique: false,
+    }
+}
+
+fn enum_prop_type() -> PropertyType {
+    PropertyType::Enum(vec![b"SD".to_vec(), b"HD".to_vec()])
+}
+
+const PROP_ID_DATA_OBJECT: u16 = 0;
+const PROP_ID_FIXED_BYTES: u16 = 1;
+const PROP_ID_ENUM: u16 = 2;
+const PROP_ID_TIMESTAMP: u16 = 3;
+
+fn create_entity_of_class_with_other_prop_types() -> EntityId {
+    let class_id = create_class();
+    assert_ok!(TestModule::add_class_schema(
+        class_id,
+        vec![],
+        vec![
+            new_prop(b"Video", PropertyType::DataObject),
+            new_prop(b"Hash", PropertyType::FixedBytes(32)),
+            new_prop(b"Quality", enum_prop_type()),
+            new_prop(b"Published at", PropertyType::Timestamp),
+        ]
+    ));
+    create_entity_of_class(class_id)
+}
+
+fn assert_add_class_schema_with_prop_type_err(prop_type: PropertyType, err: &'static str) {
+    let class_id = create_class();
+    assert_err!(
+        TestModule::add_class_schema(class_id, vec![], vec![new_prop(b"Prop", prop_type)]),
+        err
+    );
+}
+
+#[test]
+fn cannot_add_class_schema_with_invalid_fixed_bytes_prop() {
+    with_test_externalities(|| {
+        assert_add_class_schema_with_prop_type_err(
+            PropertyType::FixedBytes(0),
+            ERROR_FIXED_BYTES_PROP_HAS_ZERO_LEN,
+        );
+    })
+}
+
+#[test]
+fn cannot_add_class_schema_with_invalid_enum_prop() {
+    with_test_externalities(|| {
+        assert_add_class_schema_with_prop_type_err(
+            PropertyType::Enum(vec![]),
+            ERROR_ENUM_PROP_HAS_NO_VARIANTS,
+        );
+        assert_add_class_schema_with_prop_type_err(
+            PropertyType::Enum(vec![b"SD".to_vec(); MAX_ENUM_VARIANTS + 1]),
+            ERROR_ENUM_PROP_HAS_TOO_MANY_VARIANTS,
+        );
+        assert_add_class_schema_with_prop_type_err(
+            PropertyType::Enum(vec![b"SD".to_vec(), vec![]]),
+            ERROR_ENUM_VARIANT_NAME_INVALID,
+        );
+        assert_add_class_schema_with_prop_type_err(
+            PropertyType::Enum(vec![b"SD".to_vec(), b"SD".to_vec()]),
+            ERROR_ENUM_VARIANT_NAMES_NOT_UNIQUE,
+        );
+    })
+}
+
+#[test]
+fn add_schema_to_entity_with_other_prop_types_successfully() {
+    with_test_externalities(|| {
+        let entity_id = create_entity_of_class_with_other_prop_types();
+        let prop_values = vec![
+            prop_value(
+                PROP_ID_DATA_OBJECT,
+                PropertyValue::DataObject(KNOWN_CONTENT_ID),
+            ),
+            prop_value(PROP_ID_FIXED_BYTES, PropertyValue::FixedBytes(vec![7; 32])),
+            prop_value(PROP_ID_ENUM, PropertyValue::Enum(1)),
+            prop_value(
+                PROP_ID_TIMESTAMP,
+                PropertyValue::Timestamp(1_589_000_000_000),
+            ),
+        ];
+
+        assert_ok!(TestModule::add_schema_support_to_entity(
+            entity_id,
+            SCHEMA_ID_0,
+            prop_values.clone()
+        ));
+        assert_eq!(TestModule::entity_by_id(entity_id).values, prop_values);
+    })
+}
+
+#[test]
+fn cannot_update_entity_props_with_invalid_other_prop_type_values() {
+    with_test_externalities(|| {
+        let entity_id = create_entity_of_class_with_other_prop_types();
+        assert_ok!(TestModule::add_schema_support_to_entity(
+            entity_id,
+            SCHEMA_ID_0,
+            vec![]
+        ));
+
+        let assert_update_err = |value: ClassPropertyValueOf<Runtime>, err: &'static str| {
+            assert_err!(
+                TestModule::update_entity_property_values(entity_id, vec![value]),
+                err
+            );
+        };
+
+        assert_update_err(
+            prop_value(
+                PROP_ID_DATA_OBJECT,
+                PropertyValue::DataObject(UNKNOWN_CONTENT_ID),
+            ),
+            ERROR_DATA_OBJECT_NOT_FOUND,
+        );
+        assert_update_err(
+            prop_value(PROP_ID_FIXED_BYTES, PropertyValue::FixedBytes(vec![7; 31])),
+            ERROR_FIXED_BYTES_PROP_LEN_MISMATCH,
+        );
+        assert_update_err(
+            prop_value(PROP_ID_ENUM, PropertyValue::Enum(2)),
+            ERROR_UNKNOWN_ENUM_VARIANT,
+        );
+        assert_update_err(
+            prop_value(PROP_ID_TIMESTAMP, PropertyValue::Uint64(1_589_000_000_000)),
+            ERROR_PROP_VALUE_DONT_MATCH_TYPE,
+        );
+    })
+}
+
+// Unique properties
+// --------------------------------------
+
+fn create_class_with_unique_text_prop() -> ClassId {
+    let class_id = create_class();
+    assert_ok!(TestModule::add_class_schema(
+        class_id,
+        vec![],
+        vec![Property {
+            unique: true,
+            ..good_prop_text()
+        }]
+    ));
+    class_id
+}
+
+fn create_entity_with_unique_text(class_id: ClassId, text: &[u8]) -> EntityId {
+    let entity_id = create_entity_of_class(class_id);
+    assert_ok!(TestModule::add_schema_support_to_entity(
+        entity_id,
+        SCHEMA_ID_0,
+        vec![prop_value(0, PropertyValue::Text(text.to_vec()))]
+    ));
+    entity_id
+}
+
+fn entity_id_by_unique_text(class_id: ClassId, text: &[u8]) -> Option<EntityId> {
+    let value_hash = property_value_hash(&PropertyValue::Text(text.to_vec()));
+    TestModule::entity_id_by_unique_value((class_id, 0, value_hash))
+}
+
+#[test]
+fn cannot_add_schema_to_entity_when_unique_prop_value_is_used() {
+    with_test_externalities(|| {
+        let class_id = create_class_with_unique_text_prop();
+        let entity_id = create_entity_with_unique_text(class_id, b"handle");
+        assert_eq!(
+            entity_id_by_unique_text(class_id, b"handle"),
+            Some(entity_id)
+        );
+
+        let other_entity_id = create_entity_of_class(class_id);
+        assert_err!(
+            TestModule::add_schema_support_to_entity(
+                other_entity_id,
+                SCHEMA_ID_0,
+                vec![prop_value(0, PropertyValue::Text(b"handle".to_vec()))]
+            ),
+            ERROR_PROP_VALUE_NOT_UNIQUE
+        );
+
+        // None values of the unique properties are not indexed.
+        assert_ok!(TestModule::add_schema_support_to_entity(
+            other_entity_id,
+            SCHEMA_ID_0,
+            vec![]
+        ));
+        let another_entity_id = create_entity_of_class(class_id);
+        assert_ok!(TestModule::add_schema_support_to_entity(
+            another_entity_id,
+            SCHEMA_ID_0,
+            vec![]
+        ));
+    })
+}
+
+#[test]
+fn unique_prop_value_is_released_on_update_and_delete() {
+    with_test_externalities(|| {
+        let class_id = create_class_with_unique_text_prop();
+        let entity_id = create_entity_with_unique_text(class_id, b"handle");
+        let other_entity_id = create_entity_with_unique_text(class_id, b"other handle");
+
+        assert_err!(
+            TestModule::update_entity_property_values(
+                other_entity_id,
+                vec![prop_value(0, PropertyValue::Text(b"handle".to_vec()))]
+            ),
+            ERROR_PROP_VALUE_NOT_UNIQUE
+        );
+
+        // Entity can keep its own unique value.
+        assert_ok!(TestModule::update_entity_property_values(
+            entity_id,
+            vec![prop_value(0, PropertyValue::Text(b"handle".to_vec()))]
+        ));
+
+        assert_ok!(TestModule::update_entity_property_values(
+            entity_id,
+            vec![prop_value(0, PropertyValue::Text(b"new handle".to_vec()))]
+        ));
+        assert_eq!(entity_id_by_unique_text(class_id, b"handle"), None);
+        assert_eq!(
+            entity_id_by_unique_text(class_id, b"new handle"),
+            Some(entity_id)
+        );
+
+        assert_ok!(TestModule::update_entity_property_values(
+            other_entity_id,
+            vec![prop_value(0, PropertyValue::Text(b"handle".to_vec()))]
+        ));
+        assert_eq!(
+            entity_id_by_unique_text(class_id, b"handle"),
+            Some(other_entity_id)
+        );
+
+        assert_ok!(TestModule::delete_entity(
+            other_entity_id,
+            EntityDeletionMode::FailIfReferenced
+        ));
+        assert_eq!(entity_id_by_unique_text(class_id, b"handle"), None);
+    })
+}
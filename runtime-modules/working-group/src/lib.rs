@@ -160,6 +160,20 @@ pub trait Trait<I: Instance>:
 
     /// Defines max workers number in the working group.
     type MaxWorkerNumberLimit: Get<u32>;
+
+    /// Handles the worker leaving the working group.
+    type OnWorkerExit: OnWorkerExit<WorkerId<Self>>;
+}
+
+/// Notifies the dependent modules about the worker leaving the working group (on exit or
+/// termination), so they can clean up the worker data.
+pub trait OnWorkerExit<WorkerId> {
+    /// Called after the worker was removed from the working group.
+    fn on_worker_exit(worker_id: &WorkerId);
+}
+
+impl<WorkerId> OnWorkerExit<WorkerId> for () {
+    fn on_worker_exit(_worker_id: &WorkerId) {}
 }
 
 decl_event!(
@@ -325,6 +339,7 @@ decl_storage! {
     }
         add_extra_genesis {
         config(phantom): rstd::marker::PhantomData<I>;
+        // Mint capacity of any working group instance, the name is kept for chain spec compatibility.
         config(storage_working_group_mint_capacity): minting::BalanceOf<T>;
         config(opening_human_readable_text_constraint): InputValidationLengthConstraint;
         config(worker_application_human_readable_text_constraint): InputValidationLengthConstraint;
@@ -1376,6 +1391,25 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             .collect()
     }
 
+    /// Returns all opening ids with the underlying hiring opening not deactivated yet.
+    pub fn get_active_opening_ids() -> Vec<OpeningId<T>> {
+        <OpeningById<T, I>>::enumerate()
+            .filter_map(|(opening_id, opening)| {
+                let hiring_opening = hiring::OpeningById::<T>::get(opening.hiring_opening_id);
+
+                if let hiring::OpeningStage::Active {
+                    stage: hiring::ActiveOpeningStage::Deactivated { .. },
+                    ..
+                } = hiring_opening.stage
+                {
+                    None
+                } else {
+                    Some(opening_id)
+                }
+            })
+            .collect()
+    }
+
     fn make_stake_opt_imbalance(
         opt_balance: &Option<BalanceOf<T>>,
         source_account: &T::AccountId,
@@ -1438,6 +1472,8 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         WorkerById::<T, I>::remove(worker_id);
         Self::decrease_active_worker_counter();
 
+        T::OnWorkerExit::on_worker_exit(worker_id);
+
         // Trigger the event
         let event = match exit_initiation_origin {
             ExitInitiationOrigin::Lead => {
@@ -134,6 +134,7 @@ parameter_types! {
 impl Trait<TestWorkingGroupInstance> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type OnWorkerExit = ();
 }
 
 pub type Membership = membership::members::Module<Test>;
@@ -0,0 +1,22 @@
+use srml_support::StorageLinkedMap;
+
+use crate::{AccountId, ForumWorkingGroup, ForumWorkingGroupInstance, MemberId, Runtime};
+
+/// Authorizes the forum management by the forum working group: the lead manages the categories,
+/// while all active workers (including the lead) moderate the threads and posts on behalf of
+/// their member profiles.
+pub struct ForumWorkingGroupModerators;
+
+impl forum::ForumModeratorRegistry<AccountId, MemberId> for ForumWorkingGroupModerators {
+    fn is_forum_lead(id: &AccountId) -> bool {
+        ForumWorkingGroup::current_lead().map_or(false, |lead_id| {
+            ForumWorkingGroup::worker_by_id(lead_id).role_account_id == *id
+        })
+    }
+
+    fn get_forum_moderator(id: &AccountId) -> Option<MemberId> {
+        working_group::WorkerById::<Runtime, ForumWorkingGroupInstance>::enumerate()
+            .find(|(_, worker)| worker.role_account_id == *id)
+            .map(|(_, worker)| worker.member_id)
+    }
+}
@@ -1,4 +1,5 @@
 pub mod content_working_group;
+pub mod forum;
 pub mod proposals;
 pub mod storage;
 pub mod working_group;
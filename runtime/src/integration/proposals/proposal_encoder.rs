@@ -18,6 +18,7 @@ use srml_support::print;
 macro_rules! wrap_working_group_call {
     ($working_group:expr, $working_group_instance_call:expr) => {{
         match $working_group {
+            WorkingGroup::Forum => Call::ForumWorkingGroup($working_group_instance_call),
             WorkingGroup::Storage => Call::StorageWorkingGroup($working_group_instance_call),
         }
     }};
@@ -119,6 +120,17 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
                     Wg::terminate_role_call(terminate_role_params)
                 )
             }
+            ProposalDetails::RecallCouncilMember(councilor, start_by_election) => Call::Council(
+                governance::council::Call::recall_council_member(councilor, start_by_election),
+            ),
+            ProposalDetails::SlashCouncilMember(councilor, amount, slash_backers, destination) => {
+                Call::CouncilElection(governance::election::Call::slash_council_member(
+                    councilor,
+                    amount,
+                    slash_backers,
+                    destination,
+                ))
+            }
         };
 
         call.encode()
@@ -1,19 +1,24 @@
 use rstd::vec::Vec;
 use srml_support::traits::Randomness;
+use storage::data_directory::ContentIdExists;
 
-use crate::{ActorId, Runtime};
+use crate::{ActorId, ContentId, Runtime};
+
+/// Checks the data objects referenced by the versioned store entities in the data directory.
+pub struct DataObjectChecker;
+
+impl versioned_store::DataObjectChecker<Runtime> for DataObjectChecker {
+    fn data_object_exists(content_id: &ContentId) -> bool {
+        <storage::data_directory::Module<Runtime>>::has_content(content_id)
+    }
+}
 
 /// Provides random storage provider id. We use it when assign the content to the storage provider.
 pub struct StorageProviderHelper;
 
 impl storage::data_directory::StorageProviderHelper<Runtime> for StorageProviderHelper {
     fn get_random_storage_provider() -> Result<ActorId, &'static str> {
-        let ids = crate::StorageWorkingGroup::get_regular_worker_ids();
-
-        let live_ids: Vec<ActorId> = ids
-            .into_iter()
-            .filter(|id| !<service_discovery::Module<Runtime>>::is_account_info_expired(id))
-            .collect();
+        let live_ids = Self::get_live_storage_provider_ids();
 
         if live_ids.is_empty() {
             Err("No valid storage provider found.")
@@ -22,9 +27,35 @@ impl storage::data_directory::StorageProviderHelper<Runtime> for StorageProvider
             Ok(live_ids[index])
         }
     }
+
+    fn get_random_storage_providers(count: u32) -> Result<Vec<ActorId>, &'static str> {
+        let live_ids = Self::get_live_storage_provider_ids();
+
+        if live_ids.is_empty() {
+            Err("No valid storage provider found.")
+        } else {
+            // Take consecutive ids starting from the random one to get the distinct providers.
+            let start_index = Self::random_index(live_ids.len());
+            Ok(live_ids
+                .iter()
+                .cycle()
+                .skip(start_index)
+                .take(live_ids.len().min(count as usize))
+                .cloned()
+                .collect())
+        }
+    }
 }
 
 impl StorageProviderHelper {
+    fn get_live_storage_provider_ids() -> Vec<ActorId> {
+        let ids = crate::StorageWorkingGroup::get_regular_worker_ids();
+
+        ids.into_iter()
+            .filter(|id| !<service_discovery::Module<Runtime>>::is_account_info_expired(id))
+            .collect()
+    }
+
     fn random_index(upper_bound: usize) -> usize {
         let seed = crate::RandomnessCollectiveFlip::random_seed();
         let mut rand: u64 = 0;
@@ -1,15 +1,18 @@
 use rstd::marker::PhantomData;
 use srml_support::{StorageLinkedMap, StorageMap};
 
-use crate::StorageWorkingGroupInstance;
+use crate::{ForumWorkingGroupInstance, StorageWorkingGroupInstance};
 use stake::{BalanceOf, NegativeImbalance};
 
 pub struct StakingEventsHandler<T> {
     pub marker: PhantomData<T>,
 }
 
-impl<T: stake::Trait + working_group::Trait<StorageWorkingGroupInstance>>
-    stake::StakingEventsHandler<T> for StakingEventsHandler<T>
+impl<
+        T: stake::Trait
+            + working_group::Trait<ForumWorkingGroupInstance>
+            + working_group::Trait<StorageWorkingGroupInstance>,
+    > stake::StakingEventsHandler<T> for StakingEventsHandler<T>
 {
     /// Unstake remaining sum back to the source_account_id
     fn unstaked(
@@ -24,6 +27,15 @@ impl<T: stake::Trait + working_group::Trait<StorageWorkingGroupInstance>>
 
         let hiring_application_id = hiring::ApplicationIdByStakingId::<T>::get(*stake_id);
 
+        if working_group::MemberIdByHiringApplicationId::<T, ForumWorkingGroupInstance>::exists(
+            hiring_application_id,
+        ) {
+            return <working_group::Module<T, ForumWorkingGroupInstance>>::refund_working_group_stake(
+				*stake_id,
+				remaining_imbalance,
+			);
+        }
+
         if working_group::MemberIdByHiringApplicationId::<T, StorageWorkingGroupInstance>::exists(
             hiring_application_id,
         ) {
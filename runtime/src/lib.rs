@@ -17,6 +17,7 @@ mod tests;
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 mod integration;
+pub mod runtime_api;
 
 use authority_discovery_primitives::{
     AuthorityId as EncodedAuthorityId, Signature as EncodedSignature,
@@ -61,15 +62,18 @@ pub use timestamp::Call as TimestampCall;
 
 use integration::proposals::{CouncilManager, ExtrinsicProposalEncoder, MembershipOriginValidator};
 pub use proposals_codex::ProposalsConfigParameters;
+pub use storage::data_directory::{default_member_quota, Quota};
 
 pub use common;
 pub use forum;
 pub use working_group;
 
-pub use governance::election_params::ElectionParameters;
+pub use governance::election_params::{ElectionParameters, TallyMode};
 use governance::{council, election};
 use membership::members;
-use storage::{data_directory, data_object_storage_registry, data_object_type_registry};
+use storage::{
+    data_directory, data_object_storage_registry, data_object_type_registry, storage_challenge,
+};
 pub use versioned_store;
 
 pub use content_working_group as content_wg;
@@ -129,6 +133,9 @@ pub type PostId = u64;
 /// Represent an actor in membership group, which is the same in the working groups.
 pub type ActorId = u64;
 
+/// Represents a member identifier, which also identifies the forum users.
+pub type MemberId = u64;
+
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
 /// of data like extrinsics, allowing for them to continue syncing the network through upgrades
@@ -161,7 +168,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("joystream-node"),
     impl_name: create_runtime_str!("joystream-node"),
     authoring_version: 6,
-    spec_version: 21,
+    spec_version: 22,
     impl_version: 0,
     apis: RUNTIME_API_VERSIONS,
 };
@@ -443,9 +450,12 @@ impl finality_tracker::Trait for Runtime {
 
 impl versioned_store::Trait for Runtime {
     type Event = Event;
+    type ContentId = ContentId;
+    type DataObjectChecker = integration::storage::DataObjectChecker;
 }
 
 impl versioned_store_permissions::Trait for Runtime {
+    type Event = Event;
     type Credential = Credential;
     type CredentialChecker = (
         integration::content_working_group::ContentWorkingGroupCredentials,
@@ -554,6 +564,8 @@ impl governance::election::Trait for Runtime {
 impl governance::council::Trait for Runtime {
     type Event = Event;
     type CouncilTermEnded = (CouncilElection,);
+    type CouncilSeatsVacated = (CouncilElection,);
+    type CouncilElectionStatus = (CouncilElection,);
 }
 
 impl memo::Trait for Runtime {
@@ -562,6 +574,10 @@ impl memo::Trait for Runtime {
 
 parameter_types! {
     pub const MaxObjectsPerInjection: u32 = 100;
+    pub const RejectedContentRetentionPeriod: BlockNumber = 7 * DAYS;
+    pub const StorageChallengePeriod: BlockNumber = 6 * HOURS;
+    pub const StorageChallengeResponsePeriod: BlockNumber = HOURS;
+    pub const StorageChallengesPerPeriod: u32 = 10;
 }
 
 impl storage::data_object_type_registry::Trait for Runtime {
@@ -574,8 +590,11 @@ impl storage::data_directory::Trait for Runtime {
     type ContentId = ContentId;
     type StorageProviderHelper = integration::storage::StorageProviderHelper;
     type IsActiveDataObjectType = DataObjectTypeRegistry;
+    type DataObjectTypeReplication = DataObjectTypeRegistry;
     type MemberOriginValidator = MembershipOriginValidator<Self>;
     type MaxObjectsPerInjection = MaxObjectsPerInjection;
+    type RejectedContentRetentionPeriod = RejectedContentRetentionPeriod;
+    type ContentRemovalHandler = DataObjectStorageRegistry;
 }
 
 impl storage::data_object_storage_registry::Trait for Runtime {
@@ -584,9 +603,19 @@ impl storage::data_object_storage_registry::Trait for Runtime {
     type ContentIdExists = DataDirectory;
 }
 
+impl storage::storage_challenge::Trait for Runtime {
+    type Event = Event;
+    type ChallengeId = u64;
+    type Randomness = RandomnessCollectiveFlip;
+    type StorageProofVerifier = storage_challenge::MerkleStorageProofVerifier;
+    type ChallengePeriod = StorageChallengePeriod;
+    type ChallengeResponsePeriod = StorageChallengeResponsePeriod;
+    type ChallengesPerPeriod = StorageChallengesPerPeriod;
+}
+
 impl members::Trait for Runtime {
     type Event = Event;
-    type MemberId = u64;
+    type MemberId = MemberId;
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = ActorId;
@@ -607,25 +636,22 @@ impl members::Trait for Runtime {
 /// Shim registry which will proxy ForumUserRegistry behaviour to the members module
 pub struct ShimMembershipRegistry {}
 
-impl forum::ForumUserRegistry<AccountId> for ShimMembershipRegistry {
-    fn get_forum_user(id: &AccountId) -> Option<forum::ForumUser<AccountId>> {
-        if members::Module::<Runtime>::is_member_account(id) {
-            // For now we don't retreive the members profile since it is not used for anything,
-            // but in the future we may need it to read out more
-            // information possibly required to construct a
-            // ForumUser.
-
-            // Now convert member profile to a forum user
-            Some(forum::ForumUser { id: id.clone() })
-        } else {
-            None
+impl forum::ForumUserRegistry<MemberId> for ShimMembershipRegistry {
+    fn get_forum_user(id: &MemberId) -> Option<forum::ForumUser<MemberId>> {
+        match members::Module::<Runtime>::member_profile(id) {
+            // Suspended members cannot participate in the forum.
+            Some(profile) if !profile.suspended => Some(forum::ForumUser { id: *id }),
+            _ => None,
         }
     }
 }
 
 impl forum::Trait for Runtime {
     type Event = Event;
+    type ForumUserId = MemberId;
+    type ForumUserOriginValidator = MembershipOriginValidator<Self>;
     type MembershipRegistry = ShimMembershipRegistry;
+    type ModeratorRegistry = integration::forum::ForumWorkingGroupModerators;
     type ThreadId = ThreadId;
     type PostId = PostId;
 }
@@ -633,6 +659,9 @@ impl forum::Trait for Runtime {
 impl migration::Trait for Runtime {
     type Event = Event;
 }
+// The forum working group instance alias.
+pub type ForumWorkingGroupInstance = working_group::Instance1;
+
 // The storage working group instance alias.
 pub type StorageWorkingGroupInstance = working_group::Instance2;
 
@@ -640,9 +669,16 @@ parameter_types! {
     pub const MaxWorkerNumberLimit: u32 = 100;
 }
 
+impl working_group::Trait<ForumWorkingGroupInstance> for Runtime {
+    type Event = Event;
+    type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type OnWorkerExit = ();
+}
+
 impl working_group::Trait<StorageWorkingGroupInstance> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type OnWorkerExit = Discovery;
 }
 
 impl service_discovery::Trait for Runtime {
@@ -737,8 +773,8 @@ construct_runtime!(
         Memo: memo::{Module, Call, Storage, Event<T>},
         Members: members::{Module, Call, Storage, Event<T>, Config<T>},
         Forum: forum::{Module, Call, Storage, Event<T>, Config<T>},
-        VersionedStore: versioned_store::{Module, Call, Storage, Event<T>, Config},
-        VersionedStorePermissions: versioned_store_permissions::{Module, Call, Storage},
+        VersionedStore: versioned_store::{Module, Call, Storage, Event<T>, Config<T>},
+        VersionedStorePermissions: versioned_store_permissions::{Module, Call, Storage, Event<T>},
         Stake: stake::{Module, Call, Storage},
         Minting: minting::{Module, Call, Storage},
         RecurringRewards: recurringrewards::{Module, Call, Storage},
@@ -746,7 +782,7 @@ construct_runtime!(
         ContentWorkingGroup: content_wg::{Module, Call, Storage, Event<T>, Config<T>},
         // --- Storage
         DataObjectTypeRegistry: data_object_type_registry::{Module, Call, Storage, Event<T>, Config<T>},
-        DataDirectory: data_directory::{Module, Call, Storage, Event<T>},
+        DataDirectory: data_directory::{Module, Call, Storage, Event<T>, Config},
         DataObjectStorageRegistry: data_object_storage_registry::{Module, Call, Storage, Event<T>, Config<T>},
         Discovery: service_discovery::{Module, Call, Storage, Event<T>},
         // --- Proposals
@@ -754,8 +790,11 @@ construct_runtime!(
         ProposalsDiscussion: proposals_discussion::{Module, Call, Storage, Event<T>},
         ProposalsCodex: proposals_codex::{Module, Call, Storage, Error, Config<T>},
         // --- Working groups
-        // reserved for the future use: ForumWorkingGroup: working_group::<Instance1>::{Module, Call, Storage, Event<T>},
         StorageWorkingGroup: working_group::<Instance2>::{Module, Call, Storage, Config<T>, Error, Event<T>},
+        // --- Storage challenges
+        StorageChallenge: storage_challenge::{Module, Call, Storage, Event<T>},
+        // --- Forum working group
+        ForumWorkingGroup: working_group::<Instance1>::{Module, Call, Storage, Config<T>, Error, Event<T>},
     }
 );
 
@@ -906,4 +945,81 @@ impl_runtime_apis! {
             opaque::SessionKeys::generate(seed)
         }
     }
+
+    impl runtime_api::ProposalsApi<Block> for Runtime {
+        fn proposals_by_status(
+            status: runtime_api::ProposalStatusFilter,
+            from_id: runtime_api::ProposalId,
+            limit: u32,
+        ) -> Vec<(runtime_api::ProposalId, runtime_api::ProposalOf)> {
+            runtime_api::proposals_by_status(status, from_id, limit)
+        }
+    }
+
+    impl runtime_api::ProposalsEngineApi<Block> for Runtime {
+        fn voting_outcome(
+            proposal_id: runtime_api::ProposalId,
+            additional_votes: runtime_api::VotingResults,
+        ) -> Option<runtime_api::ProposalVotingOutcomeOf> {
+            runtime_api::voting_outcome(proposal_id, additional_votes)
+        }
+    }
+
+    impl runtime_api::MembershipApi<Block> for Runtime {
+        fn member_profile(member_id: runtime_api::MemberId) -> Option<runtime_api::MemberProfileInfo> {
+            runtime_api::member_profile(member_id)
+        }
+    }
+
+    impl runtime_api::WorkingGroupApi<Block> for Runtime {
+        fn active_workers(group: common::working_group::WorkingGroup) -> Vec<(ActorId, runtime_api::WorkerOf)> {
+            runtime_api::active_workers(group)
+        }
+
+        fn active_openings(
+            group: common::working_group::WorkingGroup,
+        ) -> Vec<(runtime_api::OpeningId, runtime_api::OpeningOf)> {
+            runtime_api::active_openings(group)
+        }
+    }
+
+    impl runtime_api::DataDirectoryApi<Block> for Runtime {
+        fn storage_relationships(content_id: ContentId) -> Vec<runtime_api::StorageRelationshipInfo> {
+            runtime_api::storage_relationships(content_id)
+        }
+
+        fn member_storage_usage(member_id: runtime_api::MemberId) -> runtime_api::MemberStorageUsageInfo {
+            runtime_api::member_storage_usage(member_id)
+        }
+
+        fn under_replicated_content_ids() -> Vec<ContentId> {
+            DataObjectStorageRegistry::under_replicated_content_ids()
+        }
+    }
+
+    impl runtime_api::ServiceDiscoveryApi<Block> for Runtime {
+        fn live_storage_providers() -> Vec<(ActorId, runtime_api::EndpointRecordOf)> {
+            Discovery::live_endpoint_records()
+        }
+    }
+
+    impl runtime_api::VersionedStoreApi<Block> for Runtime {
+        fn entity_id_by_unique_value(
+            class_id: runtime_api::ClassId,
+            in_class_index: u16,
+            value: runtime_api::PropertyValue,
+        ) -> Option<runtime_api::EntityId> {
+            runtime_api::entity_id_by_unique_value(class_id, in_class_index, value)
+        }
+    }
+
+    impl runtime_api::CouncilElectionApi<Block> for Runtime {
+        fn election_result(round: u32) -> Option<runtime_api::ElectionResultOf> {
+            runtime_api::election_result(round)
+        }
+
+        fn election_history() -> Vec<(u32, runtime_api::ElectionResultOf)> {
+            runtime_api::election_history()
+        }
+    }
 }
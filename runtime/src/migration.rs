@@ -2,13 +2,85 @@
 #![allow(clippy::redundant_closure_call)] // disable it because of the substrate lib design
 
 use crate::VERSION;
-use proposals_codex::ProposalsConfigParameters;
+use codec::Decode;
+use common::constraints::InputValidationLengthConstraint;
+use common::BlockAndTime;
+use governance::election_params::{ElectionParameters, TallyMode};
+use membership::members;
+use proposals_codex::{
+    ProposalDetails, ProposalDetailsOf, ProposalEncoder, ProposalsConfigParameters,
+};
 use rstd::prelude::*;
 use sr_primitives::{
     print,
-    traits::{One, Zero},
+    traits::{Bounded, One, Zero},
 };
+use srml_support::storage::{generator, unhashed};
 use srml_support::{debug, decl_event, decl_module, decl_storage};
+use storage::cid::Cid;
+use storage::data_directory::{DataObject, DataObjectInternal, LiaisonJudgement};
+use storage::data_object_type_registry::DataObjectType;
+use versioned_store::{Class, ClassById, ClassId, ClassSchema, Property, PropertyType};
+
+/// Data object type layout before the replication factor was introduced.
+#[derive(Decode)]
+struct DataObjectTypeV1 {
+    description: Vec<u8>,
+    active: bool,
+}
+
+/// Data object layout before the content hash was introduced.
+#[derive(Decode)]
+struct DataObjectV1<MemberId, BlockNumber, Moment, DataObjectTypeId, StorageProviderId> {
+    owner: MemberId,
+    added_at: BlockAndTime<BlockNumber, Moment>,
+    type_id: DataObjectTypeId,
+    size: u64,
+    liaison: StorageProviderId,
+    liaison_judgement: LiaisonJudgement,
+    ipfs_content_id: Vec<u8>,
+}
+
+/// Versioned store class property layout of the previous runtime.
+#[derive(Decode)]
+struct PropertyV1 {
+    prop_type: PropertyType,
+    required: bool,
+    name: Vec<u8>,
+    description: Vec<u8>,
+}
+
+/// Versioned store class schema layout before the schema deprecation was introduced.
+#[derive(Decode)]
+struct ClassSchemaV1 {
+    properties: Vec<u16>,
+}
+
+/// Versioned store class layout of the previous runtime.
+#[derive(Decode)]
+struct ClassV1 {
+    id: ClassId,
+    properties: Vec<PropertyV1>,
+    schemas: Vec<ClassSchemaV1>,
+    name: Vec<u8>,
+    description: Vec<u8>,
+}
+
+/// Election parameters layout before the tally mode was introduced.
+#[derive(Decode)]
+struct ElectionParametersV1<Balance, BlockNumber> {
+    announcing_period: BlockNumber,
+    voting_period: BlockNumber,
+    revealing_period: BlockNumber,
+    council_size: u32,
+    candidacy_limit: u32,
+    new_term_duration: BlockNumber,
+    min_council_stake: Balance,
+    min_voting_stake: Balance,
+}
+
+/// Index of the `SetElectionParameters` variant in the encoded proposal details.
+const SET_ELECTION_PARAMETERS_PROPOSAL_DETAILS_INDEX: u8 = 2;
 
 impl<T: Trait> Module<T> {
     /// This method is called from on_initialize() when a runtime upgrade is detected. This
@@ -23,9 +95,20 @@ impl<T: Trait> Module<T> {
         // have been initialized with config() or build() chainspec construction mechanism.
         // Other tasks like resetting values, migrating values etc.
 
-        Self::initialize_storage_working_group_mint();
-        Self::initialize_storage_working_group_text_constraints();
-        Self::clear_storage_data();
+        Self::initialize_working_group_mint::<working_group::Instance1>("forum");
+        Self::initialize_working_group_text_constraints::<working_group::Instance1>();
+        Self::initialize_data_directory_default_quota();
+        Self::migrate_data_object_types();
+        Self::migrate_data_objects();
+
+        service_discovery::Module::<T>::schedule_existing_records_pruning();
+        Self::migrate_versioned_store_classes();
+        versioned_store::Module::<T>::rebuild_entity_references();
+        versioned_store_permissions::Module::<T>::rebuild_entities_by_maintainer();
+        Self::migrate_set_election_parameters_proposals();
+        Self::initialize_forum_poll_alternative_text_constraint();
+        forum::Module::<T>::migrate_forum_user_ids(Self::forum_user_id_of_account);
+        forum::Module::<T>::migrate_categories();
 
         proposals_codex::Module::<T>::set_config_values(ProposalsConfigParameters::default());
     }
@@ -35,9 +118,15 @@ pub trait Trait:
     system::Trait
     + minting::Trait
     + proposals_codex::Trait
+    + working_group::Trait<working_group::Instance1>
     + working_group::Trait<working_group::Instance2>
     + storage::data_directory::Trait
     + storage::data_object_storage_registry::Trait
+    + storage::data_object_type_registry::Trait
+    + service_discovery::Trait
+    + versioned_store::Trait
+    + versioned_store_permissions::Trait
+    + forum::Trait<ForumUserId = <Self as members::Trait>::MemberId>
 {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 }
@@ -82,50 +171,266 @@ decl_module! {
 }
 
 impl<T: Trait> Module<T> {
-    fn initialize_storage_working_group_mint() {
+    fn initialize_working_group_mint<I: working_group::Instance>(working_group_name: &str)
+    where
+        T: working_group::Trait<I>,
+    {
         let mint_id_result = <minting::Module<T>>::add_mint(<minting::BalanceOf<T>>::zero(), None);
 
         if let Ok(mint_id) = mint_id_result {
-            <working_group::Mint<T, working_group::Instance2>>::put(mint_id);
+            <working_group::Mint<T, I>>::put(mint_id);
         } else {
-            print("Failed to create a mint for the storage working group");
+            print("Failed to create a mint for the working group:");
+            print(working_group_name);
         }
     }
 
-    fn initialize_storage_working_group_text_constraints() {
-        <working_group::OpeningHumanReadableText<working_group::Instance2>>::put(
-            working_group::default_text_constraint(),
-        );
-        <working_group::WorkerApplicationHumanReadableText<working_group::Instance2>>::put(
+    fn initialize_working_group_text_constraints<I: working_group::Instance>() {
+        <working_group::OpeningHumanReadableText<I>>::put(working_group::default_text_constraint());
+        <working_group::WorkerApplicationHumanReadableText<I>>::put(
             working_group::default_text_constraint(),
         );
-        <working_group::WorkerExitRationaleText<working_group::Instance2>>::put(
-            working_group::default_text_constraint(),
+        <working_group::WorkerExitRationaleText<I>>::put(working_group::default_text_constraint());
+    }
+
+    fn initialize_forum_poll_alternative_text_constraint() {
+        <forum::PollAlternativeTextConstraint>::put(InputValidationLengthConstraint::new(1, 99));
+    }
+
+    fn initialize_data_directory_default_quota() {
+        <storage::data_directory::DefaultQuota>::put(
+            storage::data_directory::default_member_quota(),
         );
     }
 
-    fn clear_storage_data() {
-        // Clear storage data object registry data.
-        for id in <storage::data_directory::Module<T>>::known_content_ids() {
-            <storage::data_object_storage_registry::RelationshipsByContentId<T>>::remove(id);
+    // Re-encodes the data object types stored without the replication factor. The types already
+    // stored with the current layout are skipped.
+    fn migrate_data_object_types() {
+        let mut id = <storage::data_object_type_registry::Module<T>>::first_data_object_type_id();
+        while id < <storage::data_object_type_registry::Module<T>>::next_data_object_type_id() {
+            let key =
+                <storage::data_object_type_registry::DataObjectTypes<T> as generator::StorageMap<
+                    T::DataObjectTypeId,
+                    DataObjectType,
+                >>::storage_map_final_key(id);
+
+            let is_migrated = unhashed::get::<DataObjectType>(key.as_ref()).is_some();
+
+            if !is_migrated {
+                if let Some(old_type) = unhashed::get::<DataObjectTypeV1>(key.as_ref()) {
+                    <storage::data_object_type_registry::DataObjectTypes<T>>::insert(
+                        id,
+                        DataObjectType {
+                            description: old_type.description,
+                            active: old_type.active,
+                            ..DataObjectType::default()
+                        },
+                    );
+                }
+            }
+
+            id += T::DataObjectTypeId::one();
         }
+    }
+
+    // Re-encodes the known data objects stored without the content hash. The content hash is
+    // parsed from the IPFS content id. The storage providers of the existing relationships are
+    // assigned to the migrated content and its ready relationships are counted. The migrated
+    // content has no committed chunks root, so it isn't challenged by the storage challenges.
+    fn migrate_data_objects() {
+        for content_id in <storage::data_directory::Module<T>>::known_content_ids() {
+            let key =
+                <storage::data_directory::DataObjectByContentId<T> as generator::StorageMap<
+                    T::ContentId,
+                    DataObject<T>,
+                >>::storage_map_final_key(content_id);
+
+            let is_migrated = unhashed::get::<DataObject<T>>(key.as_ref()).is_some();
+            if is_migrated {
+                continue;
+            }
 
-        let mut potential_id = <T as storage::data_object_storage_registry::Trait>::DataObjectStorageRelationshipId::zero();
-        while potential_id
-            < storage::data_object_storage_registry::Module::<T>::next_relationship_id()
-        {
-            <storage::data_object_storage_registry::Relationships<T>>::remove(&potential_id);
+            let old_object = match unhashed::get::<
+                DataObjectV1<
+                    <T as members::Trait>::MemberId,
+                    T::BlockNumber,
+                    T::Moment,
+                    T::DataObjectTypeId,
+                    storage::StorageProviderId<T>,
+                >,
+            >(key.as_ref())
+            {
+                Some(old_object) => old_object,
+                None => continue,
+            };
 
-            potential_id += <T as storage::data_object_storage_registry::Trait>::DataObjectStorageRelationshipId::one();
+            let content_hash = Cid::parse(&old_object.ipfs_content_id)
+                .map(|cid| cid.hash)
+                .unwrap_or_default();
+
+            let mut storage_providers = Vec::new();
+            storage_providers.push(old_object.liaison);
+            let mut ready_storage_providers = Vec::new();
+            for id in
+                <storage::data_object_storage_registry::Module<T>>::relationships_by_content_id(
+                    content_id,
+                )
+            {
+                if let Some(dosr) =
+                    <storage::data_object_storage_registry::Module<T>>::relationships(id)
+                {
+                    if !storage_providers.contains(&dosr.storage_provider_id) {
+                        storage_providers.push(dosr.storage_provider_id);
+                    }
+
+                    if dosr.ready && !ready_storage_providers.contains(&dosr.storage_provider_id) {
+                        ready_storage_providers.push(dosr.storage_provider_id);
+                    }
+                }
+            }
+
+            <storage::data_directory::DataObjectByContentId<T>>::insert(
+                content_id,
+                DataObjectInternal {
+                    owner: old_object.owner,
+                    added_at: old_object.added_at,
+                    type_id: old_object.type_id,
+                    size: old_object.size,
+                    liaison: old_object.liaison,
+                    liaison_judgement: old_object.liaison_judgement,
+                    ipfs_content_id: old_object.ipfs_content_id,
+                    content_hash,
+                    chunks_root: None,
+                },
+            );
+            <storage::data_directory::AssignedStorageProviders<T>>::insert(
+                content_id,
+                storage_providers,
+            );
+            <storage::data_object_storage_registry::ReadyRelationshipsCount<T>>::insert(
+                content_id,
+                ready_storage_providers.len() as u32,
+            );
         }
+    }
+
+    // Re-encodes the versioned store classes stored with the previous runtime layout. None of the
+    // migrated class schemas is deprecated.
+    fn migrate_versioned_store_classes() {
+        for class_id in 0..versioned_store::NextClassId::get() {
+            let key = <ClassById as generator::StorageMap<ClassId, Class>>::storage_map_final_key(
+                class_id,
+            );
 
-        storage::data_object_storage_registry::NextRelationshipId::<T>::kill();
+            if let Some(old_class) = unhashed::get::<ClassV1>(key.as_ref()) {
+                let properties = old_class
+                    .properties
+                    .into_iter()
+                    .map(|property| Property {
+                        prop_type: property.prop_type,
+                        required: property.required,
+                        name: property.name,
+                        description: property.description,
+                        unique: false,
+                    })
+                    .collect();
 
-        // Clear storage data directory data.
-        for id in <storage::data_directory::Module<T>>::known_content_ids() {
-            <storage::data_directory::DataObjectByContentId<T>>::remove(id);
+                let schemas = old_class
+                    .schemas
+                    .into_iter()
+                    .map(|schema| ClassSchema {
+                        properties: schema.properties,
+                        deprecated: false,
+                    })
+                    .collect();
+
+                ClassById::insert(
+                    class_id,
+                    Class {
+                        id: old_class.id,
+                        properties,
+                        schemas,
+                        name: old_class.name,
+                        description: old_class.description,
+                    },
+                );
+            }
         }
+    }
 
-        <storage::data_directory::KnownContentIds<T>>::kill();
+    // Forum user of an account which authored or moderated the forum threads and posts, the
+    // first member the account controls, or else the first member it is the root account of.
+    // Accounts which are not member accounts, like the former forum sudo, map to the unclaimed
+    // forum user id.
+    pub(crate) fn forum_user_id_of_account(
+        account_id: &T::AccountId,
+    ) -> <T as members::Trait>::MemberId {
+        let controlled_member_ids =
+            <members::Module<T>>::member_ids_by_controller_account_id(account_id);
+        let owned_member_ids = <members::Module<T>>::member_ids_by_root_account_id(account_id);
+
+        controlled_member_ids
+            .first()
+            .or_else(|| owned_member_ids.first())
+            .cloned()
+            .unwrap_or_else(Self::unclaimed_forum_user_id)
+    }
+
+    // Forum user id of the migrated threads and posts authored or moderated by the accounts
+    // without a membership. Member ids are assigned sequentially, so no member gets this id and
+    // nobody can act on behalf of the unclaimed forum user.
+    pub(crate) fn unclaimed_forum_user_id() -> <T as members::Trait>::MemberId {
+        <T as members::Trait>::MemberId::max_value()
+    }
+
+    // Re-encodes the details and the call code of the `set election parameters` proposals
+    // created with the election parameters layout before the tally mode was introduced. The
+    // migrated proposals keep the single choice tally mode.
+    fn migrate_set_election_parameters_proposals() {
+        for proposal_id in 1..=<proposals_engine::Module<T>>::proposal_count() {
+            let proposal_id = <T as proposals_engine::Trait>::ProposalId::from(proposal_id);
+            let key = <proposals_codex::ProposalDetailsByProposalId<T> as generator::StorageMap<
+                <T as proposals_engine::Trait>::ProposalId,
+                ProposalDetailsOf<T>,
+            >>::storage_map_final_key(proposal_id);
+
+            let raw_details = match unhashed::get_raw(key.as_ref()) {
+                Some(raw_details) => raw_details,
+                None => continue,
+            };
+
+            if raw_details.first() != Some(&SET_ELECTION_PARAMETERS_PROPOSAL_DETAILS_INDEX) {
+                continue;
+            }
+
+            // Details of the new layout have the trailing tally mode left after decoding.
+            let mut input = &raw_details[1..];
+            let old_parameters = match ElectionParametersV1::decode(&mut input) {
+                Ok(old_parameters) if input.is_empty() => old_parameters,
+                _ => continue,
+            };
+
+            let proposal_details = ProposalDetails::SetElectionParameters(ElectionParameters {
+                announcing_period: old_parameters.announcing_period,
+                voting_period: old_parameters.voting_period,
+                revealing_period: old_parameters.revealing_period,
+                council_size: old_parameters.council_size,
+                candidacy_limit: old_parameters.candidacy_limit,
+                new_term_duration: old_parameters.new_term_duration,
+                min_council_stake: old_parameters.min_council_stake,
+                min_voting_stake: old_parameters.min_voting_stake,
+                tally_mode: TallyMode::SingleChoice,
+            });
+
+            <proposals_engine::DispatchableCallCode<T>>::insert(
+                proposal_id,
+                T::ProposalEncoder::encode_proposal(proposal_details.clone()),
+            );
+
+            <proposals_codex::ProposalDetailsByProposalId<T>>::insert(
+                proposal_id,
+                proposal_details,
+            );
+        }
     }
 }
@@ -0,0 +1,400 @@
+//! Joystream-specific runtime APIs. They are served to the clients by the node `joystream-rpc`
+//! layer, so that the clients don't need to decode the raw storage.
+
+use codec::{Decode, Encode};
+use rstd::prelude::*;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use srml_support::{StorageLinkedMap, StorageMap};
+use substrate_client::decl_runtime_apis;
+
+use crate::{
+    AccountId, ActorId, Balance, BlockNumber, ContentId, CouncilElection, DataDirectory,
+    DataObjectStorageRegistry, DataObjectTypeRegistry, ForumWorkingGroupInstance, Members, Moment,
+    ProposalsEngine, Runtime, StorageWorkingGroupInstance, VersionedStore,
+};
+use common::working_group::WorkingGroup;
+use governance::election;
+use membership::members;
+pub use proposals_engine::VotingResults;
+use storage::data_directory::{Quota, QuotaUsage};
+pub use versioned_store::{ClassId, EntityId};
+
+/// Alias for the member id.
+pub type MemberId = <Runtime as members::Trait>::MemberId;
+
+/// Alias for the proposal id.
+pub type ProposalId = <Runtime as proposals_engine::Trait>::ProposalId;
+
+/// Maximum number of proposal ids queried by `proposals_by_status` at once.
+pub const MAX_PROPOSALS_PAGE_SIZE: u32 = 100;
+
+/// Alias for the stake id.
+pub type StakeId = <Runtime as stake::Trait>::StakeId;
+
+/// Alias for the data object storage relationship id.
+pub type DataObjectStorageRelationshipId =
+    <Runtime as storage::data_object_storage_registry::Trait>::DataObjectStorageRelationshipId;
+
+/// Alias for the data object type id.
+pub type DataObjectTypeId =
+    <Runtime as storage::data_object_type_registry::Trait>::DataObjectTypeId;
+
+/// Alias for the working group opening id.
+pub type OpeningId = <Runtime as hiring::Trait>::OpeningId;
+
+/// Alias for the working group application id.
+pub type ApplicationId = <Runtime as hiring::Trait>::ApplicationId;
+
+/// Alias for the proposal.
+pub type ProposalOf =
+    proposals_engine::Proposal<BlockNumber, MemberId, Balance, StakeId, AccountId>;
+
+/// Alias for the simulated proposal voting outcome.
+pub type ProposalVotingOutcomeOf = proposals_engine::ProposalVotingOutcome<BlockNumber>;
+
+/// Alias for the storage provider service endpoints record.
+pub type EndpointRecordOf = service_discovery::EndpointRecord<BlockNumber>;
+
+/// Alias for the versioned store property value.
+pub type PropertyValue = versioned_store::PropertyValueOf<Runtime>;
+
+/// Alias for the working group worker.
+pub type WorkerOf = working_group::Worker<
+    AccountId,
+    <Runtime as recurringrewards::Trait>::RewardRelationshipId,
+    StakeId,
+    BlockNumber,
+    MemberId,
+>;
+
+/// Alias for the working group opening.
+pub type OpeningOf = working_group::Opening<OpeningId, BlockNumber, Balance, ApplicationId>;
+
+/// Alias for the archived council election round result.
+pub type ElectionResultOf = election::ElectionResult<AccountId, Balance, BlockNumber>;
+
+/// Proposal status filter for the proposals queries.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalStatusFilter {
+    /// Proposals open for voting.
+    Active,
+
+    /// Approved proposals waiting for their grace period to expire.
+    PendingExecution,
+
+    /// Proposals with the final decision (including the executed ones).
+    Finalized,
+}
+
+/// Member profile with its registered roles.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct MemberProfileInfo {
+    /// Member id.
+    pub member_id: MemberId,
+
+    /// The unique handle chosen by member.
+    pub handle: Vec<u8>,
+
+    /// A Url to member's Avatar image.
+    pub avatar_uri: Vec<u8>,
+
+    /// Short text chosen by member to share information about themselves.
+    pub about: Vec<u8>,
+
+    /// Blocknumber when member was registered.
+    pub registered_at_block: BlockNumber,
+
+    /// Timestamp when member was registered.
+    pub registered_at_time: Moment,
+
+    /// Whether the member is suspended or not.
+    pub suspended: bool,
+
+    /// Member's root account id.
+    pub root_account: AccountId,
+
+    /// Member's controller account id.
+    pub controller_account: AccountId,
+
+    /// Roles the member has enrolled in.
+    pub roles: Vec<members::ActorInRole<ActorId>>,
+}
+
+/// Storage provider relationship of the data object.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct StorageRelationshipInfo {
+    /// Relationship id.
+    pub relationship_id: DataObjectStorageRelationshipId,
+
+    /// Storage provider id.
+    pub storage_provider_id: ActorId,
+
+    /// Active state (True=Active).
+    pub ready: bool,
+}
+
+/// Member storage quota and usage of the data object type.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct DataObjectTypeStorageUsageInfo {
+    /// Data object type id.
+    pub type_id: DataObjectTypeId,
+
+    /// Per-member quota of the data object type if it is limited.
+    pub quota: Option<Quota>,
+
+    /// Storage used by the member uploads of the data object type.
+    pub usage: QuotaUsage,
+}
+
+/// Member storage quota and usage.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct MemberStorageUsageInfo {
+    /// Member storage quota.
+    pub quota: Quota,
+
+    /// Storage used by the member uploads.
+    pub usage: QuotaUsage,
+
+    /// Member storage quotas and usage per data object type.
+    pub data_object_types: Vec<DataObjectTypeStorageUsageInfo>,
+}
+
+decl_runtime_apis! {
+    /// Proposals queries.
+    pub trait ProposalsApi {
+        /// Returns proposals with the provided status and ids in the `[from_id, from_id + limit)`
+        /// range. The limit is capped with `MAX_PROPOSALS_PAGE_SIZE`.
+        fn proposals_by_status(
+            status: ProposalStatusFilter,
+            from_id: ProposalId,
+            limit: u32,
+        ) -> Vec<(ProposalId, ProposalOf)>;
+    }
+
+    /// Proposals engine voting queries.
+    pub trait ProposalsEngineApi {
+        /// Simulates the voting for the active proposal with the provided additional votes.
+        /// Returns the resulting decision status, votes remaining for approval and slashing and
+        /// the expiration block. Returns None if the proposal doesn't exist or is finalized.
+        fn voting_outcome(
+            proposal_id: ProposalId,
+            additional_votes: VotingResults,
+        ) -> Option<ProposalVotingOutcomeOf>;
+    }
+
+    /// Membership queries.
+    pub trait MembershipApi {
+        /// Returns member profile with its roles if the member exists.
+        fn member_profile(member_id: MemberId) -> Option<MemberProfileInfo>;
+    }
+
+    /// Working groups queries.
+    pub trait WorkingGroupApi {
+        /// Returns active workers (including the leader) of the working group.
+        fn active_workers(group: WorkingGroup) -> Vec<(ActorId, WorkerOf)>;
+
+        /// Returns openings of the working group which are not deactivated yet.
+        fn active_openings(group: WorkingGroup) -> Vec<(OpeningId, OpeningOf)>;
+    }
+
+    /// Data directory queries.
+    pub trait DataDirectoryApi {
+        /// Returns storage provider relationships of the data object.
+        fn storage_relationships(content_id: ContentId) -> Vec<StorageRelationshipInfo>;
+
+        /// Returns the member storage quotas and current usage.
+        fn member_storage_usage(member_id: MemberId) -> MemberStorageUsageInfo;
+
+        /// Returns content ids with less ready storage relationships than their replication
+        /// factor requires.
+        fn under_replicated_content_ids() -> Vec<ContentId>;
+    }
+
+    /// Service discovery queries.
+    pub trait ServiceDiscoveryApi {
+        /// Returns non-expired service endpoints records of the active storage providers.
+        fn live_storage_providers() -> Vec<(ActorId, EndpointRecordOf)>;
+    }
+
+    /// Versioned store queries.
+    pub trait VersionedStoreApi {
+        /// Returns the entity of the class with the provided value of the unique property.
+        fn entity_id_by_unique_value(
+            class_id: ClassId,
+            in_class_index: u16,
+            value: PropertyValue,
+        ) -> Option<EntityId>;
+    }
+
+    /// Council election history queries.
+    pub trait CouncilElectionApi {
+        /// Returns the result of the election round if it is still kept in the history.
+        fn election_result(round: u32) -> Option<ElectionResultOf>;
+
+        /// Returns the results of the most recent election rounds, oldest first.
+        fn election_history() -> Vec<(u32, ElectionResultOf)>;
+    }
+}
+
+// Runtime API implementation helpers.
+
+pub(crate) fn proposals_by_status(
+    status: ProposalStatusFilter,
+    from_id: ProposalId,
+    limit: u32,
+) -> Vec<(ProposalId, ProposalOf)> {
+    let from_id = from_id.max(1);
+    let to_id = from_id
+        .saturating_add(limit.min(MAX_PROPOSALS_PAGE_SIZE))
+        .min(ProposalsEngine::proposal_count().saturating_add(1));
+    let in_page = |proposal_id: &ProposalId| *proposal_id >= from_id && *proposal_id < to_id;
+
+    match status {
+        ProposalStatusFilter::Active => <proposals_engine::ActiveProposalIds<Runtime>>::enumerate()
+            .map(|(proposal_id, _)| proposal_id)
+            .filter(in_page)
+            .map(|proposal_id| (proposal_id, ProposalsEngine::proposals(proposal_id)))
+            .collect(),
+        ProposalStatusFilter::PendingExecution => {
+            <proposals_engine::PendingExecutionProposalIds<Runtime>>::enumerate()
+                .map(|(proposal_id, _)| proposal_id)
+                .filter(in_page)
+                .map(|proposal_id| (proposal_id, ProposalsEngine::proposals(proposal_id)))
+                .collect()
+        }
+        ProposalStatusFilter::Finalized => (from_id..to_id)
+            .filter(|proposal_id| {
+                <proposals_engine::Proposals<Runtime>>::exists(proposal_id)
+                    && !<proposals_engine::PendingExecutionProposalIds<Runtime>>::exists(
+                        proposal_id,
+                    )
+            })
+            .map(|proposal_id| (proposal_id, ProposalsEngine::proposals(proposal_id)))
+            .filter(|(_, proposal)| {
+                matches!(
+                    proposal.status,
+                    proposals_engine::ProposalStatus::Finalized(_)
+                )
+            })
+            .collect(),
+    }
+}
+
+pub(crate) fn voting_outcome(
+    proposal_id: ProposalId,
+    additional_votes: VotingResults,
+) -> Option<ProposalVotingOutcomeOf> {
+    ProposalsEngine::simulate_voting(proposal_id, additional_votes).ok()
+}
+
+pub(crate) fn member_profile(member_id: MemberId) -> Option<MemberProfileInfo> {
+    Members::member_profile(member_id).map(|profile| MemberProfileInfo {
+        member_id,
+        handle: profile.handle,
+        avatar_uri: profile.avatar_uri,
+        about: profile.about,
+        registered_at_block: profile.registered_at_block,
+        registered_at_time: profile.registered_at_time,
+        suspended: profile.suspended,
+        root_account: profile.root_account,
+        controller_account: profile.controller_account,
+        roles: profile.roles.to_vec(),
+    })
+}
+
+pub(crate) fn active_workers(group: WorkingGroup) -> Vec<(ActorId, WorkerOf)> {
+    match group {
+        WorkingGroup::Forum => working_group_active_workers::<ForumWorkingGroupInstance>(),
+        WorkingGroup::Storage => working_group_active_workers::<StorageWorkingGroupInstance>(),
+    }
+}
+
+pub(crate) fn active_openings(group: WorkingGroup) -> Vec<(OpeningId, OpeningOf)> {
+    match group {
+        WorkingGroup::Forum => working_group_active_openings::<ForumWorkingGroupInstance>(),
+        WorkingGroup::Storage => working_group_active_openings::<StorageWorkingGroupInstance>(),
+    }
+}
+
+pub(crate) fn storage_relationships(content_id: ContentId) -> Vec<StorageRelationshipInfo> {
+    DataObjectStorageRegistry::relationships_by_content_id(content_id)
+        .into_iter()
+        .filter_map(|relationship_id| {
+            DataObjectStorageRegistry::relationships(relationship_id).map(|relationship| {
+                StorageRelationshipInfo {
+                    relationship_id,
+                    storage_provider_id: relationship.storage_provider_id,
+                    ready: relationship.ready,
+                }
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn member_storage_usage(member_id: MemberId) -> MemberStorageUsageInfo {
+    let first_type_id = DataObjectTypeRegistry::first_data_object_type_id();
+    let next_type_id = DataObjectTypeRegistry::next_data_object_type_id();
+
+    let data_object_types = (first_type_id..next_type_id)
+        .filter(|type_id| DataObjectTypeRegistry::data_object_types(type_id).is_some())
+        .map(|type_id| DataObjectTypeStorageUsageInfo {
+            type_id,
+            quota: DataDirectory::data_object_type_quotas(type_id),
+            usage: DataDirectory::member_usage_by_data_object_type(member_id, type_id),
+        })
+        .collect();
+
+    MemberStorageUsageInfo {
+        quota: DataDirectory::member_quota(member_id),
+        usage: DataDirectory::member_usage(member_id),
+        data_object_types,
+    }
+}
+
+pub(crate) fn entity_id_by_unique_value(
+    class_id: ClassId,
+    in_class_index: u16,
+    value: PropertyValue,
+) -> Option<EntityId> {
+    let value_hash = versioned_store::property_value_hash(&value);
+    VersionedStore::entity_id_by_unique_value((class_id, in_class_index, value_hash))
+}
+
+pub(crate) fn election_result(round: u32) -> Option<ElectionResultOf> {
+    CouncilElection::election_results(round)
+}
+
+pub(crate) fn election_history() -> Vec<(u32, ElectionResultOf)> {
+    CouncilElection::archived_rounds()
+        .into_iter()
+        .filter_map(|round| CouncilElection::election_results(round).map(|result| (round, result)))
+        .collect()
+}
+
+fn working_group_active_workers<I: working_group::Instance>() -> Vec<(ActorId, WorkerOf)>
+where
+    Runtime: working_group::Trait<I>,
+{
+    <working_group::WorkerById<Runtime, I>>::enumerate().collect()
+}
+
+fn working_group_active_openings<I: working_group::Instance>() -> Vec<(OpeningId, OpeningOf)>
+where
+    Runtime: working_group::Trait<I>,
+{
+    working_group::Module::<Runtime, I>::get_active_opening_ids()
+        .into_iter()
+        .map(|opening_id| {
+            (
+                opening_id,
+                working_group::Module::<Runtime, I>::opening_by_id(opening_id),
+            )
+        })
+        .collect()
+}
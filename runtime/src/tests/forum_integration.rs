@@ -0,0 +1,85 @@
+use super::initial_test_ext;
+use crate::integration::forum::ForumWorkingGroupModerators;
+use crate::runtime_api::WorkerOf;
+use crate::{AccountId, MemberId, Migration, Runtime};
+
+use forum::ForumModeratorRegistry;
+use membership::members;
+use srml_support::{StorageLinkedMap, StorageMap, StorageValue};
+use working_group::{Instance1, Instance2, Worker};
+
+fn worker(account_id: [u8; 32], member_id: MemberId) -> WorkerOf {
+    Worker {
+        role_account_id: account_id.into(),
+        member_id,
+        ..Worker::default()
+    }
+}
+
+#[test]
+fn forum_working_group_moderators_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let lead_account: AccountId = [1; 32].into();
+        let moderator_account: AccountId = [2; 32].into();
+        let storage_worker_account: AccountId = [3; 32].into();
+
+        // No forum workers yet.
+        assert!(!ForumWorkingGroupModerators::is_forum_lead(&lead_account));
+        assert_eq!(
+            ForumWorkingGroupModerators::get_forum_moderator(&lead_account),
+            None
+        );
+
+        <working_group::WorkerById<Runtime, Instance1>>::insert(0, worker([1; 32], 10));
+        <working_group::WorkerById<Runtime, Instance1>>::insert(1, worker([2; 32], 11));
+        <working_group::CurrentLead<Runtime, Instance1>>::put(0);
+
+        // Workers of other working groups don't moderate the forum.
+        <working_group::WorkerById<Runtime, Instance2>>::insert(0, worker([3; 32], 12));
+
+        assert!(ForumWorkingGroupModerators::is_forum_lead(&lead_account));
+        assert_eq!(
+            ForumWorkingGroupModerators::get_forum_moderator(&lead_account),
+            Some(10)
+        );
+
+        assert!(!ForumWorkingGroupModerators::is_forum_lead(
+            &moderator_account
+        ));
+        assert_eq!(
+            ForumWorkingGroupModerators::get_forum_moderator(&moderator_account),
+            Some(11)
+        );
+
+        assert!(!ForumWorkingGroupModerators::is_forum_lead(
+            &storage_worker_account
+        ));
+        assert_eq!(
+            ForumWorkingGroupModerators::get_forum_moderator(&storage_worker_account),
+            None
+        );
+    });
+}
+
+#[test]
+fn forum_user_id_of_non_member_account_is_unclaimed() {
+    initial_test_ext().execute_with(|| {
+        let controller_account: AccountId = [1; 32].into();
+        let root_account: AccountId = [2; 32].into();
+        let sudo_account: AccountId = [3; 32].into();
+
+        <members::MemberIdsByControllerAccountId<Runtime>>::insert(&controller_account, vec![0]);
+        <members::MemberIdsByRootAccountId<Runtime>>::insert(&root_account, vec![1]);
+
+        assert_eq!(Migration::forum_user_id_of_account(&controller_account), 0);
+        assert_eq!(Migration::forum_user_id_of_account(&root_account), 1);
+
+        // Accounts without a membership don't map to the first member.
+        let unclaimed_forum_user_id = Migration::unclaimed_forum_user_id();
+        assert_eq!(
+            Migration::forum_user_id_of_account(&sudo_account),
+            unclaimed_forum_user_id
+        );
+        assert_ne!(unclaimed_forum_user_id, MemberId::default());
+    });
+}
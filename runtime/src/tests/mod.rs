@@ -2,13 +2,21 @@
 
 #![cfg(test)]
 
+mod forum_integration;
 mod proposals_integration;
+mod runtime_api;
 mod storage_integration;
 
 pub(crate) fn initial_test_ext() -> runtime_io::TestExternalities {
-    let t = system::GenesisConfig::default()
+    let mut t = system::GenesisConfig::default()
         .build_storage::<crate::Runtime>()
         .unwrap();
 
+    storage::data_directory::GenesisConfig {
+        default_quota: storage::data_directory::default_member_quota(),
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
     t.into()
 }
@@ -4,7 +4,7 @@
 
 mod working_group_proposals;
 
-use crate::{BlockNumber, ElectionParameters, ProposalCancellationFee, Runtime};
+use crate::{BlockNumber, ElectionParameters, ProposalCancellationFee, Runtime, TallyMode};
 use codec::Encode;
 use membership::members;
 use proposals_engine::{
@@ -15,7 +15,7 @@ use proposals_engine::{
 
 use sr_primitives::traits::{DispatchResult, OnFinalize, OnInitialize};
 use sr_primitives::AccountId32;
-use srml_support::traits::Currency;
+use srml_support::traits::{Currency, ReservableCurrency};
 use srml_support::{StorageLinkedMap, StorageValue};
 use system::RawOrigin;
 
@@ -427,6 +427,7 @@ fn elect_single_councilor() {
             new_term_duration: 2000000,
             min_council_stake: 0,
             min_voting_stake: 0,
+            tally_mode: TallyMode::SingleChoice,
         },
     );
     assert_eq!(res, Ok(()));
@@ -679,6 +680,7 @@ fn set_election_parameters_proposal_execution_succeeds() {
             new_term_duration: 14400,
             min_council_stake: 1,
             min_voting_stake: 1,
+            tally_mode: TallyMode::SingleChoice,
         };
         assert_eq!(Election::announcing_period(), 0);
 
@@ -722,3 +724,66 @@ fn set_validator_count_proposal_execution_succeeds() {
         assert_eq!(<staking::ValidatorCount>::get(), new_validator_count);
     });
 }
+
+#[test]
+fn recall_council_member_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+        let councilor: [u8; 32] = [5; 32];
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_recall_council_member_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(200_000_u32)),
+                councilor.clone().into(),
+                false,
+            )
+        });
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        assert!(!Council::is_councilor(&councilor.into()));
+        assert_eq!(Council::active_council().len(), 5);
+    });
+}
+
+#[test]
+fn slash_council_member_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+        let councilor: AccountId32 = [5; 32].into();
+
+        setup_members(15);
+        setup_council();
+        increase_total_balance_issuance_using_account_id(account_id.clone().into(), 500000);
+        increase_total_balance_issuance_using_account_id(councilor.clone(), 1000);
+
+        assert!(Balances::reserve(&councilor, 1000).is_ok());
+        <governance::council::ActiveCouncil<Runtime>>::mutate(|council| {
+            council[5].stake = 1000;
+        });
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_slash_council_member_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(200_000_u32)),
+                councilor.clone(),
+                400,
+                true,
+                governance::election::SlashedStakeDestination::Burn,
+            )
+        })
+        .disable_setup_enviroment();
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        assert_eq!(Balances::reserved_balance(&councilor), 600);
+        assert_eq!(Council::active_council()[5].stake, 600);
+    });
+}
@@ -8,7 +8,7 @@ use hiring::ActivateOpeningAt;
 use proposals_codex::AddOpeningParameters;
 use working_group::{OpeningPolicyCommitment, RewardPolicy};
 
-use crate::{Balance, BlockNumber, StorageWorkingGroupInstance};
+use crate::{Balance, BlockNumber, ForumWorkingGroupInstance, StorageWorkingGroupInstance};
 use rstd::collections::btree_set::BTreeSet;
 
 type StorageWorkingGroup = working_group::Module<Runtime, StorageWorkingGroupInstance>;
@@ -310,6 +310,45 @@ fn create_add_working_group_leader_opening_proposal_execution_succeeds() {
     });
 }
 
+#[test]
+fn create_add_forum_working_group_leader_opening_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        let opening_id =
+            working_group::Module::<Runtime, ForumWorkingGroupInstance>::next_opening_id();
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_add_working_group_leader_opening_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(100_000_u32)),
+                AddOpeningParameters {
+                    activate_at: ActivateOpeningAt::CurrentBlock,
+                    commitment: OpeningPolicyCommitment::default(),
+                    human_readable_text: Vec::new(),
+                    working_group: WorkingGroup::Forum,
+                },
+            )
+        });
+
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        // The opening is created in the forum working group only.
+        assert!(<working_group::OpeningById<
+            Runtime,
+            ForumWorkingGroupInstance,
+        >>::exists(opening_id));
+        assert!(!<working_group::OpeningById<
+            Runtime,
+            StorageWorkingGroupInstance,
+        >>::exists(opening_id));
+    });
+}
+
 #[test]
 fn create_begin_review_working_group_leader_applications_proposal_execution_succeeds() {
     initial_test_ext().execute_with(|| {
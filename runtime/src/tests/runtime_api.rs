@@ -0,0 +1,83 @@
+use super::initial_test_ext;
+use crate::runtime_api::{self, StorageRelationshipInfo};
+use crate::{ContentId, Runtime};
+
+use governance::election::{ArchivedRounds, ElectionResult, ElectionResults};
+use srml_support::{StorageMap, StorageValue};
+use storage::data_object_storage_registry::{
+    DataObjectStorageRelationship, Relationships, RelationshipsByContentId,
+};
+
+#[test]
+fn storage_relationships_query_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let content_id = ContentId::default();
+
+        // No relationships yet.
+        assert!(runtime_api::storage_relationships(content_id).is_empty());
+
+        <Relationships<Runtime>>::insert(
+            1,
+            DataObjectStorageRelationship {
+                content_id,
+                storage_provider_id: 7,
+                ready: true,
+            },
+        );
+        <Relationships<Runtime>>::insert(
+            2,
+            DataObjectStorageRelationship {
+                content_id,
+                storage_provider_id: 19,
+                ready: false,
+            },
+        );
+
+        // Relationship 3 doesn't exist and should be skipped.
+        <RelationshipsByContentId<Runtime>>::insert(content_id, vec![1, 2, 3]);
+
+        assert_eq!(
+            runtime_api::storage_relationships(content_id),
+            vec![
+                StorageRelationshipInfo {
+                    relationship_id: 1,
+                    storage_provider_id: 7,
+                    ready: true,
+                },
+                StorageRelationshipInfo {
+                    relationship_id: 2,
+                    storage_provider_id: 19,
+                    ready: false,
+                },
+            ]
+        );
+    });
+}
+
+#[test]
+fn member_profile_query_returns_none_for_missing_member() {
+    initial_test_ext().execute_with(|| {
+        assert!(runtime_api::member_profile(1).is_none());
+    });
+}
+
+#[test]
+fn election_history_query_succeeds() {
+    initial_test_ext().execute_with(|| {
+        assert!(runtime_api::election_history().is_empty());
+
+        let result = ElectionResult {
+            ended_at: 10,
+            winners: vec![[1; 32].into()],
+            ..Default::default()
+        };
+        <ElectionResults<Runtime>>::insert(2, result.clone());
+
+        // Result of round 1 doesn't exist and should be skipped.
+        ArchivedRounds::put(vec![1, 2]);
+
+        assert_eq!(runtime_api::election_history(), vec![(2, result.clone())]);
+        assert_eq!(runtime_api::election_result(2), Some(result));
+        assert!(runtime_api::election_result(1).is_none());
+    });
+}
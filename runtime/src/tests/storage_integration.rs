@@ -41,3 +41,33 @@ fn storage_provider_helper_succeeds() {
 		assert!(worker_ids.contains(&random_provider_id));
 	});
 }
+
+#[test]
+fn storage_provider_helper_returns_distinct_providers() {
+    initial_test_ext().execute_with(|| {
+        let worker_ids = vec![1, 7, 19];
+
+        let account_info = service_discovery::AccountInfo {
+            identity: Vec::new(),
+            expires_at: 1000,
+        };
+
+        for worker_id in worker_ids.iter() {
+            <working_group::WorkerById<Runtime, Instance2>>::insert(worker_id, Worker::default());
+            <service_discovery::AccountInfoByStorageProviderId<Runtime>>::insert(
+                worker_id,
+                account_info.clone(),
+            );
+        }
+
+        let mut provider_ids = <StorageProviderHelper as storage::data_directory::StorageProviderHelper<Runtime>>::get_random_storage_providers(2).unwrap();
+        assert_eq!(provider_ids.len(), 2);
+        assert_ne!(provider_ids[0], provider_ids[1]);
+        assert!(provider_ids.iter().all(|id| worker_ids.contains(id)));
+
+        // Only the available providers are returned.
+        provider_ids = <StorageProviderHelper as storage::data_directory::StorageProviderHelper<Runtime>>::get_random_storage_providers(5).unwrap();
+        provider_ids.sort();
+        assert_eq!(provider_ids, worker_ids);
+    });
+}
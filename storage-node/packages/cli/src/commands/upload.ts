@@ -9,17 +9,23 @@ import { discover } from '@joystream/service-discovery/discover'
 import Debug from 'debug'
 import chalk from 'chalk'
 import { aliceKeyPair } from './dev'
+import { blake2AsU8a } from '@polkadot/util-crypto'
+import { u8aConcat } from '@polkadot/util'
 const debug = Debug('joystream:storage-cli:upload')
 
 // Defines maximum content length for the assets (files). Limits the upload.
 const MAX_CONTENT_LENGTH = 500 * 1024 * 1024 // 500Mb
 
+// Defines the content chunk size of the runtime storage proofs (STORAGE_PROOF_CHUNK_SIZE).
+const STORAGE_PROOF_CHUNK_SIZE = 1024
+
 // Defines the necessary parameters for the AddContent runtime tx.
 interface AddContentParams {
   accountId: string
   ipfsCid: string
   contentId: ContentId
   fileSize: BN
+  chunksRoot: Uint8Array
   dataObjectTypeId: number
   memberId: number
 }
@@ -72,6 +78,30 @@ export class UploadCommand extends BaseCommand {
     return await ipfsHash.of(file)
   }
 
+  // Computes the Merkle root of the file chunks. The runtime verifies the storage proofs against it.
+  private computeChunksRoot(): Uint8Array {
+    const content = fs.readFileSync(this.mediaSourceFilePath)
+
+    let level: Uint8Array[] = []
+    for (let offset = 0; offset < content.length; offset += STORAGE_PROOF_CHUNK_SIZE) {
+      level.push(blake2AsU8a(u8aConcat([0], content.subarray(offset, offset + STORAGE_PROOF_CHUNK_SIZE))))
+    }
+    if (level.length === 0) {
+      level.push(blake2AsU8a(new Uint8Array([0])))
+    }
+
+    // The last node of the level with an odd number of nodes is paired with itself.
+    while (level.length > 1) {
+      const nextLevel: Uint8Array[] = []
+      for (let index = 0; index < level.length; index += 2) {
+        nextLevel.push(blake2AsU8a(u8aConcat([1], level[index], level[index + 1] || level[index])))
+      }
+      level = nextLevel
+    }
+
+    return level[0]
+  }
+
   // Read the file size from the file system.
   private getFileSize(): number {
     const stats = fs.statSync(this.mediaSourceFilePath)
@@ -98,6 +128,7 @@ export class UploadCommand extends BaseCommand {
       ipfsCid: await this.computeIpfsHash(),
       contentId: ContentId.generate(),
       fileSize: new BN(this.getFileSize()),
+      chunksRoot: this.computeChunksRoot(),
       dataObjectTypeId,
       memberId,
     }
@@ -112,7 +143,8 @@ export class UploadCommand extends BaseCommand {
         p.contentId,
         p.dataObjectTypeId,
         p.fileSize,
-        p.ipfsCid
+        p.ipfsCid,
+        p.chunksRoot
       )
 
       if (dataObject.isNone) {
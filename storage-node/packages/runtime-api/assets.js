@@ -29,9 +29,16 @@ class AssetsApi {
   /*
    * Create and return a data object.
    */
-  async createDataObject(accountId, memberId, contentId, doTypeId, size, ipfsCid) {
+  async createDataObject(accountId, memberId, contentId, doTypeId, size, ipfsCid, chunksRoot) {
     contentId = parseContentId(contentId)
-    const tx = this.base.api.tx.dataDirectory.addContent(memberId, contentId, doTypeId, size, ipfsCid)
+    const tx = this.base.api.tx.dataDirectory.addContent(
+      memberId,
+      contentId,
+      doTypeId,
+      size,
+      ipfsCid,
+      chunksRoot
+    )
     await this.base.signAndSend(accountId, tx)
 
     // If the data object constructed properly, we should now be able to return
@@ -108,6 +108,22 @@ export class ElectionStage extends Enum {
 
 export type AnyElectionStage = Announcing | Voting | Revealing
 
+export type TallyModeKey = 'SingleChoice' | 'Phragmen'
+
+export class TallyMode extends Enum {
+  constructor(value?: TallyModeKey) {
+    super(['SingleChoice', 'Phragmen'], value)
+  }
+}
+
+export type SlashedStakeDestinationKey = 'Burn' | 'CouncilMint'
+
+export class SlashedStakeDestination extends Enum {
+  constructor(value?: SlashedStakeDestinationKey) {
+    super(['Burn', 'CouncilMint'], value)
+  }
+}
+
 export type IElectionParameters = {
   announcing_period: BlockNumber
   voting_period: BlockNumber
@@ -117,6 +133,7 @@ export type IElectionParameters = {
   new_term_duration: BlockNumber
   min_council_stake: Balance
   min_voting_stake: Balance
+  tally_mode: TallyMode
 }
 
 export class ElectionParameters extends Struct {
@@ -131,6 +148,7 @@ export class ElectionParameters extends Struct {
         new_term_duration: 'BlockNumber',
         min_council_stake: 'Balance',
         min_voting_stake: 'Balance',
+        tally_mode: TallyMode,
       },
       value
     )
@@ -159,6 +177,81 @@ export class ElectionParameters extends Struct {
   get min_voting_stake() {
     return this.get('min_voting_stake') as Balance
   }
+  get tally_mode() {
+    return this.get('tally_mode') as TallyMode
+  }
+}
+
+export class ApplicantResult extends Struct {
+  constructor(value?: any) {
+    super(
+      {
+        applicant: 'AccountId',
+        stake: 'Balance',
+        backing: 'Balance',
+        votes: 'u32',
+      },
+      value
+    )
+  }
+
+  get applicant(): AccountId {
+    return this.get('applicant') as AccountId
+  }
+
+  get stake(): Balance {
+    return this.get('stake') as Balance
+  }
+
+  get backing(): Balance {
+    return this.get('backing') as Balance
+  }
+
+  get votes(): u32 {
+    return this.get('votes') as u32
+  }
+}
+
+export class ApplicantResults extends Vec.with(ApplicantResult) {}
+
+export class ElectionResult extends Struct {
+  constructor(value?: any) {
+    super(
+      {
+        ended_at: 'BlockNumber',
+        tally_mode: TallyMode,
+        applicants: ApplicantResults,
+        revealed_votes: 'u32',
+        unrevealed_commitments: 'u32',
+        winners: 'Vec<AccountId>',
+      },
+      value
+    )
+  }
+
+  get ended_at(): BlockNumber {
+    return this.get('ended_at') as BlockNumber
+  }
+
+  get tally_mode(): TallyMode {
+    return this.get('tally_mode') as TallyMode
+  }
+
+  get applicants(): ApplicantResults {
+    return this.get('applicants') as ApplicantResults
+  }
+
+  get revealed_votes(): u32 {
+    return this.get('revealed_votes') as u32
+  }
+
+  get unrevealed_commitments(): u32 {
+    return this.get('unrevealed_commitments') as u32
+  }
+
+  get winners(): Vec<AccountId> {
+    return this.get('winners') as Vec<AccountId>
+  }
 }
 
 // TODO Refactor: split this function and move to corresponding modules: election and proposals.
@@ -191,7 +284,12 @@ export function registerCouncilAndElectionTypes() {
         new_term_duration: 'BlockNumber',
         min_council_stake: 'Balance',
         min_voting_stake: 'Balance',
+        tally_mode: 'TallyMode',
       },
+      TallyMode,
+      SlashedStakeDestination,
+      ApplicantResult,
+      ElectionResult,
       Seat,
       Seats,
       Backer,
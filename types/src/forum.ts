@@ -1,10 +1,11 @@
-import { getTypeRegistry, bool, u32, u64, Text, Option, Vec as Vector, GenericAccountId } from '@polkadot/types'
-import { AccountId } from '@polkadot/types/interfaces'
-import { BlockAndTime, JoyStruct, ThreadId, PostId } from './common'
+import { getTypeRegistry, bool, u32, u64, Null, Text, Option, Vec as Vector, GenericAccountId } from '@polkadot/types'
+import { AccountId, BlockNumber } from '@polkadot/types/interfaces'
+import { BlockAndTime, JoyEnum, JoyStruct, ThreadId, PostId } from './common'
+import { MemberId } from './members'
 
 export type ModerationActionType = {
   moderated_at: BlockAndTime
-  moderator_id: AccountId
+  moderator_id: MemberId
   rationale: Text
 }
 
@@ -13,7 +14,7 @@ export class ModerationAction extends JoyStruct<ModerationActionType> {
     super(
       {
         moderated_at: BlockAndTime,
-        moderator_id: GenericAccountId,
+        moderator_id: MemberId,
         rationale: Text,
       },
       value
@@ -24,7 +25,7 @@ export class ModerationAction extends JoyStruct<ModerationActionType> {
     return this.getField('moderated_at')
   }
 
-  get moderator_id(): AccountId {
+  get moderator_id(): MemberId {
     return this.getField('moderator_id')
   }
 
@@ -60,6 +61,53 @@ export class PostTextChange extends JoyStruct<PostTextChangeType> {
 
 export class VecPostTextChange extends Vector.with(PostTextChange) {}
 
+export const PostReactionDef = {
+  Like: Null,
+  Dislike: Null,
+  Thanks: Null,
+  Laugh: Null,
+} as const
+export type PostReactionKey = keyof typeof PostReactionDef
+export class PostReaction extends JoyEnum(PostReactionDef) {}
+export class OptionPostReaction extends Option.with(PostReaction) {}
+
+export type PostReactionCountsType = {
+  likes: u32
+  dislikes: u32
+  thanks: u32
+  laughs: u32
+}
+
+export class PostReactionCounts extends JoyStruct<PostReactionCountsType> {
+  constructor(value: PostReactionCountsType) {
+    super(
+      {
+        likes: u32,
+        dislikes: u32,
+        thanks: u32,
+        laughs: u32,
+      },
+      value
+    )
+  }
+
+  get likes(): u32 {
+    return this.getField('likes')
+  }
+
+  get dislikes(): u32 {
+    return this.getField('dislikes')
+  }
+
+  get thanks(): u32 {
+    return this.getField('thanks')
+  }
+
+  get laughs(): u32 {
+    return this.getField('laughs')
+  }
+}
+
 export class OptionModerationAction extends Option.with(ModerationAction) {}
 
 export class CategoryId extends u64 {}
@@ -110,6 +158,8 @@ export type CategoryType = {
   num_direct_subcategories: u32
   num_direct_unmoderated_threads: u32
   num_direct_moderated_threads: u32
+  num_direct_moved_subcategories: u32
+  num_direct_moved_threads: u32
   position_in_parent_category: OptionChildPositionInParentCategory
   moderator_id: AccountId
 }
@@ -127,6 +177,8 @@ export class Category extends JoyStruct<CategoryType> {
         num_direct_subcategories: u32,
         num_direct_unmoderated_threads: u32,
         num_direct_moderated_threads: u32,
+        num_direct_moved_subcategories: u32,
+        num_direct_moved_threads: u32,
         position_in_parent_category: OptionChildPositionInParentCategory,
         moderator_id: GenericAccountId,
       },
@@ -174,12 +226,24 @@ export class Category extends JoyStruct<CategoryType> {
     return this.getField('num_direct_moderated_threads')
   }
 
+  get num_direct_moved_subcategories(): u32 {
+    return this.getField('num_direct_moved_subcategories')
+  }
+
+  get num_direct_moved_threads(): u32 {
+    return this.getField('num_direct_moved_threads')
+  }
+
   get num_threads_created(): u32 {
-    return new u32(this.num_direct_unmoderated_threads.add(this.num_direct_moderated_threads))
+    return new u32(
+      this.num_direct_unmoderated_threads
+        .add(this.num_direct_moderated_threads)
+        .add(this.num_direct_moved_threads)
+    )
   }
 
   get hasSubcategories(): boolean {
-    return !this.num_direct_subcategories.isZero()
+    return this.num_direct_subcategories.gt(this.num_direct_moved_subcategories)
   }
 
   get hasUnmoderatedThreads(): boolean {
@@ -209,6 +273,87 @@ export class Category extends JoyStruct<CategoryType> {
   }
 }
 
+export type PollAlternativeType = {
+  text: Text
+  vote_count: u32
+}
+
+export class PollAlternative extends JoyStruct<PollAlternativeType> {
+  constructor(value: PollAlternativeType) {
+    super(
+      {
+        text: Text,
+        vote_count: u32,
+      },
+      value
+    )
+  }
+
+  get text(): string {
+    return this.getString('text')
+  }
+
+  get vote_count(): u32 {
+    return this.getField('vote_count')
+  }
+}
+
+export class VecPollAlternative extends Vector.with(PollAlternative) {}
+
+export type PollType = {
+  end_block: BlockNumber
+  alternatives: VecPollAlternative
+}
+
+export class Poll extends JoyStruct<PollType> {
+  constructor(value: PollType) {
+    super(
+      {
+        end_block: u32, // BlockNumber
+        alternatives: VecPollAlternative,
+      },
+      value
+    )
+  }
+
+  get end_block(): BlockNumber {
+    return this.getField('end_block')
+  }
+
+  get alternatives(): VecPollAlternative {
+    return this.getField('alternatives')
+  }
+}
+
+export class OptionPoll extends Option.with(Poll) {}
+
+export class VecPollAlternativeText extends Vector.with(Text) {}
+
+export type PollParametersType = {
+  end_block: BlockNumber
+  alternatives: VecPollAlternativeText
+}
+
+export class PollParameters extends JoyStruct<PollParametersType> {
+  constructor(value: PollParametersType) {
+    super(
+      {
+        end_block: u32, // BlockNumber
+        alternatives: VecPollAlternativeText,
+      },
+      value
+    )
+  }
+
+  get end_block(): BlockNumber {
+    return this.getField('end_block')
+  }
+
+  get alternatives(): VecPollAlternativeText {
+    return this.getField('alternatives')
+  }
+}
+
 export type ThreadType = {
   id: ThreadId
   title: Text
@@ -218,7 +363,9 @@ export type ThreadType = {
   num_unmoderated_posts: u32
   num_moderated_posts: u32
   created_at: BlockAndTime
-  author_id: AccountId
+  author_id: MemberId
+  locked: bool
+  poll: OptionPoll
 }
 
 export class Thread extends JoyStruct<ThreadType> {
@@ -233,7 +380,9 @@ export class Thread extends JoyStruct<ThreadType> {
         num_unmoderated_posts: u32,
         num_moderated_posts: u32,
         created_at: BlockAndTime,
-        author_id: GenericAccountId,
+        author_id: MemberId,
+        locked: bool,
+        poll: OptionPoll,
       },
       value
     )
@@ -283,9 +432,17 @@ export class Thread extends JoyStruct<ThreadType> {
     return this.getField('created_at')
   }
 
-  get author_id(): AccountId {
+  get author_id(): MemberId {
     return this.getField('author_id')
   }
+
+  get locked(): boolean {
+    return this.getBoolean('locked')
+  }
+
+  get poll(): Poll | undefined {
+    return this.unwrapOrUndefined('poll')
+  }
 }
 
 export type PostType = {
@@ -296,7 +453,8 @@ export type PostType = {
   moderation: OptionModerationAction
   text_change_history: VecPostTextChange
   created_at: BlockAndTime
-  author_id: AccountId
+  author_id: MemberId
+  reaction_counts: PostReactionCounts
 }
 
 // TODO deprectated: replaced w/ Post
@@ -311,7 +469,8 @@ export class Post extends JoyStruct<PostType> {
         moderation: OptionModerationAction,
         text_change_history: VecPostTextChange,
         created_at: BlockAndTime,
-        author_id: GenericAccountId,
+        author_id: MemberId,
+        reaction_counts: PostReactionCounts,
       },
       value
     )
@@ -353,9 +512,13 @@ export class Post extends JoyStruct<PostType> {
     return this.getField('created_at')
   }
 
-  get author_id(): AccountId {
+  get author_id(): MemberId {
     return this.getField('author_id')
   }
+
+  get reaction_counts(): PostReactionCounts {
+    return this.getField('reaction_counts')
+  }
 }
 
 export type ReplyType = {
@@ -405,6 +568,11 @@ export function registerForumTypes() {
     getTypeRegistry().register({
       PostTextChange,
       ModerationAction,
+      PostReaction,
+      PostReactionCounts,
+      PollAlternative,
+      Poll,
+      PollParameters,
       ChildPositionInParentCategory,
       CategoryId,
       Category,
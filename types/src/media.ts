@@ -1,4 +1,4 @@
-import { Enum, Struct, Option, Vec as Vector, H256, BTreeMap, getTypeRegistry, u64, bool, Text } from '@polkadot/types'
+import { Enum, Struct, Option, Vec as Vector, H256, BTreeMap, getTypeRegistry, u32, u64, bool, Text, Bytes } from '@polkadot/types'
 import { BlockAndTime } from './common'
 import { MemberId } from './members'
 import { StorageProviderId } from './working-group' // this should be in discovery really
@@ -40,6 +40,26 @@ export class LiaisonJudgement extends Enum {
   }
 }
 
+export class Multihash extends Struct {
+  constructor(value?: any) {
+    super(
+      {
+        code: u64,
+        digest: Bytes,
+      },
+      value
+    )
+  }
+
+  get code(): u64 {
+    return this.get('code') as u64
+  }
+
+  get digest(): Bytes {
+    return this.get('digest') as Bytes
+  }
+}
+
 export class DataObject extends Struct {
   constructor(value?: any) {
     super(
@@ -51,6 +71,8 @@ export class DataObject extends Struct {
         liaison: StorageProviderId,
         liaison_judgement: LiaisonJudgement,
         ipfs_content_id: Text,
+        content_hash: Multihash,
+        chunks_root: Option.with(H256),
       },
       value
     )
@@ -84,6 +106,14 @@ export class DataObject extends Struct {
   get ipfs_content_id(): Text {
     return this.get('ipfs_content_id') as Text
   }
+
+  get content_hash(): Multihash {
+    return this.get('content_hash') as Multihash
+  }
+
+  get chunks_root(): Option<H256> {
+    return this.get('chunks_root') as Option<H256>
+  }
 }
 
 export class DataObjectStorageRelationship extends Struct {
@@ -117,6 +147,7 @@ export class DataObjectType extends Struct {
       {
         description: Text,
         active: bool,
+        replication_factor: u32,
       },
       value
     )
@@ -129,6 +160,10 @@ export class DataObjectType extends Struct {
   get active(): bool {
     return this.get('active') as bool
   }
+
+  get replication_factor(): u32 {
+    return this.get('replication_factor') as u32
+  }
 }
 
 export class DataObjectsMap extends BTreeMap.with(ContentId, DataObject) {}
@@ -138,6 +173,7 @@ export function registerMediaTypes() {
     getTypeRegistry().register({
       ContentId,
       LiaisonJudgement,
+      Multihash,
       DataObject,
       DataObjectStorageRelationshipId,
       DataObjectStorageRelationship,
@@ -19,7 +19,7 @@ import { ThreadId, JoyStruct, WorkingGroup } from './common'
 import { MemberId } from './members'
 import { RoleParameters } from './roles'
 import { StakeId } from './stake'
-import { ElectionParameters } from './council'
+import { ElectionParameters, SlashedStakeDestination } from './council'
 import { ActivateOpeningAt, OpeningId, ApplicationId } from './hiring'
 import { WorkingGroupOpeningPolicyCommitment, WorkerId, RewardPolicy } from './working-group'
 
@@ -571,6 +571,8 @@ export class ProposalDetails extends Enum {
         SlashWorkingGroupLeaderStake: Tuple.with([WorkerId, 'Balance', WorkingGroup]),
         SetWorkingGroupLeaderReward: Tuple.with([WorkerId, 'Balance', WorkingGroup]),
         TerminateWorkingGroupLeaderRole: TerminateRoleParameters,
+        RecallCouncilMember: Tuple.with(['AccountId', 'bool']),
+        SlashCouncilMember: Tuple.with(['AccountId', 'Balance', 'bool', SlashedStakeDestination]),
       },
       value,
       index
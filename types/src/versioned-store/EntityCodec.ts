@@ -124,6 +124,17 @@ function plainToSubstrate(propType: string, value: any): PropertyValue {
     case 'InternalVec':
       return ok(new PV.InternalVec(valueAsArr()))
 
+    // Other values:
+
+    case 'DataObject':
+      return ok(new PV.DataObject(value as string))
+    case 'FixedBytes':
+      return ok(new PV.FixedBytes(value as string))
+    case 'Enum':
+      return ok(new PV.EnumVariant(value as string))
+    case 'Timestamp':
+      return ok(new PV.Timestamp(value as string))
+
     default: {
       throw new Error(`Unknown property type name: ${propType}`)
     }
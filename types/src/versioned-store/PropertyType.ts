@@ -1,5 +1,5 @@
-import { u16, Null } from '@polkadot/types'
-import { Enum, Tuple } from '@polkadot/types/codec'
+import { u16, Null, Text as PolkaText } from '@polkadot/types'
+import { Enum, Tuple, Vec as Vector } from '@polkadot/types/codec'
 import ClassId from './ClassId'
 
 export class None extends Null {}
@@ -38,6 +38,13 @@ export class InternalVec extends Tuple.with([u16, ClassId]) {
   }
 }
 
+// Other values:
+
+export class DataObject extends Null {}
+export class FixedBytes extends u16 {}
+export class EnumVariants extends Vector.with(PolkaText) {}
+export class Timestamp extends Null {}
+
 export type PropertyTypeEnum =
   | None
 
@@ -63,6 +70,12 @@ export type PropertyTypeEnum =
   | TextVec
   | InternalVec
 
+  // Other values:
+  | DataObject
+  | FixedBytes
+  | EnumVariants
+  | Timestamp
+
 type PropertyTypeEnumValue = {
   [typeName: string]: PropertyTypeEnum
 }
@@ -94,6 +107,12 @@ export class PropertyType extends Enum {
         Int64Vec,
         TextVec,
         InternalVec,
+
+        // Other values:
+        DataObject,
+        FixedBytes,
+        Enum: EnumVariants,
+        Timestamp,
       },
       value,
       index
@@ -20,3 +20,9 @@ export type PropertyTypeName =
   | 'Int64Vec'
   | 'TextVec'
   | 'InternalVec'
+
+  // Other values:
+  | 'DataObject'
+  | 'FixedBytes'
+  | 'Enum'
+  | 'Timestamp'
@@ -9,9 +9,11 @@ import {
   i16,
   i32,
   i64,
+  Bytes,
 } from '@polkadot/types'
 import { Enum } from '@polkadot/types/codec'
 import EntityId from './EntityId'
+import { ContentId } from '../media'
 
 export class None extends Null {}
 
@@ -40,6 +42,13 @@ export class Int64Vec extends Vector.with(i64) {}
 export class TextVec extends Vector.with(PolkaText) {}
 export class InternalVec extends Vector.with(EntityId) {}
 
+// Other values:
+
+export class DataObject extends ContentId {}
+export class FixedBytes extends Bytes {}
+export class EnumVariant extends u16 {}
+export class Timestamp extends u64 {}
+
 export type PropertyValueEnum =
   | None
 
@@ -65,6 +74,12 @@ export type PropertyValueEnum =
   | TextVec
   | InternalVec
 
+  // Other values:
+  | DataObject
+  | FixedBytes
+  | EnumVariant
+  | Timestamp
+
 export type PropertyValueEnumValue = {
   [typeName: string]: PropertyValueEnum
 }
@@ -96,6 +111,12 @@ export class PropertyValue extends Enum {
         Int64Vec,
         TextVec,
         InternalVec,
+
+        // Other values:
+        DataObject,
+        FixedBytes,
+        Enum: EnumVariant,
+        Timestamp,
       },
       value,
       index
@@ -14,6 +14,7 @@ export type PropertyTsType = {
   required: Bool
   name: Text
   description: Text
+  unique: Bool
 }
 
 export class Property extends JoyStruct<PropertyTsType> {
@@ -24,6 +25,7 @@ export class Property extends JoyStruct<PropertyTsType> {
         required: Bool,
         name: Text,
         description: Text,
+        unique: Bool,
       },
       value
     )
@@ -44,6 +46,10 @@ export class Property extends JoyStruct<PropertyTsType> {
   get description(): string {
     return this.getString('description')
   }
+
+  get unique(): boolean {
+    return this.getBoolean('unique')
+  }
 }
 
 export class VecProperty extends Vector.with(Property) {}
@@ -52,6 +58,7 @@ export class VecU16 extends Vector.with(u16) {}
 
 export type ClassSchemaType = {
   properties: VecU16
+  deprecated: Bool
 }
 
 export class ClassSchema extends JoyStruct<ClassSchemaType> {
@@ -59,6 +66,7 @@ export class ClassSchema extends JoyStruct<ClassSchemaType> {
     super(
       {
         properties: VecU16,
+        deprecated: Bool,
       },
       value
     )
@@ -67,6 +75,10 @@ export class ClassSchema extends JoyStruct<ClassSchemaType> {
   get properties(): VecU16 {
     return this.getField('properties')
   }
+
+  get deprecated(): Bool {
+    return this.getField('deprecated')
+  }
 }
 
 export class VecClassSchema extends Vector.with(ClassSchema) {}
@@ -5,6 +5,7 @@ import { CredentialSet } from '../../common'
 type IEntityPermissions = {
   update: CredentialSet
   maintainer_has_all_permissions: bool
+  maintainer_can_delete: bool
 }
 
 export default class EntityPermissions extends JoyStruct<IEntityPermissions> {
@@ -13,6 +14,7 @@ export default class EntityPermissions extends JoyStruct<IEntityPermissions> {
       {
         update: CredentialSet,
         maintainer_has_all_permissions: bool,
+        maintainer_can_delete: bool,
       },
       value
     )
@@ -25,4 +27,8 @@ export default class EntityPermissions extends JoyStruct<IEntityPermissions> {
   get maintainer_has_all_permissions(): bool {
     return this.getField('maintainer_has_all_permissions')
   }
+
+  get maintainer_can_delete(): bool {
+    return this.getField('maintainer_can_delete')
+  }
 }